@@ -0,0 +1,334 @@
+// Cartridge header, located at 0x0100-0x014F of every ROM.
+//
+// 0134-0143 - Title (upper case ASCII, padded with 0x00)
+// 0143      - CGB Flag (0x80 = CGB enhanced, 0xC0 = CGB only)
+// 0146      - SGB Flag (0x03 = SGB functions supported)
+// 0147      - Cartridge Type (memory bank controller and extra hardware)
+// 0148      - ROM Size (32 KiB << n)
+// 0149      - RAM Size
+// 014D      - Header Checksum (verified by the boot ROM)
+// 014E-014F - Global Checksum (big endian, never verified)
+
+use std::fmt;
+
+/// Memory bank controller of a cartridge.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Mbc {
+    /// No controller, 32 KiB of ROM.
+    None,
+    /// MBC1, up to 2 MiB ROM and 32 KiB RAM.
+    Mbc1,
+    /// MBC2, up to 256 KiB ROM and 512x4 bits of built-in RAM.
+    Mbc2,
+    /// MBC3, up to 2 MiB ROM, 32 KiB RAM and an optional real time clock.
+    Mbc3,
+    /// MBC5, up to 8 MiB ROM and 128 KiB RAM.
+    Mbc5,
+}
+
+/// Game Boy Color support declared by the header.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CgbSupport {
+    /// Monochrome game.
+    None,
+    /// Uses Game Boy Color features, but also runs on the original.
+    Compatible,
+    /// Only runs on the Game Boy Color.
+    Only,
+}
+
+/// Hardware on the cartridge, decoded from the cartridge type byte.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CartridgeType {
+    /// Cartridge type byte at 0x0147.
+    pub code: u8,
+    /// Memory bank controller.
+    pub mbc: Mbc,
+    /// External RAM.
+    pub ram: bool,
+    /// Battery keeping the RAM (and clock) alive.
+    pub battery: bool,
+    /// MBC3 real time clock.
+    pub timer: bool,
+    /// MBC5 rumble motor.
+    pub rumble: bool,
+}
+
+impl CartridgeType {
+    fn from_code(code: u8) -> Option<CartridgeType> {
+        let (mbc, ram, battery, timer, rumble) = match code {
+            0x00 => (Mbc::None, false, false, false, false),
+            0x01 => (Mbc::Mbc1, false, false, false, false),
+            0x02 => (Mbc::Mbc1, true, false, false, false),
+            0x03 => (Mbc::Mbc1, true, true, false, false),
+            0x05 => (Mbc::Mbc2, false, false, false, false),
+            0x06 => (Mbc::Mbc2, false, true, false, false),
+            0x08 => (Mbc::None, true, false, false, false),
+            0x09 => (Mbc::None, true, true, false, false),
+            0x0F => (Mbc::Mbc3, false, true, true, false),
+            0x10 => (Mbc::Mbc3, true, true, true, false),
+            0x11 => (Mbc::Mbc3, false, false, false, false),
+            0x12 => (Mbc::Mbc3, true, false, false, false),
+            0x13 => (Mbc::Mbc3, true, true, false, false),
+            0x19 => (Mbc::Mbc5, false, false, false, false),
+            0x1A => (Mbc::Mbc5, true, false, false, false),
+            0x1B => (Mbc::Mbc5, true, true, false, false),
+            0x1C => (Mbc::Mbc5, false, false, false, true),
+            0x1D => (Mbc::Mbc5, true, false, false, true),
+            0x1E => (Mbc::Mbc5, true, true, false, true),
+            _ => return None,
+        };
+        Some(CartridgeType {
+            code,
+            mbc,
+            ram,
+            battery,
+            timer,
+            rumble,
+        })
+    }
+}
+
+/// Error returned when a ROM image can't be loaded.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CartridgeError {
+    /// The image is too small to hold a header.
+    TooSmall(usize),
+    /// The cartridge type byte isn't supported.
+    UnsupportedType(u8),
+    /// The ROM size byte is invalid.
+    InvalidRomSize(u8),
+    /// The RAM size byte is invalid.
+    InvalidRamSize(u8),
+    /// The image is shorter than the ROM size declared by the header.
+    Truncated {
+        /// Size declared by the header.
+        expected: usize,
+        /// Size of the image.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for CartridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CartridgeError::TooSmall(len) => {
+                write!(f, "ROM is too small to hold a header ({} bytes)", len)
+            }
+            CartridgeError::UnsupportedType(code) => {
+                write!(f, "unsupported cartridge type {:#04x}", code)
+            }
+            CartridgeError::InvalidRomSize(code) => {
+                write!(f, "invalid ROM size code {:#04x}", code)
+            }
+            CartridgeError::InvalidRamSize(code) => {
+                write!(f, "invalid RAM size code {:#04x}", code)
+            }
+            CartridgeError::Truncated { expected, actual } => write!(
+                f,
+                "ROM is truncated: header declares {} bytes but only {} were given",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CartridgeError {}
+
+/// A ROM image and its parsed header.
+#[derive(Clone)]
+pub struct Cartridge {
+    /// Game title.
+    pub title: String,
+    /// Game Boy Color support.
+    pub cgb: CgbSupport,
+    /// Super Game Boy support.
+    pub sgb: bool,
+    /// Hardware on the cartridge.
+    pub kind: CartridgeType,
+    /// Number of 16 KiB ROM banks.
+    pub rom_banks: usize,
+    /// Size of the external RAM in bytes.
+    pub ram_size: usize,
+    /// Header checksum at 0x014D.
+    pub header_checksum: u8,
+    /// Whether the header checksum matches. The boot ROM locks up when it
+    /// doesn't, but homebrew that skipped rgbfix often gets it wrong.
+    pub header_checksum_valid: bool,
+    /// Checksum of the whole ROM at 0x014E-0x014F.
+    pub global_checksum: u16,
+    /// Whether the global checksum matches. Games run fine without it.
+    pub global_checksum_valid: bool,
+    /// The ROM image.
+    pub rom: Vec<u8>,
+}
+
+impl Cartridge {
+    /// Parses and validates the header of a ROM image.
+    pub fn new(rom: Vec<u8>) -> Result<Cartridge, CartridgeError> {
+        if rom.len() < 0x150 {
+            return Err(CartridgeError::TooSmall(rom.len()));
+        }
+
+        let cgb = match rom[0x143] {
+            0xC0 => CgbSupport::Only,
+            0x80 => CgbSupport::Compatible,
+            _ => CgbSupport::None,
+        };
+        // Newer cartridges reuse the last title bytes for the CGB flag.
+        let title_end = if cgb == CgbSupport::None {
+            0x144
+        } else {
+            0x143
+        };
+        let title = rom[0x134..title_end]
+            .iter()
+            .take_while(|&&b| b != 0)
+            .map(|&b| if b.is_ascii_graphic() { b as char } else { ' ' })
+            .collect::<String>()
+            .trim()
+            .to_string();
+
+        let kind = CartridgeType::from_code(rom[0x147])
+            .ok_or(CartridgeError::UnsupportedType(rom[0x147]))?;
+
+        let rom_banks = match rom[0x148] {
+            n @ 0x00..=0x08 => 2 << n,
+            n => return Err(CartridgeError::InvalidRomSize(n)),
+        };
+        if rom.len() < rom_banks * 0x4000 {
+            return Err(CartridgeError::Truncated {
+                expected: rom_banks * 0x4000,
+                actual: rom.len(),
+            });
+        }
+
+        let ram_size = match rom[0x149] {
+            // MBC2 has its RAM built in, so the header always declares none.
+            _ if kind.mbc == Mbc::Mbc2 => 512,
+            0x00 => 0,
+            0x01 => 0x800,
+            0x02 => 0x2000,
+            0x03 => 0x8000,
+            0x04 => 0x20000,
+            0x05 => 0x10000,
+            n => return Err(CartridgeError::InvalidRamSize(n)),
+        };
+
+        let header_checksum = rom[0x14D];
+        let global_checksum = ((rom[0x14E] as u16) << 8) | rom[0x14F] as u16;
+        let global_sum = rom
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != 0x14E && i != 0x14F)
+            .fold(0u16, |sum, (_, &b)| sum.wrapping_add(b as u16));
+
+        Ok(Cartridge {
+            title,
+            cgb,
+            sgb: rom[0x146] == 0x03,
+            kind,
+            rom_banks,
+            ram_size,
+            header_checksum,
+            header_checksum_valid: header_checksum_of(&rom) == header_checksum,
+            global_checksum,
+            global_checksum_valid: global_sum == global_checksum,
+            rom,
+        })
+    }
+}
+
+fn header_checksum_of(rom: &[u8]) -> u8 {
+    rom[0x134..=0x14C]
+        .iter()
+        .fold(0u8, |x, &b| x.wrapping_sub(b).wrapping_sub(1))
+}
+
+// Builds a blank ROM with a valid header, for tests that need a cartridge.
+#[cfg(test)]
+pub fn test_rom(kind: u8, rom_size: u8, ram_size: u8) -> Vec<u8> {
+    let mut rom = vec![0; 0x8000 << rom_size];
+    rom[0x134..0x13A].copy_from_slice(b"TESTER");
+    rom[0x147] = kind;
+    rom[0x148] = rom_size;
+    rom[0x149] = ram_size;
+    rom[0x14D] = header_checksum_of(&rom);
+    rom
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_header_fields() {
+        let mut rom = test_rom(0x1B, 0x02, 0x03);
+        rom[0x143] = 0x80;
+        rom[0x146] = 0x03;
+        rom[0x14D] = header_checksum_of(&rom);
+
+        let cartridge = Cartridge::new(rom).unwrap();
+        assert_eq!(cartridge.title, "TESTER");
+        assert_eq!(cartridge.cgb, CgbSupport::Compatible);
+        assert!(cartridge.sgb);
+        assert_eq!(cartridge.kind.mbc, Mbc::Mbc5);
+        assert!(cartridge.kind.ram && cartridge.kind.battery);
+        assert_eq!(cartridge.rom_banks, 8);
+        assert_eq!(cartridge.ram_size, 0x8000);
+    }
+
+    #[test]
+    fn parses_bundled_game() {
+        let rom = std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../rom/game.gb"))
+            .unwrap();
+        let cartridge = Cartridge::new(rom).unwrap();
+        assert_eq!(cartridge.title, "RAPHAPLAYER");
+        assert_eq!(cartridge.kind.mbc, Mbc::Mbc5);
+        assert_eq!(cartridge.rom_banks, 8);
+        assert!(cartridge.global_checksum_valid);
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        assert_eq!(
+            Cartridge::new(vec![0; 0x100]).err(),
+            Some(CartridgeError::TooSmall(0x100))
+        );
+
+        let mut rom = test_rom(0x00, 0x01, 0x00);
+        rom.truncate(0x8000);
+        assert_eq!(
+            Cartridge::new(rom).err(),
+            Some(CartridgeError::Truncated {
+                expected: 0x10000,
+                actual: 0x8000
+            })
+        );
+
+        assert_eq!(
+            Cartridge::new(test_rom(0xFC, 0x00, 0x00)).err(),
+            Some(CartridgeError::UnsupportedType(0xFC))
+        );
+    }
+
+    #[test]
+    fn accepts_a_bad_header_checksum() {
+        let mut rom = test_rom(0x00, 0x00, 0x00);
+        assert!(Cartridge::new(rom.clone()).unwrap().header_checksum_valid);
+        rom[0x14D] ^= 0xFF;
+        let stored = rom[0x14D];
+        let cartridge = Cartridge::new(rom).unwrap();
+        assert!(!cartridge.header_checksum_valid);
+        assert_eq!(cartridge.header_checksum, stored);
+    }
+
+    #[test]
+    fn verifies_global_checksum() {
+        let mut rom = test_rom(0x00, 0x00, 0x00);
+        let sum = rom.iter().fold(0u16, |sum, &b| sum.wrapping_add(b as u16));
+        rom[0x14E] = (sum >> 8) as u8;
+        rom[0x14F] = sum as u8;
+        assert!(Cartridge::new(rom).unwrap().global_checksum_valid);
+    }
+}
@@ -1,5 +1,5 @@
-use crate::gameboy::cpu::registers::CpuFlag::{C, H, N, Z};
-use crate::gameboy::cpu::Cpu;
+use crate::cpu::registers::CpuFlag::{C, H, N, Z};
+use crate::cpu::Cpu;
 
 fn alu_sub(cpu: &mut Cpu, b: u8, usec: bool) {
     let c = if usec && cpu.registers.getflag(C) {
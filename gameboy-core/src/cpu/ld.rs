@@ -1,5 +1,4 @@
-use crate::gameboy::cpu::registers::CpuFlag::{C, H, N, Z};
-use crate::gameboy::cpu::Cpu;
+use crate::cpu::Cpu;
 
 pub fn r_hlm_b(cpu: &mut Cpu) {
     let addr = ((cpu.registers.h as u16) << 8) | cpu.registers.l as u16;
@@ -297,29 +296,6 @@ pub fn rr_d(cpu: &mut Cpu) {
 pub fn rr_e(cpu: &mut Cpu) {
     cpu.registers.e = cpu.get_byte();
 }
-pub fn rr_h(cpu: &mut Cpu) {
-    let mut a = cpu.registers.a;
-    let mut adjust = if cpu.registers.getflag(C) { 0x60 } else { 0x00 };
-    if cpu.registers.getflag(H) {
-        adjust |= 0x06;
-    };
-    if !cpu.registers.getflag(N) {
-        if a & 0x0F > 0x09 {
-            adjust |= 0x06;
-        };
-        if a > 0x99 {
-            adjust |= 0x60;
-        };
-        a = a.wrapping_add(adjust);
-    } else {
-        a = a.wrapping_sub(adjust);
-    }
-
-    cpu.registers.flag(C, adjust >= 0x60);
-    cpu.registers.flag(H, false);
-    cpu.registers.flag(Z, a == 0);
-    cpu.registers.a = a;
-}
 pub fn rr_l(cpu: &mut Cpu) {
     cpu.registers.l = cpu.get_byte();
 }
@@ -1,5 +1,5 @@
-use crate::gameboy::cpu::registers::CpuFlag::{C, H, N, Z};
-use crate::gameboy::cpu::Cpu;
+use crate::cpu::registers::CpuFlag::{C, H, N, Z};
+use crate::cpu::Cpu;
 
 pub fn bit(cpu: &mut Cpu, a: u8, b: u8) -> u32 {
     let r = a & (1 << (b as u32)) == 0;
@@ -37,6 +37,30 @@ pub fn rrca(cpu: &mut Cpu) {
     cpu.registers.a = alu_rrc(cpu, cpu.registers.a);
     cpu.registers.flag(Z, false);
 }
+// Decimal adjust A after a BCD addition (N=0) or subtraction (N=1). Unlike
+// the Z80, a subtraction is only corrected based on the H and C flags.
+pub fn daa(cpu: &mut Cpu) {
+    let mut a = cpu.registers.a;
+    let mut adjust = if cpu.registers.getflag(C) { 0x60 } else { 0 };
+    if cpu.registers.getflag(H) {
+        adjust |= 0x06;
+    }
+    if !cpu.registers.getflag(N) {
+        if a & 0x0F > 0x09 {
+            adjust |= 0x06;
+        }
+        if a > 0x99 {
+            adjust |= 0x60;
+        }
+        a = a.wrapping_add(adjust);
+    } else {
+        a = a.wrapping_sub(adjust);
+    }
+    cpu.registers.flag(C, adjust >= 0x60);
+    cpu.registers.flag(H, false);
+    cpu.registers.flag(Z, a == 0);
+    cpu.registers.a = a;
+}
 
 fn alu_srflagupdate(cpu: &mut Cpu, r: u8, c: bool) {
     cpu.registers.flag(H, false);
@@ -102,6 +126,33 @@ fn alu_srl(cpu: &mut Cpu, a: u8) -> u8 {
     r
 }
 
+// HALT sleeps until an interrupt is pending, even with IME=0 (in which case
+// it is not serviced). If one is pending already with IME=0, the CPU doesn't
+// halt and hits the HALT bug instead.
+pub fn halt(cpu: &mut Cpu) -> u32 {
+    if !cpu.ime && cpu.memory.inte & cpu.memory.intf & 0x1F != 0 {
+        cpu.halt_bug = true;
+    } else {
+        cpu.halt = 1;
+    }
+    1
+}
+// STOP is followed by a padding byte that is skipped. It resets DIV and halts
+// the system clock until a selected joypad line goes low. On CGB, when the
+// speed switch was armed through KEY1, it switches speed instead of stopping.
+// With a key already held it behaves as a 1-byte HALT and DIV keeps running.
+pub fn stop(cpu: &mut Cpu) -> u32 {
+    if cpu.memory.input.line_low() {
+        cpu.halt = 1;
+        return 1;
+    }
+    cpu.registers.pc = cpu.registers.pc.wrapping_add(1);
+    cpu.memory.write_byte(0xFF04, 0);
+    if !cpu.memory.switch_speed() {
+        cpu.stop = 1;
+    }
+    1
+}
 pub fn cbmap(cpu: &mut Cpu) -> u32 {
     let op = cpu.get_byte();
     match op {
@@ -1019,3 +1070,94 @@ pub fn cbmap(cpu: &mut Cpu) -> u32 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::daa;
+    use crate::cartridge::{test_rom, Cartridge};
+    use crate::cpu::registers::CpuFlag::{C, H, N, Z};
+    use crate::cpu::Cpu;
+    use std::ops::RangeInclusive;
+
+    // Correction applied after an addition, indexed by the incoming C flag, the
+    // high nibble, the incoming H flag (None = either) and the low nibble.
+    #[allow(clippy::type_complexity)]
+    const ADD_TABLE: [(
+        bool,
+        RangeInclusive<u8>,
+        Option<bool>,
+        RangeInclusive<u8>,
+        u8,
+        bool,
+    ); 9] = [
+        (false, 0x0..=0x9, Some(false), 0x0..=0x9, 0x00, false),
+        (false, 0x0..=0x9, Some(true), 0x0..=0x9, 0x06, false),
+        (false, 0x0..=0x8, None, 0xA..=0xF, 0x06, false),
+        (false, 0xA..=0xF, Some(false), 0x0..=0x9, 0x60, true),
+        (false, 0x9..=0xF, None, 0xA..=0xF, 0x66, true),
+        (false, 0xA..=0xF, Some(true), 0x0..=0x9, 0x66, true),
+        (true, 0x0..=0xF, Some(false), 0x0..=0x9, 0x60, true),
+        (true, 0x0..=0xF, Some(true), 0x0..=0x9, 0x66, true),
+        (true, 0x0..=0xF, None, 0xA..=0xF, 0x66, true),
+    ];
+
+    // After a subtraction only the flags select the correction.
+    const SUB_TABLE: [(bool, bool, u8, bool); 4] = [
+        (false, false, 0x00, false),
+        (false, true, 0x06, false),
+        (true, false, 0x60, true),
+        (true, true, 0x66, true),
+    ];
+
+    fn reference(a: u8, n: bool, h: bool, c: bool) -> (u8, bool) {
+        if n {
+            let &(_, _, correction, carry) = SUB_TABLE
+                .iter()
+                .find(|row| row.0 == c && row.1 == h)
+                .unwrap();
+            return (a.wrapping_sub(correction), carry);
+        }
+
+        let rows: Vec<_> = ADD_TABLE
+            .iter()
+            .filter(|row| {
+                row.0 == c
+                    && row.1.contains(&(a >> 4))
+                    && row.2.map_or(true, |flag| flag == h)
+                    && row.3.contains(&(a & 0x0F))
+            })
+            .collect();
+        assert_eq!(
+            rows.len(),
+            1,
+            "table must cover a={:#04x} h={} c={}",
+            a,
+            h,
+            c
+        );
+        (a.wrapping_add(rows[0].4), rows[0].5)
+    }
+
+    #[test]
+    fn daa_matches_reference_table() {
+        let mut cpu = Cpu::new(Cartridge::new(test_rom(0x00, 0x00, 0x00)).unwrap());
+
+        for a in 0..=0xFF {
+            for flags in 0..16 {
+                let f = flags << 4;
+                cpu.registers.a = a;
+                cpu.registers.f = f;
+                daa(&mut cpu);
+
+                let n = f & N as u8 != 0;
+                let (expected, carry) =
+                    reference(a, n, f & H as u8 != 0, f & C as u8 != 0);
+                assert_eq!(cpu.registers.a, expected, "a={:#04x} f={:#04x}", a, f);
+                assert_eq!(cpu.registers.getflag(Z), expected == 0);
+                assert_eq!(cpu.registers.getflag(N), n);
+                assert!(!cpu.registers.getflag(H));
+                assert_eq!(cpu.registers.getflag(C), carry, "a={:#04x} f={:#04x}", a, f);
+            }
+        }
+    }
+}
@@ -4,37 +4,58 @@ mod misc;
 mod registers;
 mod stack;
 
-use crate::gameboy::cpu::registers::Registers;
-use crate::gameboy::mmu::MemoryManagementUnit;
+use crate::cartridge::Cartridge;
+use crate::cpu::registers::Registers;
+use crate::mmu::MemoryManagementUnit;
 
 pub struct Cpu {
     pub registers: Registers,
     pub ime: bool,
-    pub setdi: u32,
     pub setei: u32,
     pub halt: u32,
+    // Set by HALT with IME=0 and an interrupt already pending: the CPU doesn't
+    // halt and fails to increment PC, so the next byte is read twice.
+    pub halt_bug: bool,
     pub stop: u32,
+    // Set to the opcode (and its address) after running an illegal one. The
+    // CPU stops fetching instructions until reset, while the rest of the
+    // hardware keeps running.
+    pub locked: Option<(u8, u16)>,
     pub memory: MemoryManagementUnit,
 }
 
 impl Cpu {
-    pub fn new(data: Vec<u8>) -> Self {
-        let memory = MemoryManagementUnit::new(data);
-        let registers = Registers::new();
+    pub fn new(cartridge: Cartridge) -> Self {
+        let memory = MemoryManagementUnit::new(cartridge);
+        let registers = match memory.cgb {
+            true => Registers::new_cgb(),
+            false => Registers::new(),
+        };
 
         Cpu {
             registers,
             memory,
             ime: false,
-            setdi: 0,
             setei: 0,
             halt: 0,
+            halt_bug: false,
             stop: 0,
+            locked: None,
         }
     }
+    // Starts executing the boot ROM instead of the cartridge entry point.
+    pub fn boot(&mut self, boot_rom: Vec<u8>) {
+        self.memory.boot_rom = Some(boot_rom);
+        self.memory.write_byte(0xFF40, 0);
+        self.registers = Registers::zeroed();
+    }
     pub fn get_byte(&mut self) -> u8 {
         let byte = self.memory.read_byte(self.registers.pc);
-        self.registers.pc = self.registers.pc.wrapping_add(1);
+        if self.halt_bug {
+            self.halt_bug = false;
+        } else {
+            self.registers.pc = self.registers.pc.wrapping_add(1);
+        }
         byte
     }
     pub fn get_word(&mut self) -> u16 {
@@ -43,14 +64,6 @@ impl Cpu {
         word
     }
     fn updateime(&mut self) {
-        self.setdi = match self.setdi {
-            2 => 1,
-            1 => {
-                self.ime = false;
-                0
-            }
-            _ => 0,
-        };
         self.setei = match self.setei {
             2 => 1,
             1 => {
@@ -66,7 +79,7 @@ impl Cpu {
             return 0;
         }
 
-        let triggered = self.memory.inte & self.memory.intf;
+        let triggered = self.memory.inte & self.memory.intf & 0x1F;
         if triggered == 0 {
             return 0;
         }
@@ -78,11 +91,13 @@ impl Cpu {
         self.ime = false;
 
         let n = triggered.trailing_zeros();
-        if n >= 5 {
-            panic!("Invalid interrupt triggered");
-        }
         self.memory.intf &= !(1 << n);
-        let pc = self.registers.pc;
+        let mut pc = self.registers.pc;
+        // EI right before a bugged HALT: the interrupt returns to the HALT.
+        if self.halt_bug {
+            self.halt_bug = false;
+            pc = pc.wrapping_sub(1);
+        }
         stack::pushstack(self, pc);
         self.registers.pc = 0x0040 | ((n as u16) << 3);
 
@@ -90,6 +105,15 @@ impl Cpu {
     }
 
     pub fn exec(&mut self) -> u32 {
+        if self.locked.is_some() {
+            return 1;
+        }
+        if self.stop == 1 {
+            if !self.memory.input.line_low() {
+                return 1;
+            }
+            self.stop = 0;
+        }
         self.updateime();
         match self.handleinterrupt() {
             0 => {}
@@ -97,8 +121,9 @@ impl Cpu {
         };
 
         if self.halt == 1 {
-            // Emulate an noop instruction
-            1
+            // Nothing can wake the CPU before the next hardware event, so skip
+            // straight to it instead of idling one cycle at a time.
+            (self.memory.next_event() + 3) / 4
         } else {
             self.operation()
         }
@@ -106,7 +131,11 @@ impl Cpu {
 
     pub fn do_cycle(&mut self) -> u32 {
         let ticks = self.exec() * 4;
-        self.memory.do_cycle(ticks)
+        if self.stop == 1 {
+            self.memory.do_stopped_cycle(ticks)
+        } else {
+            self.memory.do_cycle(ticks)
+        }
     }
 
     fn operation(&mut self) -> u32 {
@@ -173,10 +202,7 @@ impl Cpu {
                 misc::rrca(self);
                 1
             }
-            0x10 => {
-                // stack::djnzn(self);
-                1
-            }
+            0x10 => misc::stop(self),
             0x11 => {
                 ld::denn(self);
                 3
@@ -263,7 +289,7 @@ impl Cpu {
                 2
             }
             0x27 => {
-                ld::rr_h(self);
+                misc::daa(self);
                 1
             }
             0x28 => stack::jrzn(self),
@@ -557,10 +583,7 @@ impl Cpu {
                 ld::hlmr_l(self);
                 2
             }
-            0x76 => {
-                self.halt = 1;
-                1
-            }
+            0x76 => misc::halt(self),
             0x77 => {
                 ld::hlmr_a(self);
                 2
@@ -988,7 +1011,8 @@ impl Cpu {
                 2
             }
             0xF3 => {
-                self.setdi = 2;
+                self.ime = false;
+                self.setei = 0;
                 1
             }
             0xF5 => {
@@ -1029,9 +1053,10 @@ impl Cpu {
                 stack::rst(self, 0x38);
                 4
             }
+            // 0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB, 0xEC, 0xED, 0xF4, 0xFC, 0xFD
             _ => {
-                self.stop = 1;
-                panic!("{:#06x} not implemented", op);
+                self.locked = Some((op, self.registers.pc.wrapping_sub(1)));
+                1
             }
         }
     }
@@ -36,6 +36,39 @@ impl Registers {
         }
     }
 
+    // State after the CGB boot ROM. Games check for A=0x11 to detect a CGB.
+    pub fn new_cgb() -> Self {
+        use CpuFlag::*;
+        Self {
+            a: 0x11,
+            f: Z as u8,
+            b: 0x00,
+            c: 0x00,
+            d: 0xFF,
+            e: 0x56,
+            h: 0x00,
+            l: 0x0D,
+            pc: 0x0100,
+            sp: 0xFFFE,
+        }
+    }
+
+    // State at power on, before the boot ROM runs.
+    pub fn zeroed() -> Self {
+        Self {
+            a: 0,
+            f: 0,
+            b: 0,
+            c: 0,
+            d: 0,
+            e: 0,
+            h: 0,
+            l: 0,
+            pc: 0,
+            sp: 0,
+        }
+    }
+
     pub fn flag(&mut self, flags: CpuFlag, set: bool) {
         let mask = flags as u8;
         match set {
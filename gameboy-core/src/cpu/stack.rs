@@ -1,5 +1,5 @@
-use crate::gameboy::cpu::registers::CpuFlag::{C, Z};
-use crate::gameboy::cpu::Cpu;
+use crate::cpu::registers::CpuFlag::{C, Z};
+use crate::cpu::Cpu;
 
 pub fn pushstack(cpu: &mut Cpu, value: u16) {
     cpu.registers.sp = cpu.registers.sp.wrapping_sub(2);
@@ -0,0 +1,732 @@
+// Originally ported from https://github.com/mvdnes/rboy
+// Licensed under MIT License https://github.com/mvdnes/rboy/blob/master/LICENSE
+//
+// Also for inspiration:
+// https://github.com/alexcrichton/jba/blob/rust/src/gpu.rs
+
+/// RGB colors of the four DMG shades, from lightest to darkest.
+pub type Palette = [[u8; 3]; 4];
+
+/// Default palette, plain shades of grey.
+pub const GREY_PALETTE: Palette = [[255; 3], [192; 3], [96; 3], [0; 3]];
+
+pub struct Gpu {
+    cgb: bool,
+    mode: u8,
+    clock: u32,
+    off_clock: u32,
+    line: u8,
+    lyc: u8,
+    lcd_on: bool,
+    win_tilemap: u16,
+    win_on: bool,
+    tilebase: u16,
+    bg_tilemap: u16,
+    sprite_size: u32,
+    sprite_on: bool,
+    bg_on: bool,
+    // STAT interrupt enables (bits 3-6 of 0xFF41) and the state of the line
+    // they are ORed into.
+    stat: u8,
+    stat_line: bool,
+    scy: u8,
+    scx: u8,
+    winy: u8,
+    winx: u8,
+    wy_trigger: bool,
+    wy_pos: i32,
+    palbr: u8,
+    pal0r: u8,
+    pal1r: u8,
+    palb: [u8; 4],
+    pal0: [u8; 4],
+    pal1: [u8; 4],
+    // Background colour numbers of the line being drawn, before the palette
+    // is applied. Sprites flagged to be behind the background only show over
+    // colour 0.
+    bgcolors: [u8; 160],
+    // CGB BG map attribute bit 7: the background is drawn over sprites.
+    bgprio: [bool; 160],
+    palette: Palette,
+    // CGB palette RAM (8 palettes of 4 colours in RGB555) and the index
+    // registers BCPS/OCPS, where bit 7 enables auto-increment on writes.
+    cbgpal: [u8; 64],
+    cbgpal_index: u8,
+    csprpal: [u8; 64],
+    csprpal_index: u8,
+    pub vram: [u8; 16 << 10],
+    pub voam: [u8; 0xA0],
+    vrambank: usize,
+    pub data: Box<[u8; 92160]>,
+    pub updated: bool,
+    // Set when entering HBlank on a visible line, for HDMA.
+    pub hblank: bool,
+    pub interrupt: u8,
+}
+
+impl Gpu {
+    pub fn new(cgb: bool) -> Gpu {
+        Gpu {
+            cgb,
+            mode: 0,
+            clock: 0,
+            off_clock: 0,
+            line: 0,
+            lyc: 0,
+            lcd_on: false,
+            win_tilemap: 0x9C00,
+            win_on: false,
+            tilebase: 0x8000,
+            bg_tilemap: 0x9C00,
+            sprite_size: 8,
+            sprite_on: false,
+            bg_on: false,
+            stat: 0,
+            stat_line: false,
+            scy: 0,
+            scx: 0,
+            winy: 0,
+            winx: 0,
+            wy_trigger: false,
+            wy_pos: -1,
+            palbr: 0,
+            pal0r: 0,
+            pal1r: 0,
+            palb: [0; 4],
+            pal0: [0; 4],
+            pal1: [0; 4],
+            bgcolors: [0; 160],
+            bgprio: [false; 160],
+            palette: GREY_PALETTE,
+            cbgpal: [0xFF; 64],
+            cbgpal_index: 0,
+            csprpal: [0xFF; 64],
+            csprpal_index: 0,
+            vram: [0; 16 << 10],
+            voam: [0; 0xA0],
+            data: Box::new([0; 92160]),
+            updated: false,
+            hblank: false,
+            interrupt: 0,
+            vrambank: 0,
+        }
+    }
+
+    // A blank (or frozen) frame still completes every 70224 cycles while the
+    // LCD isn't drawing, either because it is off or the system is stopped.
+    pub fn idle(&mut self, ticks: u32) {
+        self.off_clock += ticks;
+        if self.off_clock >= 70224 {
+            self.off_clock -= 70224;
+            self.updated = true;
+        }
+    }
+
+    // Whether the LCD is in HBlank on a visible line. With the LCD off STAT
+    // reads mode 0 too, and there is no HBlank to wait for.
+    pub fn in_hblank(&self) -> bool {
+        !self.lcd_on || (self.line < 144 && self.clock > 80 + 172)
+    }
+
+    // Ticks until the next mode change (or blank frame with the LCD off).
+    pub fn next_event(&self) -> u32 {
+        if !self.lcd_on {
+            70224 - self.off_clock
+        } else if self.line < 144 && self.clock <= 80 {
+            81 - self.clock
+        } else if self.line < 144 && self.clock <= 80 + 172 {
+            80 + 172 + 1 - self.clock
+        } else {
+            456 - self.clock
+        }
+    }
+
+    pub fn do_cycle(&mut self, ticks: u32) {
+        if !self.lcd_on {
+            self.idle(ticks);
+            return;
+        }
+
+        let mut ticksleft = ticks;
+
+        while ticksleft > 0 {
+            let curticks = if ticksleft >= 80 { 80 } else { ticksleft };
+            self.clock += curticks;
+            ticksleft -= curticks;
+
+            // If clock >= 456, then we've completed an entire line. This line might
+            // have been part of a vblank or part of a scanline.
+            if self.clock >= 456 {
+                self.clock -= 456;
+                self.line = (self.line + 1) % 154;
+
+                if self.line >= 144 && self.mode != 1 {
+                    self.change_mode(1);
+                }
+            }
+
+            if self.line < 144 {
+                // RDOAM takes 80 cycles
+                if self.clock <= 80 {
+                    if self.mode != 2 {
+                        self.change_mode(2);
+                    }
+                // RDVRAM takes 172 cycles
+                } else if self.clock <= (80 + 172) {
+                    if self.mode != 3 {
+                        self.change_mode(3);
+                    }
+                // HBLANK takes rest of time before line rendered
+                } else if self.mode != 0 {
+                    self.change_mode(0);
+                }
+            }
+
+            self.update_stat();
+        }
+    }
+
+    fn change_mode(&mut self, mode: u8) {
+        self.mode = mode;
+
+        match self.mode {
+            0 => {
+                for x in 0..160 {
+                    self.set_pixel(x, 0);
+                }
+                self.draw_background();
+                self.draw_sprites();
+                self.hblank = true;
+            }
+            1 => {
+                self.wy_trigger = false;
+                self.interrupt |= 0x01;
+                self.updated = true;
+            }
+            3 => {
+                if !self.wy_trigger && self.line == self.winy {
+                    self.wy_trigger = true;
+                    self.wy_pos = -1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // The four STAT sources (LY=LYC, OAM, VBlank and HBlank) are ORed into a
+    // single line, and the interrupt is only requested on its rising edge. A
+    // source becoming active while another one holds the line high does not
+    // interrupt again ("STAT blocking").
+    fn update_stat(&mut self) {
+        let line = self.lcd_on
+            && (self.stat & 0x40 == 0x40 && self.line == self.lyc
+                || self.stat & 0x20 == 0x20 && self.mode == 2
+                || self.stat & 0x10 == 0x10 && self.mode == 1
+                || self.stat & 0x08 == 0x08 && self.mode == 0);
+        if line && !self.stat_line {
+            self.interrupt |= 0x02;
+        }
+        self.stat_line = line;
+    }
+
+    pub fn read_byte(&self, a: u16) -> u8 {
+        match a {
+            0x8000..=0x9FFF => {
+                self.vram[(self.vrambank * 0x2000) | (a as usize & 0x1FFF)]
+            }
+            0xFE00..=0xFE9F => self.voam[a as usize - 0xFE00],
+            0xFF40 => {
+                (if self.lcd_on { 0x80 } else { 0 })
+                    | (if self.win_tilemap == 0x9C00 { 0x40 } else { 0 })
+                    | (if self.win_on { 0x20 } else { 0 })
+                    | (if self.tilebase == 0x8000 { 0x10 } else { 0 })
+                    | (if self.bg_tilemap == 0x9C00 { 0x08 } else { 0 })
+                    | (if self.sprite_size == 16 { 0x04 } else { 0 })
+                    | (if self.sprite_on { 0x02 } else { 0 })
+                    | (if self.bg_on { 0x01 } else { 0 })
+            }
+            0xFF41 => {
+                0x80 | self.stat
+                    | (if self.line == self.lyc { 0x04 } else { 0 })
+                    | self.mode
+            }
+            0xFF42 => self.scy,
+            0xFF43 => self.scx,
+            0xFF44 => self.line,
+            0xFF45 => self.lyc,
+            0xFF47 => self.palbr,
+            0xFF48 => self.pal0r,
+            0xFF49 => self.pal1r,
+            0xFF4A => self.winy,
+            0xFF4B => self.winx,
+            0xFF4F if self.cgb => self.vrambank as u8 | 0xFE,
+            0xFF68 if self.cgb => self.cbgpal_index | 0x40,
+            0xFF69 if self.cgb => self.cbgpal[self.cbgpal_index as usize & 0x3F],
+            0xFF6A if self.cgb => self.csprpal_index | 0x40,
+            0xFF6B if self.cgb => self.csprpal[self.csprpal_index as usize & 0x3F],
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_byte(&mut self, a: u16, v: u8) {
+        match a {
+            0x8000..=0x9FFF => {
+                self.vram[(self.vrambank * 0x2000) | (a as usize & 0x1FFF)] = v
+            }
+            0xFE00..=0xFE9F => self.voam[a as usize - 0xFE00] = v,
+            0xFF40 => {
+                let was_on = self.lcd_on;
+                self.lcd_on = v & 0x80 == 0x80;
+                self.win_tilemap = if v & 0x40 == 0x40 { 0x9C00 } else { 0x9800 };
+                self.win_on = v & 0x20 == 0x20;
+                self.tilebase = if v & 0x10 == 0x10 { 0x8000 } else { 0x8800 };
+                self.bg_tilemap = if v & 0x08 == 0x08 { 0x9C00 } else { 0x9800 };
+                self.sprite_size = if v & 0x04 == 0x04 { 16 } else { 8 };
+                self.sprite_on = v & 0x02 == 0x02;
+                self.bg_on = v & 0x01 == 0x01;
+                // Turning the LCD off shows a blank screen right away, later
+                // frames are paced by `off_clock`.
+                if was_on && !self.lcd_on {
+                    self.off_clock = 0;
+                    for pixel in self.data.chunks_mut(4) {
+                        pixel[..3].copy_from_slice(&self.palette[0]);
+                        pixel[3] = 255;
+                    }
+                    self.updated = true;
+                }
+                if !self.lcd_on {
+                    self.clock = 0;
+                    self.line = 0;
+                    self.mode = 0;
+                    self.wy_trigger = false;
+                }
+                self.update_stat();
+            }
+            0xFF41 => {
+                self.stat = v & 0x78;
+                self.update_stat();
+            }
+            0xFF42 => self.scy = v,
+            0xFF43 => self.scx = v,
+            0xFF45 => {
+                self.lyc = v;
+                self.update_stat();
+            }
+            0xFF47 => {
+                self.palbr = v;
+                self.update_palettes();
+            }
+            0xFF48 => {
+                self.pal0r = v;
+                self.update_palettes();
+            }
+            0xFF49 => {
+                self.pal1r = v;
+                self.update_palettes();
+            }
+            0xFF4A => self.winy = v,
+            0xFF4B => self.winx = v,
+            0xFF4F if self.cgb => self.vrambank = (v & 0x01) as usize,
+            0xFF68 if self.cgb => self.cbgpal_index = v & 0xBF,
+            0xFF69 if self.cgb => {
+                write_palette(&mut self.cbgpal, &mut self.cbgpal_index, v)
+            }
+            0xFF6A if self.cgb => self.csprpal_index = v & 0xBF,
+            0xFF6B if self.cgb => {
+                write_palette(&mut self.csprpal, &mut self.csprpal_index, v)
+            }
+            _ => {}
+        }
+    }
+
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+    }
+
+    fn update_palettes(&mut self) {
+        for index in 0..4 {
+            self.palb[index] = (self.palbr >> (2 * index)) & 0x03;
+            self.pal0[index] = (self.pal0r >> (2 * index)) & 0x03;
+            self.pal1[index] = (self.pal1r >> (2 * index)) & 0x03;
+        }
+    }
+
+    fn set_pixel(&mut self, x: usize, shade: u8) {
+        self.set_color(x, self.palette[shade as usize]);
+    }
+
+    fn set_color(&mut self, x: usize, color: [u8; 3]) {
+        let offset = self.line as usize * 160 * 4 + x * 4;
+        self.data[offset..offset + 3].copy_from_slice(&color);
+    }
+
+    fn draw_background(&mut self) {
+        // With LCDC bit 0 cleared both the background and the window are blank,
+        // and sprites always show over them. On CGB it only does the latter.
+        if !self.bg_on && !self.cgb {
+            self.bgcolors = [0; 160];
+            return;
+        }
+        let wx_trigger = self.winx <= 166;
+        let winy = if self.win_on && self.wy_trigger && wx_trigger {
+            self.wy_pos += 1;
+            self.wy_pos
+        } else {
+            -1
+        };
+
+        let wintiley = (winy as u16 >> 3) & 31;
+
+        let bgy = self.scy.wrapping_add(self.line);
+        let bgtiley = (bgy as u16 >> 3) & 31;
+
+        for x in 0..160 {
+            let winx = -((self.winx as i32) - 7) + (x as i32);
+            let bgx = self.scx as u32 + x as u32;
+
+            let (tilemapbase, tiley, tilex, pixely, pixelx) = if winy >= 0 && winx >= 0 {
+                (
+                    self.win_tilemap,
+                    wintiley,
+                    (winx as u16 >> 3),
+                    winy as u16 & 0x07,
+                    winx as u8 & 0x07,
+                )
+            } else {
+                (
+                    self.bg_tilemap,
+                    bgtiley,
+                    (bgx as u16 >> 3) & 31,
+                    bgy as u16 & 0x07,
+                    bgx as u8 & 0x07,
+                )
+            };
+
+            let mapaddress = (tilemapbase + tiley * 32 + tilex) as usize & 0x1FFF;
+            let tilenr: u8 = self.vram[mapaddress];
+            // On CGB the attributes of each tile are at the same address in bank 1.
+            let attrs = if self.cgb {
+                self.vram[0x2000 | mapaddress]
+            } else {
+                0
+            };
+            let bank = if attrs & 0x08 != 0 { 0x2000 } else { 0 };
+            let pixely = if attrs & 0x40 != 0 {
+                7 - pixely
+            } else {
+                pixely
+            };
+            let pixelx = if attrs & 0x20 != 0 {
+                7 - pixelx
+            } else {
+                pixelx
+            };
+
+            let tileaddress = self.tilebase
+                + (if self.tilebase == 0x8000 {
+                    tilenr as u16
+                } else {
+                    (tilenr as i8 as i16 + 128) as u16
+                }) * 16;
+
+            let a0 = tileaddress + (pixely * 2);
+
+            let (b1, b2) = (
+                self.vram[bank | (a0 as usize & 0x1FFF)],
+                self.vram[bank | ((a0 + 1) as usize & 0x1FFF)],
+            );
+
+            let xbit = 7 - pixelx as u32;
+            let colnr = if b1 & (1 << xbit) != 0 { 1 } else { 0 }
+                | if b2 & (1 << xbit) != 0 { 2 } else { 0 };
+
+            self.bgcolors[x] = colnr as u8;
+            self.bgprio[x] = attrs & 0x80 != 0;
+            if self.cgb {
+                self.set_color(x, cgb_color(&self.cbgpal, attrs & 0x07, colnr));
+            } else {
+                self.set_pixel(x, self.palb[colnr]);
+            }
+        }
+    }
+
+    fn draw_sprites(&mut self) {
+        if !self.sprite_on {
+            return;
+        }
+
+        let line = self.line as i32;
+        let sprite_size = self.sprite_size as i32;
+
+        let mut sprites_to_draw = [(0, 0, 0); 10];
+        let mut sidx = 0;
+        for index in 0..40 {
+            let spriteaddr = 0xFE00 + (index as u16) * 4;
+            let spritey = self.read_byte(spriteaddr) as u16 as i32 - 16;
+            if line < spritey || line >= spritey + sprite_size {
+                continue;
+            }
+            let spritex = self.read_byte(spriteaddr + 1) as u16 as i32 - 8;
+            sprites_to_draw[sidx] = (spritex, spritey, index);
+            sidx += 1;
+            if sidx >= 10 {
+                break;
+            }
+        }
+
+        // The sprite with the lowest X (then the lowest OAM index) wins a pixel,
+        // so draw in that order and leave pixels that are already taken. On CGB
+        // only the OAM index counts, and they are already in that order.
+        if !self.cgb {
+            sprites_to_draw[..sidx].sort_unstable_by(|a, b| {
+                if a.0 != b.0 {
+                    return a.0.cmp(&b.0);
+                }
+                a.2.cmp(&b.2)
+            });
+        }
+
+        let mut taken = [false; 160];
+        for &(spritex, spritey, i) in &sprites_to_draw[..sidx] {
+            if !(-7..160).contains(&spritex) {
+                continue;
+            }
+
+            let spriteaddr = 0xFE00 + (i as u16) * 4;
+            let tilenum = (self.read_byte(spriteaddr + 2)
+                & (if self.sprite_size == 16 { 0xFE } else { 0xFF }))
+                as u16;
+            let flags = self.read_byte(spriteaddr + 3) as usize;
+            let palette = if flags & (1 << 4) != 0 {
+                self.pal1
+            } else {
+                self.pal0
+            };
+            let xflip: bool = flags & (1 << 5) != 0;
+            let yflip: bool = flags & (1 << 6) != 0;
+            let behind: bool = flags & (1 << 7) != 0;
+            let bank = if self.cgb && flags & (1 << 3) != 0 {
+                0x2000
+            } else {
+                0
+            };
+
+            let tiley: u16 = if yflip {
+                (sprite_size - 1 - (line - spritey)) as u16
+            } else {
+                (line - spritey) as u16
+            };
+
+            let tileaddress = 0x8000u16 + tilenum * 16 + tiley * 2;
+            let (b1, b2) = (
+                self.vram[bank | (tileaddress as usize & 0x1FFF)],
+                self.vram[bank | ((tileaddress + 1) as usize & 0x1FFF)],
+            );
+
+            for x in 0..8 {
+                if spritex + x < 0 || spritex + x >= 160 {
+                    continue;
+                }
+                let px = (spritex + x) as usize;
+
+                let xbit = 1 << (if xflip { x } else { 7 - x } as u32);
+                let colnr = (if b1 & xbit != 0 { 1 } else { 0 })
+                    | (if b2 & xbit != 0 { 2 } else { 0 });
+                if colnr == 0 || taken[px] {
+                    continue;
+                }
+                // An opaque sprite pixel hides the sprites below it even when
+                // the background is drawn over it.
+                taken[px] = true;
+                // The CGB BG attribute can also put the background on top, unless
+                // LCDC bit 0 takes priority away from it.
+                if (behind || self.bgprio[px]) && self.bg_on && self.bgcolors[px] != 0 {
+                    continue;
+                }
+                if self.cgb {
+                    let color = cgb_color(&self.csprpal, flags as u8 & 0x07, colnr);
+                    self.set_color(px, color);
+                } else {
+                    self.set_pixel(px, palette[colnr]);
+                }
+            }
+        }
+    }
+}
+
+// Writes BCPD/OCPD at the index in BCPS/OCPS, then auto-increments it.
+fn write_palette(ram: &mut [u8; 64], index: &mut u8, v: u8) {
+    ram[*index as usize & 0x3F] = v;
+    if *index & 0x80 == 0x80 {
+        *index = 0x80 | (index.wrapping_add(1) & 0x3F);
+    }
+}
+
+// Colours are little endian RGB555, scaled up to 8 bits per channel.
+fn cgb_color(ram: &[u8; 64], palette: u8, colnr: usize) -> [u8; 3] {
+    let offset = palette as usize * 8 + colnr * 2;
+    let color = ram[offset] as u16 | (ram[offset + 1] as u16) << 8;
+    let channel = |shift: u16| {
+        let v = (color >> shift) as u8 & 0x1F;
+        v << 3 | v >> 2
+    };
+    [channel(0), channel(5), channel(10)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gpu;
+
+    fn gpu_with_stat(stat: u8) -> Gpu {
+        let mut gpu = Gpu::new(false);
+        gpu.write_byte(0xFF40, 0x91);
+        gpu.write_byte(0xFF41, stat);
+        gpu.interrupt = 0;
+        gpu
+    }
+
+    // Runs until just before the end of `lines` scanlines and counts the STAT
+    // interrupts requested.
+    fn stat_interrupts(gpu: &mut Gpu, lines: u32) -> u32 {
+        let mut count = 0;
+        for _ in 0..lines * 456 / 4 - 1 {
+            gpu.do_cycle(4);
+            if gpu.interrupt & 0x02 == 0x02 {
+                count += 1;
+            }
+            gpu.interrupt = 0;
+        }
+        count
+    }
+
+    #[test]
+    fn hblank_interrupt_fires_once_per_line() {
+        let mut gpu = gpu_with_stat(0x08);
+        assert_eq!(stat_interrupts(&mut gpu, 10), 10);
+    }
+
+    #[test]
+    fn oam_interrupt_is_blocked_by_hblank() {
+        let mut gpu = gpu_with_stat(0x20);
+        assert_eq!(stat_interrupts(&mut gpu, 10), 10);
+
+        // HBlank hands the line straight over to OAM, so there's no new edge.
+        let mut gpu = gpu_with_stat(0x28);
+        assert_eq!(stat_interrupts(&mut gpu, 10), 10);
+    }
+
+    #[test]
+    fn lyc_interrupt_fires_once_per_frame() {
+        let mut gpu = gpu_with_stat(0x40);
+        gpu.write_byte(0xFF45, 2);
+        assert_eq!(stat_interrupts(&mut gpu, 2), 0);
+        gpu.do_cycle(4);
+        assert_eq!(gpu.interrupt & 0x02, 0x02);
+        assert_eq!(gpu.read_byte(0xFF41), 0xC4 | 2);
+        gpu.interrupt = 0;
+
+        // Nothing more until line 2 of the next frame.
+        assert_eq!(stat_interrupts(&mut gpu, 154), 0);
+        gpu.do_cycle(4);
+        assert_eq!(gpu.interrupt & 0x02, 0x02);
+    }
+
+    // LCD on with sprites, tile 1 solid colour 3 and identity palettes.
+    fn gpu_with_sprites() -> Gpu {
+        let mut gpu = Gpu::new(false);
+        gpu.write_byte(0xFF40, 0x93);
+        gpu.write_byte(0xFF47, 0xE4);
+        gpu.write_byte(0xFF48, 0xE4);
+        for a in 0x8010..0x8020 {
+            gpu.write_byte(a, 0xFF);
+        }
+        gpu
+    }
+
+    fn set_sprite(gpu: &mut Gpu, index: u16, x: u8, flags: u8) {
+        let address = 0xFE00 + index * 4;
+        gpu.write_byte(address, 16);
+        gpu.write_byte(address + 1, x + 8);
+        gpu.write_byte(address + 2, 1);
+        gpu.write_byte(address + 3, flags);
+    }
+
+    // Draws line 0 and returns the red channel of its pixels.
+    fn draw_line(gpu: &mut Gpu) -> Vec<u8> {
+        gpu.do_cycle(260);
+        gpu.data[..160 * 4].iter().step_by(4).copied().collect()
+    }
+
+    #[test]
+    fn sprites_pick_obp0_or_obp1() {
+        let mut gpu = gpu_with_sprites();
+        gpu.write_byte(0xFF49, 0x1B);
+        assert_eq!(gpu.read_byte(0xFF48), 0xE4);
+        assert_eq!(gpu.read_byte(0xFF49), 0x1B);
+
+        set_sprite(&mut gpu, 0, 0, 0x10);
+        set_sprite(&mut gpu, 1, 8, 0x00);
+        let line = draw_line(&mut gpu);
+        assert_eq!(line[0..8], [255; 8]);
+        assert_eq!(line[8..16], [0; 8]);
+    }
+
+    #[test]
+    fn sprites_behind_background_still_hide_other_sprites() {
+        let mut gpu = gpu_with_sprites();
+        // Background tile 0 is colour 1 everywhere.
+        for a in (0x8000..0x8010).step_by(2) {
+            gpu.write_byte(a, 0xFF);
+        }
+        set_sprite(&mut gpu, 0, 0, 0x80);
+        set_sprite(&mut gpu, 1, 4, 0x00);
+        let line = draw_line(&mut gpu);
+        assert_eq!(line[0..8], [192; 8]);
+        assert_eq!(line[8..12], [0; 4]);
+        assert_eq!(line[12], 192);
+    }
+
+    #[test]
+    fn lcdc_bit_0_blanks_the_background() {
+        let mut gpu = gpu_with_sprites();
+        for a in (0x8000..0x8010).step_by(2) {
+            gpu.write_byte(a, 0xFF);
+        }
+        gpu.write_byte(0xFF40, 0xB2);
+        assert_eq!(gpu.read_byte(0xFF40), 0xB2);
+
+        set_sprite(&mut gpu, 0, 0, 0x80);
+        let line = draw_line(&mut gpu);
+        assert_eq!(line[0..8], [0; 8]);
+        assert_eq!(line[8], 255);
+    }
+
+    #[test]
+    fn cgb_background_uses_bank_1_attributes() {
+        let mut gpu = Gpu::new(true);
+        gpu.write_byte(0xFF40, 0x91);
+
+        // Palette 1: colour 0 red, colour 1 blue.
+        gpu.write_byte(0xFF68, 0x88);
+        for v in [0x1F, 0x00, 0x00, 0x7C] {
+            gpu.write_byte(0xFF69, v);
+        }
+        assert_eq!(gpu.read_byte(0xFF68), 0xCC);
+        gpu.write_byte(0xFF68, 0x0A);
+        assert_eq!(gpu.read_byte(0xFF69), 0x00);
+        assert_eq!(gpu.read_byte(0xFF69), 0x00);
+
+        // Tile 0 in bank 1 is colour 1, and the first map entry uses it with
+        // palette 1. Everything else is tile 0 from bank 0 with palette 0.
+        gpu.write_byte(0xFF4F, 1);
+        for a in (0x8000..0x8010).step_by(2) {
+            gpu.write_byte(a, 0xFF);
+        }
+        gpu.write_byte(0x9800, 0x09);
+        gpu.write_byte(0xFF4F, 0);
+
+        gpu.do_cycle(260);
+        assert_eq!(gpu.data[0..3], [0, 0, 255]);
+        assert_eq!(gpu.data[8 * 4..8 * 4 + 3], [255, 255, 255]);
+    }
+}
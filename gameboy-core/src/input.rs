@@ -1,54 +1,60 @@
-mod cpu;
-mod gpu;
-mod mmu;
-
-use crate::gameboy::cpu::Cpu;
-
-#[derive(Copy, Clone)]
-pub enum Button {
-    A,
-    B,
-    Left,
-    Right,
-    Up,
-    Down,
-    Start,
-    Select,
-}
+use crate::Button;
 
 pub struct Input {
-    current: u8,
+    // P14/P15 as written, 0 selects the line.
+    select: u8,
     buttons: u8,
     directions: u8,
+    pub interrupt: u8,
 }
 
 impl Default for Input {
     fn default() -> Self {
         Input {
-            current: 0x10,
+            select: 0x00,
             buttons: 0xf,
             directions: 0xf,
+            interrupt: 0,
         }
     }
 }
 
 impl Input {
     pub fn read_byte(&self) -> u8 {
-        match self.current {
-            0x20 => self.buttons,
-            0x10 => self.directions,
-            _ => 0xf,
+        0xC0 | self.select | self.lines()
+    }
+
+    // P10-P13: with both lines selected a key on either one pulls its bit low.
+    fn lines(&self) -> u8 {
+        let mut lines = 0x0F;
+        if self.select & 0x10 == 0 {
+            lines &= self.directions;
+        }
+        if self.select & 0x20 == 0 {
+            lines &= self.buttons;
         }
+        lines & 0x0F
     }
 
-    pub fn write_byte(&mut self, value: u8) {
-        match !value & 0x30 {
-            0x20 => self.current = 0x20,
-            0x10 => self.current = 0x10,
-            _ => {}
+    // True while a key on the selected line is held, which wakes up STOP.
+    pub fn line_low(&self) -> bool {
+        self.lines() != 0x0F
+    }
+
+    // The joypad interrupt is requested when one of P10-P13 goes from high to
+    // low, either from a key press or from selecting a line with a key held.
+    fn update_interrupt(&mut self, before: u8) {
+        if before & !self.lines() != 0 {
+            self.interrupt |= 0x10;
         }
     }
 
+    pub fn write_byte(&mut self, value: u8) {
+        let before = self.lines();
+        self.select = value & 0x30;
+        self.update_interrupt(before);
+    }
+
     // The eight gameboy buttons/direction keys are arranged in form of a 2x4 matrix.
     // Select either button or direction keys by writing to this register, then read-out bit 0-3.
     // Bit 7 - Not used
@@ -66,6 +72,7 @@ impl Input {
     // Bit 1 - P11 Input Left or Button B (0=Pressed) 1101 = 0xd
     // Bit 0 - P10 Input Right or Button A (0=Pressed) 1110 = 0xe
     pub fn keydown(&mut self, key: Button) {
+        let before = self.lines();
         match key {
             Button::A => {
                 self.buttons &= 0xe;
@@ -92,6 +99,7 @@ impl Input {
                 self.directions &= 0xe;
             }
         }
+        self.update_interrupt(before);
     }
 
     pub fn keyup(&mut self, key: Button) {
@@ -124,79 +132,39 @@ impl Input {
     }
 }
 
-pub struct MemoryBankController {
-    rom: Vec<u8>,
-    rombank: usize,
-    rombanks: usize,
-}
-
-impl MemoryBankController {
-    pub fn new(data: Vec<u8>) -> MemoryBankController {
-        MemoryBankController {
-            rom: data,
-            rombank: 1,
-            rombanks: 8,
-        }
-    }
-    pub fn readrom(&self, a: u16) -> u8 {
-        let bank = if a < 0x4000 { 0 } else { self.rombank };
-        let idx = (bank * 0x4000) | ((a as usize) & 0x3FFF);
-        *self.rom.get(idx).unwrap_or(&0xFF)
-    }
-    pub fn writerom(&mut self, a: u16, v: u8) {
-        if let 0x2000..=0x3FFF = a {
-            let lower = match (v as usize) & 0x1F {
-                0 => 1,
-                n => n,
-            };
-            self.rombank = ((self.rombank & 0x60) | lower) % self.rombanks;
-        }
-    }
-}
-
-pub struct GameBoy {
-    width: u32,
-    height: u32,
-    cpu: Cpu,
-}
+#[cfg(test)]
+mod tests {
+    use super::{Button, Input};
 
-impl GameBoy {
-    pub fn new(rom_data: &[u8]) -> Self {
-        Self {
-            cpu: Cpu::new(rom_data.to_vec()),
-            width: 160,
-            height: 144,
-        }
-    }
-    pub fn width(&self) -> u32 {
-        self.width
+    #[test]
+    fn p1_reads_back_the_selected_lines() {
+        let mut input = Input::default();
+        input.keydown(Button::A);
+        input.keydown(Button::Down);
+        // Both lines selected, the two matrices are combined.
+        assert_eq!(input.read_byte(), 0xC6);
+        input.write_byte(0x10);
+        assert_eq!(input.read_byte(), 0xDE);
+        input.write_byte(0x20);
+        assert_eq!(input.read_byte(), 0xE7);
+        input.write_byte(0x30);
+        assert_eq!(input.read_byte(), 0xFF);
     }
-    pub fn height(&self) -> u32 {
-        self.height
-    }
-    pub fn frame(&mut self) {
-        let waitticks = 70224;
-        let mut ticks = 0;
 
-        'frame: loop {
-            while ticks < waitticks {
-                ticks += self.cpu.do_cycle();
-                if self.cpu.memory.gpu.updated {
-                    self.cpu.memory.gpu.updated = false;
-                    break 'frame;
-                }
-            }
+    #[test]
+    fn joypad_interrupt_on_high_to_low_transition() {
+        let mut input = Input::default();
+        input.write_byte(0x20);
+        input.keydown(Button::Start);
+        assert_eq!(input.interrupt, 0);
+        input.keydown(Button::Left);
+        assert_eq!(input.interrupt, 0x10);
 
-            ticks -= waitticks;
-        }
-    }
-    pub fn data(&self) -> &[u8] {
-        &*self.cpu.memory.gpu.data
-    }
-    pub fn keydown(&mut self, key: Button) {
-        self.cpu.memory.input.keydown(key);
-    }
-    pub fn keyup(&mut self, key: Button) {
-        self.cpu.memory.input.keyup(key);
+        input.interrupt = 0;
+        input.keyup(Button::Left);
+        assert_eq!(input.interrupt, 0);
+        // Selecting the buttons with Start held pulls P13 low.
+        input.write_byte(0x10);
+        assert_eq!(input.interrupt, 0x10);
     }
 }
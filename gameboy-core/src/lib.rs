@@ -0,0 +1,467 @@
+//! Game Boy emulator core.
+//!
+//! [`GameBoy`] runs a cartridge one frame (or one instruction) at a time. After
+//! each frame the frontend presents [`GameBoy::data`] (160x144 RGBA pixels) and
+//! [`GameBoy::audio_samples`], and forwards key presses with
+//! [`GameBoy::keydown`] and [`GameBoy::keyup`].
+//!
+//! ```no_run
+//! use gameboy_core::{Button, GameBoy};
+//!
+//! let rom = std::fs::read("game.gb").unwrap();
+//! let mut gb = GameBoy::new(&rom).unwrap();
+//! gb.keydown(Button::Start);
+//! gb.frame().unwrap();
+//! assert_eq!(gb.data().len(), 160 * 144 * 4);
+//! ```
+
+#![warn(missing_docs)]
+
+mod cartridge;
+mod cpu;
+mod gpu;
+mod input;
+mod link;
+mod linked;
+mod mbc;
+mod mmu;
+mod printer;
+mod serial;
+mod sound;
+mod timer;
+
+use crate::cpu::Cpu;
+use std::fmt;
+
+pub use crate::cartridge::{Cartridge, CartridgeError, CartridgeType, CgbSupport, Mbc};
+pub use crate::gpu::{Palette, GREY_PALETTE};
+pub use crate::link::{Capture, LinkCable, Loopback, TcpCable};
+pub use crate::linked::LinkedPair;
+pub use crate::printer::{PrintedImage, Printer};
+
+/// Game Boy buttons and directions of the joypad.
+#[derive(Copy, Clone)]
+pub enum Button {
+    /// A button.
+    A,
+    /// B button.
+    B,
+    /// Left on the directional pad.
+    Left,
+    /// Right on the directional pad.
+    Right,
+    /// Up on the directional pad.
+    Up,
+    /// Down on the directional pad.
+    Down,
+    /// Start button.
+    Start,
+    /// Select button.
+    Select,
+}
+
+/// Error returned by [`GameBoy::load_boot_rom`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum BootRomError {
+    /// The boot ROM isn't 256 bytes long.
+    InvalidSize(usize),
+}
+
+impl fmt::Display for BootRomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BootRomError::InvalidSize(len) => {
+                write!(f, "boot ROM must be 256 bytes, got {}", len)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BootRomError {}
+
+/// Error returned by [`GameBoy::frame`] when the emulated hardware stops.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EmulationError {
+    /// The CPU ran an illegal opcode and locked up, like real hardware does.
+    /// Only [`GameBoy::reset`] brings it back.
+    LockedUp {
+        /// The illegal opcode.
+        opcode: u8,
+        /// Address of the opcode.
+        address: u16,
+    },
+}
+
+impl fmt::Display for EmulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmulationError::LockedUp { opcode, address } => write!(
+                f,
+                "CPU locked up on illegal opcode {:#04x} at {:#06x}",
+                opcode, address
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EmulationError {}
+
+/// A Game Boy with a cartridge inserted. It is `Send`, so it can run on a
+/// worker thread.
+pub struct GameBoy {
+    width: u32,
+    height: u32,
+    // Kept to power cycle the console on reset.
+    cartridge: Cartridge,
+    boot_rom: Option<Vec<u8>>,
+    palette: Palette,
+    sample_rate: u32,
+    cpu: Cpu,
+}
+
+impl GameBoy {
+    /// Parses the cartridge header of a ROM image and powers on with it.
+    pub fn new(rom_data: &[u8]) -> Result<Self, CartridgeError> {
+        Ok(Self::with_cartridge(Cartridge::new(rom_data.to_vec())?))
+    }
+    /// Powers on with an already parsed cartridge.
+    pub fn with_cartridge(cartridge: Cartridge) -> Self {
+        Self {
+            cpu: Cpu::new(cartridge.clone()),
+            cartridge,
+            boot_rom: None,
+            palette: GREY_PALETTE,
+            sample_rate: 44100,
+            width: 160,
+            height: 144,
+        }
+    }
+    /// Power cycles the console. Battery backed RAM (and the clock) survive,
+    /// and so do the boot ROM, palette, sample rate and link cable.
+    pub fn reset(&mut self) {
+        let ram = self.battery_ram();
+        let cable = self.cpu.memory.serial.cable.take();
+        self.cpu = Cpu::new(self.cartridge.clone());
+        self.cpu.memory.serial.cable = cable;
+        if let Some(ram) = ram {
+            self.cpu.memory.mbc.load_ram(&ram);
+        }
+        if let Some(boot_rom) = &self.boot_rom {
+            self.cpu.boot(boot_rom.clone());
+        }
+        self.cpu.memory.gpu.set_palette(self.palette);
+        self.cpu.memory.sound.set_sample_rate(self.sample_rate);
+    }
+    /// Runs the DMG boot ROM (scrolling logo) before the cartridge. Call it
+    /// before the first frame.
+    pub fn load_boot_rom(&mut self, boot_rom: &[u8]) -> Result<(), BootRomError> {
+        if boot_rom.len() != 0x100 {
+            return Err(BootRomError::InvalidSize(boot_rom.len()));
+        }
+        self.boot_rom = Some(boot_rom.to_vec());
+        self.cpu.boot(boot_rom.to_vec());
+        Ok(())
+    }
+    /// Game title from the cartridge header.
+    pub fn title(&self) -> &str {
+        &self.cartridge.title
+    }
+    /// Sets the colors of the four shades of grey. CGB games use their own
+    /// palettes instead.
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+        self.cpu.memory.gpu.set_palette(palette);
+    }
+    /// Width of the screen in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+    /// Height of the screen in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+    /// Runs the CPU for a single instruction (or interrupt dispatch) and
+    /// returns the number of clock cycles it took.
+    pub fn step(&mut self) -> u32 {
+        self.cpu.do_cycle()
+    }
+    /// Runs until the next frame is complete (70224 cycles, even with the LCD
+    /// off). Fails once the CPU has locked up, but the screen and sound still
+    /// advance, as on real hardware.
+    pub fn frame(&mut self) -> Result<(), EmulationError> {
+        while !self.cpu.memory.gpu.updated {
+            self.cpu.do_cycle();
+        }
+        self.cpu.memory.gpu.updated = false;
+
+        match self.cpu.locked {
+            Some((opcode, address)) => Err(EmulationError::LockedUp { opcode, address }),
+            None => Ok(()),
+        }
+    }
+    /// The last frame, as RGBA bytes row by row.
+    pub fn data(&self) -> &[u8] {
+        &*self.cpu.memory.gpu.data
+    }
+    /// Interleaved stereo samples (left, right) generated since the last call.
+    pub fn audio_samples(&mut self) -> Vec<f32> {
+        self.cpu.memory.sound.take_samples()
+    }
+    /// Contents of the battery backed cartridge RAM, in the .sav format used by
+    /// other emulators. None if the cartridge has no battery.
+    pub fn battery_ram(&self) -> Option<Vec<u8>> {
+        if !self.cartridge.kind.battery {
+            return None;
+        }
+        Some(self.cpu.memory.mbc.save_ram())
+    }
+    /// Restores the battery backed cartridge RAM from a .sav file.
+    pub fn load_battery_ram(&mut self, data: &[u8]) {
+        if self.cartridge.kind.battery {
+            self.cpu.memory.mbc.load_ram(data);
+        }
+    }
+    /// Makes the cartridge clock (if any) follow the host clock instead of the
+    /// emulated cycles. Call it regularly with the current unix time.
+    pub fn sync_rtc(&mut self, unix_time: u64) {
+        self.cpu.memory.mbc.sync_rtc(unix_time);
+    }
+    /// Whether the cartridge rumble motor is currently on.
+    pub fn rumble(&self) -> bool {
+        self.cpu.memory.mbc.rumble()
+    }
+    /// Sets the rate of the samples returned by [`GameBoy::audio_samples`].
+    /// A rate of 0 is clamped to 1 Hz.
+    pub fn set_audio_sample_rate(&mut self, sample_rate: u32) {
+        let sample_rate = sample_rate.max(1);
+        self.sample_rate = sample_rate;
+        self.cpu.memory.sound.set_sample_rate(sample_rate);
+    }
+    /// Plugs a link cable into the serial port, replacing the current one.
+    pub fn plug_link_cable(&mut self, cable: impl LinkCable + 'static) {
+        self.cpu.memory.serial.cable = Some(Box::new(cable));
+    }
+    /// Unplugs the link cable, if any.
+    pub fn unplug_link_cable(&mut self) -> Option<Box<dyn LinkCable>> {
+        self.cpu.memory.serial.cable.take()
+    }
+    /// Presses a button.
+    pub fn keydown(&mut self, key: Button) {
+        self.cpu.memory.input.keydown(key);
+    }
+    /// Releases a button.
+    pub fn keyup(&mut self, key: Button) {
+        self.cpu.memory.input.keyup(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BootRomError, Button, EmulationError, GameBoy};
+    use crate::cartridge::test_rom;
+
+    // ROM only cartridge running `code` from the entry point at 0x0100.
+    fn game(code: &[u8]) -> GameBoy {
+        let mut rom = test_rom(0x00, 0x00, 0x00);
+        rom[0x0100..0x0100 + code.len()].copy_from_slice(code);
+        GameBoy::new(&rom).unwrap()
+    }
+
+    #[test]
+    fn game_boy_is_send() {
+        fn assert_send<T: Send>(_: T) {}
+        let mut gb = game(&[]);
+        gb.plug_link_cable(crate::Capture::new());
+        assert_send(gb);
+    }
+
+    #[test]
+    fn illegal_opcode_locks_up_until_reset() {
+        // NOP; 0xD3
+        let mut gb = game(&[0x00, 0xD3]);
+        let locked = Err(EmulationError::LockedUp {
+            opcode: 0xD3,
+            address: 0x0101,
+        });
+        assert_eq!(gb.frame(), locked);
+
+        // Not even interrupts wake it up.
+        gb.cpu.ime = true;
+        gb.cpu.memory.write_byte(0xFFFF, 0x01);
+        assert_eq!(gb.frame(), locked);
+        assert_eq!(gb.cpu.registers.pc, 0x0102);
+
+        gb.reset();
+        assert_eq!(gb.cpu.locked, None);
+        assert_eq!(gb.cpu.registers.pc, 0x0100);
+    }
+
+    // Cycles until the next frame, counted from `step`.
+    fn frame_cycles(gb: &mut GameBoy) -> u32 {
+        let mut cycles = 0;
+        while !gb.cpu.memory.gpu.updated {
+            cycles += gb.step();
+        }
+        gb.cpu.memory.gpu.updated = false;
+        cycles
+    }
+
+    #[test]
+    fn frames_complete_with_the_lcd_off() {
+        // LD A,0x00; LDH (0x40),A; JR -2 (once) or JR -4 (over and over)
+        for jump in [0xFE, 0xFC] {
+            let mut gb = game(&[0x3E, 0x00, 0xE0, 0x40, 0x18, jump]);
+            // Turning the LCD off ends the frame right away.
+            assert!(frame_cycles(&mut gb) < 100);
+            for _ in 0..3 {
+                let cycles = frame_cycles(&mut gb);
+                assert!(cycles.abs_diff(70224) <= 12, "{} cycles", cycles);
+            }
+            assert_eq!(gb.frame(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn halt_bug_reads_the_next_byte_twice() {
+        // LD A,0x04; LDH (0xFF),A; LDH (0x0F),A; HALT; INC B; NOP
+        let mut gb = game(&[0x3E, 0x04, 0xE0, 0xFF, 0xE0, 0x0F, 0x76, 0x04, 0x00]);
+        for _ in 0..4 {
+            gb.step();
+        }
+        assert_eq!(gb.cpu.halt, 0);
+        gb.step();
+        gb.step();
+        assert_eq!(gb.cpu.registers.b, 0x02);
+        assert_eq!(gb.cpu.registers.pc, 0x0108);
+    }
+
+    #[test]
+    fn halt_skips_idle_time_until_an_interrupt() {
+        // LD A,0x01; LDH (0xFF),A; HALT; NOP
+        let mut gb = game(&[0x3E, 0x01, 0xE0, 0xFF, 0x76, 0x00]);
+        for _ in 0..3 {
+            gb.step();
+        }
+        let mut steps = 0;
+        while gb.cpu.halt == 1 {
+            gb.step();
+            steps += 1;
+        }
+        // One step per mode change rather than per cycle until VBlank.
+        assert!(steps < 3 * 144 + 2, "{} steps", steps);
+        assert_eq!(gb.cpu.memory.read_byte(0xFF44), 144);
+        // Woken up without servicing the interrupt, then ran the NOP.
+        assert_eq!(gb.cpu.registers.pc, 0x0106);
+    }
+
+    #[test]
+    fn oam_dma_starts_after_the_instruction_writing_ff46() {
+        // LD A,0xC0; LDH (0x46),A; NOP...
+        let mut gb = game(&[0x3E, 0xC0, 0xE0, 0x46]);
+        gb.cpu.memory.write_byte(0xC000, 0x42);
+        gb.step();
+        gb.step();
+        // Setting up takes the next M-cycle, then OAM is busy for 160 more.
+        assert_eq!(gb.cpu.memory.read_byte(0xFE00), 0x00);
+        gb.step();
+        assert_eq!(gb.cpu.memory.read_byte(0xFE00), 0xFF);
+        for _ in 0..159 {
+            gb.step();
+        }
+        assert_eq!(gb.cpu.memory.read_byte(0xFE00), 0xFF);
+        gb.step();
+        assert_eq!(gb.cpu.memory.read_byte(0xFE00), 0x42);
+    }
+
+    #[test]
+    fn stop_sleeps_until_a_key_is_pressed() {
+        // STOP; INC A; JR -3
+        let mut gb = game(&[0x10, 0x00, 0x3C, 0x18, 0xFD]);
+        gb.cpu.memory.timer.do_cycle(0x1000);
+        assert_ne!(gb.cpu.memory.read_byte(0xFF04), 0);
+
+        gb.step();
+        assert_eq!(gb.cpu.registers.pc, 0x0102);
+        assert_eq!(gb.cpu.stop, 1);
+        assert_eq!(gb.cpu.memory.read_byte(0xFF04), 0);
+
+        // Frames keep coming while DIV and the CPU are frozen.
+        assert_eq!(gb.frame(), Ok(()));
+        assert_eq!(gb.cpu.memory.read_byte(0xFF04), 0);
+        assert_eq!(gb.cpu.registers.a, 0x01);
+
+        gb.keydown(Button::Down);
+        assert_eq!(gb.frame(), Ok(()));
+        assert_eq!(gb.cpu.stop, 0);
+        assert_ne!(gb.cpu.registers.a, 0x01);
+    }
+
+    #[test]
+    fn stop_switches_speed_when_armed() {
+        let mut rom = test_rom(0x00, 0x00, 0x00);
+        rom[0x143] = 0x80;
+        rom[0x14D] = rom[0x14D].wrapping_sub(0x80);
+        // LD A,0x01; LDH (0x4D),A; STOP; NOP
+        rom[0x0100..0x0106].copy_from_slice(&[0x3E, 0x01, 0xE0, 0x4D, 0x10, 0x00]);
+        let mut gb = GameBoy::new(&rom).unwrap();
+
+        gb.step();
+        gb.step();
+        assert_eq!(gb.cpu.memory.read_byte(0xFF4D), 0x7F);
+        gb.step();
+        assert_eq!(gb.cpu.memory.read_byte(0xFF4D), 0xFE);
+        assert_eq!(gb.cpu.stop, 0);
+        assert_eq!(gb.cpu.registers.pc, 0x0106);
+        // A NOP now takes half as long on the system clock.
+        assert_eq!(gb.step(), 2);
+
+        // KEY1 only exists on CGB.
+        assert_eq!(game(&[]).cpu.memory.read_byte(0xFF4D), 0xFF);
+    }
+
+    #[test]
+    fn unused_interrupt_bits_never_dispatch() {
+        // EI; NOP; JR -2
+        let mut gb = game(&[0xFB, 0x00, 0x18, 0xFE]);
+        gb.cpu.memory.write_byte(0xFFFF, 0xE0);
+        gb.cpu.memory.write_byte(0xFF0F, 0xFF);
+        assert_eq!(gb.cpu.memory.read_byte(0xFF0F), 0xFF);
+        for _ in 0..10 {
+            gb.step();
+        }
+        assert_eq!(gb.cpu.registers.pc, 0x0102);
+    }
+
+    #[test]
+    fn battery_ram_follows_header_flag() {
+        let mut gb = GameBoy::new(&test_rom(0x02, 0x00, 0x02)).unwrap();
+        gb.load_battery_ram(&[0x12; 0x2000]);
+        assert_eq!(gb.battery_ram(), None);
+
+        let mut gb = GameBoy::new(&test_rom(0x03, 0x00, 0x02)).unwrap();
+        assert_eq!(gb.battery_ram(), Some(vec![0; 0x2000]));
+        gb.load_battery_ram(&[0x12; 0x2000]);
+        assert_eq!(gb.battery_ram(), Some(vec![0x12; 0x2000]));
+    }
+
+    #[test]
+    fn boot_rom_is_unmapped_by_ff50() {
+        let mut gb = GameBoy::new(&test_rom(0x00, 0x00, 0x00)).unwrap();
+        assert_eq!(
+            gb.load_boot_rom(&[0; 0x80]),
+            Err(BootRomError::InvalidSize(0x80))
+        );
+
+        // LD A,0x01; LDH (0x50),A
+        let mut boot_rom = vec![0; 0x100];
+        boot_rom[..4].copy_from_slice(&[0x3E, 0x01, 0xE0, 0x50]);
+        gb.load_boot_rom(&boot_rom).unwrap();
+        assert_eq!(gb.cpu.registers.pc, 0x0000);
+        assert_eq!(gb.cpu.memory.read_byte(0x0000), 0x3E);
+
+        gb.cpu.do_cycle();
+        gb.cpu.do_cycle();
+        assert_eq!(gb.cpu.registers.pc, 0x0004);
+        assert_eq!(gb.cpu.memory.read_byte(0x0000), 0x00);
+    }
+}
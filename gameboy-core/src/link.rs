@@ -0,0 +1,237 @@
+// Link cable backends for the serial port.
+
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The other end of the link cable, as seen from the serial port.
+///
+/// A transfer exchanges one byte in each direction. The Game Boy driving the
+/// clock calls [`LinkCable::send`], while one waiting on an external clock
+/// keeps polling [`LinkCable::receive`]. Cables are `Send` so that the
+/// `GameBoy` they're plugged into can move to another thread.
+pub trait LinkCable: Send {
+    /// Shifts `byte` out with the internal clock and returns the byte shifted
+    /// in from the other side, or `None` when nothing answers (the serial
+    /// port then reads 0xFF, like with no cable plugged in).
+    fn send(&mut self, byte: u8) -> Option<u8>;
+    /// Checks whether the other side started a transfer with its own clock.
+    /// If so, `reply` is shifted back and the received byte is returned.
+    fn receive(&mut self, reply: u8) -> Option<u8>;
+}
+
+/// A cable plugged back into the same Game Boy: every byte sent comes back.
+pub struct Loopback;
+
+impl LinkCable for Loopback {
+    fn send(&mut self, byte: u8) -> Option<u8> {
+        Some(byte)
+    }
+    fn receive(&mut self, _reply: u8) -> Option<u8> {
+        None
+    }
+}
+
+/// Collects the bytes the game sends as text, e.g. test ROMs printing their
+/// results. Clones share the same buffer, so keep one to read it back.
+#[derive(Clone, Default)]
+pub struct Capture {
+    text: Arc<Mutex<String>>,
+    stdout: bool,
+}
+
+impl Capture {
+    /// Captures into a buffer only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Also prints everything to stdout as it arrives.
+    pub fn stdout() -> Self {
+        Self {
+            stdout: true,
+            ..Self::default()
+        }
+    }
+    /// Everything received so far.
+    pub fn text(&self) -> String {
+        self.text.lock().unwrap().clone()
+    }
+}
+
+impl LinkCable for Capture {
+    fn send(&mut self, byte: u8) -> Option<u8> {
+        self.text.lock().unwrap().push(byte as char);
+        if self.stdout {
+            print!("{}", byte as char);
+            let _ = io::stdout().flush();
+        }
+        None
+    }
+    fn receive(&mut self, _reply: u8) -> Option<u8> {
+        None
+    }
+}
+
+// Messages are three bytes: a kind, a sequence number and the data byte. A
+// reply carries the sequence number of the transfer it answers, so one that
+// arrives after the transfer timed out isn't taken for the next one's.
+const TRANSFER: u8 = 0;
+const REPLY: u8 = 1;
+
+// How long the clocking side waits for the other emulator to answer by default.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// A cable between two emulator instances over TCP, one of them listening
+/// and the other connecting.
+///
+/// When both sides clock a transfer at the same time, each one takes the
+/// other's byte, so neither waits for a reply.
+pub struct TcpCable {
+    stream: TcpStream,
+    sequence: u8,
+    timeout: Duration,
+}
+
+impl TcpCable {
+    /// Waits for the other emulator to connect.
+    pub fn listen(address: impl ToSocketAddrs) -> io::Result<Self> {
+        let (stream, _) = TcpListener::bind(address)?.accept()?;
+        Self::with_stream(stream)
+    }
+    /// Connects to an emulator listening at `address`.
+    pub fn connect(address: impl ToSocketAddrs) -> io::Result<Self> {
+        Self::with_stream(TcpStream::connect(address)?)
+    }
+
+    fn with_stream(stream: TcpStream) -> io::Result<Self> {
+        stream.set_nodelay(true)?;
+        stream.set_nonblocking(true)?;
+        Ok(Self {
+            stream,
+            sequence: 0,
+            timeout: REPLY_TIMEOUT,
+        })
+    }
+    /// How long a transfer waits for the other emulator before reading 0xFF,
+    /// one second by default. The emulator doesn't run in the meantime.
+    pub fn set_reply_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    // Reads the next message if a whole one has arrived.
+    fn poll(&mut self) -> io::Result<Option<[u8; 3]>> {
+        let mut message = [0; 3];
+        match self.stream.peek(&mut message) {
+            Ok(0) => Err(ErrorKind::UnexpectedEof.into()),
+            Ok(3) => {
+                self.stream.read_exact(&mut message)?;
+                Ok(Some(message))
+            }
+            Ok(_) => Ok(None),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn exchange(&mut self, byte: u8) -> io::Result<Option<u8>> {
+        self.sequence = self.sequence.wrapping_add(1);
+        self.stream.write_all(&[TRANSFER, self.sequence, byte])?;
+        let deadline = Instant::now() + self.timeout;
+        while Instant::now() < deadline {
+            match self.poll()? {
+                Some([REPLY, sequence, value]) if sequence == self.sequence => {
+                    return Ok(Some(value))
+                }
+                // Both sides clocked a transfer at the same time.
+                Some([TRANSFER, _, value]) => return Ok(Some(value)),
+                // A late reply to a transfer that timed out.
+                Some(_) => {}
+                None => std::thread::sleep(Duration::from_micros(100)),
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl LinkCable for TcpCable {
+    fn send(&mut self, byte: u8) -> Option<u8> {
+        self.exchange(byte).ok().flatten()
+    }
+    fn receive(&mut self, reply: u8) -> Option<u8> {
+        match self.poll() {
+            Ok(Some([TRANSFER, sequence, value])) => {
+                self.stream.write_all(&[REPLY, sequence, reply]).ok()?;
+                Some(value)
+            }
+            // A late reply to a transfer that timed out.
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinkCable, TcpCable};
+    use crate::serial::Serial;
+    use std::net::{TcpListener, TcpStream};
+    use std::time::Duration;
+
+    fn connected() -> (TcpCable, TcpCable) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let a = TcpCable::connect(listener.local_addr().unwrap()).unwrap();
+        let b = TcpCable::with_stream(listener.accept().unwrap().0).unwrap();
+        (a, b)
+    }
+
+    fn answer(mut cable: TcpCable, reply: u8) -> std::thread::JoinHandle<u8> {
+        std::thread::spawn(move || loop {
+            if let Some(byte) = cable.receive(reply) {
+                return byte;
+            }
+        })
+    }
+
+    #[test]
+    fn tcp_cable_exchanges_bytes() {
+        let (mut a, b) = connected();
+        let b = answer(b, 0x22);
+        assert_eq!(a.send(0x11), Some(0x22));
+        assert_eq!(b.join().unwrap(), 0x11);
+    }
+
+    #[test]
+    fn tcp_cable_drops_late_replies() {
+        let (mut a, mut b) = connected();
+        a.set_reply_timeout(Duration::from_millis(20));
+        assert_eq!(a.send(0x11), None);
+        // The other side only answers once the transfer timed out.
+        while b.receive(0x22).is_none() {}
+
+        let b = answer(b, 0x44);
+        a.set_reply_timeout(Duration::from_secs(1));
+        assert_eq!(a.send(0x33), Some(0x44));
+        assert_eq!(b.join().unwrap(), 0x33);
+    }
+
+    #[test]
+    fn serial_reads_0xff_when_the_peer_does_not_answer() {
+        let (mut a, _b) = connected();
+        a.set_reply_timeout(Duration::from_millis(20));
+        let mut serial = Serial::new(false);
+        serial.cable = Some(Box::new(a));
+        serial.write_byte(0xFF01, 0x11);
+        serial.write_byte(0xFF02, 0x81);
+        serial.do_cycle(8 * 512);
+        assert_eq!(serial.read_byte(0xFF01), 0xFF);
+        assert_eq!(serial.interrupt, 0x08);
+    }
+
+    #[test]
+    fn tcp_cable_gives_up_without_an_answer() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut a = TcpCable::connect(listener.local_addr().unwrap()).unwrap();
+        let _b: TcpStream = listener.accept().unwrap().0;
+        assert_eq!(a.send(0x11), None);
+    }
+}
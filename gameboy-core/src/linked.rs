@@ -0,0 +1,217 @@
+// Two Game Boys connected by a link cable in the same process.
+
+use crate::link::LinkCable;
+use crate::{EmulationError, GameBoy};
+use std::sync::{Arc, Mutex};
+
+// What each end of the cable knows about the other Game Boy's serial port.
+#[derive(Default)]
+struct Wire {
+    // SB, and whether the port waits for a transfer on the external clock.
+    data: [u8; 2],
+    waiting: [bool; 2],
+    // Byte clocked in by the other side, picked up on the next poll.
+    received: [Option<u8>; 2],
+}
+
+struct End {
+    wire: Arc<Mutex<Wire>>,
+    side: usize,
+}
+
+impl LinkCable for End {
+    fn send(&mut self, byte: u8) -> Option<u8> {
+        let mut wire = self.wire.lock().unwrap();
+        let other = 1 - self.side;
+        if !wire.waiting[other] {
+            return None;
+        }
+        wire.waiting[other] = false;
+        wire.received[other] = Some(byte);
+        Some(wire.data[other])
+    }
+    fn receive(&mut self, _reply: u8) -> Option<u8> {
+        self.wire.lock().unwrap().received[self.side].take()
+    }
+}
+
+/// Two Game Boys with their serial ports wired together, e.g. to drive a
+/// trade or a battle from a headless test.
+///
+/// Both run in lockstep: the one behind always runs the next instruction, so
+/// neither gets ahead by more than an instruction and results don't depend on
+/// the host. Transfers go a bit at a time on both sides: the Game Boy waiting
+/// on the external clock notices a transfer within 512 cycles and then shifts
+/// a bit every 512 cycles, so SB changes mid-transfer on both ends.
+pub struct LinkedPair {
+    players: [GameBoy; 2],
+    cycles: [u64; 2],
+    wire: Arc<Mutex<Wire>>,
+}
+
+impl LinkedPair {
+    /// Connects two Game Boys, replacing any link cable they had.
+    pub fn new(mut first: GameBoy, mut second: GameBoy) -> Self {
+        let wire = Arc::new(Mutex::new(Wire::default()));
+        first.plug_link_cable(End {
+            wire: wire.clone(),
+            side: 0,
+        });
+        second.plug_link_cable(End {
+            wire: wire.clone(),
+            side: 1,
+        });
+        Self {
+            players: [first, second],
+            cycles: [0; 2],
+            wire,
+        }
+    }
+    /// One of the two Game Boys, 0 or 1.
+    pub fn player(&self, index: usize) -> &GameBoy {
+        &self.players[index]
+    }
+    /// One of the two Game Boys, 0 or 1, e.g. to press its buttons.
+    pub fn player_mut(&mut self, index: usize) -> &mut GameBoy {
+        &mut self.players[index]
+    }
+    /// Runs an instruction on the Game Boy that is behind and returns which
+    /// one it was.
+    pub fn step(&mut self) -> usize {
+        let index = self.behind();
+        self.run(index);
+        index
+    }
+    /// Runs both until each has completed a frame. One that gets there first
+    /// waits for the other. Fails if either CPU has locked up, the first
+    /// player's error taking precedence.
+    pub fn frame(&mut self) -> Result<(), EmulationError> {
+        let mut done = [false; 2];
+        while !(done[0] && done[1]) {
+            let index = match done {
+                [true, _] => 1,
+                [_, true] => 0,
+                _ => self.behind(),
+            };
+            self.run(index);
+            let gpu = &mut self.players[index].cpu.memory.gpu;
+            if gpu.updated {
+                gpu.updated = false;
+                done[index] = true;
+            }
+        }
+        for player in &self.players {
+            if let Some((opcode, address)) = player.cpu.locked {
+                return Err(EmulationError::LockedUp { opcode, address });
+            }
+        }
+        Ok(())
+    }
+
+    fn behind(&self) -> usize {
+        if self.cycles[0] <= self.cycles[1] {
+            0
+        } else {
+            1
+        }
+    }
+
+    fn run(&mut self, index: usize) {
+        self.sync(1 - index);
+        self.cycles[index] += self.players[index].step() as u64;
+    }
+
+    // Shows the serial port of a player to the other one, which is about to run.
+    fn sync(&mut self, index: usize) {
+        let memory = &self.players[index].cpu.memory;
+        let data = memory.serial.read_byte(0xFF01);
+        let control = memory.serial.read_byte(0xFF02);
+        let mut wire = self.wire.lock().unwrap();
+        wire.data[index] = data;
+        wire.waiting[index] = control & 0x81 == 0x80 && wire.received[index].is_none();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkedPair;
+    use crate::cartridge::test_rom;
+    use crate::GameBoy;
+
+    // Sends `data` with the given SC value, then loops.
+    fn sender(delay: usize, data: u8, control: u8) -> GameBoy {
+        let mut rom = test_rom(0x00, 0x00, 0x00);
+        let mut code = vec![0x00; delay];
+        // LD A,data; LDH (0x01),A; LD A,control; LDH (0x02),A; JR -2
+        code.extend([
+            0x3E, data, 0xE0, 0x01, 0x3E, control, 0xE0, 0x02, 0x18, 0xFE,
+        ]);
+        rom[0x0100..0x0100 + code.len()].copy_from_slice(&code);
+        GameBoy::new(&rom).unwrap()
+    }
+
+    #[test]
+    fn linked_pair_exchanges_bytes() {
+        // The second one waits on the external clock before the first sends.
+        let mut pair = LinkedPair::new(sender(8, 0x42, 0x81), sender(0, 0x24, 0x80));
+        pair.frame().unwrap();
+        for (index, received) in [(0, 0x24), (1, 0x42)] {
+            let memory = &mut pair.player_mut(index).cpu.memory;
+            assert_eq!(memory.read_byte(0xFF01), received);
+            assert_eq!(memory.read_byte(0xFF02) & 0x80, 0);
+            assert_eq!(memory.intf & 0x08, 0x08);
+        }
+    }
+
+    #[test]
+    fn both_sides_shift_a_bit_at_a_time() {
+        let mut pair = LinkedPair::new(sender(8, 0x42, 0x81), sender(0, 0x24, 0x80));
+        let mut finished = [None; 2];
+        let mut partial = [false; 2];
+        while finished.iter().any(Option::is_none) && pair.cycles[0] < 70224 {
+            let index = pair.step();
+            let memory = &mut pair.player_mut(index).cpu.memory;
+            let data = memory.read_byte(0xFF01);
+            partial[index] |= ![0x42, 0x24].contains(&data);
+            if finished[index].is_none() && memory.intf & 0x08 == 0x08 {
+                finished[index] = Some(pair.cycles[index]);
+            }
+        }
+        assert_eq!(partial, [true, true]);
+        let [Some(first), Some(second)] = finished else {
+            panic!("transfer didn't complete");
+        };
+        assert!(
+            first.abs_diff(second) <= 512 + 16,
+            "{} vs {}",
+            first,
+            second
+        );
+    }
+
+    #[test]
+    fn frame_stops_each_player_at_its_frame() {
+        let mut second = sender(0, 0, 0);
+        // Half a frame ahead of the first one.
+        let mut cycles = 0;
+        while cycles < 35112 {
+            cycles += second.step();
+        }
+        let mut pair = LinkedPair::new(sender(0, 0, 0), second);
+        pair.frame().unwrap();
+        // The second one waited for the first instead of running on.
+        let [first, second] = pair.cycles;
+        assert!(first - second > 35000, "{} vs {}", first, second);
+        for index in 0..2 {
+            assert!(!pair.player(index).cpu.memory.gpu.updated);
+        }
+    }
+
+    #[test]
+    fn nobody_listening_reads_0xff() {
+        let mut pair = LinkedPair::new(sender(0, 0x42, 0x81), sender(0, 0x24, 0x00));
+        pair.frame().unwrap();
+        assert_eq!(pair.player_mut(0).cpu.memory.read_byte(0xFF01), 0xFF);
+        assert_eq!(pair.player_mut(1).cpu.memory.read_byte(0xFF01), 0x24);
+    }
+}
@@ -0,0 +1,295 @@
+// MBC1, up to 2 MiB ROM and 32 KiB RAM.
+//
+// 0000-1FFF - RAM Enable (0x0A in the lower 4 bits enables it)
+// 2000-3FFF - BANK1, lower 5 bits of the ROM bank (0 is mapped as 1)
+// 4000-5FFF - BANK2, upper 2 bits of the ROM bank, or the RAM bank
+// 6000-7FFF - Banking Mode (0=BANK2 only applies to 4000-7FFF,
+//                           1=BANK2 also applies to 0000-3FFF and the RAM)
+
+use super::{load, ram_index, rom_byte, MemoryBankController};
+use crate::cartridge::Cartridge;
+
+pub struct Mbc1 {
+    rom: Vec<u8>,
+    rombank0: usize,
+    rombank: usize,
+    rombanks: usize,
+    ram: Vec<u8>,
+    ramon: bool,
+    rambank: usize,
+    rambanks: usize,
+    bank1: usize,
+    bank2: usize,
+    mode: u8,
+    multicart: bool,
+}
+
+impl Mbc1 {
+    pub fn new(cartridge: Cartridge) -> Mbc1 {
+        Mbc1 {
+            multicart: is_multicart(&cartridge.rom),
+            rom: cartridge.rom,
+            rombank0: 0,
+            rombank: 1,
+            rombanks: cartridge.rom_banks,
+            ram: vec![0; cartridge.ram_size],
+            ramon: false,
+            rambank: 0,
+            rambanks: (cartridge.ram_size / 0x2000).max(1),
+            bank1: 1,
+            bank2: 0,
+            mode: 0,
+        }
+    }
+
+    // BANK1 and BANK2 combine into the ROM bank number. In mode 1, BANK2 also
+    // selects the bank mapped at 0x0000-0x3FFF and the RAM bank. Multicarts
+    // only wire 4 bits of BANK1 to the ROM.
+    fn update_banks(&mut self) {
+        let (upper, lower) = if self.multicart {
+            (self.bank2 << 4, self.bank1 & 0x0F)
+        } else {
+            (self.bank2 << 5, self.bank1)
+        };
+        self.rombank = (upper | lower) % self.rombanks;
+        if self.mode == 1 {
+            self.rombank0 = upper % self.rombanks;
+            self.rambank = self.bank2 % self.rambanks;
+        } else {
+            self.rombank0 = 0;
+            self.rambank = 0;
+        }
+    }
+}
+
+// The logo every cartridge header holds at 0x0104-0x0133.
+const NINTENDO_LOGO: [u8; 0x30] = [
+    0xCE, 0xED, 0x66, 0x66, 0xCC, 0x0D, 0x00, 0x0B, 0x03, 0x73, 0x00, 0x83, 0x00, 0x0C,
+    0x00, 0x0D, 0x00, 0x08, 0x11, 0x1F, 0x88, 0x89, 0x00, 0x0E, 0xDC, 0xCC, 0x6E, 0xE6,
+    0xDD, 0xDD, 0xD9, 0x99, 0xBB, 0xBB, 0x67, 0x63, 0x6E, 0x0E, 0xEC, 0xCC, 0xDD, 0xDC,
+    0x99, 0x9F, 0xBB, 0xB9, 0x33, 0x3E,
+];
+
+// MBC1M multicarts are 1 MiB ROMs holding several games, each starting with
+// its own header (and Nintendo logo) at the beginning of a 256 KiB block. Like
+// other emulators, two logos are required so that blank ROMs don't qualify.
+fn is_multicart(rom: &[u8]) -> bool {
+    if rom.len() != 0x100000 {
+        return false;
+    }
+    let logos = (0..4)
+        .filter(|game| {
+            let header = game * 0x40000 + 0x0104;
+            rom[header..header + 0x30] == NINTENDO_LOGO
+        })
+        .count();
+    logos >= 2
+}
+
+impl MemoryBankController for Mbc1 {
+    fn readrom(&self, a: u16) -> u8 {
+        let bank = if a < 0x4000 {
+            self.rombank0
+        } else {
+            self.rombank
+        };
+        rom_byte(&self.rom, bank, a)
+    }
+    fn writerom(&mut self, a: u16, v: u8) {
+        match a {
+            0x0000..=0x1FFF => self.ramon = v & 0x0F == 0x0A,
+            0x2000..=0x3FFF => {
+                self.bank1 = match (v as usize) & 0x1F {
+                    0 => 1,
+                    n => n,
+                };
+                self.update_banks();
+            }
+            0x4000..=0x5FFF => {
+                self.bank2 = (v & 0x03) as usize;
+                self.update_banks();
+            }
+            _ => {
+                self.mode = v & 0x01;
+                self.update_banks();
+            }
+        }
+    }
+    fn readram(&self, a: u16) -> u8 {
+        if !self.ramon || self.ram.is_empty() {
+            return 0xFF;
+        }
+        self.ram[ram_index(&self.ram, self.rambank, a)]
+    }
+    fn writeram(&mut self, a: u16, v: u8) {
+        if !self.ramon || self.ram.is_empty() {
+            return;
+        }
+        let idx = ram_index(&self.ram, self.rambank, a);
+        self.ram[idx] = v;
+    }
+    fn save_ram(&self) -> Vec<u8> {
+        self.ram.clone()
+    }
+    fn load_ram(&mut self, data: &[u8]) {
+        load(&mut self.ram, data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mbc1, NINTENDO_LOGO};
+    use crate::cartridge::{test_rom, Cartridge};
+    use crate::mbc::{tagged_cartridge, MemoryBankController};
+
+    // MBC1+RAM+BATTERY with 32 KiB of RAM.
+    fn mbc1(rom_size: u8) -> Mbc1 {
+        Mbc1::new(tagged_cartridge(0x03, rom_size, 0x03))
+    }
+
+    fn low_bank(mbc: &Mbc1) -> u8 {
+        mbc.readrom(0x1000)
+    }
+
+    fn high_bank(mbc: &Mbc1) -> u8 {
+        mbc.readrom(0x5000)
+    }
+
+    #[test]
+    fn mbc1_ram_enable_register() {
+        let mut mbc = mbc1(0x06);
+        mbc.writeram(0xA000, 0x12);
+        assert_eq!(mbc.readram(0xA000), 0xFF);
+
+        mbc.writerom(0x0000, 0x0A);
+        mbc.writeram(0xA000, 0x12);
+        assert_eq!(mbc.readram(0xA000), 0x12);
+
+        mbc.writerom(0x1FFF, 0x1B);
+        assert_eq!(mbc.readram(0xA000), 0xFF);
+        mbc.writerom(0x1234, 0xFA);
+        assert_eq!(mbc.readram(0xA000), 0x12);
+    }
+
+    #[test]
+    fn mbc1_ram_reads_0xff_while_disabled() {
+        let mut mbc = mbc1(0x06);
+        mbc.writerom(0x0000, 0x0A);
+        mbc.writeram(0xBFFF, 0x34);
+        mbc.writerom(0x0000, 0x00);
+        assert_eq!(mbc.readram(0xA000), 0xFF);
+        assert_eq!(mbc.readram(0xBFFF), 0xFF);
+        // Writes are dropped too.
+        mbc.writeram(0xBFFF, 0x56);
+        mbc.writerom(0x0000, 0x0A);
+        assert_eq!(mbc.readram(0xBFFF), 0x34);
+    }
+
+    #[test]
+    fn mbc1_mode_1_switches_between_ram_banks() {
+        let mut mbc = mbc1(0x06);
+        mbc.writerom(0x0000, 0x0A);
+        mbc.writerom(0x6000, 0x01);
+        for bank in 0..4 {
+            mbc.writerom(0x4000, bank);
+            mbc.writeram(0xA000, 0x10 + bank);
+            mbc.writeram(0xBFFF, 0x20 + bank);
+        }
+        for bank in 0..4 {
+            mbc.writerom(0x4000, bank);
+            assert_eq!(mbc.readram(0xA000), 0x10 + bank);
+            assert_eq!(mbc.readram(0xBFFF), 0x20 + bank);
+        }
+        assert_eq!(mbc.save_ram()[3 * 0x2000], 0x13);
+    }
+
+    #[test]
+    fn mbc1_bank1_register() {
+        let mut mbc = mbc1(0x06);
+        assert_eq!(high_bank(&mbc), 1);
+
+        mbc.writerom(0x2000, 0x1F);
+        assert_eq!(high_bank(&mbc), 0x1F);
+        mbc.writerom(0x3FFF, 0x00);
+        assert_eq!(high_bank(&mbc), 0x01);
+        // Only the low 5 bits are stored, and the zero check happens on them.
+        mbc.writerom(0x2000, 0xE0);
+        assert_eq!(high_bank(&mbc), 0x01);
+        mbc.writerom(0x2000, 0xE2);
+        assert_eq!(high_bank(&mbc), 0x02);
+    }
+
+    #[test]
+    fn mbc1_bank2_register_selects_upper_rom_bits() {
+        let mut mbc = mbc1(0x06);
+        mbc.writerom(0x2000, 0x03);
+        mbc.writerom(0x4000, 0x02);
+        assert_eq!(high_bank(&mbc), 0x43);
+        assert_eq!(low_bank(&mbc), 0x00);
+
+        // Banks 0x20, 0x40 and 0x60 can't be mapped to 0x4000-0x7FFF.
+        mbc.writerom(0x2000, 0x00);
+        assert_eq!(high_bank(&mbc), 0x41);
+        mbc.writerom(0x5FFF, 0x07);
+        assert_eq!(high_bank(&mbc), 0x61);
+    }
+
+    #[test]
+    fn mbc1_bank2_register_is_masked_by_rom_size() {
+        let mut mbc = mbc1(0x04);
+        mbc.writerom(0x2000, 0x05);
+        mbc.writerom(0x4000, 0x01);
+        assert_eq!(high_bank(&mbc), 0x05);
+    }
+
+    #[test]
+    fn mbc1_mode_register() {
+        let mut mbc = mbc1(0x06);
+        mbc.writerom(0x0000, 0x0A);
+        mbc.writerom(0x4000, 0x02);
+        mbc.writeram(0xA000, 0x55);
+
+        mbc.writerom(0x6000, 0x01);
+        assert_eq!(low_bank(&mbc), 0x40);
+        assert_eq!(mbc.readram(0xA000), 0x00);
+        mbc.writeram(0xA000, 0x66);
+
+        mbc.writerom(0x7FFF, 0x00);
+        assert_eq!(low_bank(&mbc), 0x00);
+        assert_eq!(mbc.readram(0xA000), 0x55);
+
+        mbc.writerom(0x6000, 0x01);
+        assert_eq!(mbc.readram(0xA000), 0x66);
+    }
+
+    #[test]
+    fn mbc1_multicart_uses_four_bank1_bits() {
+        let mut rom = test_rom(0x01, 0x05, 0x00);
+        for bank in 0..rom.len() / 0x4000 {
+            rom[bank * 0x4000 + 0x1000] = bank as u8;
+        }
+        // The menu and the second game both have a header.
+        rom[0x0104..0x0134].copy_from_slice(&NINTENDO_LOGO);
+        rom[0x40104..0x40134].copy_from_slice(&NINTENDO_LOGO);
+        let mut mbc = Mbc1::new(Cartridge::new(rom).unwrap());
+
+        mbc.writerom(0x2000, 0x12);
+        mbc.writerom(0x4000, 0x01);
+        assert_eq!(high_bank(&mbc), 0x12);
+        mbc.writerom(0x6000, 0x01);
+        assert_eq!(low_bank(&mbc), 0x10);
+    }
+
+    #[test]
+    fn mbc1_blank_1mib_rom_is_not_a_multicart() {
+        let mut rom = test_rom(0x01, 0x05, 0x00);
+        for bank in 0..rom.len() / 0x4000 {
+            rom[bank * 0x4000 + 0x1000] = bank as u8;
+        }
+        let mut mbc = Mbc1::new(Cartridge::new(rom).unwrap());
+
+        mbc.writerom(0x2000, 0x12);
+        mbc.writerom(0x4000, 0x01);
+        assert_eq!(high_bank(&mbc), 0x32);
+    }
+}
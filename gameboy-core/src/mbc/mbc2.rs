@@ -0,0 +1,105 @@
+// MBC2, up to 256 KiB ROM and 512x4 bits of built-in RAM.
+//
+// 0000-3FFF - RAM Enable (address bit 8 clear, 0x0A in the lower 4 bits)
+//             ROM Bank Number (address bit 8 set, 4 bits, 0 is mapped as 1)
+// A000-A1FF - Built-in RAM, only the lower 4 bits of each byte are used.
+//             The 512 bytes are mirrored up to 0xBFFF.
+
+use super::{load, rom_byte, MemoryBankController};
+use crate::cartridge::Cartridge;
+
+pub struct Mbc2 {
+    rom: Vec<u8>,
+    rombank: usize,
+    rombanks: usize,
+    ram: [u8; 512],
+    ramon: bool,
+}
+
+impl Mbc2 {
+    pub fn new(cartridge: Cartridge) -> Mbc2 {
+        Mbc2 {
+            rom: cartridge.rom,
+            rombank: 1,
+            rombanks: cartridge.rom_banks,
+            ram: [0; 512],
+            ramon: false,
+        }
+    }
+}
+
+impl MemoryBankController for Mbc2 {
+    fn readrom(&self, a: u16) -> u8 {
+        let bank = if a < 0x4000 { 0 } else { self.rombank };
+        rom_byte(&self.rom, bank, a)
+    }
+    fn writerom(&mut self, a: u16, v: u8) {
+        match a {
+            0x0000..=0x3FFF if a & 0x0100 == 0 => self.ramon = v & 0x0F == 0x0A,
+            0x0000..=0x3FFF => {
+                let bank = match (v as usize) & 0x0F {
+                    0 => 1,
+                    n => n,
+                };
+                self.rombank = bank % self.rombanks;
+            }
+            _ => {}
+        }
+    }
+    fn readram(&self, a: u16) -> u8 {
+        if !self.ramon {
+            return 0xFF;
+        }
+        0xF0 | self.ram[(a as usize) & 0x01FF]
+    }
+    fn writeram(&mut self, a: u16, v: u8) {
+        if !self.ramon {
+            return;
+        }
+        self.ram[(a as usize) & 0x01FF] = v & 0x0F;
+    }
+    fn save_ram(&self) -> Vec<u8> {
+        self.ram.to_vec()
+    }
+    fn load_ram(&mut self, data: &[u8]) {
+        load(&mut self.ram, data);
+        for v in self.ram.iter_mut() {
+            *v &= 0x0F;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mbc2;
+    use crate::mbc::{tagged_cartridge, MemoryBankController};
+
+    #[test]
+    fn mbc2_selects_registers_with_address_bit_8() {
+        let mut mbc = Mbc2::new(tagged_cartridge(0x06, 0x03, 0x00));
+        mbc.writerom(0x0100, 0x0A);
+        mbc.writeram(0xA000, 0x05);
+        assert_eq!(mbc.readram(0xA000), 0xFF);
+        assert_eq!(mbc.readrom(0x5000), 0x0A);
+
+        mbc.writerom(0x3E00, 0x0A);
+        mbc.writeram(0xA000, 0x05);
+        assert_eq!(mbc.readram(0xA000), 0xF5);
+
+        mbc.writerom(0x2100, 0x10);
+        assert_eq!(mbc.readrom(0x5000), 0x01);
+        mbc.writerom(0x2100, 0x0F);
+        assert_eq!(mbc.readrom(0x5000), 0x0F);
+    }
+
+    #[test]
+    fn mbc2_ram_is_four_bits_wide_and_mirrored() {
+        let mut mbc = Mbc2::new(tagged_cartridge(0x06, 0x00, 0x00));
+        mbc.writerom(0x0000, 0x0A);
+        mbc.writeram(0xA1FF, 0xAB);
+        assert_eq!(mbc.readram(0xA1FF), 0xFB);
+        assert_eq!(mbc.readram(0xBFFF), 0xFB);
+        assert_eq!(mbc.save_ram().len(), 512);
+        assert_eq!(mbc.save_ram()[511], 0x0B);
+    }
+}
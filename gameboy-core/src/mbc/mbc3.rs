@@ -0,0 +1,179 @@
+// MBC3, up to 2 MiB ROM, 32 KiB RAM and an optional real time clock.
+//
+// 0000-1FFF - RAM and Timer Enable (0x0A in the lower 4 bits enables them)
+// 2000-3FFF - ROM Bank Number (7 bits, 0 is mapped as 1)
+// 4000-5FFF - RAM Bank Number (0x00-0x03) or RTC Register Select (0x08-0x0C)
+// 6000-7FFF - Latch Clock Data (writing 0x00 then 0x01 latches the clock)
+
+use super::rtc::Rtc;
+use super::{load, ram_index, rom_byte, MemoryBankController};
+use crate::cartridge::Cartridge;
+
+pub struct Mbc3 {
+    rom: Vec<u8>,
+    rombank: usize,
+    rombanks: usize,
+    ram: Vec<u8>,
+    ramon: bool,
+    rambank: usize,
+    rambanks: usize,
+    rtc: Option<Rtc>,
+    rtcreg: Option<u8>,
+}
+
+impl Mbc3 {
+    pub fn new(cartridge: Cartridge) -> Mbc3 {
+        Mbc3 {
+            rom: cartridge.rom,
+            rombank: 1,
+            rombanks: cartridge.rom_banks,
+            ram: vec![0; cartridge.ram_size],
+            ramon: false,
+            rambank: 0,
+            rambanks: (cartridge.ram_size / 0x2000).max(1),
+            rtc: if cartridge.kind.timer {
+                Some(Rtc::new())
+            } else {
+                None
+            },
+            rtcreg: None,
+        }
+    }
+}
+
+impl MemoryBankController for Mbc3 {
+    fn readrom(&self, a: u16) -> u8 {
+        let bank = if a < 0x4000 { 0 } else { self.rombank };
+        rom_byte(&self.rom, bank, a)
+    }
+    fn writerom(&mut self, a: u16, v: u8) {
+        match a {
+            0x0000..=0x1FFF => self.ramon = v & 0x0F == 0x0A,
+            0x2000..=0x3FFF => {
+                let bank = match (v as usize) & 0x7F {
+                    0 => 1,
+                    n => n,
+                };
+                self.rombank = bank % self.rombanks;
+            }
+            0x4000..=0x5FFF => match v {
+                0x08..=0x0C => self.rtcreg = Some(v),
+                _ => {
+                    self.rtcreg = None;
+                    self.rambank = ((v & 0x03) as usize) % self.rambanks;
+                }
+            },
+            _ => {
+                if let Some(rtc) = &mut self.rtc {
+                    rtc.latch(v);
+                }
+            }
+        }
+    }
+    fn readram(&self, a: u16) -> u8 {
+        if !self.ramon {
+            return 0xFF;
+        }
+        if let Some(register) = self.rtcreg {
+            return self
+                .rtc
+                .as_ref()
+                .map_or(0xFF, |rtc| rtc.read_byte(register));
+        }
+        if self.ram.is_empty() {
+            return 0xFF;
+        }
+        self.ram[ram_index(&self.ram, self.rambank, a)]
+    }
+    fn writeram(&mut self, a: u16, v: u8) {
+        if !self.ramon {
+            return;
+        }
+        if let Some(register) = self.rtcreg {
+            if let Some(rtc) = &mut self.rtc {
+                rtc.write_byte(register, v);
+            }
+            return;
+        }
+        if self.ram.is_empty() {
+            return;
+        }
+        let idx = ram_index(&self.ram, self.rambank, a);
+        self.ram[idx] = v;
+    }
+    fn save_ram(&self) -> Vec<u8> {
+        let mut data = self.ram.clone();
+        if let Some(rtc) = &self.rtc {
+            data.extend_from_slice(&rtc.save());
+        }
+        data
+    }
+    fn load_ram(&mut self, data: &[u8]) {
+        load(&mut self.ram, data);
+        if let Some(rtc) = &mut self.rtc {
+            if data.len() > self.ram.len() {
+                rtc.load(&data[self.ram.len()..]);
+            }
+        }
+    }
+    fn do_cycle(&mut self, ticks: u32) {
+        if let Some(rtc) = &mut self.rtc {
+            rtc.do_cycle(ticks);
+        }
+    }
+    fn sync_rtc(&mut self, unix_time: u64) {
+        if let Some(rtc) = &mut self.rtc {
+            rtc.sync(unix_time);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mbc3;
+    use crate::mbc::{tagged_cartridge, MemoryBankController};
+
+    #[test]
+    fn mbc3_selects_seven_bit_rom_banks() {
+        let mut mbc = Mbc3::new(tagged_cartridge(0x13, 0x06, 0x03));
+        mbc.writerom(0x2000, 0x7F);
+        assert_eq!(mbc.readrom(0x5000), 0x7F);
+        mbc.writerom(0x2000, 0x80);
+        assert_eq!(mbc.readrom(0x5000), 0x01);
+    }
+
+    #[test]
+    fn mbc3_maps_rtc_registers_over_ram() {
+        let mut mbc = Mbc3::new(tagged_cartridge(0x10, 0x00, 0x03));
+        mbc.writerom(0x0000, 0x0A);
+        mbc.writerom(0x4000, 0x01);
+        mbc.writeram(0xA000, 0x42);
+
+        mbc.writerom(0x4000, 0x09);
+        mbc.writeram(0xA000, 0x1E);
+        mbc.writerom(0x6000, 0x00);
+        mbc.writerom(0x6000, 0x01);
+        assert_eq!(mbc.readram(0xA000), 0x1E);
+
+        mbc.writerom(0x4000, 0x01);
+        assert_eq!(mbc.readram(0xA000), 0x42);
+
+        let save = mbc.save_ram();
+        assert_eq!(save.len(), 0x8000 + 48);
+        assert_eq!(save[0x8000 + 4], 0x1E);
+
+        let mut loaded = Mbc3::new(tagged_cartridge(0x10, 0x00, 0x03));
+        loaded.load_ram(&save);
+        assert_eq!(loaded.save_ram(), save);
+    }
+
+    #[test]
+    fn mbc3_without_timer_ignores_rtc_registers() {
+        let mut mbc = Mbc3::new(tagged_cartridge(0x13, 0x00, 0x03));
+        mbc.writerom(0x0000, 0x0A);
+        mbc.writerom(0x4000, 0x08);
+        mbc.writeram(0xA000, 0x1E);
+        assert_eq!(mbc.readram(0xA000), 0xFF);
+        assert_eq!(mbc.save_ram().len(), 0x8000);
+    }
+}
@@ -0,0 +1,136 @@
+// MBC5, up to 8 MiB ROM and 128 KiB RAM.
+//
+// 0000-1FFF - RAM Enable (0x0A in the lower 4 bits enables it)
+// 2000-2FFF - Lower 8 bits of the ROM bank (bank 0 can be mapped)
+// 3000-3FFF - Bit 8 of the ROM bank
+// 4000-5FFF - RAM Bank Number (0x00-0x0F). Rumble cartridges drive the motor
+//             with bit 3 instead, leaving 3 bits for the RAM bank.
+
+use super::{load, ram_index, rom_byte, MemoryBankController};
+use crate::cartridge::Cartridge;
+
+pub struct Mbc5 {
+    rom: Vec<u8>,
+    rombank: usize,
+    rombanks: usize,
+    ram: Vec<u8>,
+    ramon: bool,
+    rambank: usize,
+    rambanks: usize,
+    has_rumble: bool,
+    rumble: bool,
+}
+
+impl Mbc5 {
+    pub fn new(cartridge: Cartridge) -> Mbc5 {
+        Mbc5 {
+            rom: cartridge.rom,
+            rombank: 1,
+            rombanks: cartridge.rom_banks,
+            ram: vec![0; cartridge.ram_size],
+            ramon: false,
+            rambank: 0,
+            rambanks: (cartridge.ram_size / 0x2000).max(1),
+            has_rumble: cartridge.kind.rumble,
+            rumble: false,
+        }
+    }
+}
+
+impl MemoryBankController for Mbc5 {
+    fn readrom(&self, a: u16) -> u8 {
+        let bank = if a < 0x4000 { 0 } else { self.rombank };
+        rom_byte(&self.rom, bank, a)
+    }
+    fn writerom(&mut self, a: u16, v: u8) {
+        match a {
+            0x0000..=0x1FFF => self.ramon = v & 0x0F == 0x0A,
+            0x2000..=0x2FFF => {
+                self.rombank = ((self.rombank & 0x100) | v as usize) % self.rombanks;
+            }
+            0x3000..=0x3FFF => {
+                let bank = (self.rombank & 0xFF) | (((v & 0x01) as usize) << 8);
+                self.rombank = bank % self.rombanks;
+            }
+            0x4000..=0x5FFF if self.has_rumble => {
+                self.rumble = v & 0x08 != 0;
+                self.rambank = ((v & 0x07) as usize) % self.rambanks;
+            }
+            0x4000..=0x5FFF => {
+                self.rambank = ((v & 0x0F) as usize) % self.rambanks;
+            }
+            _ => {}
+        }
+    }
+    fn readram(&self, a: u16) -> u8 {
+        if !self.ramon || self.ram.is_empty() {
+            return 0xFF;
+        }
+        self.ram[ram_index(&self.ram, self.rambank, a)]
+    }
+    fn writeram(&mut self, a: u16, v: u8) {
+        if !self.ramon || self.ram.is_empty() {
+            return;
+        }
+        let idx = ram_index(&self.ram, self.rambank, a);
+        self.ram[idx] = v;
+    }
+    fn save_ram(&self) -> Vec<u8> {
+        self.ram.clone()
+    }
+    fn load_ram(&mut self, data: &[u8]) {
+        load(&mut self.ram, data);
+    }
+    fn rumble(&self) -> bool {
+        self.rumble
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mbc5;
+    use crate::cartridge::{test_rom, Cartridge};
+    use crate::mbc::{tagged_cartridge, MemoryBankController};
+
+    #[test]
+    fn mbc5_selects_nine_bit_rom_banks() {
+        let mut rom = test_rom(0x19, 0x08, 0x00);
+        for bank in 0..rom.len() / 0x4000 {
+            rom[bank * 0x4000 + 0x1000] = bank as u8;
+            rom[bank * 0x4000 + 0x1001] = (bank >> 8) as u8;
+        }
+        let mut mbc = Mbc5::new(Cartridge::new(rom).unwrap());
+        mbc.writerom(0x2000, 0x34);
+        mbc.writerom(0x3000, 0x01);
+        assert_eq!((mbc.readrom(0x5000), mbc.readrom(0x5001)), (0x34, 0x01));
+        mbc.writerom(0x2000, 0x00);
+        mbc.writerom(0x3000, 0x00);
+        assert_eq!((mbc.readrom(0x5000), mbc.readrom(0x5001)), (0x00, 0x00));
+    }
+
+    #[test]
+    fn mbc5_selects_sixteen_ram_banks() {
+        let mut mbc = Mbc5::new(tagged_cartridge(0x1B, 0x00, 0x04));
+        mbc.writerom(0x0000, 0x0A);
+        for bank in 0..16 {
+            mbc.writerom(0x4000, bank);
+            mbc.writeram(0xA000, bank);
+        }
+        mbc.writerom(0x4000, 0x0F);
+        assert_eq!(mbc.readram(0xA000), 0x0F);
+        assert_eq!(mbc.save_ram()[5 * 0x2000], 0x05);
+        assert!(!mbc.rumble());
+    }
+
+    #[test]
+    fn mbc5_rumble_uses_bit_3() {
+        let mut mbc = Mbc5::new(tagged_cartridge(0x1E, 0x00, 0x04));
+        mbc.writerom(0x0000, 0x0A);
+        mbc.writerom(0x4000, 0x0A);
+        assert!(mbc.rumble());
+        mbc.writeram(0xA000, 0x12);
+        mbc.writerom(0x4000, 0x02);
+        assert!(!mbc.rumble());
+        assert_eq!(mbc.readram(0xA000), 0x12);
+    }
+}
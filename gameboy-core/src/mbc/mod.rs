@@ -0,0 +1,137 @@
+// Memory bank controllers. The cartridge maps its ROM at 0x0000-0x7FFF and its
+// RAM (if any) at 0xA000-0xBFFF. Writes to the ROM area don't change the ROM,
+// they program the controller registers that select the visible banks.
+
+mod mbc1;
+mod mbc2;
+mod mbc3;
+mod mbc5;
+mod rtc;
+
+use crate::cartridge::{Cartridge, Mbc};
+
+// `Send` so a `GameBoy` can be moved to another thread.
+pub trait MemoryBankController: Send {
+    fn readrom(&self, a: u16) -> u8;
+    fn writerom(&mut self, a: u16, v: u8);
+    fn readram(&self, a: u16) -> u8;
+    fn writeram(&mut self, a: u16, v: u8);
+
+    // Cartridge RAM as stored in .sav files, followed by the clock state when
+    // the cartridge has one.
+    fn save_ram(&self) -> Vec<u8>;
+    fn load_ram(&mut self, data: &[u8]);
+
+    fn do_cycle(&mut self, _ticks: u32) {}
+    fn sync_rtc(&mut self, _unix_time: u64) {}
+    fn rumble(&self) -> bool {
+        false
+    }
+}
+
+pub fn new(cartridge: Cartridge) -> Box<dyn MemoryBankController> {
+    match cartridge.kind.mbc {
+        Mbc::None => Box::new(RomOnly::new(cartridge)),
+        Mbc::Mbc1 => Box::new(mbc1::Mbc1::new(cartridge)),
+        Mbc::Mbc2 => Box::new(mbc2::Mbc2::new(cartridge)),
+        Mbc::Mbc3 => Box::new(mbc3::Mbc3::new(cartridge)),
+        Mbc::Mbc5 => Box::new(mbc5::Mbc5::new(cartridge)),
+    }
+}
+
+fn rom_byte(rom: &[u8], bank: usize, a: u16) -> u8 {
+    let idx = (bank * 0x4000) | ((a as usize) & 0x3FFF);
+    *rom.get(idx).unwrap_or(&0xFF)
+}
+
+// External RAM is switched in 8 KiB banks. Smaller chips are mirrored.
+fn ram_index(ram: &[u8], bank: usize, a: u16) -> usize {
+    ((bank * 0x2000) | ((a as usize) & 0x1FFF)) % ram.len()
+}
+
+fn load(ram: &mut [u8], data: &[u8]) {
+    let len = data.len().min(ram.len());
+    ram[..len].copy_from_slice(&data[..len]);
+}
+
+// 32 KiB ROM, optionally with up to 8 KiB of RAM, and no controller.
+struct RomOnly {
+    rom: Vec<u8>,
+    ram: Vec<u8>,
+}
+
+impl RomOnly {
+    fn new(cartridge: Cartridge) -> RomOnly {
+        RomOnly {
+            rom: cartridge.rom,
+            ram: vec![0; cartridge.ram_size],
+        }
+    }
+}
+
+impl MemoryBankController for RomOnly {
+    fn readrom(&self, a: u16) -> u8 {
+        *self.rom.get(a as usize).unwrap_or(&0xFF)
+    }
+    fn writerom(&mut self, _a: u16, _v: u8) {}
+    fn readram(&self, a: u16) -> u8 {
+        if self.ram.is_empty() {
+            return 0xFF;
+        }
+        self.ram[ram_index(&self.ram, 0, a)]
+    }
+    fn writeram(&mut self, a: u16, v: u8) {
+        if self.ram.is_empty() {
+            return;
+        }
+        let idx = ram_index(&self.ram, 0, a);
+        self.ram[idx] = v;
+    }
+    fn save_ram(&self) -> Vec<u8> {
+        self.ram.clone()
+    }
+    fn load_ram(&mut self, data: &[u8]) {
+        load(&mut self.ram, data);
+    }
+}
+
+// Builds a cartridge whose ROM banks hold their own number at offset 0x1000.
+#[cfg(test)]
+pub fn tagged_cartridge(kind: u8, rom_size: u8, ram_size: u8) -> Cartridge {
+    let mut rom = crate::cartridge::test_rom(kind, rom_size, ram_size);
+    for bank in 0..rom.len() / 0x4000 {
+        rom[bank * 0x4000 + 0x1000] = bank as u8;
+    }
+    Cartridge::new(rom).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tagged_cartridge;
+
+    #[test]
+    fn dispatches_on_cartridge_type() {
+        // ROM only: RAM is always mapped and the bank registers don't exist.
+        let mut mbc = super::new(tagged_cartridge(0x09, 0x00, 0x02));
+        mbc.writerom(0x2000, 0x00);
+        assert_eq!(mbc.readrom(0x5000), 1);
+        mbc.writeram(0xA000, 0x12);
+        assert_eq!(mbc.readram(0xA000), 0x12);
+
+        // MBC1 maps no RAM until it is enabled.
+        let mut mbc = super::new(tagged_cartridge(0x03, 0x02, 0x02));
+        mbc.writeram(0xA000, 0x12);
+        assert_eq!(mbc.readram(0xA000), 0xFF);
+
+        // MBC2 RAM is 4 bits wide.
+        let mut mbc = super::new(tagged_cartridge(0x06, 0x02, 0x00));
+        mbc.writerom(0x0000, 0x0A);
+        mbc.writeram(0xA000, 0x12);
+        assert_eq!(mbc.readram(0xA000), 0xF2);
+
+        // MBC5 can map bank 0 at 0x4000-0x7FFF.
+        let mut mbc = super::new(tagged_cartridge(0x19, 0x02, 0x00));
+        mbc.writerom(0x2000, 0x00);
+        assert_eq!(mbc.readrom(0x5000), 0);
+    }
+}
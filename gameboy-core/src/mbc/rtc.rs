@@ -0,0 +1,310 @@
+// MBC3 real time clock. The registers are mapped at 0xA000-0xBFFF in place of
+// RAM when 0x08-0x0C is written to 0x4000-0x5FFF.
+//
+// 08 - RTC S  - Seconds (0-59)
+// 09 - RTC M  - Minutes (0-59)
+// 0A - RTC H  - Hours (0-23)
+// 0B - RTC DL - Lower 8 bits of the day counter
+// 0C - RTC DH - Upper 1 bit of the day counter, carry and halt flags
+//   Bit 0 - Bit 8 of the day counter
+//   Bit 6 - Halt (0=Active, 1=Stop timer)
+//   Bit 7 - Day counter carry (1=Counter overflowed)
+//
+// Reads return a snapshot of the clock, latched by writing 0x00 then 0x01 to
+// 0x6000-0x7FFF. Writes go to the running clock.
+//
+// The clock is advanced by emulated cycles. A frontend can instead sync it
+// with the host clock, so it keeps running while the emulator is closed, like
+// the battery powered clock of a real cartridge.
+
+const CYCLES_PER_SECOND: u32 = 4194304;
+
+// Battery RAM is followed by this trailer in the .sav files of most emulators:
+// the five clock registers, the five latched registers (each as a 32-bit little
+// endian word) and the unix time of the save (64-bit little endian). Older
+// saves store the time in 32 bits, making it 44 bytes long.
+pub const TRAILER_SIZE: usize = 48;
+
+pub struct Rtc {
+    seconds: u8,
+    minutes: u8,
+    hours: u8,
+    days: u16,
+    halt: bool,
+    carry: bool,
+    latched: [u8; 5],
+    latching: bool,
+    cycles: u32,
+    host_clock: bool,
+    timestamp: u64,
+}
+
+impl Rtc {
+    pub fn new() -> Rtc {
+        Rtc {
+            seconds: 0,
+            minutes: 0,
+            hours: 0,
+            days: 0,
+            halt: false,
+            carry: false,
+            latched: [0; 5],
+            latching: false,
+            cycles: 0,
+            host_clock: false,
+            timestamp: 0,
+        }
+    }
+
+    pub fn do_cycle(&mut self, ticks: u32) {
+        if self.host_clock || self.halt {
+            return;
+        }
+        self.cycles += ticks;
+        while self.cycles >= CYCLES_PER_SECOND {
+            self.cycles -= CYCLES_PER_SECOND;
+            self.tick();
+        }
+    }
+
+    // Switches the clock to follow the host, and catches up with the time
+    // elapsed since the last sync (or since the save was written).
+    pub fn sync(&mut self, unix_time: u64) {
+        if self.host_clock || self.timestamp != 0 {
+            let elapsed = unix_time.saturating_sub(self.timestamp);
+            if !self.halt {
+                self.advance(elapsed);
+            }
+        }
+        self.host_clock = true;
+        self.timestamp = unix_time;
+    }
+
+    pub fn latch(&mut self, v: u8) {
+        if self.latching && v == 0x01 {
+            self.latched = self.registers();
+        }
+        self.latching = v == 0x00;
+    }
+
+    pub fn read_byte(&self, register: u8) -> u8 {
+        match register {
+            0x08..=0x0C => self.latched[(register - 0x08) as usize],
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_byte(&mut self, register: u8, v: u8) {
+        match register {
+            0x08 => {
+                self.seconds = v & 0x3F;
+                self.cycles = 0;
+            }
+            0x09 => self.minutes = v & 0x3F,
+            0x0A => self.hours = v & 0x1F,
+            0x0B => self.days = (self.days & 0x100) | v as u16,
+            0x0C => {
+                self.days = (self.days & 0xFF) | (((v & 0x01) as u16) << 8);
+                self.halt = v & 0x40 != 0;
+                self.carry = v & 0x80 != 0;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn save(&self) -> [u8; TRAILER_SIZE] {
+        let mut data = [0; TRAILER_SIZE];
+        let registers = self.registers().into_iter().chain(self.latched);
+        for (word, v) in data.chunks_mut(4).zip(registers) {
+            word[0] = v;
+        }
+        data[40..].copy_from_slice(&self.timestamp.to_le_bytes());
+        data
+    }
+
+    pub fn load(&mut self, data: &[u8]) {
+        if data.len() < TRAILER_SIZE - 4 {
+            return;
+        }
+        for register in 0x08..=0x0C {
+            let i = (register - 0x08) as usize;
+            self.write_byte(register, data[i * 4]);
+            self.latched[i] = data[20 + i * 4];
+        }
+        let mut timestamp = [0; 8];
+        let len = (data.len() - 40).min(8);
+        timestamp[..len].copy_from_slice(&data[40..40 + len]);
+        self.timestamp = u64::from_le_bytes(timestamp);
+    }
+
+    fn registers(&self) -> [u8; 5] {
+        [
+            self.seconds,
+            self.minutes,
+            self.hours,
+            self.days as u8,
+            ((self.days >> 8) as u8)
+                | if self.halt { 0x40 } else { 0 }
+                | if self.carry { 0x80 } else { 0 },
+        ]
+    }
+
+    // Out of range values (e.g. 61 seconds) count up to the register limit and
+    // wrap to 0 without carrying into the next register.
+    fn tick(&mut self) {
+        self.seconds = (self.seconds + 1) & 0x3F;
+        if self.seconds != 60 {
+            return;
+        }
+        self.seconds = 0;
+        self.minutes = (self.minutes + 1) & 0x3F;
+        if self.minutes != 60 {
+            return;
+        }
+        self.minutes = 0;
+        self.hours = (self.hours + 1) & 0x1F;
+        if self.hours != 24 {
+            return;
+        }
+        self.hours = 0;
+        self.days += 1;
+        if self.days == 512 {
+            self.days = 0;
+            self.carry = true;
+        }
+    }
+
+    fn advance(&mut self, mut seconds: u64) {
+        while seconds > 0
+            && (self.seconds >= 60 || self.minutes >= 60 || self.hours >= 24)
+        {
+            self.tick();
+            seconds -= 1;
+        }
+        let total = seconds
+            + self.seconds as u64
+            + self.minutes as u64 * 60
+            + self.hours as u64 * 3600
+            + self.days as u64 * 86400;
+        let days = total / 86400;
+        self.seconds = (total % 60) as u8;
+        self.minutes = (total / 60 % 60) as u8;
+        self.hours = (total / 3600 % 24) as u8;
+        self.days = (days % 512) as u16;
+        if days >= 512 {
+            self.carry = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Rtc, CYCLES_PER_SECOND};
+
+    fn latched(rtc: &mut Rtc) -> [u8; 5] {
+        rtc.latch(0x00);
+        rtc.latch(0x01);
+        [0x08, 0x09, 0x0A, 0x0B, 0x0C].map(|r| rtc.read_byte(r))
+    }
+
+    #[test]
+    fn counts_emulated_seconds() {
+        let mut rtc = Rtc::new();
+        rtc.do_cycle(CYCLES_PER_SECOND - 1);
+        assert_eq!(latched(&mut rtc), [0, 0, 0, 0, 0]);
+        rtc.do_cycle(1);
+        assert_eq!(latched(&mut rtc), [1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn rolls_over_into_days_and_carry() {
+        let mut rtc = Rtc::new();
+        rtc.write_byte(0x08, 59);
+        rtc.write_byte(0x09, 59);
+        rtc.write_byte(0x0A, 23);
+        rtc.write_byte(0x0B, 0xFF);
+        rtc.write_byte(0x0C, 0x00);
+        rtc.do_cycle(CYCLES_PER_SECOND);
+        assert_eq!(latched(&mut rtc), [0, 0, 0, 0x00, 0x01]);
+
+        rtc.write_byte(0x08, 59);
+        rtc.write_byte(0x09, 59);
+        rtc.write_byte(0x0A, 23);
+        rtc.write_byte(0x0B, 0xFF);
+        rtc.write_byte(0x0C, 0x01);
+        rtc.do_cycle(CYCLES_PER_SECOND);
+        assert_eq!(latched(&mut rtc), [0, 0, 0, 0x00, 0x80]);
+    }
+
+    #[test]
+    fn out_of_range_values_wrap_without_carry() {
+        let mut rtc = Rtc::new();
+        rtc.write_byte(0x08, 63);
+        rtc.do_cycle(CYCLES_PER_SECOND);
+        assert_eq!(latched(&mut rtc), [0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn halt_stops_the_clock() {
+        let mut rtc = Rtc::new();
+        rtc.write_byte(0x0C, 0x40);
+        rtc.do_cycle(CYCLES_PER_SECOND * 3);
+        rtc.sync(1000);
+        rtc.sync(2000);
+        assert_eq!(latched(&mut rtc), [0, 0, 0, 0, 0x40]);
+    }
+
+    #[test]
+    fn reads_are_latched() {
+        let mut rtc = Rtc::new();
+        rtc.write_byte(0x09, 12);
+        assert_eq!(rtc.read_byte(0x09), 0);
+
+        // Only a 0x00 -> 0x01 sequence latches the clock.
+        rtc.latch(0x01);
+        assert_eq!(rtc.read_byte(0x09), 0);
+        rtc.latch(0x00);
+        rtc.latch(0x02);
+        rtc.latch(0x01);
+        assert_eq!(rtc.read_byte(0x09), 0);
+        rtc.latch(0x00);
+        rtc.latch(0x01);
+        assert_eq!(rtc.read_byte(0x09), 12);
+    }
+
+    #[test]
+    fn host_clock_replaces_emulated_cycles() {
+        let mut rtc = Rtc::new();
+        rtc.sync(1_000_000);
+        rtc.do_cycle(CYCLES_PER_SECOND * 2);
+        assert_eq!(latched(&mut rtc), [0, 0, 0, 0, 0]);
+
+        rtc.sync(1_000_000 + 86400 * 2 + 3600 + 61);
+        assert_eq!(latched(&mut rtc), [1, 1, 1, 2, 0]);
+    }
+
+    #[test]
+    fn trailer_round_trip() {
+        let mut rtc = Rtc::new();
+        rtc.write_byte(0x0A, 5);
+        latched(&mut rtc);
+        rtc.write_byte(0x0B, 0x34);
+        rtc.write_byte(0x0C, 0xC1);
+        rtc.sync(0x0123_4567_89AB);
+
+        let data = rtc.save();
+        assert_eq!(&data[8..16], &[5, 0, 0, 0, 0x34, 0, 0, 0]);
+        assert_eq!(data[16], 0xC1);
+        assert_eq!(data[28], 5);
+        assert_eq!(&data[40..], &0x0123_4567_89ABu64.to_le_bytes());
+
+        let mut loaded = Rtc::new();
+        loaded.load(&data);
+        assert_eq!(loaded.save(), data);
+
+        // The older format stores a 32-bit timestamp.
+        let mut loaded = Rtc::new();
+        loaded.load(&data[..44]);
+        assert_eq!(loaded.timestamp, 0x4567_89AB);
+    }
+}
@@ -0,0 +1,522 @@
+use crate::cartridge::{Cartridge, CgbSupport};
+use crate::gpu::Gpu;
+use crate::input::Input;
+use crate::mbc::{self, MemoryBankController};
+use crate::serial::Serial;
+use crate::sound::Sound;
+use crate::timer::Timer;
+
+const WRAM_SIZE: usize = 0x8000;
+const ZRAM_SIZE: usize = 0x7F;
+
+pub struct MemoryManagementUnit {
+    wram: [u8; WRAM_SIZE],
+    zram: [u8; ZRAM_SIZE],
+    pub inte: u8,
+    pub intf: u8,
+    pub input: Input,
+    pub serial: Serial,
+    pub gpu: Gpu,
+    pub timer: Timer,
+    pub sound: Sound,
+    wrambank: usize,
+    pub mbc: Box<dyn MemoryBankController>,
+    // Mapped over 0x0000-0x00FF until the boot ROM writes to 0xFF50.
+    pub boot_rom: Option<Vec<u8>>,
+    // Running in CGB mode, selected by the cartridge header.
+    pub cgb: bool,
+    // KEY1: the CPU runs at twice the clock of the rest of the system in
+    // double speed mode. Arming the switch makes the next STOP toggle it.
+    pub double_speed: bool,
+    speed_switch: bool,
+    // CGB VRAM DMA. `hdma_len` is the number of 16 byte blocks left minus one,
+    // and `hdma_active` is set while an HBlank transfer is in progress.
+    hdma_src: u16,
+    hdma_dst: u16,
+    hdma_len: u8,
+    hdma_active: bool,
+    // CPU ticks the CPU is held for by DMA, added to the next cycle.
+    stall: u32,
+    // OAM DMA copies one byte per M-cycle, starting one M-cycle after the
+    // write to 0xFF46. `oam_dma_left` is the number of bytes still to copy.
+    // The write is the last M-cycle of the instruction making it, so the
+    // cycles of that instruction don't count (`oam_dma_started`).
+    oam_dma: u8,
+    oam_dma_src: u16,
+    oam_dma_left: u16,
+    oam_dma_delay: u8,
+    oam_dma_started: bool,
+}
+
+impl MemoryManagementUnit {
+    pub fn new(cartridge: Cartridge) -> MemoryManagementUnit {
+        let cgb = cartridge.cgb != CgbSupport::None;
+        let mbc = mbc::new(cartridge);
+
+        let mut res = MemoryManagementUnit {
+            wram: [0; WRAM_SIZE],
+            zram: [0; ZRAM_SIZE],
+            wrambank: 1,
+            inte: 0,
+            intf: 0,
+            input: Input::default(),
+            serial: Serial::new(cgb),
+            gpu: Gpu::new(cgb),
+            timer: Timer::new(),
+            sound: Sound::new(44100),
+            mbc,
+            boot_rom: None,
+            cgb,
+            double_speed: false,
+            speed_switch: false,
+            hdma_src: 0,
+            hdma_dst: 0,
+            hdma_len: 0x7F,
+            hdma_active: false,
+            stall: 0,
+            oam_dma: 0xFF,
+            oam_dma_src: 0,
+            oam_dma_left: 0,
+            oam_dma_delay: 0,
+            oam_dma_started: false,
+        };
+
+        res.write_byte(0xFF05, 0);
+        res.write_byte(0xFF06, 0);
+        res.write_byte(0xFF07, 0);
+        res.write_byte(0xFF10, 0x80);
+        res.write_byte(0xFF11, 0xBF);
+        res.write_byte(0xFF12, 0xF3);
+        res.write_byte(0xFF14, 0xBF);
+        res.write_byte(0xFF16, 0x3F);
+        res.write_byte(0xFF16, 0x3F);
+        res.write_byte(0xFF17, 0);
+        res.write_byte(0xFF19, 0xBF);
+        res.write_byte(0xFF1A, 0x7F);
+        res.write_byte(0xFF1B, 0xFF);
+        res.write_byte(0xFF1C, 0x9F);
+        res.write_byte(0xFF1E, 0xFF);
+        res.write_byte(0xFF20, 0xFF);
+        res.write_byte(0xFF21, 0);
+        res.write_byte(0xFF22, 0);
+        res.write_byte(0xFF23, 0xBF);
+        res.write_byte(0xFF24, 0x77);
+        res.write_byte(0xFF25, 0xF3);
+        res.write_byte(0xFF26, 0xF1);
+        res.write_byte(0xFF40, 0x91);
+        res.write_byte(0xFF42, 0);
+        res.write_byte(0xFF43, 0);
+        res.write_byte(0xFF45, 0);
+        res.write_byte(0xFF47, 0xFC);
+        res.write_byte(0xFF48, 0xFF);
+        res.write_byte(0xFF49, 0xFF);
+        res.write_byte(0xFF4A, 0);
+        res.write_byte(0xFF4B, 0);
+
+        res
+    }
+
+    // Takes CPU ticks and returns them converted to the system clock.
+    pub fn do_cycle(&mut self, cputicks: u32) -> u32 {
+        let cputicks = cputicks + std::mem::take(&mut self.stall);
+        self.oam_dma_cycle(cputicks);
+        self.intf |= self.input.interrupt;
+        self.input.interrupt = 0;
+        self.timer.do_cycle(cputicks);
+        self.intf |= self.timer.interrupt;
+        self.timer.interrupt = 0;
+        self.serial.do_cycle(cputicks);
+        self.intf |= self.serial.interrupt;
+        self.serial.interrupt = 0;
+
+        let ticks = self.system_ticks(cputicks);
+        self.sound.do_cycle(ticks);
+        self.mbc.do_cycle(ticks);
+
+        self.gpu.do_cycle(ticks);
+        self.intf |= self.gpu.interrupt;
+        self.gpu.interrupt = 0;
+
+        if self.gpu.hblank {
+            self.gpu.hblank = false;
+            if self.hdma_active {
+                self.hdma_block();
+            }
+        }
+
+        ticks
+    }
+
+    // In STOP mode the system clock is halted: DIV, sound and the LCD are
+    // frozen. Only the cartridge's own clock keeps running, and frames are
+    // still paced so the frontend keeps presenting the last picture.
+    pub fn do_stopped_cycle(&mut self, cputicks: u32) -> u32 {
+        let ticks = self.system_ticks(cputicks);
+        self.mbc.do_cycle(ticks);
+        self.gpu.idle(ticks);
+        ticks
+    }
+
+    // CPU ticks until the next point where an interrupt could be raised or a
+    // frame could complete.
+    pub fn next_event(&self) -> u32 {
+        let gpu = match self.double_speed {
+            true => self.gpu.next_event().saturating_mul(2),
+            false => self.gpu.next_event(),
+        };
+        gpu.min(self.timer.next_event())
+            .min(self.serial.next_event())
+    }
+
+    fn system_ticks(&self, cputicks: u32) -> u32 {
+        if self.double_speed {
+            cputicks / 2
+        } else {
+            cputicks
+        }
+    }
+
+    // FF55 - HDMA5: writing starts a transfer of (bits 0-6 + 1) * 16 bytes.
+    // With bit 7 cleared it's a general purpose DMA done at once, otherwise
+    // one block is copied at each HBlank, the first one right away if the
+    // LCD is already in HBlank or off. Writing bit 7 cleared during an HBlank
+    // transfer cancels it.
+    fn start_hdma(&mut self, value: u8) {
+        if self.hdma_active && value & 0x80 == 0 {
+            self.hdma_active = false;
+            return;
+        }
+        self.hdma_len = value & 0x7F;
+        if value & 0x80 == 0x80 {
+            self.hdma_active = true;
+            if self.gpu.in_hblank() {
+                self.hdma_block();
+            }
+        } else {
+            for _ in 0..=self.hdma_len {
+                self.hdma_block();
+            }
+        }
+    }
+
+    // Copies 16 bytes into VRAM, which holds the CPU for 32 system ticks.
+    // Sources from 0xE000 up aren't valid, they read from 0xA000-0xBFFF.
+    fn hdma_block(&mut self) {
+        for _ in 0..0x10 {
+            let src = match self.hdma_src {
+                0xE000..=0xFFFF => self.hdma_src & !0x4000,
+                src => src,
+            };
+            let b = self.read_bus(src);
+            self.gpu.write_byte(0x8000 | (self.hdma_dst & 0x1FFF), b);
+            self.hdma_src = self.hdma_src.wrapping_add(1);
+            self.hdma_dst = self.hdma_dst.wrapping_add(1);
+        }
+        self.stall += if self.double_speed { 64 } else { 32 };
+        self.hdma_len = self.hdma_len.wrapping_sub(1) & 0x7F;
+        if self.hdma_len == 0x7F {
+            self.hdma_active = false;
+        }
+    }
+
+    // Performs the CGB speed switch if it was armed through KEY1.
+    pub fn switch_speed(&mut self) -> bool {
+        if !self.speed_switch {
+            return false;
+        }
+        self.speed_switch = false;
+        self.double_speed = !self.double_speed;
+        true
+    }
+
+    pub fn write_word(&mut self, address: u16, value: u16) {
+        self.write_byte(address, (value & 0xFF) as u8);
+        self.write_byte(address + 1, (value >> 8) as u8);
+    }
+
+    pub fn read_word(&mut self, address: u16) -> u16 {
+        (self.read_byte(address) as u16) | ((self.read_byte(address + 1) as u16) << 8)
+    }
+
+    fn start_oam_dma(&mut self, value: u8) {
+        self.oam_dma = value;
+        // Sources from 0xE000 up read the echo of WRAM.
+        self.oam_dma_src = match value {
+            0xE0..=0xFF => (value as u16) << 8 & 0xDFFF,
+            _ => (value as u16) << 8,
+        };
+        self.oam_dma_left = 0xA0;
+        self.oam_dma_delay = 1;
+        self.oam_dma_started = true;
+    }
+
+    fn oam_dma_cycle(&mut self, cputicks: u32) {
+        if std::mem::take(&mut self.oam_dma_started) {
+            return;
+        }
+        for _ in 0..cputicks / 4 {
+            if self.oam_dma_left == 0 {
+                break;
+            }
+            if self.oam_dma_delay > 0 {
+                self.oam_dma_delay -= 1;
+                continue;
+            }
+            let b = self.read_bus(self.oam_dma_address());
+            let i = 0xA0 - self.oam_dma_left;
+            self.gpu.write_byte(0xFE00 + i, b);
+            self.oam_dma_left -= 1;
+        }
+    }
+
+    fn oam_dma_address(&self) -> u16 {
+        self.oam_dma_src + 0xA0 - self.oam_dma_left
+    }
+
+    // While OAM DMA runs the CPU can't reach OAM, nor the bus DMA reads from:
+    // VRAM, or the external bus for everything but the I/O registers and HRAM.
+    fn oam_dma_blocks(&self, address: u16) -> bool {
+        if self.oam_dma_left == 0 || self.oam_dma_delay > 0 {
+            return false;
+        }
+        let bus = |a: u16| match a {
+            0x8000..=0x9FFF => 1,
+            0xFE00..=0xFFFF => 0,
+            _ => 2,
+        };
+        match address {
+            0xFE00..=0xFEFF => true,
+            _ => bus(address) != 0 && bus(address) == bus(self.oam_dma_src),
+        }
+    }
+
+    pub fn read_byte(&mut self, address: u16) -> u8 {
+        if self.oam_dma_blocks(address) {
+            // OAM reads as 0xFF, and the bus as the byte being copied.
+            return match address {
+                0xFE00..=0xFEFF => 0xFF,
+                _ => self.read_bus(self.oam_dma_address()),
+            };
+        }
+        self.read_bus(address)
+    }
+
+    fn read_bus(&mut self, address: u16) -> u8 {
+        match address {
+            0x0000..=0x00FF if self.boot_rom.is_some() => {
+                self.boot_rom.as_ref().unwrap()[address as usize]
+            }
+            0x0000..=0x7FFF => self.mbc.readrom(address),
+            0x8000..=0x9FFF => self.gpu.read_byte(address),
+            0xA000..=0xBFFF => self.mbc.readram(address),
+            0xC000..=0xCFFF | 0xE000..=0xEFFF => self.wram[address as usize & 0x0FFF],
+            0xD000..=0xDFFF | 0xF000..=0xFDFF => {
+                self.wram[(self.wrambank * 0x1000) | address as usize & 0x0FFF]
+            }
+            0xFE00..=0xFE9F => self.gpu.read_byte(address),
+            0xFF00 => self.input.read_byte(),
+            0xFF01..=0xFF02 => self.serial.read_byte(address),
+            0xFF04..=0xFF07 => self.timer.read_byte(address),
+            0xFF0F => self.intf | 0b11100000,
+            0xFF10..=0xFF3F => self.sound.read_byte(address),
+            0xFF4D if self.cgb => {
+                0x7E | (self.double_speed as u8) << 7 | self.speed_switch as u8
+            }
+            0xFF46 => self.oam_dma,
+            0xFF40..=0xFF4F => self.gpu.read_byte(address),
+            0xFF55 if self.cgb => self.hdma_len | (!self.hdma_active as u8) << 7,
+            0xFF68..=0xFF6B => self.gpu.read_byte(address),
+            0xFF70 if self.cgb => self.wrambank as u8 | 0xF8,
+            0xFF80..=0xFFFE => self.zram[address as usize & 0x007F],
+            0xFFFF => self.inte,
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_byte(&mut self, address: u16, value: u8) {
+        if self.oam_dma_blocks(address) {
+            return;
+        }
+        match address {
+            0x0000..=0x7FFF => self.mbc.writerom(address, value),
+            0x8000..=0x9FFF => self.gpu.write_byte(address, value),
+            0xA000..=0xBFFF => self.mbc.writeram(address, value),
+            0xC000..=0xCFFF | 0xE000..=0xEFFF => {
+                self.wram[address as usize & 0x0FFF] = value
+            }
+            0xD000..=0xDFFF | 0xF000..=0xFDFF => {
+                self.wram[(self.wrambank * 0x1000) | (address as usize & 0x0FFF)] = value
+            }
+            0xFE00..=0xFE9F => self.gpu.write_byte(address, value),
+            0xFF00 => self.input.write_byte(value),
+            0xFF01..=0xFF02 => self.serial.write_byte(address, value),
+            0xFF04..=0xFF07 => self.timer.write_byte(address, value),
+            0xFF46 => self.start_oam_dma(value),
+            0xFF4D if self.cgb => self.speed_switch = value & 0x01 == 0x01,
+            0xFF40..=0xFF4F => self.gpu.write_byte(address, value),
+            0xFF68..=0xFF6B => self.gpu.write_byte(address, value),
+            0xFF0F => self.intf = value & 0x1F,
+            0xFF10..=0xFF3F => self.sound.write_byte(address, value),
+            0xFF50 if value != 0 => self.boot_rom = None,
+            0xFF51 if self.cgb => {
+                self.hdma_src = (value as u16) << 8 | (self.hdma_src & 0x00F0)
+            }
+            0xFF52 if self.cgb => {
+                self.hdma_src = (self.hdma_src & 0xFF00) | (value & 0xF0) as u16
+            }
+            0xFF53 if self.cgb => {
+                self.hdma_dst = ((value & 0x1F) as u16) << 8 | (self.hdma_dst & 0x00F0)
+            }
+            0xFF54 if self.cgb => {
+                self.hdma_dst = (self.hdma_dst & 0x1F00) | (value & 0xF0) as u16
+            }
+            0xFF55 if self.cgb => self.start_hdma(value),
+            0xFF70 if self.cgb => {
+                self.wrambank = match value & 0x7 {
+                    0 => 1,
+                    n => n as usize,
+                };
+            }
+            0xFF80..=0xFFFE => self.zram[address as usize & 0x007F] = value,
+            0xFFFF => self.inte = value,
+            _ => {}
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoryManagementUnit;
+    use crate::cartridge::{test_rom, Cartridge};
+
+    fn cgb() -> MemoryManagementUnit {
+        let mut rom = test_rom(0x00, 0x00, 0x00);
+        rom[0x143] = 0x80;
+        rom[0x14D] = rom[0x14D].wrapping_sub(0x80);
+        let mut mmu = MemoryManagementUnit::new(Cartridge::new(rom).unwrap());
+        for i in 0..0x20 {
+            mmu.write_byte(0xC000 + i, i as u8 + 1);
+        }
+        // From 0xC000 to 0x8100.
+        mmu.write_byte(0xFF51, 0xC0);
+        mmu.write_byte(0xFF52, 0x00);
+        mmu.write_byte(0xFF53, 0x81);
+        mmu.write_byte(0xFF54, 0x00);
+        mmu
+    }
+
+    #[test]
+    fn general_purpose_dma_copies_at_once() {
+        let mut mmu = cgb();
+        mmu.write_byte(0xFF55, 0x01);
+        for i in 0..0x20 {
+            assert_eq!(mmu.read_byte(0x8100 + i), i as u8 + 1);
+        }
+        assert_eq!(mmu.read_byte(0xFF55), 0xFF);
+        assert_eq!(mmu.do_cycle(4), 4 + 2 * 32);
+    }
+
+    #[test]
+    fn general_purpose_dma_copies_every_block() {
+        for (length, blocks) in [(0x05, 6), (0x7F, 128)] {
+            let mut mmu = cgb();
+            for i in 0..0x800 {
+                mmu.write_byte(0xC000 + i, (i / 0x10) as u8 + 1);
+            }
+            mmu.write_byte(0xFF55, length);
+            for block in 0..0x80 {
+                let expected = if block < blocks { block as u8 + 1 } else { 0 };
+                assert_eq!(mmu.read_byte(0x8100 + block * 0x10 + 0x0F), expected);
+            }
+            assert_eq!(mmu.read_byte(0xFF55), 0xFF);
+            assert_eq!(mmu.do_cycle(4), 4 + blocks as u32 * 32);
+        }
+    }
+
+    #[test]
+    fn hblank_dma_copies_a_block_per_hblank() {
+        let mut mmu = cgb();
+        mmu.write_byte(0xFF55, 0x81);
+        assert_eq!(mmu.read_byte(0xFF55), 0x01);
+        assert_eq!(mmu.read_byte(0x8100), 0);
+
+        // Line 0 enters HBlank after 252 cycles.
+        mmu.do_cycle(256);
+        assert_eq!(mmu.read_byte(0x810F), 0x10);
+        assert_eq!(mmu.read_byte(0x8110), 0);
+        assert_eq!(mmu.read_byte(0xFF55), 0x00);
+
+        mmu.write_byte(0xFF55, 0x00);
+        assert_eq!(mmu.read_byte(0xFF55), 0x80);
+        mmu.do_cycle(456);
+        assert_eq!(mmu.read_byte(0x8110), 0);
+    }
+
+    #[test]
+    fn hblank_dma_starts_at_once_in_hblank_or_with_the_lcd_off() {
+        let mut mmu = cgb();
+        mmu.do_cycle(256);
+        mmu.write_byte(0xFF55, 0x81);
+        assert_eq!(mmu.read_byte(0x810F), 0x10);
+        assert_eq!(mmu.read_byte(0xFF55), 0x00);
+        mmu.do_cycle(456);
+        assert_eq!(mmu.read_byte(0x811F), 0x20);
+        assert_eq!(mmu.read_byte(0xFF55), 0xFF);
+
+        let mut mmu = cgb();
+        mmu.write_byte(0xFF40, 0x00);
+        mmu.write_byte(0xFF55, 0x81);
+        assert_eq!(mmu.read_byte(0x810F), 0x10);
+        // No more HBlanks until the LCD is turned back on.
+        mmu.do_cycle(70224);
+        assert_eq!(mmu.read_byte(0x8110), 0);
+        assert_eq!(mmu.read_byte(0xFF55), 0x00);
+    }
+
+    #[test]
+    fn dma_sources_from_0xe000_read_cartridge_ram() {
+        // MBC1+RAM, CGB compatible.
+        let mut rom = test_rom(0x02, 0x00, 0x02);
+        rom[0x143] = 0x80;
+        rom[0x14D] = rom[0x14D].wrapping_sub(0x80);
+        let mut mmu = MemoryManagementUnit::new(Cartridge::new(rom).unwrap());
+        mmu.write_byte(0x0000, 0x0A);
+        mmu.write_byte(0xA000, 0x5A);
+        mmu.write_byte(0xC000, 0xC3);
+        mmu.write_byte(0xFF51, 0xE0);
+        mmu.write_byte(0xFF52, 0x00);
+        mmu.write_byte(0xFF53, 0x81);
+        mmu.write_byte(0xFF54, 0x00);
+        mmu.write_byte(0xFF55, 0x00);
+        assert_eq!(mmu.read_byte(0x8100), 0x5A);
+    }
+
+    #[test]
+    fn oam_dma_takes_160_cycles_and_blocks_the_bus() {
+        let rom = test_rom(0x00, 0x00, 0x00);
+        let mut mmu = MemoryManagementUnit::new(Cartridge::new(rom).unwrap());
+        for i in 0..0xA0 {
+            mmu.write_byte(0xC000 + i, i as u8 + 1);
+        }
+        mmu.write_byte(0xFF46, 0xC0);
+        assert_eq!(mmu.read_byte(0xFF46), 0xC0);
+        // The rest of the instruction that wrote to 0xFF46.
+        mmu.do_cycle(12);
+        assert_eq!(mmu.read_byte(0xFE00), 0);
+
+        // The first byte is copied one M-cycle after the write.
+        mmu.do_cycle(8);
+        assert_eq!(mmu.read_byte(0xFE00), 0xFF);
+        // WRAM is on the bus being used, HRAM isn't.
+        assert_eq!(mmu.read_byte(0xC080), 2);
+        mmu.write_byte(0xFF80, 0x42);
+        assert_eq!(mmu.read_byte(0xFF80), 0x42);
+
+        mmu.do_cycle(158 * 4);
+        assert_eq!(mmu.read_byte(0xFE00), 0xFF);
+        mmu.do_cycle(4);
+        assert_eq!(mmu.read_byte(0xFE00), 1);
+        assert_eq!(mmu.read_byte(0xFE9F), 0xA0);
+        assert_eq!(mmu.read_byte(0xC080), 0x81);
+    }
+}
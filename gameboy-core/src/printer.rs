@@ -0,0 +1,427 @@
+// Game Boy Printer, plugged into the serial port.
+//
+// The Game Boy sends packets with the internal clock, and the printer answers
+// each byte. A packet is made of:
+//
+//   0x88 0x33         - magic bytes
+//   command           - 0x01 init, 0x02 print, 0x04 data, 0x08 break, 0x0F status
+//   compression       - 1 if the data is run-length encoded
+//   length            - 16-bit little endian length of the data
+//   data              - image data, or the print settings
+//   checksum          - 16-bit little endian sum of the bytes from the command
+//   0x00 0x00         - the printer answers 0x81 (alive) and its status
+//
+// Image data is 2bpp tiles, 20 per row. A print command renders everything
+// received since the last one using its palette, the same format as BGP.
+
+use crate::link::LinkCable;
+use std::sync::{Arc, Mutex};
+
+// Status bits.
+const CHECKSUM_ERROR: u8 = 0x01;
+const PRINTING: u8 = 0x02;
+const DATA_FULL: u8 = 0x04;
+const UNPROCESSED: u8 = 0x08;
+
+// The printer buffers up to 9 data packets of 2 tile rows (16 lines) each.
+const BUFFER_SIZE: usize = 9 * 640;
+
+/// A printout, 160 pixels wide.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrintedImage {
+    /// Height in pixels.
+    pub height: usize,
+    /// Shades from 0 (white) to 3 (black), row by row.
+    pub pixels: Vec<u8>,
+}
+
+impl PrintedImage {
+    /// Width in pixels.
+    pub const WIDTH: usize = 160;
+
+    /// Encodes the image as an 8-bit greyscale PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        const GREYS: [u8; 4] = [0xFF, 0xAA, 0x55, 0x00];
+        let mut raw = Vec::with_capacity((Self::WIDTH + 1) * self.height);
+        for row in self.pixels.chunks(Self::WIDTH) {
+            // No filter.
+            raw.push(0);
+            raw.extend(row.iter().map(|&shade| GREYS[shade as usize & 0x03]));
+        }
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend((Self::WIDTH as u32).to_be_bytes());
+        ihdr.extend((self.height as u32).to_be_bytes());
+        // 8 bits per pixel, greyscale, deflate, no filter, no interlace.
+        ihdr.extend([8, 0, 0, 0, 0]);
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut png, b"IHDR", &ihdr);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Magic1,
+    Magic2,
+    Command,
+    Compression,
+    LengthLow,
+    LengthHigh,
+    Data,
+    ChecksumLow,
+    ChecksumHigh,
+    Alive,
+    Status,
+}
+
+struct Inner {
+    state: State,
+    command: u8,
+    compressed: bool,
+    length: u16,
+    packet: Vec<u8>,
+    checksum: u16,
+    received_checksum: u16,
+    status: u8,
+    buffer: Vec<u8>,
+    images: Vec<PrintedImage>,
+}
+
+/// A Game Boy Printer. Clones share the same printer, so keep one to collect
+/// the printouts.
+#[derive(Clone)]
+pub struct Printer {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Default for Printer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Printer {
+    /// A printer with no paper out yet.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Inner {
+                state: State::Magic1,
+                command: 0,
+                compressed: false,
+                length: 0,
+                packet: Vec::new(),
+                checksum: 0,
+                received_checksum: 0,
+                status: 0,
+                buffer: Vec::new(),
+                images: Vec::new(),
+            })),
+        }
+    }
+    /// Everything printed so far.
+    pub fn images(&self) -> Vec<PrintedImage> {
+        self.inner.lock().unwrap().images.clone()
+    }
+    /// Removes and returns everything printed so far.
+    pub fn take_images(&self) -> Vec<PrintedImage> {
+        std::mem::take(&mut self.inner.lock().unwrap().images)
+    }
+}
+
+impl LinkCable for Printer {
+    fn send(&mut self, byte: u8) -> Option<u8> {
+        Some(self.inner.lock().unwrap().receive_byte(byte))
+    }
+    fn receive(&mut self, _reply: u8) -> Option<u8> {
+        None
+    }
+}
+
+impl Inner {
+    // Takes a byte of the packet and returns the answer shifted back.
+    fn receive_byte(&mut self, byte: u8) -> u8 {
+        let mut reply = 0x00;
+        self.state = match self.state {
+            State::Magic1 if byte == 0x88 => State::Magic2,
+            State::Magic1 => State::Magic1,
+            State::Magic2 if byte == 0x33 => {
+                self.checksum = 0;
+                self.packet.clear();
+                State::Command
+            }
+            State::Magic2 => State::Magic1,
+            State::Command => {
+                self.command = byte;
+                self.checksum = byte as u16;
+                State::Compression
+            }
+            State::Compression => {
+                self.compressed = byte & 0x01 == 0x01;
+                self.checksum += byte as u16;
+                State::LengthLow
+            }
+            State::LengthLow => {
+                self.length = byte as u16;
+                self.checksum += byte as u16;
+                State::LengthHigh
+            }
+            State::LengthHigh => {
+                self.length |= (byte as u16) << 8;
+                self.checksum += byte as u16;
+                match self.length {
+                    0 => State::ChecksumLow,
+                    _ => State::Data,
+                }
+            }
+            State::Data => {
+                self.packet.push(byte);
+                self.checksum = self.checksum.wrapping_add(byte as u16);
+                match self.packet.len() == self.length as usize {
+                    true => State::ChecksumLow,
+                    false => State::Data,
+                }
+            }
+            State::ChecksumLow => {
+                self.received_checksum = byte as u16;
+                State::ChecksumHigh
+            }
+            State::ChecksumHigh => {
+                self.received_checksum |= (byte as u16) << 8;
+                if self.received_checksum == self.checksum {
+                    self.status &= !CHECKSUM_ERROR;
+                    self.run_command();
+                } else {
+                    self.status |= CHECKSUM_ERROR;
+                }
+                State::Alive
+            }
+            State::Alive => {
+                reply = 0x81;
+                State::Status
+            }
+            State::Status => {
+                reply = self.status;
+                // Printing is instant, but report it once so games see it start.
+                self.status &= !PRINTING;
+                State::Magic1
+            }
+        };
+        reply
+    }
+
+    fn run_command(&mut self) {
+        match self.command {
+            0x01 => {
+                self.buffer.clear();
+                self.status = 0;
+            }
+            0x02 if self.packet.len() >= 3 => {
+                self.print(self.packet[2]);
+                self.buffer.clear();
+                self.status = PRINTING;
+            }
+            0x04 => {
+                let data = std::mem::take(&mut self.packet);
+                if self.compressed {
+                    decompress(&data, &mut self.buffer);
+                } else {
+                    self.buffer.extend(&data);
+                }
+                self.buffer.truncate(BUFFER_SIZE);
+                self.status &= !(UNPROCESSED | DATA_FULL);
+                if !self.buffer.is_empty() {
+                    self.status |= UNPROCESSED;
+                }
+                if self.buffer.len() >= BUFFER_SIZE {
+                    self.status |= DATA_FULL;
+                }
+            }
+            0x08 => {
+                self.buffer.clear();
+                self.status &= !(UNPROCESSED | DATA_FULL);
+            }
+            _ => {}
+        }
+    }
+
+    fn print(&mut self, palette: u8) {
+        // A palette of 0 means the default one.
+        let palette = if palette == 0 { 0xE4 } else { palette };
+        // 20 tiles of 16 bytes for every 8 lines.
+        let height = self.buffer.len() / 320 * 8;
+        let mut pixels = Vec::with_capacity(PrintedImage::WIDTH * height);
+        for y in 0..height {
+            for x in 0..PrintedImage::WIDTH {
+                let tile = (y / 8) * 20 + x / 8;
+                let offset = tile * 16 + (y % 8) * 2;
+                let (b1, b2) = (self.buffer[offset], self.buffer[offset + 1]);
+                let xbit = 7 - (x % 8);
+                let colnr = (b1 >> xbit) & 1 | ((b2 >> xbit) & 1) << 1;
+                pixels.push((palette >> (colnr * 2)) & 0x03);
+            }
+        }
+        self.images.push(PrintedImage { height, pixels });
+    }
+}
+
+// Run-length encoding: a control byte with bit 7 set repeats the next byte
+// (control & 0x7F) + 2 times, otherwise (control + 1) bytes follow as is.
+fn decompress(data: &[u8], out: &mut Vec<u8>) {
+    let mut i = 0;
+    while i < data.len() {
+        let control = data[i];
+        i += 1;
+        if control & 0x80 == 0x80 {
+            if let Some(&byte) = data.get(i) {
+                out.extend(std::iter::repeat(byte).take((control & 0x7F) as usize + 2));
+            }
+            i += 1;
+        } else {
+            let end = (i + control as usize + 1).min(data.len());
+            out.extend(&data[i..end]);
+            i = end;
+        }
+    }
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+// A zlib stream made of uncompressed deflate blocks, which is enough for the
+// small images the printer makes.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xFFFF).peekable();
+    if blocks.peek().is_none() {
+        out.extend([0x01, 0x00, 0x00, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(blocks.peek().is_none() as u8);
+        out.extend((block.len() as u16).to_le_bytes());
+        out.extend((!(block.len() as u16)).to_le_bytes());
+        out.extend(block);
+    }
+
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    out.extend((b << 16 | a).to_be_bytes());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PrintedImage, Printer};
+    use crate::link::LinkCable;
+
+    // Sends a packet and returns the two bytes answered at its end.
+    fn send_packet(
+        printer: &mut Printer,
+        command: u8,
+        compression: u8,
+        data: &[u8],
+    ) -> (u8, u8) {
+        let length = (data.len() as u16).to_le_bytes();
+        let mut packet = vec![0x88, 0x33, command, compression, length[0], length[1]];
+        packet.extend(data);
+        let checksum = packet[2..]
+            .iter()
+            .fold(0u16, |sum, &b| sum.wrapping_add(b as u16));
+        packet.extend(checksum.to_le_bytes());
+        packet.extend([0x00, 0x00]);
+
+        let replies: Vec<u8> = packet.iter().map(|&b| printer.send(b).unwrap()).collect();
+        (replies[replies.len() - 2], replies[replies.len() - 1])
+    }
+
+    #[test]
+    fn prints_received_tiles() {
+        let mut printer = Printer::new();
+        assert_eq!(send_packet(&mut printer, 0x01, 0, &[]), (0x81, 0x00));
+
+        // First tile row: colour 3 in the first line of each tile.
+        let mut band = vec![0; 640];
+        for tile in 0..20 {
+            band[tile * 16] = 0xFF;
+            band[tile * 16 + 1] = 0xFF;
+        }
+        assert_eq!(send_packet(&mut printer, 0x04, 0, &band), (0x81, 0x08));
+        // Second band, all colour 1 and compressed: 640 bytes alternating
+        // 0xFF and 0x00 in runs of 2 bytes.
+        let compressed: Vec<u8> = (0..320).flat_map(|_| [0x01, 0xFF, 0x00]).collect();
+        assert_eq!(
+            send_packet(&mut printer, 0x04, 1, &compressed),
+            (0x81, 0x08)
+        );
+        assert_eq!(send_packet(&mut printer, 0x04, 0, &[]), (0x81, 0x08));
+
+        // One sheet, no margins, palette 0xE4, default exposure.
+        assert_eq!(
+            send_packet(&mut printer, 0x02, 0, &[1, 0, 0xE4, 0x40]).1,
+            0x02
+        );
+        assert_eq!(send_packet(&mut printer, 0x0F, 0, &[]), (0x81, 0x00));
+
+        let images = printer.take_images();
+        assert_eq!(images.len(), 1);
+        let image = &images[0];
+        assert_eq!(image.height, 32);
+        assert_eq!(image.pixels[0], 3);
+        assert_eq!(image.pixels[PrintedImage::WIDTH - 1], 3);
+        assert_eq!(image.pixels[PrintedImage::WIDTH], 0);
+        assert_eq!(image.pixels[16 * PrintedImage::WIDTH], 1);
+        assert!(printer.images().is_empty());
+    }
+
+    #[test]
+    fn reports_checksum_errors() {
+        let mut printer = Printer::new();
+        let packet = [0x88, 0x33, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+        let replies: Vec<u8> = packet.iter().map(|&b| printer.send(b).unwrap()).collect();
+        assert_eq!(replies[8..], [0x81, 0x01]);
+    }
+
+    #[test]
+    fn encodes_png() {
+        let image = PrintedImage {
+            height: 2,
+            pixels: vec![0; 2 * PrintedImage::WIDTH],
+        };
+        let png = image.to_png();
+        assert_eq!(png[..8], *b"\x89PNG\r\n\x1a\n");
+        // IHDR: 160x2.
+        assert_eq!(png[16..24], [0, 0, 0, 160, 0, 0, 0, 2]);
+        // IEND and its well-known CRC.
+        assert_eq!(
+            png[png.len() - 12..],
+            [0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]
+        );
+    }
+}
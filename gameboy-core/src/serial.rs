@@ -0,0 +1,201 @@
+// Serial port, shifting a byte in and out over the link cable.
+//
+// FF01 - SB - Serial transfer data
+// FF02 - SC - Serial Transfer Control
+//   Bit 7 - Transfer Start Flag (1=Transfer in progress, or requested)
+//   Bit 1 - Clock Speed (CGB only, 0=Normal, 1=Fast)
+//   Bit 0 - Shift Clock (0=External Clock, 1=Internal Clock)
+//
+// With the internal clock a bit is shifted every 512 cycles (8192 Hz), or 16
+// cycles with the CGB fast clock. The serial interrupt is requested once all
+// 8 bits have been shifted. With the external clock the other Game Boy drives
+// the transfer, which may never happen. Once it does, the byte it sent is
+// shifted in at the normal speed like with the internal clock.
+
+use crate::link::LinkCable;
+
+// How often to look for a transfer clocked by the other side, which is also
+// the bit period of that transfer.
+const POLL_INTERVAL: u32 = 512;
+
+pub struct Serial {
+    data: u8,
+    control: u8,
+    cgb: bool,
+    // Byte received from the other side, shifted into SB a bit at a time.
+    incoming: u8,
+    bits: u8,
+    clock: u32,
+    pub cable: Option<Box<dyn LinkCable>>,
+    pub interrupt: u8,
+}
+
+impl Serial {
+    pub fn new(cgb: bool) -> Serial {
+        Serial {
+            data: 0,
+            control: 0,
+            cgb,
+            incoming: 0,
+            bits: 0,
+            clock: 0,
+            cable: None,
+            interrupt: 0,
+        }
+    }
+
+    pub fn do_cycle(&mut self, ticks: u32) {
+        self.clock += ticks;
+        if self.internal_transfer() {
+            while self.bits > 0 && self.clock >= self.period() {
+                self.clock -= self.period();
+                self.shift();
+            }
+            if self.bits == 0 {
+                self.finish();
+            }
+        } else if self.bits > 0 {
+            while self.bits > 0 && self.clock >= POLL_INTERVAL {
+                self.clock -= POLL_INTERVAL;
+                self.shift();
+            }
+            if self.bits == 0 {
+                self.finish();
+            }
+        } else if self.clock >= POLL_INTERVAL {
+            self.clock %= POLL_INTERVAL;
+            let received = match &mut self.cable {
+                Some(cable) => cable.receive(self.data),
+                None => None,
+            };
+            // The other side doesn't wait for us, but only a transfer we
+            // requested ends up in SB.
+            if let Some(value) = received {
+                if self.control & 0x80 == 0x80 {
+                    self.incoming = value;
+                    self.bits = 8;
+                }
+            }
+        }
+    }
+
+    // Ticks until the transfer in progress completes.
+    pub fn next_event(&self) -> u32 {
+        if self.internal_transfer() {
+            (self.bits as u32 * self.period()).saturating_sub(self.clock)
+        } else if self.bits > 0 {
+            (self.bits as u32 * POLL_INTERVAL).saturating_sub(self.clock)
+        } else if self.control & 0x80 == 0x80 && self.cable.is_some() {
+            POLL_INTERVAL.saturating_sub(self.clock).max(1)
+        } else {
+            u32::MAX
+        }
+    }
+
+    pub fn read_byte(&self, a: u16) -> u8 {
+        match a {
+            0xFF01 => self.data,
+            0xFF02 if self.cgb => 0x7C | self.control,
+            0xFF02 => 0x7E | self.control,
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_byte(&mut self, a: u16, v: u8) {
+        match a {
+            0xFF01 => self.data = v,
+            0xFF02 => {
+                self.control = v & if self.cgb { 0x83 } else { 0x81 };
+                self.clock = 0;
+                self.bits = 0;
+                if self.internal_transfer() {
+                    self.incoming = match &mut self.cable {
+                        Some(cable) => cable.send(self.data).unwrap_or(0xFF),
+                        None => 0xFF,
+                    };
+                    self.bits = 8;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn internal_transfer(&self) -> bool {
+        self.control & 0x81 == 0x81
+    }
+
+    fn period(&self) -> u32 {
+        if self.control & 0x02 == 0x02 {
+            16
+        } else {
+            512
+        }
+    }
+
+    fn shift(&mut self) {
+        self.data = self.data << 1 | self.incoming >> 7;
+        self.incoming <<= 1;
+        self.bits -= 1;
+    }
+
+    fn finish(&mut self) {
+        self.control &= 0x7F;
+        self.bits = 0;
+        self.interrupt |= 0x08;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Serial;
+    use crate::link::{Capture, Loopback};
+
+    #[test]
+    fn internal_clock_shifts_a_bit_every_512_cycles() {
+        let mut serial = Serial::new(false);
+        serial.cable = Some(Box::new(Loopback));
+        serial.write_byte(0xFF01, 0xA5);
+        serial.write_byte(0xFF02, 0x81);
+        assert_eq!(serial.next_event(), 8 * 512);
+
+        serial.do_cycle(512);
+        assert_eq!(serial.read_byte(0xFF01), 0x4B);
+        serial.do_cycle(7 * 512 - 1);
+        assert_eq!(serial.interrupt, 0);
+        assert_eq!(serial.read_byte(0xFF02), 0xFF);
+
+        serial.do_cycle(1);
+        assert_eq!(serial.interrupt, 0x08);
+        assert_eq!(serial.read_byte(0xFF01), 0xA5);
+        assert_eq!(serial.read_byte(0xFF02), 0x7F);
+    }
+
+    #[test]
+    fn unplugged_transfers_read_0xff() {
+        let mut serial = Serial::new(false);
+        serial.write_byte(0xFF01, 0x12);
+        serial.write_byte(0xFF02, 0x81);
+        serial.do_cycle(8 * 512);
+        assert_eq!(serial.read_byte(0xFF01), 0xFF);
+        assert_eq!(serial.interrupt, 0x08);
+
+        // Nothing ever clocks an external transfer.
+        serial.interrupt = 0;
+        serial.write_byte(0xFF02, 0x80);
+        serial.do_cycle(100_000);
+        assert_eq!(serial.interrupt, 0);
+    }
+
+    #[test]
+    fn capture_collects_the_bytes_sent() {
+        let capture = Capture::new();
+        let mut serial = Serial::new(false);
+        serial.cable = Some(Box::new(capture.clone()));
+        for &byte in b"Passed" {
+            serial.write_byte(0xFF01, byte);
+            serial.write_byte(0xFF02, 0x81);
+            serial.do_cycle(8 * 512);
+        }
+        assert_eq!(capture.text(), "Passed");
+    }
+}
@@ -0,0 +1,815 @@
+// Audio Processing Unit
+//
+// Four channels are mixed into a stereo output:
+//   Channel 1 - Square wave with frequency sweep (FF10-FF14)
+//   Channel 2 - Square wave (FF16-FF19)
+//   Channel 3 - Programmable wave from wave RAM (FF1A-FF1E, FF30-FF3F)
+//   Channel 4 - Noise from a linear feedback shift register (FF20-FF23)
+//
+// FF24 - NR50 - Master volume for the left (bits 6-4) and right (bits 2-0) outputs
+// FF25 - NR51 - Panning, left (bits 7-4) and right (bits 3-0) per channel
+// FF26 - NR52 - Power (bit 7) and channel status (bits 3-0, read only)
+//
+// A frame sequencer clocked at 512 Hz drives the length counters (256 Hz), the
+// frequency sweep (128 Hz) and the volume envelopes (64 Hz).
+
+const CPU_CLOCK: u32 = 4_194_304;
+const FRAME_SEQUENCER_PERIOD: u32 = CPU_CLOCK / 512;
+
+// Bits that always read back as 1, for FF10-FF2F.
+const READ_MASK: [u8; 0x20] = [
+    0x80, 0x3F, 0x00, 0xFF, 0xBF, 0xFF, 0x3F, 0x00, 0xFF, 0xBF, 0x7F, 0xFF, 0x9F, 0xFF,
+    0xBF, 0xFF, 0xFF, 0x00, 0x00, 0xBF, 0x00, 0x00, 0x70, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+    0xFF, 0xFF, 0xFF, 0xFF,
+];
+
+const DUTY: [[u8; 8]; 4] = [
+    [0, 0, 0, 0, 0, 0, 0, 1],
+    [1, 0, 0, 0, 0, 0, 0, 1],
+    [1, 0, 0, 0, 0, 1, 1, 1],
+    [0, 1, 1, 1, 1, 1, 1, 0],
+];
+
+struct LengthCounter {
+    enabled: bool,
+    value: u16,
+    max: u16,
+}
+
+impl LengthCounter {
+    fn new(max: u16) -> LengthCounter {
+        LengthCounter {
+            enabled: false,
+            value: 0,
+            max,
+        }
+    }
+
+    fn load(&mut self, length: u16) {
+        self.value = self.max - length;
+    }
+
+    fn trigger(&mut self) {
+        if self.value == 0 {
+            self.value = self.max;
+        }
+    }
+
+    // Returns false once the counter expires and the channel must be disabled.
+    fn clock(&mut self) -> bool {
+        if self.enabled && self.value > 0 {
+            self.value -= 1;
+            return self.value != 0;
+        }
+        true
+    }
+}
+
+struct VolumeEnvelope {
+    initial: u8,
+    increase: bool,
+    period: u8,
+    volume: u8,
+    timer: u8,
+}
+
+impl VolumeEnvelope {
+    fn new() -> VolumeEnvelope {
+        VolumeEnvelope {
+            initial: 0,
+            increase: false,
+            period: 0,
+            volume: 0,
+            timer: 0,
+        }
+    }
+
+    fn write(&mut self, v: u8) {
+        self.initial = v >> 4;
+        self.increase = v & 0x08 == 0x08;
+        self.period = v & 0x07;
+    }
+
+    fn dac_enabled(&self) -> bool {
+        self.initial != 0 || self.increase
+    }
+
+    fn trigger(&mut self) {
+        self.volume = self.initial;
+        self.timer = self.period;
+    }
+
+    fn clock(&mut self) {
+        if self.period == 0 {
+            return;
+        }
+        if self.timer > 0 {
+            self.timer -= 1;
+        }
+        if self.timer == 0 {
+            self.timer = self.period;
+            if self.increase && self.volume < 15 {
+                self.volume += 1;
+            } else if !self.increase && self.volume > 0 {
+                self.volume -= 1;
+            }
+        }
+    }
+}
+
+struct Sweep {
+    period: u8,
+    negate: bool,
+    shift: u8,
+    timer: u8,
+    enabled: bool,
+    shadow: u16,
+    negated: bool,
+}
+
+impl Sweep {
+    fn new() -> Sweep {
+        Sweep {
+            period: 0,
+            negate: false,
+            shift: 0,
+            timer: 0,
+            enabled: false,
+            shadow: 0,
+            negated: false,
+        }
+    }
+
+    fn reload_timer(&mut self) {
+        self.timer = if self.period == 0 { 8 } else { self.period };
+    }
+
+    fn calculate(&mut self) -> u16 {
+        let delta = self.shadow >> self.shift;
+        if self.negate {
+            self.negated = true;
+            self.shadow.wrapping_sub(delta)
+        } else {
+            self.shadow + delta
+        }
+    }
+}
+
+struct SquareChannel {
+    on: bool,
+    duty: u8,
+    phase: u8,
+    frequency: u16,
+    timer: u32,
+    length: LengthCounter,
+    envelope: VolumeEnvelope,
+    sweep: Option<Sweep>,
+}
+
+impl SquareChannel {
+    fn new(with_sweep: bool) -> SquareChannel {
+        SquareChannel {
+            on: false,
+            duty: 0,
+            phase: 0,
+            frequency: 0,
+            timer: 0,
+            length: LengthCounter::new(64),
+            envelope: VolumeEnvelope::new(),
+            sweep: if with_sweep { Some(Sweep::new()) } else { None },
+        }
+    }
+
+    fn period(&self) -> u32 {
+        (2048 - self.frequency as u32) * 4
+    }
+
+    fn write(&mut self, register: u16, v: u8) {
+        match register {
+            0 => {
+                if let Some(sweep) = self.sweep.as_mut() {
+                    sweep.period = (v >> 4) & 0x07;
+                    sweep.shift = v & 0x07;
+                    let was_negated = sweep.negated;
+                    sweep.negate = v & 0x08 == 0x08;
+                    if was_negated && !sweep.negate {
+                        self.on = false;
+                    }
+                }
+            }
+            1 => {
+                self.duty = v >> 6;
+                self.length.load((v & 0x3F) as u16);
+            }
+            2 => {
+                self.envelope.write(v);
+                if !self.envelope.dac_enabled() {
+                    self.on = false;
+                }
+            }
+            3 => self.frequency = (self.frequency & 0x0700) | v as u16,
+            4 => {
+                self.frequency = (self.frequency & 0x00FF) | (((v & 0x07) as u16) << 8);
+                self.length.enabled = v & 0x40 == 0x40;
+                if v & 0x80 == 0x80 {
+                    self.trigger();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn trigger(&mut self) {
+        self.on = self.envelope.dac_enabled();
+        self.length.trigger();
+        self.timer = self.period();
+        self.envelope.trigger();
+
+        let frequency = self.frequency;
+        if let Some(sweep) = self.sweep.as_mut() {
+            sweep.shadow = frequency;
+            sweep.negated = false;
+            sweep.reload_timer();
+            sweep.enabled = sweep.period != 0 || sweep.shift != 0;
+            if sweep.shift != 0 && sweep.calculate() > 2047 {
+                self.on = false;
+            }
+        }
+    }
+
+    fn clock_sweep(&mut self) {
+        let Some(sweep) = self.sweep.as_mut() else {
+            return;
+        };
+        if sweep.timer > 0 {
+            sweep.timer -= 1;
+        }
+        if sweep.timer != 0 {
+            return;
+        }
+        sweep.reload_timer();
+        if !sweep.enabled || sweep.period == 0 {
+            return;
+        }
+
+        let frequency = sweep.calculate();
+        if frequency > 2047 {
+            self.on = false;
+        } else if sweep.shift != 0 {
+            sweep.shadow = frequency;
+            self.frequency = frequency;
+            if sweep.calculate() > 2047 {
+                self.on = false;
+            }
+        }
+    }
+
+    fn clock_length(&mut self) {
+        if !self.length.clock() {
+            self.on = false;
+        }
+    }
+
+    fn step(&mut self, ticks: u32) {
+        if !self.on {
+            return;
+        }
+        let mut ticks = ticks;
+        while ticks >= self.timer {
+            ticks -= self.timer;
+            self.timer = self.period();
+            self.phase = (self.phase + 1) & 0x07;
+        }
+        self.timer -= ticks;
+    }
+
+    fn dac_enabled(&self) -> bool {
+        self.envelope.dac_enabled()
+    }
+
+    fn output(&self) -> u8 {
+        if !self.on {
+            return 0;
+        }
+        DUTY[self.duty as usize][self.phase as usize] * self.envelope.volume
+    }
+}
+
+struct WaveChannel {
+    on: bool,
+    dac: bool,
+    volume_code: u8,
+    frequency: u16,
+    timer: u32,
+    position: u8,
+    length: LengthCounter,
+    ram: [u8; 16],
+}
+
+impl WaveChannel {
+    fn new() -> WaveChannel {
+        WaveChannel {
+            on: false,
+            dac: false,
+            volume_code: 0,
+            frequency: 0,
+            timer: 0,
+            position: 0,
+            length: LengthCounter::new(256),
+            ram: [0; 16],
+        }
+    }
+
+    fn period(&self) -> u32 {
+        (2048 - self.frequency as u32) * 2
+    }
+
+    fn write(&mut self, register: u16, v: u8) {
+        match register {
+            0 => {
+                self.dac = v & 0x80 == 0x80;
+                if !self.dac {
+                    self.on = false;
+                }
+            }
+            1 => self.length.load(v as u16),
+            2 => self.volume_code = (v >> 5) & 0x03,
+            3 => self.frequency = (self.frequency & 0x0700) | v as u16,
+            4 => {
+                self.frequency = (self.frequency & 0x00FF) | (((v & 0x07) as u16) << 8);
+                self.length.enabled = v & 0x40 == 0x40;
+                if v & 0x80 == 0x80 {
+                    self.on = self.dac;
+                    self.length.trigger();
+                    self.timer = self.period();
+                    self.position = 0;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn clock_length(&mut self) {
+        if !self.length.clock() {
+            self.on = false;
+        }
+    }
+
+    fn step(&mut self, ticks: u32) {
+        if !self.on {
+            return;
+        }
+        let mut ticks = ticks;
+        while ticks >= self.timer {
+            ticks -= self.timer;
+            self.timer = self.period();
+            self.position = (self.position + 1) & 0x1F;
+        }
+        self.timer -= ticks;
+    }
+
+    fn output(&self) -> u8 {
+        if !self.on {
+            return 0;
+        }
+        let byte = self.ram[self.position as usize / 2];
+        let sample = if self.position & 0x01 == 0 {
+            byte >> 4
+        } else {
+            byte & 0x0F
+        };
+        match self.volume_code {
+            0 => 0,
+            n => sample >> (n - 1),
+        }
+    }
+}
+
+struct NoiseChannel {
+    on: bool,
+    shift: u8,
+    narrow: bool,
+    divisor: u8,
+    timer: u32,
+    lfsr: u16,
+    length: LengthCounter,
+    envelope: VolumeEnvelope,
+}
+
+impl NoiseChannel {
+    fn new() -> NoiseChannel {
+        NoiseChannel {
+            on: false,
+            shift: 0,
+            narrow: false,
+            divisor: 0,
+            timer: 0,
+            lfsr: 0x7FFF,
+            length: LengthCounter::new(64),
+            envelope: VolumeEnvelope::new(),
+        }
+    }
+
+    fn period(&self) -> u32 {
+        let divisor = match self.divisor {
+            0 => 8,
+            n => (n as u32) << 4,
+        };
+        divisor << self.shift
+    }
+
+    fn write(&mut self, register: u16, v: u8) {
+        match register {
+            1 => self.length.load((v & 0x3F) as u16),
+            2 => {
+                self.envelope.write(v);
+                if !self.envelope.dac_enabled() {
+                    self.on = false;
+                }
+            }
+            3 => {
+                self.shift = v >> 4;
+                self.narrow = v & 0x08 == 0x08;
+                self.divisor = v & 0x07;
+            }
+            4 => {
+                self.length.enabled = v & 0x40 == 0x40;
+                if v & 0x80 == 0x80 {
+                    self.on = self.envelope.dac_enabled();
+                    self.length.trigger();
+                    self.timer = self.period();
+                    self.envelope.trigger();
+                    self.lfsr = 0x7FFF;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn clock_length(&mut self) {
+        if !self.length.clock() {
+            self.on = false;
+        }
+    }
+
+    fn step(&mut self, ticks: u32) {
+        // The LFSR isn't clocked at all with a clock shift of 14 or 15.
+        if !self.on || self.shift >= 14 {
+            return;
+        }
+        let mut ticks = ticks;
+        while ticks >= self.timer {
+            ticks -= self.timer;
+            self.timer = self.period();
+
+            let bit = (self.lfsr ^ (self.lfsr >> 1)) & 0x01;
+            self.lfsr = (self.lfsr >> 1) | (bit << 14);
+            if self.narrow {
+                self.lfsr = (self.lfsr & !0x40) | (bit << 6);
+            }
+        }
+        self.timer -= ticks;
+    }
+
+    fn dac_enabled(&self) -> bool {
+        self.envelope.dac_enabled()
+    }
+
+    fn output(&self) -> u8 {
+        if !self.on || self.lfsr & 0x01 == 0x01 {
+            return 0;
+        }
+        self.envelope.volume
+    }
+}
+
+pub struct Sound {
+    on: bool,
+    registers: [u8; 0x20],
+    channel1: SquareChannel,
+    channel2: SquareChannel,
+    channel3: WaveChannel,
+    channel4: NoiseChannel,
+    sequencer_step: u8,
+    sequencer_timer: u32,
+    sample_rate: u32,
+    sample_clock: u32,
+    charge: f32,
+    capacitor: [f32; 2],
+    samples: Vec<f32>,
+}
+
+impl Sound {
+    pub fn new(sample_rate: u32) -> Sound {
+        Sound {
+            on: true,
+            registers: [0; 0x20],
+            channel1: SquareChannel::new(true),
+            channel2: SquareChannel::new(false),
+            channel3: WaveChannel::new(),
+            channel4: NoiseChannel::new(),
+            sequencer_step: 0,
+            sequencer_timer: FRAME_SEQUENCER_PERIOD,
+            sample_rate,
+            sample_clock: 0,
+            charge: capacitor_charge(sample_rate),
+            capacitor: [0.0; 2],
+            samples: Vec::new(),
+        }
+    }
+
+    // A rate of 0 is taken as 1 Hz rather than dividing by zero.
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        let sample_rate = sample_rate.max(1);
+        self.sample_rate = sample_rate;
+        self.sample_clock = 0;
+        self.charge = capacitor_charge(sample_rate);
+        self.samples.clear();
+    }
+
+    // Interleaved stereo samples produced since the last call.
+    pub fn take_samples(&mut self) -> Vec<f32> {
+        std::mem::take(&mut self.samples)
+    }
+
+    pub fn do_cycle(&mut self, ticks: u32) {
+        let mut ticksleft = ticks;
+
+        while ticksleft > 0 {
+            let until_sample =
+                (CPU_CLOCK - self.sample_clock + self.sample_rate - 1) / self.sample_rate;
+            let curticks = ticksleft.min(until_sample).min(self.sequencer_timer);
+            ticksleft -= curticks;
+
+            if self.on {
+                self.channel1.step(curticks);
+                self.channel2.step(curticks);
+                self.channel3.step(curticks);
+                self.channel4.step(curticks);
+            }
+
+            self.sequencer_timer -= curticks;
+            if self.sequencer_timer == 0 {
+                self.sequencer_timer = FRAME_SEQUENCER_PERIOD;
+                if self.on {
+                    self.clock_sequencer();
+                }
+            }
+
+            self.sample_clock += curticks * self.sample_rate;
+            if self.sample_clock >= CPU_CLOCK {
+                self.sample_clock -= CPU_CLOCK;
+                self.mix();
+            }
+        }
+    }
+
+    fn clock_sequencer(&mut self) {
+        if self.sequencer_step & 0x01 == 0 {
+            self.channel1.clock_length();
+            self.channel2.clock_length();
+            self.channel3.clock_length();
+            self.channel4.clock_length();
+        }
+        if self.sequencer_step == 2 || self.sequencer_step == 6 {
+            self.channel1.clock_sweep();
+        }
+        if self.sequencer_step == 7 {
+            self.channel1.envelope.clock();
+            self.channel2.envelope.clock();
+            self.channel4.envelope.clock();
+        }
+        self.sequencer_step = (self.sequencer_step + 1) & 0x07;
+    }
+
+    fn mix(&mut self) {
+        // Keep at most one second of audio around when nobody is draining it.
+        if self.samples.len() >= self.sample_rate as usize * 2 {
+            return;
+        }
+
+        let outputs = [
+            dac(self.channel1.dac_enabled(), self.channel1.output()),
+            dac(self.channel2.dac_enabled(), self.channel2.output()),
+            dac(self.channel3.dac, self.channel3.output()),
+            dac(self.channel4.dac_enabled(), self.channel4.output()),
+        ];
+
+        let nr50 = self.registers[0x14];
+        let nr51 = self.registers[0x15];
+        let mut left = 0.0;
+        let mut right = 0.0;
+        if self.on {
+            for (i, output) in outputs.iter().enumerate() {
+                if nr51 & (0x10 << i) != 0 {
+                    left += output;
+                }
+                if nr51 & (0x01 << i) != 0 {
+                    right += output;
+                }
+            }
+        }
+        left *= (((nr50 >> 4) & 0x07) + 1) as f32 / 32.0;
+        right *= ((nr50 & 0x07) + 1) as f32 / 32.0;
+
+        let left = self.high_pass(0, left);
+        let right = self.high_pass(1, right);
+        self.samples.push(left);
+        self.samples.push(right);
+    }
+
+    // Removes the DC offset the way the output capacitor does on hardware.
+    fn high_pass(&mut self, side: usize, input: f32) -> f32 {
+        let output = input - self.capacitor[side];
+        self.capacitor[side] = input - output * self.charge;
+        output
+    }
+
+    pub fn read_byte(&self, a: u16) -> u8 {
+        match a {
+            0xFF26 => {
+                (if self.on { 0x80 } else { 0 })
+                    | 0x70
+                    | (if self.channel1.on { 0x01 } else { 0 })
+                    | (if self.channel2.on { 0x02 } else { 0 })
+                    | (if self.channel3.on { 0x04 } else { 0 })
+                    | (if self.channel4.on { 0x08 } else { 0 })
+            }
+            0xFF10..=0xFF2F => {
+                let index = (a - 0xFF10) as usize;
+                self.registers[index] | READ_MASK[index]
+            }
+            0xFF30..=0xFF3F => self.channel3.ram[(a - 0xFF30) as usize],
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_byte(&mut self, a: u16, v: u8) {
+        if a == 0xFF26 {
+            self.set_power(v & 0x80 == 0x80);
+            return;
+        }
+        if let 0xFF30..=0xFF3F = a {
+            self.channel3.ram[(a - 0xFF30) as usize] = v;
+            return;
+        }
+        if !self.on || !(0xFF10..=0xFF2F).contains(&a) {
+            return;
+        }
+
+        self.registers[(a - 0xFF10) as usize] = v;
+        match a {
+            0xFF10..=0xFF14 => self.channel1.write(a - 0xFF10, v),
+            0xFF15..=0xFF19 => self.channel2.write(a - 0xFF15, v),
+            0xFF1A..=0xFF1E => self.channel3.write(a - 0xFF1A, v),
+            0xFF1F..=0xFF23 => self.channel4.write(a - 0xFF1F, v),
+            _ => {}
+        }
+    }
+
+    fn set_power(&mut self, on: bool) {
+        if on == self.on {
+            return;
+        }
+        self.on = on;
+        if on {
+            self.sequencer_step = 0;
+            return;
+        }
+
+        let ram = self.channel3.ram;
+        self.registers = [0; 0x20];
+        self.channel1 = SquareChannel::new(true);
+        self.channel2 = SquareChannel::new(false);
+        self.channel3 = WaveChannel::new();
+        self.channel3.ram = ram;
+        self.channel4 = NoiseChannel::new();
+    }
+}
+
+fn capacitor_charge(sample_rate: u32) -> f32 {
+    0.999958f32.powf(CPU_CLOCK as f32 / sample_rate as f32)
+}
+
+fn dac(enabled: bool, output: u8) -> f32 {
+    if !enabled {
+        return 0.0;
+    }
+    output as f32 / 7.5 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Sound, FRAME_SEQUENCER_PERIOD};
+
+    const FRAME: u32 = 70224;
+
+    fn status(sound: &Sound) -> u8 {
+        sound.read_byte(0xFF26) & 0x0F
+    }
+
+    #[test]
+    fn length_counter_disables_the_channel() {
+        let mut sound = Sound::new(44100);
+        sound.write_byte(0xFF12, 0xF0);
+        // Length of 1, enabled on trigger.
+        sound.write_byte(0xFF11, 0x3F);
+        sound.write_byte(0xFF14, 0xC0);
+        assert_eq!(status(&sound), 0x01);
+        sound.do_cycle(FRAME_SEQUENCER_PERIOD - 1);
+        assert_eq!(status(&sound), 0x01);
+        sound.do_cycle(1);
+        assert_eq!(status(&sound), 0x00);
+    }
+
+    #[test]
+    fn sweep_overflow_disables_channel_1() {
+        let mut sound = Sound::new(44100);
+        // Period 1, shift 1, increasing from 0x500.
+        sound.write_byte(0xFF10, 0x11);
+        sound.write_byte(0xFF12, 0xF0);
+        sound.write_byte(0xFF13, 0x00);
+        sound.write_byte(0xFF14, 0x85);
+        // The sweep is clocked on step 2; 0x780 fits, the next one doesn't.
+        sound.do_cycle(3 * FRAME_SEQUENCER_PERIOD - 1);
+        assert_eq!(status(&sound), 0x01);
+        sound.do_cycle(1);
+        assert_eq!(status(&sound), 0x00);
+
+        // Overflowing right away disables it on trigger.
+        sound.write_byte(0xFF14, 0x87);
+        assert_eq!(status(&sound), 0x00);
+    }
+
+    #[test]
+    fn noise_stops_with_clock_shift_14_or_15() {
+        for (nr43, clocked) in [(0x00, true), (0xD0, true), (0xE0, false), (0xF7, false)]
+        {
+            let mut sound = Sound::new(44100);
+            sound.write_byte(0xFF21, 0xF0);
+            sound.write_byte(0xFF22, nr43);
+            sound.write_byte(0xFF23, 0x80);
+            sound.do_cycle(4 * FRAME);
+            assert_eq!(sound.channel4.lfsr != 0x7FFF, clocked, "NR43 {:#04x}", nr43);
+        }
+    }
+
+    #[test]
+    fn power_off_clears_registers_and_ignores_writes() {
+        let mut sound = Sound::new(44100);
+        sound.write_byte(0xFF24, 0x77);
+        sound.write_byte(0xFF17, 0xF0);
+        sound.write_byte(0xFF19, 0x80);
+        sound.write_byte(0xFF30, 0x12);
+        assert_eq!(sound.read_byte(0xFF26), 0xF2);
+
+        sound.write_byte(0xFF26, 0x00);
+        assert_eq!(sound.read_byte(0xFF26), 0x70);
+        assert_eq!(sound.read_byte(0xFF24), 0x00);
+        assert_eq!(sound.read_byte(0xFF17), 0x00);
+        sound.write_byte(0xFF24, 0x77);
+        assert_eq!(sound.read_byte(0xFF24), 0x00);
+        // Wave RAM is kept and stays writable.
+        assert_eq!(sound.read_byte(0xFF30), 0x12);
+
+        sound.write_byte(0xFF26, 0x80);
+        sound.write_byte(0xFF24, 0x77);
+        assert_eq!(sound.read_byte(0xFF24), 0x77);
+    }
+
+    #[test]
+    fn nr51_routes_channels_to_each_side() {
+        for (nr51, left, right) in [(0x02, false, true), (0x20, true, false)] {
+            let mut sound = Sound::new(44100);
+            sound.write_byte(0xFF24, 0x77);
+            sound.write_byte(0xFF25, nr51);
+            sound.write_byte(0xFF16, 0x80);
+            sound.write_byte(0xFF17, 0xF0);
+            sound.write_byte(0xFF19, 0x80);
+            sound.do_cycle(FRAME);
+            let samples = sound.take_samples();
+            let heard =
+                |side: usize| samples.iter().skip(side).step_by(2).any(|&s| s != 0.0);
+            assert_eq!(heard(0), left);
+            assert_eq!(heard(1), right);
+        }
+    }
+
+    #[test]
+    fn produces_samples_at_the_requested_rate() {
+        let mut sound = Sound::new(44100);
+        sound.do_cycle(FRAME);
+        // 70224 * 44100 / 4194304 = 738.4 stereo samples.
+        assert_eq!(sound.take_samples().len(), 2 * 738);
+        sound.do_cycle(FRAME);
+        assert_eq!(sound.take_samples().len(), 2 * 738);
+
+        sound.set_sample_rate(32768);
+        sound.do_cycle(FRAME);
+        assert_eq!(sound.take_samples().len(), 2 * 548);
+
+        // A rate of 0 must not divide by zero.
+        sound.set_sample_rate(0);
+        sound.do_cycle(FRAME);
+    }
+}
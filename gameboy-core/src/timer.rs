@@ -0,0 +1,185 @@
+// The timer is driven by a 16-bit system counter that increments every clock.
+// DIV exposes its upper 8 bits, and TIMA is incremented on the falling edge of
+// the counter bit selected by TAC (ANDed with the TAC enable bit). That is why
+// writing to DIV, or changing TAC, can also increment TIMA.
+//
+// FF04 - DIV  - Divider Register (writing any value resets it to 0)
+// FF05 - TIMA - Timer Counter (interrupt on overflow, then reloaded from TMA)
+// FF06 - TMA  - Timer Modulo
+// FF07 - TAC  - Timer Control
+//   Bit 2   - Timer Enable
+//   Bit 1-0 - Input Clock Select
+//             00: CPU Clock / 1024 (counter bit 9)
+//             01: CPU Clock / 16   (counter bit 3)
+//             10: CPU Clock / 64   (counter bit 5)
+//             11: CPU Clock / 256  (counter bit 7)
+
+pub struct Timer {
+    divider: u16,
+    counter: u8,
+    modulo: u8,
+    control: u8,
+    pub interrupt: u8,
+}
+
+impl Timer {
+    pub fn new() -> Timer {
+        Timer {
+            divider: 0,
+            counter: 0,
+            modulo: 0,
+            control: 0,
+            interrupt: 0,
+        }
+    }
+
+    pub fn do_cycle(&mut self, ticks: u32) {
+        for _ in 0..ticks {
+            let before = self.signal();
+            self.divider = self.divider.wrapping_add(1);
+            if before && !self.signal() {
+                self.increment();
+            }
+        }
+    }
+
+    pub fn read_byte(&self, a: u16) -> u8 {
+        match a {
+            0xFF04 => (self.divider >> 8) as u8,
+            0xFF05 => self.counter,
+            0xFF06 => self.modulo,
+            0xFF07 => 0xF8 | self.control,
+            _ => 0xFF,
+        }
+    }
+
+    pub fn write_byte(&mut self, a: u16, v: u8) {
+        let before = self.signal();
+        match a {
+            0xFF04 => self.divider = 0,
+            0xFF05 => self.counter = v,
+            0xFF06 => self.modulo = v,
+            0xFF07 => self.control = v & 0x07,
+            _ => {}
+        }
+        if before && !self.signal() {
+            self.increment();
+        }
+    }
+
+    // Ticks until TIMA overflows, if the timer is enabled.
+    pub fn next_event(&self) -> u32 {
+        if self.control & 0x04 == 0 {
+            return u32::MAX;
+        }
+        let period = 2 << self.bit();
+        let edge = period - (self.divider as u32 & (period - 1));
+        edge + (0xFF - self.counter as u32) * period
+    }
+
+    fn bit(&self) -> u32 {
+        match self.control & 0x03 {
+            0 => 9,
+            1 => 3,
+            2 => 5,
+            _ => 7,
+        }
+    }
+
+    fn signal(&self) -> bool {
+        self.control & 0x04 == 0x04 && self.divider & (1 << self.bit()) != 0
+    }
+
+    fn increment(&mut self) {
+        self.counter = match self.counter.checked_add(1) {
+            Some(n) => n,
+            None => {
+                self.interrupt |= 0x04;
+                self.modulo
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Timer;
+
+    fn timer_with_control(control: u8) -> Timer {
+        let mut timer = Timer::new();
+        timer.write_byte(0xFF07, control);
+        timer
+    }
+
+    fn assert_frequency(control: u8, period: u32) {
+        let mut timer = timer_with_control(control);
+
+        timer.do_cycle(period - 1);
+        assert_eq!(timer.read_byte(0xFF05), 0);
+        timer.do_cycle(1);
+        assert_eq!(timer.read_byte(0xFF05), 1);
+        timer.do_cycle(period * 9);
+        assert_eq!(timer.read_byte(0xFF05), 10);
+        assert_eq!(timer.interrupt, 0);
+    }
+
+    #[test]
+    fn tac_00_increments_every_1024_cycles() {
+        assert_frequency(0x04, 1024);
+    }
+
+    #[test]
+    fn tac_01_increments_every_16_cycles() {
+        assert_frequency(0x05, 16);
+    }
+
+    #[test]
+    fn tac_10_increments_every_64_cycles() {
+        assert_frequency(0x06, 64);
+    }
+
+    #[test]
+    fn tac_11_increments_every_256_cycles() {
+        assert_frequency(0x07, 256);
+    }
+
+    #[test]
+    fn disabled_timer_does_not_count() {
+        let mut timer = timer_with_control(0x01);
+        timer.do_cycle(4096);
+        assert_eq!(timer.read_byte(0xFF05), 0);
+        assert_eq!(timer.read_byte(0xFF04), 16);
+    }
+
+    #[test]
+    fn overflow_reloads_modulo_and_requests_interrupt() {
+        let mut timer = timer_with_control(0x05);
+        timer.write_byte(0xFF06, 0xAB);
+        timer.write_byte(0xFF05, 0xFF);
+
+        timer.do_cycle(16);
+        assert_eq!(timer.read_byte(0xFF05), 0xAB);
+        assert_eq!(timer.interrupt, 0x04);
+    }
+
+    #[test]
+    fn div_reset_on_high_bit_increments_tima() {
+        let mut timer = timer_with_control(0x05);
+        timer.do_cycle(8);
+        assert_eq!(timer.read_byte(0xFF05), 0);
+
+        timer.write_byte(0xFF04, 0x12);
+        assert_eq!(timer.read_byte(0xFF04), 0);
+        assert_eq!(timer.read_byte(0xFF05), 1);
+    }
+
+    #[test]
+    fn disabling_on_high_bit_increments_tima() {
+        let mut timer = timer_with_control(0x05);
+        timer.do_cycle(8);
+
+        timer.write_byte(0xFF07, 0x01);
+        assert_eq!(timer.read_byte(0xFF05), 1);
+        assert_eq!(timer.read_byte(0xFF07), 0xF9);
+    }
+}
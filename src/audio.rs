@@ -0,0 +1,91 @@
+// Audio output for the desktop frontend. Playback needs the `audio` feature,
+// which pulls in cpal (and the ALSA development headers on Linux). Without it
+// the emulator still runs, just silently.
+
+#[cfg(feature = "audio")]
+pub use output::AudioPlayer;
+
+#[cfg(not(feature = "audio"))]
+pub use silent::AudioPlayer;
+
+#[cfg(feature = "audio")]
+mod output {
+    use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    pub struct AudioPlayer {
+        buffer: Arc<Mutex<VecDeque<f32>>>,
+        sample_rate: u32,
+        _stream: cpal::Stream,
+    }
+
+    impl AudioPlayer {
+        pub fn new() -> Option<AudioPlayer> {
+            let device = cpal::default_host().default_output_device()?;
+            let config = device.default_output_config().ok()?;
+            let sample_rate = config.sample_rate().0;
+            let channels = config.channels() as usize;
+
+            let buffer = Arc::new(Mutex::new(VecDeque::new()));
+            let source = buffer.clone();
+            let stream = device
+                .build_output_stream(
+                    &config.into(),
+                    move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+                        let mut source = source.lock().unwrap();
+                        for frame in data.chunks_mut(channels) {
+                            let left = source.pop_front().unwrap_or(0.0);
+                            let right = source.pop_front().unwrap_or(0.0);
+                            for (i, sample) in frame.iter_mut().enumerate() {
+                                *sample = if i % 2 == 0 { left } else { right };
+                            }
+                        }
+                    },
+                    |err| eprintln!("audio stream error: {}", err),
+                    None,
+                )
+                .ok()?;
+            stream.play().ok()?;
+
+            Some(AudioPlayer {
+                buffer,
+                sample_rate,
+                _stream: stream,
+            })
+        }
+
+        pub fn sample_rate(&self) -> u32 {
+            self.sample_rate
+        }
+
+        pub fn push(&self, samples: &[f32]) {
+            let mut buffer = self.buffer.lock().unwrap();
+            buffer.extend(samples);
+            // Drop the oldest audio when emulation runs ahead of playback,
+            // keeping the latency under half a second.
+            let limit = self.sample_rate as usize;
+            if buffer.len() > limit {
+                let excess = buffer.len() - limit;
+                buffer.drain(..excess);
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "audio"))]
+mod silent {
+    pub struct AudioPlayer;
+
+    impl AudioPlayer {
+        pub fn new() -> Option<AudioPlayer> {
+            None
+        }
+
+        pub fn sample_rate(&self) -> u32 {
+            44100
+        }
+
+        pub fn push(&self, _samples: &[f32]) {}
+    }
+}
@@ -2,14 +2,19 @@ extern crate gl;
 extern crate glutin;
 extern crate libc;
 
-mod gameboy;
+mod audio;
+mod options;
 
-use gameboy::{Button, GameBoy};
-use std::io::Error;
+use audio::AudioPlayer;
+use gameboy_core::{Button, Capture, Cartridge, GameBoy, Printer, TcpCable};
+use options::{Link, Options};
+use std::path::Path;
+use std::process;
 
 use std::ffi::CString;
 use std::mem;
 use std::str;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use gl::types::*;
 use glutin::event::{ElementState, VirtualKeyCode};
@@ -31,26 +36,139 @@ pub struct Glcx {
     vao: GLuint,
 }
 
-#[inline]
-pub fn load_our_game_rom() -> Result<Vec<u8>, Error> {
-    use std::{fs::File, io::Read};
-    let mut rom = Vec::new();
-    let file = File::open("./rom/game.gb");
-    file.and_then(|mut f| f.read_to_end(&mut rom))?;
-    Ok(rom)
+// Battery RAM is flushed to disk every few seconds, in case we don't exit cleanly.
+const SAVE_INTERVAL_FRAMES: u32 = 300;
+
+fn fail(message: String) -> ! {
+    eprintln!("error: {}", message);
+    process::exit(1);
+}
+
+fn load_game(options: &Options) -> GameBoy {
+    let rom_data = std::fs::read(&options.rom).unwrap_or_else(|e| {
+        fail(format!("could not read {}: {}", options.rom.display(), e))
+    });
+    let cartridge = Cartridge::new(rom_data)
+        .unwrap_or_else(|e| fail(format!("{}: {}", options.rom.display(), e)));
+    if !cartridge.header_checksum_valid {
+        eprintln!(
+            "warning: {}: header checksum mismatch, a real Game Boy would not boot it",
+            options.rom.display()
+        );
+    }
+    let mut gb = GameBoy::with_cartridge(cartridge);
+
+    if let Some(path) = &options.boot_rom {
+        let boot_rom = std::fs::read(path).unwrap_or_else(|e| {
+            fail(format!("could not read {}: {}", path.display(), e))
+        });
+        gb.load_boot_rom(&boot_rom)
+            .unwrap_or_else(|e| fail(format!("{}: {}", path.display(), e)));
+    }
+    if let Some(palette) = options.palette {
+        gb.set_palette(palette);
+    }
+    gb
+}
+
+// Plugs the link cable in. Returns the printer, to collect what it prints.
+fn connect_link(gb: &mut GameBoy, link: &Link) -> Option<Printer> {
+    match link {
+        Link::Listen(address) => {
+            println!("waiting for a link cable on {}", address);
+            let cable = TcpCable::listen(address).unwrap_or_else(|e| {
+                fail(format!("could not listen on {}: {}", address, e))
+            });
+            gb.plug_link_cable(cable);
+        }
+        Link::Connect(address) => {
+            let cable = TcpCable::connect(address).unwrap_or_else(|e| {
+                fail(format!("could not connect to {}: {}", address, e))
+            });
+            gb.plug_link_cable(cable);
+        }
+        Link::Stdout => gb.plug_link_cable(Capture::stdout()),
+        Link::Printer(_) => {
+            let printer = Printer::new();
+            gb.plug_link_cable(printer.clone());
+            return Some(printer);
+        }
+    }
+    None
 }
 
-fn main() -> Result<(), Error> {
-    let rom_data = load_our_game_rom()?;
-    let mut gb = GameBoy::new(&rom_data);
+// Writes each printout to the directory as print-<n>.png.
+fn save_printouts(printer: &Printer, dir: &Path, count: &mut u32) {
+    for image in printer.take_images() {
+        *count += 1;
+        let path = dir.join(format!("print-{}.png", count));
+        match std::fs::write(&path, image.to_png()) {
+            Ok(()) => println!("printed {}", path.display()),
+            Err(e) => eprintln!("failed to write {}: {}", path.display(), e),
+        }
+    }
+}
+
+// Writes the battery RAM to disk if it changed since the last flush.
+fn flush_save(gb: &GameBoy, path: &Path, saved: &mut Option<Vec<u8>>) {
+    let data = match gb.battery_ram() {
+        Some(data) => data,
+        None => return,
+    };
+    if saved.as_ref() == Some(&data) {
+        return;
+    }
+    match std::fs::write(path, &data) {
+        Ok(()) => *saved = Some(data),
+        Err(e) => eprintln!("failed to write {}: {}", path.display(), e),
+    }
+}
+
+fn main() {
+    let options = match options::parse(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", options::USAGE);
+            return;
+        }
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, options::USAGE);
+            process::exit(2);
+        }
+    };
+    let mut gb = load_game(&options);
+    let printer = options
+        .link
+        .as_ref()
+        .and_then(|link| connect_link(&mut gb, link));
+    let mut printouts = 0;
+
+    let save_path = options.rom.with_extension("sav");
+    if let Ok(data) = std::fs::read(&save_path) {
+        gb.load_battery_ram(&data);
+    }
+    let mut saved = gb.battery_ram();
+    let mut frames = 0;
+
+    let audio = AudioPlayer::new();
+    if let Some(audio) = &audio {
+        gb.set_audio_sample_rate(audio.sample_rate());
+    }
+
+    let title = if gb.title().is_empty() {
+        "GameBoy".to_string()
+    } else {
+        gb.title().to_string()
+    };
+    let mut locked_up = false;
 
     let event_loop: glutin::event_loop::EventLoop<()> =
         glutin::event_loop::EventLoop::with_user_event();
     let window_builder = glutin::window::WindowBuilder::new()
-        .with_title("GameBoy")
+        .with_title(&title)
         .with_inner_size(glutin::dpi::LogicalSize {
-            width: gb.width(),
-            height: gb.height(),
+            width: gb.width() * options.scale,
+            height: gb.height() * options.scale,
         });
     let gl_window = glutin::ContextBuilder::new()
         .build_windowed(window_builder, &event_loop)
@@ -69,6 +187,13 @@ fn main() -> Result<(), Error> {
             } => match wevent {
                 glutin::event::WindowEvent::KeyboardInput { input, .. } => {
                     if let Some(virt_keycode) = input.virtual_keycode {
+                        if virt_keycode == VirtualKeyCode::R
+                            && input.state == ElementState::Pressed
+                        {
+                            gb.reset();
+                            locked_up = false;
+                            window.set_title(&title);
+                        }
                         let button = match virt_keycode {
                             VirtualKeyCode::A => Button::A,
                             VirtualKeyCode::B => Button::B,
@@ -97,13 +222,36 @@ fn main() -> Result<(), Error> {
                     height: _,
                 }) => *control_flow = glutin::event_loop::ControlFlow::Poll,
                 glutin::event::WindowEvent::CloseRequested => {
+                    flush_save(&gb, &save_path, &mut saved);
                     *control_flow = glutin::event_loop::ControlFlow::Exit
                 }
                 _ => *control_flow = glutin::event_loop::ControlFlow::Poll,
             },
             glutin::event::Event::MainEventsCleared => window.request_redraw(),
             glutin::event::Event::RedrawRequested(_) => {
-                gb.frame();
+                if let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) {
+                    gb.sync_rtc(now.as_secs());
+                }
+                if let Err(e) = gb.frame() {
+                    if !locked_up {
+                        eprintln!("{}, press R to reset", e);
+                        window.set_title(&format!("{} - {}", title, e));
+                        locked_up = true;
+                    }
+                }
+                if let (Some(printer), Some(Link::Printer(dir))) =
+                    (&printer, &options.link)
+                {
+                    save_printouts(printer, dir, &mut printouts);
+                }
+                frames += 1;
+                if frames % SAVE_INTERVAL_FRAMES == 0 {
+                    flush_save(&gb, &save_path, &mut saved);
+                }
+                let samples = gb.audio_samples();
+                if let Some(audio) = &audio {
+                    audio.push(&samples);
+                }
                 cx.draw(&gb);
                 gl_window.swap_buffers().unwrap();
 
@@ -0,0 +1,150 @@
+// Command line options of the desktop frontend.
+
+use gameboy_core::{Palette, GREY_PALETTE};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "Usage: desktop [OPTIONS] <ROM>
+
+Options:
+  --scale <N>          Window size as a multiple of 160x144 (default: 1)
+  --palette <PALETTE>  grey (default), green, or four hex colors from
+                       lightest to darkest, e.g. e0f8d0,88c070,346856,081820
+  --boot-rom <FILE>    Run a 256 byte DMG boot ROM before the game
+  --link-listen <ADDR> Wait for another emulator to connect a link cable,
+                       e.g. 127.0.0.1:8765
+  --link-connect <ADDR>
+                       Connect a link cable to an emulator listening at ADDR
+  --serial-stdout      Print everything sent over serial, e.g. test results
+  --printer <DIR>      Plug in a Game Boy Printer, saving printouts to DIR
+  -h, --help           Print this message";
+
+pub struct Options {
+    pub rom: PathBuf,
+    pub scale: u32,
+    pub palette: Option<Palette>,
+    pub boot_rom: Option<PathBuf>,
+    pub link: Option<Link>,
+}
+
+// What is plugged into the serial port.
+pub enum Link {
+    Listen(String),
+    Connect(String),
+    Stdout,
+    Printer(PathBuf),
+}
+
+// Ok(None) means the help was requested.
+pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut rom = None;
+    let mut scale = 1;
+    let mut palette = None;
+    let mut boot_rom = None;
+    let mut link = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("missing value for {}", name))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--scale" => {
+                scale = match value(&arg)?.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err("--scale must be a positive integer".to_string()),
+                };
+            }
+            "--palette" => palette = Some(parse_palette(&value(&arg)?)?),
+            "--boot-rom" => boot_rom = Some(PathBuf::from(value(&arg)?)),
+            "--link-listen" => link = Some(Link::Listen(value(&arg)?)),
+            "--link-connect" => link = Some(Link::Connect(value(&arg)?)),
+            "--serial-stdout" => link = Some(Link::Stdout),
+            "--printer" => link = Some(Link::Printer(PathBuf::from(value(&arg)?))),
+            _ if arg.starts_with('-') => return Err(format!("unknown option {}", arg)),
+            _ if rom.is_some() => return Err(format!("unexpected argument {}", arg)),
+            _ => rom = Some(PathBuf::from(arg)),
+        }
+    }
+
+    Ok(Some(Options {
+        rom: rom.ok_or("missing ROM path")?,
+        scale,
+        palette,
+        boot_rom,
+        link,
+    }))
+}
+
+fn parse_palette(value: &str) -> Result<Palette, String> {
+    match value {
+        "grey" => return Ok(GREY_PALETTE),
+        "green" => return parse_palette("e0f8d0,88c070,346856,081820"),
+        _ => {}
+    }
+
+    let invalid = || format!("invalid palette {}", value);
+    let colors = value.split(',').collect::<Vec<_>>();
+    if colors.len() != 4 {
+        return Err(invalid());
+    }
+    let mut palette = [[0; 3]; 4];
+    for (shade, color) in palette.iter_mut().zip(colors) {
+        let color = color.trim_start_matches('#');
+        // from_str_radix alone would also take a sign.
+        if color.len() != 6 || !color.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let rgb = u32::from_str_radix(color, 16).map_err(|_| invalid())?;
+        *shade = [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8];
+    }
+    Ok(palette)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Link};
+
+    fn args(line: &str) -> impl Iterator<Item = String> + '_ {
+        line.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn parses_rom_and_options() {
+        let options = parse(args(
+            "--scale 3 game.gb --palette 000000,111111,222222,ffffff",
+        ))
+        .unwrap()
+        .unwrap();
+        assert_eq!(options.rom.to_str(), Some("game.gb"));
+        assert_eq!(options.scale, 3);
+        assert_eq!(options.palette.unwrap()[3], [0xFF, 0xFF, 0xFF]);
+        assert!(options.boot_rom.is_none());
+        assert!(options.link.is_none());
+
+        let options = parse(args("--boot-rom dmg.bin --palette green game.gb"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.boot_rom.unwrap().to_str(), Some("dmg.bin"));
+        assert_eq!(options.palette.unwrap()[0], [0xE0, 0xF8, 0xD0]);
+
+        let options = parse(args("--link-connect 127.0.0.1:8765 game.gb"))
+            .unwrap()
+            .unwrap();
+        assert!(matches!(options.link, Some(Link::Connect(a)) if a == "127.0.0.1:8765"));
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert!(parse(args("")).is_err());
+        assert!(parse(args("a.gb b.gb")).is_err());
+        assert!(parse(args("--scale 0 game.gb")).is_err());
+        assert!(parse(args("game.gb --scale")).is_err());
+        assert!(parse(args("game.gb --link-listen")).is_err());
+        assert!(parse(args("--palette blue game.gb")).is_err());
+        assert!(parse(args("--palette 1,2,3,4 game.gb")).is_err());
+        assert!(parse(args("--palette +12345,000000,000000,000000 game.gb")).is_err());
+        assert!(parse(args("--fast game.gb")).is_err());
+        assert!(parse(args("--help")).unwrap().is_none());
+    }
+}
@@ -1,14 +1,12 @@
 #![cfg(target_arch = "wasm32")]
 
-mod gameboy;
-
 extern crate console_error_panic_hook;
 
 use core::cell::{RefCell, RefMut};
-use gameboy::{Button, GameBoy};
+use gameboy_core::{Button, GameBoy};
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
-use web_sys::{CanvasRenderingContext2d, ImageData, KeyboardEvent};
+use web_sys::{AudioContext, CanvasRenderingContext2d, ImageData, KeyboardEvent};
 
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen(start)]
@@ -16,6 +14,11 @@ pub async fn start() {
     std::panic::set_hook(Box::new(console_error_panic_hook::hook));
 }
 
+thread_local! {
+    // Game handed over by `load_rom`, picked up by the next animation frame.
+    static NEXT_GAME: RefCell<Option<GameBoy>> = RefCell::new(None);
+}
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = console)]
@@ -32,38 +35,113 @@ fn request_animation_frame(f: &Closure<dyn FnMut()>) {
         .expect("should register `requestAnimationFrame`");
 }
 
+// Shows a message under the screen, in the page's status line.
+fn show_status(message: &str) {
+    let status = window()
+        .document()
+        .and_then(|document| document.get_element_by_id("status"));
+    if let Some(status) = status {
+        status.set_text_content(Some(message));
+    }
+}
+
 fn add_event_listener(event_str: &str, f: &js_sys::Function) {
     window()
         .add_event_listener_with_callback(event_str, f)
         .expect("should register `requestAnimationFrame`");
 }
 
+// Queues each frame's samples right after the previous chunk so playback stays
+// gapless while the emulator keeps up with real time.
+struct AudioOutput {
+    context: AudioContext,
+    next_time: f64,
+}
+
+impl AudioOutput {
+    fn new() -> Result<AudioOutput, JsValue> {
+        Ok(AudioOutput {
+            context: AudioContext::new()?,
+            next_time: 0.0,
+        })
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.context.sample_rate() as u32
+    }
+
+    fn resume(&self) {
+        let _ = self.context.resume();
+    }
+
+    fn play(&mut self, samples: &[f32]) -> Result<(), JsValue> {
+        let frames = samples.len() / 2;
+        if frames == 0 {
+            return Ok(());
+        }
+
+        let buffer =
+            self.context
+                .create_buffer(2, frames as u32, self.context.sample_rate())?;
+        let left: Vec<f32> = samples.iter().step_by(2).copied().collect();
+        let right: Vec<f32> = samples.iter().skip(1).step_by(2).copied().collect();
+        buffer.copy_to_channel(&left, 0)?;
+        buffer.copy_to_channel(&right, 1)?;
+
+        let source = self.context.create_buffer_source()?;
+        source.set_buffer(Some(&buffer));
+        source.connect_with_audio_node(&self.context.destination())?;
+
+        let now = self.context.current_time();
+        if self.next_time < now {
+            self.next_time = now + 0.05;
+        }
+        source.start_with_when(self.next_time)?;
+        self.next_time += buffer.duration();
+        Ok(())
+    }
+}
+
+// Starts (or replaces) the running game with a ROM image, e.g. read from a file
+// input or fetched. Fails with a message if the cartridge header is invalid.
 #[wasm_bindgen]
-pub async fn render() -> Result<(), JsValue> {
-    let mut gb = GameBoy::new(&ROM_DATA);
+pub fn load_rom(rom: js_sys::Uint8Array) -> Result<(), JsValue> {
+    let gb =
+        GameBoy::new(&rom.to_vec()).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    NEXT_GAME.with(|next| *next.borrow_mut() = Some(gb));
+    Ok(())
+}
 
+#[wasm_bindgen]
+pub async fn render() -> Result<(), JsValue> {
     let document = window().document().unwrap();
     let game = document.get_element_by_id("game");
     let canvas = document
         .create_element("canvas")?
         .dyn_into::<web_sys::HtmlCanvasElement>()?;
     game.unwrap().append_child(&canvas)?;
-    canvas.set_width(gb.width());
-    canvas.set_height(gb.height());
+    canvas.set_width(160);
+    canvas.set_height(144);
     let context = canvas
         .get_context("2d")?
         .unwrap()
         .dyn_into::<CanvasRenderingContext2d>()
         .unwrap();
 
+    let audio = Rc::new(RefCell::new(AudioOutput::new()?));
+
     let f_main = Rc::new(RefCell::new(None));
     let f_frame = f_main.clone();
-    gb.frame();
+    let mut game: Option<GameBoy> = None;
+    let mut locked_up = false;
 
     let current_key_code: Rc<RefCell<i32>> = Rc::new(RefCell::new(0));
     {
         let key_code = current_key_code.clone();
+        let output = audio.clone();
         let closure = Closure::<dyn FnMut(_)>::new(move |event: KeyboardEvent| {
+            // Browsers only allow audio to start after a user gesture.
+            output.borrow().resume();
             *key_code.borrow_mut() = event.key_code() as i32;
         });
         add_event_listener("keydown", closure.as_ref().unchecked_ref());
@@ -80,7 +158,21 @@ pub async fn render() -> Result<(), JsValue> {
     {
         let key_code = current_key_code.clone();
         *f_frame.borrow_mut() = Some(Closure::wrap(Box::new(move || {
-            let key: RefMut<_> = key_code.borrow_mut();
+            if let Some(mut next) = NEXT_GAME.with(|next| next.borrow_mut().take()) {
+                next.set_audio_sample_rate(audio.borrow().sample_rate());
+                document.set_title(next.title());
+                game = Some(next);
+                locked_up = false;
+            }
+            let gb = match game.as_mut() {
+                Some(gb) => gb,
+                None => {
+                    request_animation_frame(f_main.borrow().as_ref().unwrap());
+                    return;
+                }
+            };
+
+            let mut key: RefMut<_> = key_code.borrow_mut();
             match *key {
                 // A
                 65 => gb.keydown(Button::A),
@@ -107,11 +199,31 @@ pub async fn render() -> Result<(), JsValue> {
                 // Down
                 40 => gb.keydown(Button::Down),
                 -40 => gb.keyup(Button::Down),
+                // R, handled once per press
+                82 => {
+                    gb.reset();
+                    locked_up = false;
+                    show_status("");
+                    *key = 0;
+                }
                 _ => (),
             }
 
             log("Up and running");
-            gb.frame();
+            gb.sync_rtc((js_sys::Date::now() / 1000.0) as u64);
+            if let Err(e) = gb.frame() {
+                if !locked_up {
+                    let message = format!("{}, press R to reset", e);
+                    log(&message);
+                    show_status(&message);
+                    locked_up = true;
+                }
+            }
+            audio.borrow_mut().play(&gb.audio_samples()).ok();
+            if gb.rumble() {
+                // Roughly one frame, renewed every frame the motor stays on.
+                window().navigator().vibrate_with_duration(17);
+            }
             if let Ok(image_data) = ImageData::new_with_u8_clamped_array_and_sh(
                 wasm_bindgen::Clamped(gb.data()),
                 gb.width(),
@@ -127,6865 +239,3 @@ pub async fn render() -> Result<(), JsValue> {
     request_animation_frame(f_frame.borrow().as_ref().unwrap());
     Ok(())
 }
-
-const ROM_DATA: [u8; 131072] = [
-    0, 0, 195, 56, 0, 255, 255, 255, 240, 144, 245, 123, 224, 144, 234, 0, 32, 231, 241,
-    224, 144, 234, 0, 32, 201, 255, 255, 255, 255, 255, 255, 255, 233, 255, 255, 255,
-    255, 255, 255, 255, 34, 13, 32, 252, 201, 255, 255, 255, 26, 34, 19, 13, 32, 250,
-    201, 255, 243, 195, 0, 2, 255, 255, 255, 255, 245, 229, 33, 165, 192, 195, 128, 0,
-    195, 74, 54, 255, 255, 255, 255, 255, 251, 195, 103, 23, 255, 255, 255, 255, 251,
-    195, 13, 23, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 197, 213, 42, 182, 40, 11, 229, 58, 110, 103, 231, 225, 35,
-    24, 243, 232, 4, 209, 193, 225, 240, 65, 230, 2, 32, 250, 241, 217, 33, 163, 192, 52,
-    32, 2, 35, 52, 205, 128, 255, 62, 1, 224, 145, 201, 240, 65, 230, 2, 32, 250, 62,
-    192, 195, 132, 255, 213, 175, 1, 126, 25, 33, 160, 192, 205, 225, 13, 62, 192, 224,
-    146, 103, 175, 111, 14, 160, 239, 209, 201, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 24, 85, 255, 255, 206, 237, 102, 102, 204,
-    13, 0, 11, 3, 115, 0, 131, 0, 12, 0, 13, 0, 8, 17, 31, 136, 137, 0, 14, 220, 204,
-    110, 230, 221, 221, 217, 153, 187, 187, 103, 99, 110, 14, 236, 204, 221, 220, 153,
-    159, 187, 185, 51, 62, 82, 65, 80, 72, 65, 80, 76, 65, 89, 69, 82, 0, 0, 0, 0, 0, 48,
-    48, 0, 27, 2, 3, 0, 51, 1, 250, 204, 166, 250, 161, 192, 71, 250, 160, 192, 243, 87,
-    88, 49, 0, 223, 205, 183, 0, 122, 234, 160, 192, 254, 17, 32, 7, 175, 203, 59, 23,
-    234, 161, 192, 205, 69, 14, 175, 224, 66, 224, 67, 224, 65, 224, 74, 62, 7, 224, 75,
-    17, 198, 1, 33, 128, 255, 14, 12, 247, 205, 128, 255, 1, 156, 0, 205, 39, 14, 62,
-    228, 224, 71, 224, 72, 62, 27, 224, 73, 62, 192, 224, 64, 175, 224, 15, 62, 1, 224,
-    255, 224, 144, 175, 33, 163, 192, 34, 119, 224, 38, 205, 145, 57, 251, 205, 172, 5,
-    118, 0, 24, 252, 243, 248, 2, 175, 224, 15, 126, 251, 224, 255, 201, 240, 146, 183,
-    200, 224, 70, 62, 40, 61, 32, 253, 201, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 201, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 229, 33, 0, 32, 54, 4, 225, 195, 0, 68, 62, 1, 234, 30, 198, 62, 1,
-    224, 2, 248, 2, 126, 224, 1, 62, 129, 224, 2, 201, 62, 2, 234, 30, 198, 175, 224, 2,
-    62, 85, 224, 1, 62, 128, 224, 2, 201, 248, 2, 42, 95, 42, 87, 240, 65, 230, 2, 32,
-    250, 126, 18, 201, 205, 101, 2, 24, 3, 205, 109, 2, 197, 248, 4, 42, 71, 42, 79, 90,
-    203, 49, 203, 1, 121, 230, 3, 131, 87, 62, 224, 161, 128, 95, 240, 65, 230, 2, 32,
-    250, 126, 18, 193, 201, 240, 64, 203, 119, 40, 8, 24, 10, 240, 64, 203, 95, 32, 4,
-    17, 0, 152, 201, 17, 0, 156, 201, 33, 180, 5, 24, 3, 33, 198, 5, 250, 60, 197, 95,
-    135, 131, 133, 111, 140, 149, 103, 240, 144, 245, 42, 224, 144, 234, 0, 32, 42, 102,
-    111, 231, 241, 224, 144, 234, 0, 32, 201, 0, 0, 1, 3, 7, 15, 31, 63, 248, 6, 240,
-    144, 245, 58, 224, 144, 234, 0, 32, 58, 87, 58, 95, 58, 110, 103, 62, 8, 245, 26, 79,
-    250, 55, 202, 169, 79, 19, 26, 71, 250, 55, 202, 168, 71, 19, 250, 54, 202, 203, 39,
-    40, 52, 56, 26, 203, 63, 203, 63, 48, 4, 203, 57, 203, 56, 183, 40, 37, 203, 57, 203,
-    56, 203, 57, 203, 56, 61, 32, 245, 24, 24, 203, 63, 203, 63, 48, 4, 203, 33, 203, 32,
-    183, 40, 11, 203, 33, 203, 32, 203, 33, 203, 32, 61, 32, 245, 250, 53, 202, 166, 119,
-    250, 53, 202, 47, 161, 182, 34, 250, 53, 202, 166, 119, 250, 53, 202, 47, 160, 182,
-    34, 241, 61, 32, 152, 241, 224, 144, 234, 0, 32, 201, 248, 5, 58, 79, 58, 95, 58,
-    110, 103, 121, 183, 40, 36, 240, 65, 230, 2, 32, 250, 123, 34, 13, 40, 25, 28, 65, 5,
-    40, 12, 240, 65, 230, 2, 32, 250, 123, 34, 13, 5, 32, 244, 28, 240, 65, 230, 2, 32,
-    250, 115, 201, 133, 80, 3, 2, 175, 80, 3, 1, 0, 0, 0, 0, 248, 78, 3, 2, 58, 79, 3, 1,
-    28, 50, 3, 6, 234, 80, 3, 5, 178, 40, 3, 4, 119, 81, 3, 2, 153, 79, 3, 3, 22, 80, 3,
-    1, 0, 0, 0, 0, 250, 81, 3, 6, 181, 41, 3, 6, 172, 83, 3, 8, 86, 86, 3, 2, 4, 86, 3,
-    2, 34, 87, 3, 1, 79, 87, 3, 4, 176, 87, 3, 4, 218, 42, 3, 0, 38, 83, 3, 2, 127, 83,
-    3, 2, 116, 88, 3, 0, 220, 87, 3, 4, 240, 84, 3, 8, 128, 88, 3, 4, 179, 88, 3, 4, 231,
-    88, 3, 4, 41, 89, 3, 4, 85, 89, 3, 4, 129, 89, 3, 4, 192, 89, 3, 3, 201, 89, 3, 4,
-    213, 89, 3, 2, 1, 90, 3, 8, 134, 90, 3, 0, 149, 90, 3, 0, 168, 90, 3, 2, 234, 90, 3,
-    4, 101, 91, 3, 4, 105, 88, 3, 1, 224, 91, 3, 2, 203, 86, 3, 2, 134, 50, 3, 3, 108,
-    69, 5, 9, 93, 69, 5, 1, 186, 85, 2, 2, 141, 95, 2, 2, 119, 96, 2, 3, 223, 95, 2, 2,
-    192, 96, 2, 4, 77, 97, 2, 2, 49, 99, 2, 5, 245, 99, 2, 6, 83, 100, 2, 5, 34, 101, 2,
-    8, 199, 97, 2, 2, 0, 0, 0, 0, 54, 98, 2, 4, 140, 101, 2, 3, 202, 101, 2, 3, 48, 104,
-    2, 4, 93, 38, 2, 1, 222, 104, 2, 2, 43, 105, 2, 2, 0, 0, 0, 0, 69, 105, 2, 2, 214,
-    105, 2, 3, 255, 106, 2, 4, 87, 106, 2, 6, 77, 107, 2, 4, 15, 101, 4, 5, 0, 0, 0, 0,
-    104, 108, 2, 1, 17, 109, 2, 2, 153, 108, 2, 5, 26, 106, 2, 5, 212, 108, 2, 6, 36,
-    101, 4, 5, 132, 96, 4, 1, 155, 96, 4, 1, 239, 96, 4, 2, 25, 97, 4, 3, 196, 96, 4, 4,
-    143, 109, 2, 9, 77, 97, 4, 1, 127, 97, 4, 4, 170, 97, 4, 2, 240, 97, 4, 6, 156, 98,
-    4, 8, 43, 99, 4, 5, 253, 99, 4, 1, 17, 100, 4, 7, 5, 97, 4, 1, 52, 71, 5, 4, 120, 71,
-    5, 0, 154, 71, 5, 1, 1, 72, 5, 1, 0, 0, 0, 0, 208, 71, 5, 4, 7, 72, 5, 5, 247, 71, 5,
-    2, 187, 75, 5, 0, 64, 76, 5, 0, 78, 76, 5, 0, 89, 76, 5, 0, 183, 101, 4, 1, 202, 101,
-    4, 5, 0, 0, 0, 0, 0, 0, 0, 0, 102, 76, 3, 4, 185, 77, 3, 2, 197, 97, 4, 1, 217, 97,
-    4, 1, 48, 96, 2, 2, 8, 102, 2, 2, 15, 92, 3, 6, 84, 92, 3, 6, 191, 74, 5, 0, 199, 74,
-    5, 3, 48, 75, 5, 3, 152, 75, 5, 1, 122, 72, 5, 2, 0, 0, 0, 0, 15, 38, 1, 0, 239, 100,
-    4, 1, 211, 73, 5, 3, 20, 74, 5, 4, 0, 0, 0, 0, 76, 102, 2, 2, 149, 102, 2, 4, 17,
-    105, 2, 1, 176, 98, 2, 4, 34, 103, 2, 4, 87, 95, 2, 2, 238, 69, 5, 5, 144, 70, 5, 5,
-    150, 110, 2, 4, 179, 68, 5, 3, 5, 69, 5, 5, 160, 144, 59, 111, 128, 8, 0, 8, 1, 5,
-    225, 83, 16, 15, 3, 202, 120, 30, 4, 33, 11, 64, 195, 8, 0, 30, 4, 33, 39, 67, 195,
-    8, 0, 1, 249, 120, 2, 200, 110, 3, 210, 120, 4, 227, 120, 5, 1, 93, 5, 240, 92, 1,
-    144, 121, 2, 124, 112, 3, 231, 120, 4, 127, 121, 5, 58, 93, 5, 0, 93, 248, 6, 24, 2,
-    248, 2, 197, 42, 102, 111, 205, 5, 6, 193, 201, 248, 7, 24, 2, 248, 3, 197, 58, 230,
-    1, 79, 70, 205, 91, 6, 193, 201, 248, 6, 24, 2, 248, 2, 197, 78, 205, 81, 8, 193,
-    201, 42, 234, 220, 217, 42, 95, 42, 87, 26, 234, 193, 217, 14, 18, 17, 194, 217, 42,
-    18, 19, 13, 32, 250, 14, 41, 33, 222, 217, 175, 34, 13, 32, 252, 62, 240, 234, 237,
-    217, 234, 245, 217, 62, 100, 234, 221, 217, 14, 0, 33, 194, 217, 17, 212, 217, 205,
-    77, 6, 33, 196, 217, 205, 77, 6, 33, 198, 217, 205, 77, 6, 33, 200, 217, 42, 129,
-    102, 111, 140, 149, 103, 42, 18, 19, 126, 18, 19, 201, 30, 254, 120, 183, 40, 7, 203,
-    33, 203, 3, 61, 32, 249, 250, 222, 217, 163, 177, 234, 222, 217, 161, 194, 111, 8,
-    201, 250, 228, 217, 103, 132, 132, 38, 0, 111, 9, 42, 70, 35, 78, 201, 42, 79, 70,
-    205, 117, 6, 33, 0, 0, 254, 72, 208, 18, 135, 198, 24, 111, 206, 12, 149, 103, 42,
-    102, 111, 55, 201, 198, 192, 47, 254, 7, 216, 103, 214, 4, 203, 63, 203, 63, 111,
-    124, 230, 3, 198, 4, 203, 53, 181, 201, 120, 135, 135, 135, 130, 198, 231, 111, 206,
-    217, 149, 103, 201, 79, 250, 222, 217, 5, 40, 17, 5, 40, 25, 5, 40, 33, 203, 71, 192,
-    123, 224, 19, 122, 177, 224, 20, 201, 203, 79, 192, 123, 224, 24, 122, 177, 224, 25,
-    201, 203, 87, 192, 123, 224, 29, 122, 177, 224, 30, 201, 203, 95, 192, 123, 205, 158,
-    6, 224, 34, 175, 224, 35, 201, 24, 6, 24, 31, 24, 56, 24, 87, 250, 222, 217, 203, 71,
-    192, 250, 226, 217, 234, 231, 217, 224, 19, 250, 227, 217, 234, 232, 217, 33, 238,
-    217, 182, 224, 20, 201, 250, 222, 217, 203, 79, 192, 250, 226, 217, 234, 239, 217,
-    224, 24, 250, 227, 217, 234, 240, 217, 33, 246, 217, 182, 224, 25, 201, 250, 222,
-    217, 203, 87, 192, 175, 224, 26, 47, 224, 26, 250, 226, 217, 234, 247, 217, 224, 29,
-    250, 227, 217, 234, 248, 217, 33, 254, 217, 182, 224, 30, 201, 250, 222, 217, 203,
-    95, 192, 250, 226, 217, 234, 0, 218, 224, 34, 250, 6, 218, 224, 35, 201, 120, 230,
-    15, 135, 198, 132, 111, 206, 7, 149, 103, 42, 102, 111, 67, 250, 229, 217, 183, 233,
-    243, 8, 39, 9, 57, 9, 70, 9, 202, 8, 164, 7, 169, 7, 30, 8, 201, 7, 206, 7, 228, 7,
-    81, 8, 130, 8, 91, 8, 96, 8, 75, 8, 192, 121, 224, 36, 201, 33, 208, 217, 62, 15,
-    161, 135, 134, 95, 35, 62, 0, 142, 103, 107, 42, 102, 111, 250, 229, 217, 183, 245,
-    51, 197, 205, 200, 7, 232, 3, 201, 233, 192, 121, 224, 37, 201, 192, 120, 183, 250,
-    222, 217, 40, 7, 203, 79, 192, 121, 224, 22, 201, 203, 71, 192, 121, 224, 17, 201,
-    192, 198, 254, 206, 3, 135, 39, 31, 87, 250, 222, 217, 162, 192, 121, 230, 15, 87,
-    121, 230, 240, 95, 203, 51, 120, 135, 135, 128, 198, 18, 79, 242, 230, 240, 203, 55,
-    146, 48, 1, 175, 131, 254, 16, 56, 2, 62, 15, 203, 55, 226, 12, 12, 242, 246, 128,
-    226, 24, 22, 32, 18, 22, 0, 205, 181, 6, 250, 226, 217, 34, 250, 227, 217, 119, 225,
-    241, 167, 245, 233, 185, 192, 22, 0, 205, 181, 6, 42, 234, 226, 217, 126, 234, 227,
-    217, 120, 135, 198, 253, 111, 206, 6, 149, 103, 233, 192, 121, 234, 220, 217, 201,
-    62, 1, 234, 225, 217, 121, 234, 224, 217, 201, 121, 234, 225, 217, 201, 185, 192,
-    198, 254, 206, 3, 135, 39, 31, 87, 250, 222, 217, 162, 192, 120, 135, 135, 128, 198,
-    18, 111, 38, 255, 175, 34, 120, 254, 2, 200, 44, 54, 255, 201, 192, 203, 49, 250,
-    222, 217, 5, 40, 17, 5, 40, 25, 5, 40, 50, 203, 71, 192, 240, 18, 230, 15, 177, 224,
-    18, 201, 203, 79, 192, 240, 23, 230, 15, 177, 224, 23, 201, 203, 87, 192, 121, 254,
-    160, 48, 15, 254, 80, 48, 7, 183, 40, 10, 62, 96, 24, 6, 62, 64, 24, 2, 62, 32, 224,
-    28, 201, 203, 95, 192, 121, 224, 33, 201, 200, 22, 4, 205, 181, 6, 121, 230, 240,
-    203, 55, 95, 250, 230, 217, 163, 126, 40, 5, 205, 145, 6, 24, 11, 205, 145, 6, 121,
-    230, 15, 133, 111, 140, 149, 103, 84, 93, 175, 195, 194, 6, 200, 22, 4, 205, 181, 6,
-    86, 250, 229, 217, 61, 24, 2, 214, 3, 254, 3, 48, 250, 135, 198, 15, 111, 206, 9,
-    149, 103, 233, 24, 9, 24, 3, 122, 24, 8, 121, 203, 55, 254, 121, 230, 15, 130, 205,
-    145, 6, 84, 93, 175, 195, 194, 6, 200, 22, 0, 205, 181, 6, 42, 129, 95, 142, 147, 87,
-    50, 115, 175, 195, 194, 6, 200, 22, 0, 205, 181, 6, 42, 145, 95, 159, 134, 24, 236,
-    32, 21, 22, 2, 205, 181, 6, 250, 226, 217, 183, 40, 5, 34, 250, 227, 217, 119, 225,
-    241, 167, 245, 233, 22, 0, 205, 181, 6, 229, 42, 95, 42, 87, 42, 102, 111, 147, 124,
-    154, 56, 10, 123, 129, 95, 138, 147, 87, 14, 0, 24, 10, 123, 145, 95, 159, 130, 87,
-    56, 11, 14, 255, 125, 147, 124, 154, 31, 169, 23, 48, 2, 84, 93, 225, 123, 34, 114,
-    62, 6, 133, 111, 140, 149, 103, 126, 203, 190, 195, 194, 6, 120, 230, 240, 203, 55,
-    200, 61, 135, 24, 7, 120, 230, 240, 203, 55, 200, 61, 135, 135, 131, 95, 138, 147,
-    87, 23, 201, 250, 229, 217, 183, 194, 52, 11, 33, 212, 217, 17, 235, 217, 205, 132,
-    6, 245, 48, 41, 250, 202, 217, 95, 250, 203, 217, 87, 205, 169, 9, 250, 238, 217,
-    203, 191, 40, 20, 250, 222, 217, 203, 71, 32, 16, 26, 19, 224, 16, 26, 19, 224, 17,
-    26, 224, 18, 19, 26, 234, 238, 217, 125, 234, 226, 217, 124, 234, 227, 217, 30, 0,
-    205, 112, 7, 241, 220, 5, 7, 33, 214, 217, 17, 243, 217, 205, 132, 6, 245, 48, 38,
-    250, 202, 217, 95, 250, 203, 217, 87, 205, 169, 9, 250, 246, 217, 203, 191, 40, 17,
-    250, 222, 217, 203, 79, 32, 13, 19, 26, 19, 224, 22, 26, 224, 23, 19, 26, 234, 246,
-    217, 125, 234, 226, 217, 124, 234, 227, 217, 30, 1, 205, 112, 7, 241, 220, 32, 7, 33,
-    216, 217, 17, 251, 217, 205, 132, 6, 125, 234, 226, 217, 124, 234, 227, 217, 245, 48,
-    113, 250, 204, 217, 95, 250, 205, 217, 87, 205, 169, 9, 250, 254, 217, 203, 191, 40,
-    92, 250, 222, 217, 203, 87, 32, 88, 26, 19, 224, 27, 26, 19, 224, 28, 26, 19, 33,
-    221, 217, 190, 40, 68, 119, 203, 55, 33, 210, 217, 134, 35, 102, 111, 140, 149, 103,
-    175, 224, 26, 42, 224, 48, 42, 224, 49, 42, 224, 50, 42, 224, 51, 42, 224, 52, 42,
-    224, 53, 42, 224, 54, 42, 224, 55, 42, 224, 56, 42, 224, 57, 42, 224, 58, 42, 224,
-    59, 42, 224, 60, 42, 224, 61, 42, 224, 62, 42, 224, 63, 62, 128, 224, 26, 26, 234,
-    254, 217, 30, 2, 205, 112, 7, 241, 220, 59, 7, 33, 218, 217, 42, 79, 70, 205, 117, 6,
-    234, 3, 218, 254, 72, 245, 48, 64, 205, 158, 6, 234, 226, 217, 17, 0, 0, 205, 169, 9,
-    250, 6, 218, 203, 191, 40, 42, 250, 222, 217, 203, 95, 32, 38, 33, 206, 217, 42, 102,
-    111, 203, 35, 25, 42, 224, 33, 126, 230, 63, 224, 32, 250, 226, 217, 87, 126, 230,
-    128, 203, 55, 178, 234, 226, 217, 126, 230, 64, 246, 128, 234, 6, 218, 30, 3, 205,
-    112, 7, 241, 220, 92, 7, 195, 202, 11, 250, 222, 217, 203, 71, 32, 18, 33, 212, 217,
-    42, 79, 70, 205, 117, 6, 121, 183, 40, 5, 30, 0, 205, 112, 7, 250, 222, 217, 203, 79,
-    32, 18, 33, 214, 217, 42, 79, 70, 205, 117, 6, 121, 183, 40, 5, 30, 1, 205, 112, 7,
-    250, 222, 217, 203, 87, 32, 18, 33, 216, 217, 42, 79, 70, 205, 117, 6, 121, 183, 40,
-    5, 30, 2, 205, 112, 7, 250, 222, 217, 203, 95, 32, 68, 33, 218, 217, 42, 79, 70, 205,
-    117, 6, 254, 72, 48, 46, 103, 250, 206, 217, 95, 250, 207, 217, 87, 205, 159, 9, 40,
-    32, 250, 229, 217, 254, 7, 48, 25, 19, 111, 124, 38, 0, 25, 134, 205, 158, 6, 111,
-    26, 95, 230, 128, 203, 55, 181, 224, 34, 123, 230, 64, 224, 35, 121, 183, 40, 5, 30,
-    3, 205, 112, 7, 33, 230, 217, 52, 250, 220, 217, 71, 33, 229, 217, 126, 60, 184, 40,
-    2, 119, 201, 54, 0, 250, 225, 217, 183, 40, 16, 61, 71, 33, 225, 217, 175, 50, 182,
-    54, 0, 40, 15, 61, 135, 24, 24, 250, 228, 217, 60, 71, 254, 64, 32, 22, 6, 0, 250,
-    193, 217, 79, 250, 223, 217, 198, 2, 185, 32, 1, 175, 234, 223, 217, 79, 205, 53, 6,
-    120, 234, 228, 217, 201, 44, 0, 156, 0, 6, 1, 107, 1, 201, 1, 35, 2, 119, 2, 198, 2,
-    18, 3, 86, 3, 155, 3, 218, 3, 22, 4, 78, 4, 131, 4, 181, 4, 229, 4, 17, 5, 59, 5, 99,
-    5, 137, 5, 172, 5, 206, 5, 237, 5, 10, 6, 39, 6, 66, 6, 91, 6, 114, 6, 137, 6, 158,
-    6, 178, 6, 196, 6, 214, 6, 231, 6, 247, 6, 6, 7, 20, 7, 33, 7, 45, 7, 57, 7, 68, 7,
-    79, 7, 89, 7, 98, 7, 107, 7, 115, 7, 123, 7, 131, 7, 138, 7, 144, 7, 151, 7, 157, 7,
-    162, 7, 167, 7, 172, 7, 177, 7, 182, 7, 186, 7, 190, 7, 193, 7, 196, 7, 200, 7, 203,
-    7, 206, 7, 209, 7, 212, 7, 214, 7, 217, 7, 219, 7, 221, 7, 223, 7, 248, 2, 42, 102,
-    111, 240, 65, 230, 2, 32, 250, 94, 201, 240, 64, 230, 64, 40, 8, 24, 10, 240, 64,
-    230, 8, 32, 4, 22, 152, 24, 2, 22, 156, 248, 3, 58, 110, 90, 203, 55, 7, 103, 230, 3,
-    131, 87, 62, 224, 164, 133, 111, 98, 240, 65, 230, 2, 32, 250, 94, 201, 248, 2, 42,
-    254, 40, 208, 135, 135, 95, 58, 150, 216, 200, 33, 53, 218, 102, 107, 17, 4, 0, 31,
-    48, 3, 114, 25, 200, 203, 63, 48, 5, 114, 25, 114, 25, 200, 114, 25, 114, 25, 114,
-    25, 114, 25, 61, 32, 245, 201, 197, 232, 240, 248, 0, 14, 16, 175, 239, 248, 0, 62,
-    137, 34, 62, 3, 50, 205, 97, 13, 14, 0, 62, 48, 226, 242, 242, 30, 4, 242, 71, 62,
-    32, 226, 242, 242, 62, 16, 226, 242, 242, 62, 48, 226, 242, 242, 242, 242, 184, 32,
-    7, 29, 32, 232, 30, 0, 24, 10, 248, 1, 62, 0, 50, 205, 97, 13, 30, 1, 232, 16, 193,
-    201, 248, 2, 42, 102, 111, 197, 126, 230, 3, 40, 57, 245, 14, 0, 226, 62, 48, 226, 6,
-    16, 30, 8, 42, 87, 203, 58, 62, 16, 56, 2, 62, 32, 226, 242, 242, 62, 48, 226, 242,
-    242, 29, 32, 237, 5, 32, 230, 62, 32, 226, 242, 242, 62, 48, 226, 17, 88, 27, 240, 0,
-    27, 122, 179, 32, 249, 241, 61, 32, 199, 193, 201, 248, 4, 58, 71, 58, 79, 126, 254,
-    40, 48, 49, 135, 135, 95, 33, 27, 218, 42, 102, 111, 250, 53, 218, 87, 42, 254, 128,
-    40, 24, 128, 71, 18, 28, 42, 129, 79, 18, 28, 250, 29, 218, 134, 35, 18, 28, 42, 18,
-    28, 123, 254, 160, 56, 227, 123, 203, 63, 203, 63, 248, 2, 150, 95, 201, 95, 120,
-    177, 200, 115, 11, 84, 93, 19, 120, 177, 200, 203, 56, 203, 25, 48, 3, 42, 18, 19, 4,
-    12, 24, 6, 42, 18, 19, 42, 18, 19, 13, 32, 247, 5, 32, 244, 201, 33, 165, 192, 42,
-    95, 86, 35, 178, 200, 123, 185, 32, 246, 122, 184, 32, 242, 84, 93, 27, 27, 42, 18,
-    71, 19, 42, 18, 19, 176, 200, 24, 245, 33, 165, 192, 42, 182, 40, 3, 35, 24, 249,
-    120, 50, 113, 201, 240, 64, 230, 128, 200, 175, 224, 145, 118, 0, 240, 145, 183, 40,
-    249, 201, 240, 64, 230, 128, 200, 240, 68, 254, 146, 48, 250, 240, 68, 254, 145, 56,
-    250, 240, 64, 230, 127, 224, 64, 201, 197, 248, 4, 42, 79, 70, 205, 7, 14, 193, 201,
-    197, 248, 4, 42, 79, 70, 205, 39, 14, 193, 201, 232, 248, 240, 144, 248, 0, 119, 33,
-    216, 199, 42, 79, 70, 203, 40, 203, 25, 203, 40, 203, 25, 203, 40, 203, 25, 203, 40,
-    203, 25, 121, 198, 64, 234, 17, 197, 33, 218, 199, 42, 79, 70, 203, 40, 203, 25, 203,
-    40, 203, 25, 203, 40, 203, 25, 203, 40, 203, 25, 121, 198, 64, 234, 18, 197, 33, 12,
-    197, 58, 182, 202, 124, 15, 42, 70, 198, 34, 79, 48, 1, 4, 10, 224, 144, 10, 234, 0,
-    32, 250, 11, 197, 248, 6, 119, 250, 12, 197, 248, 7, 50, 42, 95, 86, 33, 35, 0, 25,
-    42, 79, 70, 248, 6, 42, 102, 111, 35, 42, 102, 111, 203, 60, 203, 29, 203, 60, 203,
-    29, 203, 60, 203, 29, 203, 60, 203, 29, 125, 33, 212, 199, 94, 147, 198, 8, 105, 96,
-    35, 94, 131, 234, 3, 197, 248, 6, 42, 102, 111, 35, 35, 35, 42, 102, 111, 203, 60,
-    203, 29, 203, 60, 203, 29, 203, 60, 203, 29, 203, 60, 203, 29, 125, 33, 214, 199, 94,
-    147, 198, 8, 95, 3, 3, 10, 131, 234, 4, 197, 62, 2, 224, 144, 62, 2, 234, 0, 32, 33,
-    13, 197, 126, 214, 15, 48, 16, 1, 58, 64, 121, 134, 79, 48, 1, 4, 10, 33, 4, 197, 78,
-    129, 119, 33, 4, 197, 94, 33, 3, 197, 70, 33, 14, 197, 78, 33, 27, 218, 54, 73, 35,
-    54, 64, 33, 29, 218, 54, 124, 123, 245, 51, 197, 205, 162, 13, 232, 3, 123, 33, 14,
-    197, 134, 119, 250, 255, 196, 234, 15, 197, 250, 0, 197, 234, 16, 197, 33, 16, 197,
-    58, 182, 202, 99, 18, 126, 248, 1, 119, 250, 16, 197, 248, 2, 50, 42, 79, 70, 105,
-    96, 126, 15, 230, 1, 248, 3, 50, 43, 42, 95, 86, 107, 98, 35, 229, 125, 248, 6, 119,
-    225, 124, 248, 5, 119, 248, 1, 42, 95, 86, 33, 3, 0, 25, 229, 125, 248, 8, 119, 225,
-    124, 248, 7, 50, 43, 43, 42, 95, 58, 43, 87, 26, 79, 19, 26, 71, 203, 56, 203, 25,
-    203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 126, 183, 40, 41, 121, 198, 8,
-    234, 3, 197, 248, 6, 42, 95, 86, 26, 79, 19, 26, 71, 203, 56, 203, 25, 203, 56, 203,
-    25, 203, 56, 203, 25, 203, 56, 203, 25, 121, 198, 8, 234, 4, 197, 195, 0, 17, 33,
-    216, 199, 70, 121, 144, 198, 8, 234, 3, 197, 248, 6, 42, 95, 86, 26, 79, 19, 26, 71,
-    203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 121, 33, 218,
-    199, 78, 145, 198, 8, 234, 4, 197, 248, 4, 42, 95, 86, 26, 19, 26, 198, 67, 234, 19,
-    197, 248, 6, 42, 95, 86, 26, 19, 26, 198, 67, 234, 20, 197, 250, 19, 197, 33, 17,
-    197, 150, 56, 57, 250, 19, 197, 14, 0, 198, 240, 95, 121, 206, 255, 87, 33, 17, 197,
-    78, 6, 0, 121, 147, 120, 154, 56, 33, 250, 20, 197, 33, 18, 197, 150, 56, 24, 250,
-    20, 197, 14, 0, 198, 241, 95, 121, 206, 255, 87, 33, 18, 197, 78, 6, 0, 121, 147,
-    120, 154, 48, 97, 248, 1, 42, 79, 70, 105, 96, 126, 7, 230, 1, 248, 7, 119, 248, 1,
-    42, 95, 86, 33, 50, 0, 25, 77, 68, 89, 80, 26, 248, 5, 34, 19, 26, 34, 126, 183, 40,
-    15, 43, 43, 126, 234, 15, 197, 248, 6, 126, 234, 16, 197, 195, 136, 15, 248, 5, 42,
-    79, 70, 250, 144, 203, 214, 1, 62, 0, 23, 238, 1, 32, 18, 197, 33, 15, 197, 42, 95,
-    86, 213, 30, 2, 33, 106, 64, 205, 8, 0, 225, 193, 33, 15, 197, 121, 34, 112, 195,
-    136, 15, 250, 47, 218, 183, 32, 109, 240, 75, 214, 7, 40, 103, 33, 3, 197, 78, 6, 0,
-    33, 8, 0, 9, 240, 75, 79, 6, 0, 92, 80, 121, 149, 120, 156, 203, 123, 40, 7, 203,
-    122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 48, 64, 250, 4, 197, 6, 0, 198, 248, 79,
-    120, 206, 255, 71, 240, 74, 111, 38, 0, 88, 84, 125, 145, 124, 152, 203, 123, 40, 7,
-    203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 48, 23, 33, 15, 197, 42, 79, 70,
-    33, 50, 0, 9, 93, 84, 26, 33, 15, 197, 34, 19, 26, 119, 195, 136, 15, 33, 15, 197,
-    42, 79, 70, 105, 96, 126, 15, 15, 230, 1, 40, 17, 33, 50, 0, 9, 93, 84, 26, 33, 15,
-    197, 34, 19, 26, 119, 195, 136, 15, 33, 14, 0, 9, 126, 254, 255, 40, 64, 33, 31, 218,
-    166, 32, 58, 33, 11, 0, 9, 52, 126, 33, 15, 197, 42, 95, 86, 33, 11, 0, 25, 77, 68,
-    10, 248, 6, 119, 33, 13, 0, 25, 126, 248, 7, 119, 248, 6, 42, 150, 32, 22, 107, 98,
-    126, 203, 55, 230, 1, 40, 7, 248, 6, 126, 61, 2, 24, 6, 33, 12, 0, 25, 126, 2, 33,
-    15, 197, 42, 70, 198, 34, 79, 48, 1, 4, 10, 224, 144, 10, 234, 0, 32, 33, 15, 197,
-    42, 79, 70, 33, 35, 0, 9, 42, 110, 95, 85, 250, 4, 197, 248, 5, 119, 250, 3, 197,
-    248, 6, 119, 250, 14, 197, 248, 7, 119, 33, 10, 0, 9, 126, 234, 29, 218, 107, 98, 35,
-    35, 35, 42, 102, 111, 121, 198, 11, 79, 48, 1, 4, 10, 79, 6, 0, 203, 33, 203, 16, 9,
-    42, 79, 70, 33, 27, 218, 121, 34, 112, 248, 5, 42, 87, 42, 95, 213, 126, 245, 51,
-    205, 162, 13, 232, 3, 123, 33, 14, 197, 134, 119, 33, 15, 197, 42, 79, 70, 33, 50, 0,
-    9, 93, 84, 26, 33, 15, 197, 34, 19, 26, 119, 195, 136, 15, 248, 0, 126, 224, 144, 17,
-    0, 32, 126, 18, 232, 8, 201, 240, 144, 234, 25, 197, 248, 6, 126, 224, 144, 234, 0,
-    32, 193, 205, 183, 53, 250, 25, 197, 224, 144, 234, 0, 32, 96, 105, 233, 240, 144,
-    234, 25, 197, 248, 6, 126, 224, 144, 234, 0, 32, 193, 205, 191, 53, 250, 25, 197,
-    224, 144, 234, 0, 32, 96, 105, 233, 240, 144, 234, 25, 197, 248, 8, 126, 224, 144,
-    234, 0, 32, 193, 205, 100, 57, 250, 25, 197, 224, 144, 234, 0, 32, 96, 105, 233, 240,
-    144, 234, 25, 197, 248, 8, 126, 224, 144, 234, 0, 32, 193, 205, 158, 53, 250, 25,
-    197, 224, 144, 234, 0, 32, 96, 105, 233, 240, 144, 234, 25, 197, 248, 6, 126, 224,
-    144, 234, 0, 32, 248, 2, 42, 95, 42, 87, 42, 102, 111, 42, 18, 19, 42, 18, 19, 126,
-    18, 250, 25, 197, 224, 144, 234, 0, 32, 201, 240, 144, 234, 25, 197, 248, 4, 58, 224,
-    144, 234, 0, 32, 58, 110, 103, 42, 95, 86, 250, 25, 197, 224, 144, 234, 0, 32, 201,
-    240, 144, 234, 25, 197, 248, 8, 126, 224, 144, 234, 0, 32, 193, 205, 109, 55, 250,
-    25, 197, 224, 144, 234, 0, 32, 96, 105, 233, 240, 144, 234, 25, 197, 248, 8, 126,
-    224, 144, 234, 0, 32, 193, 205, 5, 55, 250, 25, 197, 224, 144, 234, 0, 32, 96, 105,
-    233, 232, 245, 248, 3, 54, 0, 248, 0, 125, 84, 248, 4, 34, 114, 248, 17, 126, 248, 6,
-    119, 248, 18, 126, 248, 7, 34, 54, 0, 248, 13, 126, 248, 9, 119, 248, 14, 126, 248,
-    10, 119, 248, 16, 126, 248, 8, 150, 40, 96, 248, 9, 42, 79, 70, 248, 4, 42, 95, 86,
-    248, 15, 126, 245, 51, 197, 213, 205, 224, 18, 232, 5, 248, 4, 42, 95, 42, 87, 26,
-    94, 35, 86, 245, 26, 79, 241, 145, 32, 29, 248, 4, 42, 102, 111, 35, 42, 79, 70, 248,
-    6, 42, 102, 111, 35, 42, 102, 145, 32, 9, 124, 144, 32, 5, 248, 3, 94, 24, 31, 248,
-    8, 52, 126, 248, 3, 119, 248, 9, 42, 95, 86, 33, 3, 0, 25, 229, 125, 248, 11, 119,
-    225, 124, 248, 10, 119, 24, 152, 248, 16, 94, 232, 11, 201, 232, 249, 250, 34, 197,
-    248, 0, 119, 229, 203, 70, 225, 202, 140, 20, 33, 186, 192, 42, 79, 70, 203, 56, 203,
-    25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 33, 8, 0, 9, 229, 125, 248,
-    3, 119, 225, 124, 248, 2, 119, 250, 32, 197, 79, 7, 159, 71, 248, 3, 121, 34, 120,
-    50, 43, 43, 42, 95, 42, 87, 42, 102, 111, 123, 149, 95, 122, 156, 71, 75, 250, 30,
-    197, 95, 7, 159, 87, 121, 147, 79, 120, 154, 71, 250, 26, 197, 248, 5, 119, 250, 27,
-    197, 248, 6, 50, 42, 145, 126, 152, 48, 8, 33, 26, 197, 121, 34, 112, 24, 36, 213,
-    248, 5, 42, 95, 86, 248, 3, 42, 102, 111, 25, 209, 77, 68, 121, 147, 79, 120, 154,
-    71, 248, 5, 121, 150, 35, 120, 158, 48, 6, 33, 26, 197, 121, 34, 112, 229, 248, 2,
-    203, 78, 225, 202, 33, 21, 33, 188, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203,
-    25, 203, 56, 203, 25, 203, 56, 203, 25, 33, 8, 0, 9, 229, 125, 248, 3, 119, 225, 124,
-    248, 2, 119, 250, 33, 197, 79, 7, 159, 71, 248, 3, 121, 34, 120, 50, 43, 43, 42, 95,
-    42, 87, 42, 102, 111, 123, 149, 95, 122, 156, 71, 75, 250, 31, 197, 95, 7, 159, 87,
-    121, 147, 79, 120, 154, 71, 250, 28, 197, 248, 5, 119, 250, 29, 197, 248, 6, 50, 42,
-    145, 126, 152, 48, 8, 33, 28, 197, 121, 34, 112, 24, 36, 213, 248, 5, 42, 95, 86,
-    248, 3, 42, 102, 111, 25, 209, 77, 68, 121, 147, 79, 120, 154, 71, 248, 5, 121, 150,
-    35, 120, 158, 48, 6, 33, 28, 197, 121, 34, 112, 232, 7, 201, 232, 253, 240, 144, 248,
-    0, 119, 248, 7, 126, 224, 144, 17, 0, 32, 58, 43, 18, 42, 79, 70, 33, 5, 0, 9, 93,
-    84, 26, 248, 1, 34, 19, 26, 119, 33, 7, 0, 9, 42, 79, 70, 248, 8, 42, 94, 135, 203,
-    19, 111, 99, 9, 42, 79, 70, 203, 33, 203, 16, 248, 1, 42, 102, 111, 9, 93, 84, 248,
-    10, 42, 79, 70, 33, 16, 0, 229, 213, 197, 205, 109, 55, 232, 6, 248, 0, 126, 224,
-    144, 17, 0, 32, 126, 18, 232, 3, 201, 232, 248, 248, 5, 62, 232, 34, 54, 197, 250,
-    27, 198, 248, 7, 119, 248, 0, 54, 1, 248, 7, 126, 183, 202, 72, 22, 229, 203, 70,
-    225, 202, 50, 22, 248, 5, 42, 95, 58, 43, 87, 26, 119, 183, 202, 50, 22, 230, 15, 61,
-    79, 7, 159, 71, 105, 96, 41, 41, 9, 17, 192, 197, 25, 77, 68, 33, 3, 0, 9, 229, 125,
-    248, 3, 119, 225, 124, 248, 2, 50, 42, 95, 86, 26, 111, 19, 26, 181, 40, 87, 229,
-    248, 6, 203, 126, 225, 40, 9, 250, 27, 198, 248, 0, 174, 234, 27, 198, 250, 28, 198,
-    248, 0, 166, 32, 62, 105, 96, 42, 102, 111, 180, 40, 3, 41, 48, 51, 248, 0, 126, 248,
-    3, 34, 62, 0, 50, 43, 43, 42, 95, 86, 26, 111, 19, 26, 93, 87, 105, 96, 35, 35, 126,
-    245, 248, 5, 42, 102, 111, 241, 229, 38, 1, 229, 51, 197, 213, 245, 51, 30, 3, 33,
-    144, 93, 205, 8, 0, 232, 8, 248, 7, 126, 203, 63, 119, 248, 0, 126, 135, 119, 248, 5,
-    52, 194, 150, 21, 35, 52, 195, 150, 21, 250, 29, 198, 33, 27, 198, 166, 234, 29, 198,
-    232, 8, 201, 232, 251, 1, 4, 198, 248, 4, 54, 0, 248, 4, 126, 214, 4, 40, 117, 10,
-    183, 40, 106, 89, 80, 19, 26, 61, 18, 183, 32, 97, 10, 18, 248, 4, 94, 22, 0, 107,
-    98, 41, 41, 25, 125, 198, 240, 95, 124, 206, 197, 87, 51, 51, 107, 98, 229, 35, 35,
-    35, 93, 84, 26, 248, 2, 34, 19, 26, 119, 248, 3, 58, 182, 40, 56, 209, 213, 26, 111,
-    19, 26, 103, 181, 40, 3, 41, 48, 41, 248, 2, 42, 95, 86, 225, 229, 35, 35, 126, 197,
-    33, 0, 0, 229, 38, 0, 229, 51, 245, 248, 7, 42, 102, 111, 241, 229, 213, 245, 51, 30,
-    3, 33, 144, 93, 205, 8, 0, 232, 8, 193, 3, 3, 248, 4, 52, 24, 132, 232, 5, 201, 1,
-    23, 198, 10, 234, 28, 198, 205, 58, 55, 123, 33, 27, 198, 119, 2, 126, 230, 15, 95,
-    22, 0, 33, 28, 198, 126, 230, 15, 71, 14, 0, 120, 147, 32, 3, 121, 146, 200, 126, 47,
-    33, 27, 198, 166, 234, 29, 198, 201, 245, 229, 197, 213, 14, 1, 175, 224, 2, 33, 30,
-    198, 126, 254, 2, 32, 18, 242, 245, 51, 54, 0, 62, 102, 226, 62, 128, 224, 2, 205,
-    56, 36, 51, 24, 44, 254, 1, 32, 40, 242, 254, 85, 54, 0, 40, 4, 54, 4, 24, 12, 250,
-    32, 198, 254, 2, 32, 5, 119, 62, 85, 24, 2, 62, 102, 226, 62, 128, 224, 2, 62, 0,
-    234, 32, 198, 62, 1, 234, 31, 198, 209, 193, 225, 240, 65, 230, 2, 32, 250, 241, 217,
-    245, 229, 197, 213, 205, 126, 26, 205, 121, 36, 209, 193, 225, 240, 65, 230, 2, 32,
-    250, 241, 217, 240, 69, 214, 150, 32, 51, 250, 216, 199, 224, 67, 250, 218, 199, 224,
-    66, 250, 74, 255, 183, 40, 11, 240, 74, 214, 144, 208, 240, 74, 61, 224, 69, 201,
-    240, 75, 214, 7, 32, 12, 250, 47, 218, 183, 32, 6, 240, 64, 230, 253, 224, 64, 250,
-    48, 218, 224, 69, 201, 240, 69, 33, 48, 218, 150, 48, 30, 240, 75, 214, 7, 32, 18,
-    250, 47, 218, 183, 32, 12, 240, 65, 203, 79, 32, 250, 240, 64, 230, 253, 224, 64,
-    250, 48, 218, 224, 69, 201, 240, 65, 203, 79, 32, 250, 240, 64, 230, 223, 224, 64,
-    250, 46, 218, 183, 32, 6, 240, 64, 246, 2, 224, 64, 62, 150, 224, 69, 201, 240, 69,
-    214, 150, 32, 21, 240, 64, 230, 239, 224, 64, 250, 216, 199, 224, 67, 250, 218, 199,
-    224, 66, 62, 71, 224, 69, 201, 240, 65, 203, 79, 32, 250, 240, 64, 246, 16, 224, 64,
-    62, 150, 224, 69, 201, 250, 28, 201, 198, 7, 224, 75, 33, 30, 201, 126, 224, 74, 126,
-    214, 144, 48, 8, 240, 64, 246, 32, 224, 64, 24, 6, 240, 64, 230, 223, 224, 64, 250,
-    46, 218, 183, 40, 8, 240, 64, 230, 253, 224, 64, 24, 6, 240, 64, 246, 2, 224, 64, 1,
-    139, 198, 250, 144, 198, 2, 1, 145, 198, 250, 150, 198, 2, 1, 151, 198, 250, 156,
-    198, 2, 201, 232, 252, 1, 0, 64, 175, 248, 2, 34, 119, 120, 177, 40, 66, 248, 2, 42,
-    177, 95, 126, 176, 248, 0, 115, 35, 34, 35, 203, 62, 43, 203, 30, 248, 6, 93, 84,
-    248, 0, 26, 19, 150, 35, 26, 158, 56, 24, 248, 6, 42, 95, 86, 225, 229, 123, 149, 95,
-    122, 156, 248, 7, 50, 115, 248, 2, 126, 177, 34, 126, 176, 119, 203, 56, 203, 25,
-    203, 56, 203, 25, 24, 186, 248, 2, 94, 232, 4, 201, 0, 3, 6, 9, 12, 16, 19, 22, 25,
-    28, 31, 34, 37, 40, 43, 46, 49, 51, 54, 57, 60, 63, 65, 68, 71, 73, 76, 78, 81, 83,
-    85, 88, 90, 92, 94, 96, 98, 100, 102, 104, 106, 107, 109, 111, 112, 113, 115, 116,
-    117, 118, 120, 121, 122, 122, 123, 124, 125, 125, 126, 126, 126, 127, 127, 127, 127,
-    127, 127, 127, 126, 126, 126, 125, 125, 124, 123, 122, 122, 121, 120, 118, 117, 116,
-    115, 113, 112, 111, 109, 107, 106, 104, 102, 100, 98, 96, 94, 92, 90, 88, 85, 83, 81,
-    78, 76, 73, 71, 68, 65, 63, 60, 57, 54, 51, 49, 46, 43, 40, 37, 34, 31, 28, 25, 22,
-    19, 16, 12, 9, 6, 3, 0, 253, 250, 247, 244, 240, 237, 234, 231, 228, 225, 222, 219,
-    216, 213, 210, 207, 205, 202, 199, 196, 193, 191, 188, 185, 183, 180, 178, 175, 173,
-    171, 168, 166, 164, 162, 160, 158, 156, 154, 152, 150, 149, 147, 145, 144, 143, 141,
-    140, 139, 138, 136, 135, 134, 134, 133, 132, 131, 131, 130, 130, 130, 129, 129, 129,
-    129, 129, 129, 129, 130, 130, 130, 131, 131, 132, 133, 134, 134, 135, 136, 138, 139,
-    140, 141, 143, 144, 145, 147, 149, 150, 152, 154, 156, 158, 160, 162, 164, 166, 168,
-    171, 173, 175, 178, 180, 183, 185, 188, 191, 193, 196, 199, 202, 205, 207, 210, 213,
-    216, 219, 222, 225, 228, 231, 234, 237, 240, 244, 247, 250, 253, 0, 1, 1, 0, 0, 255,
-    255, 0, 128, 64, 0, 192, 248, 4, 126, 183, 192, 33, 39, 198, 52, 126, 230, 3, 119,
-    33, 40, 198, 150, 32, 8, 33, 40, 198, 52, 126, 230, 3, 119, 1, 35, 198, 121, 33, 39,
-    198, 134, 79, 48, 1, 4, 248, 2, 126, 2, 201, 232, 253, 250, 39, 198, 33, 40, 198,
-    150, 40, 116, 243, 33, 40, 198, 52, 126, 230, 3, 119, 198, 35, 79, 62, 198, 206, 0,
-    71, 10, 248, 0, 119, 251, 126, 230, 3, 79, 6, 0, 105, 96, 41, 41, 9, 125, 198, 41,
-    79, 124, 206, 198, 248, 1, 113, 35, 50, 42, 102, 111, 35, 35, 35, 42, 79, 70, 120,
-    177, 40, 58, 248, 1, 42, 95, 86, 26, 111, 19, 26, 103, 181, 40, 3, 41, 48, 173, 248,
-    0, 42, 203, 55, 230, 15, 95, 22, 0, 42, 102, 111, 35, 35, 126, 213, 38, 1, 229, 51,
-    248, 4, 94, 35, 86, 213, 197, 245, 51, 30, 3, 33, 144, 93, 205, 8, 0, 232, 8, 24,
-    131, 232, 3, 201, 250, 241, 199, 60, 40, 57, 250, 62, 198, 183, 32, 15, 250, 70, 198,
-    33, 63, 198, 182, 234, 222, 217, 33, 62, 198, 54, 1, 205, 101, 35, 123, 183, 32, 29,
-    250, 70, 198, 234, 222, 217, 33, 221, 217, 54, 100, 33, 62, 198, 54, 0, 234, 63, 198,
-    33, 71, 198, 54, 0, 33, 241, 199, 54, 255, 250, 69, 198, 183, 192, 250, 61, 198, 60,
-    200, 24, 0, 33, 68, 198, 52, 126, 230, 3, 192, 240, 144, 79, 33, 61, 198, 126, 224,
-    144, 17, 0, 32, 126, 18, 33, 65, 198, 58, 182, 40, 49, 175, 224, 33, 175, 224, 28,
-    175, 224, 23, 175, 224, 18, 62, 192, 224, 35, 62, 192, 224, 25, 62, 192, 224, 20, 62,
-    255, 224, 37, 42, 95, 86, 250, 61, 198, 197, 213, 205, 220, 5, 225, 193, 175, 33, 64,
-    198, 34, 119, 24, 5, 197, 205, 185, 9, 193, 121, 224, 144, 33, 0, 32, 113, 201, 33,
-    157, 198, 42, 102, 111, 42, 95, 42, 224, 69, 183, 40, 4, 22, 0, 24, 4, 250, 218, 199,
-    87, 240, 65, 203, 79, 32, 250, 123, 234, 67, 255, 122, 224, 66, 240, 69, 183, 40, 12,
-    17, 4, 0, 25, 84, 125, 33, 157, 198, 34, 114, 201, 33, 157, 198, 62, 139, 34, 54,
-    198, 201, 232, 247, 250, 203, 199, 234, 208, 199, 250, 204, 199, 234, 209, 199, 175,
-    33, 210, 199, 34, 119, 240, 144, 234, 207, 199, 33, 209, 199, 58, 182, 202, 242, 31,
-    126, 248, 5, 119, 250, 209, 199, 248, 6, 50, 42, 95, 86, 33, 19, 0, 25, 229, 125,
-    248, 9, 119, 225, 124, 248, 8, 50, 42, 95, 86, 26, 183, 32, 90, 43, 43, 43, 42, 95,
-    86, 33, 35, 0, 25, 42, 79, 70, 248, 7, 121, 34, 112, 33, 211, 199, 58, 182, 40, 12,
-    42, 95, 86, 33, 35, 0, 25, 121, 34, 112, 24, 6, 33, 203, 199, 121, 34, 112, 33, 208,
-    199, 42, 79, 70, 33, 35, 0, 9, 77, 68, 33, 205, 199, 42, 2, 3, 126, 2, 250, 208, 199,
-    234, 205, 199, 250, 209, 199, 234, 206, 199, 248, 7, 126, 234, 208, 199, 248, 8, 126,
-    234, 209, 199, 195, 122, 27, 79, 13, 248, 7, 42, 102, 111, 113, 33, 208, 199, 42, 79,
-    70, 33, 29, 0, 9, 126, 33, 31, 218, 166, 32, 56, 33, 9, 0, 9, 52, 126, 33, 208, 199,
-    42, 95, 86, 33, 9, 0, 25, 77, 68, 10, 248, 7, 119, 33, 11, 0, 25, 126, 248, 8, 119,
-    248, 7, 42, 150, 32, 20, 107, 98, 126, 230, 1, 32, 8, 33, 10, 0, 25, 126, 2, 24, 5,
-    248, 7, 126, 61, 2, 33, 208, 199, 42, 79, 70, 105, 96, 35, 229, 125, 248, 7, 119,
-    225, 124, 248, 6, 50, 42, 95, 42, 87, 26, 34, 19, 26, 119, 33, 5, 0, 9, 42, 79, 70,
-    248, 7, 42, 102, 111, 9, 77, 68, 248, 5, 42, 102, 111, 121, 34, 112, 33, 208, 199,
-    42, 79, 70, 33, 3, 0, 9, 229, 125, 248, 7, 119, 225, 124, 248, 6, 50, 42, 95, 42, 87,
-    26, 34, 19, 26, 119, 33, 7, 0, 9, 42, 79, 70, 248, 7, 42, 95, 86, 123, 145, 95, 122,
-    152, 71, 75, 248, 5, 42, 102, 111, 121, 34, 112, 33, 31, 218, 78, 250, 208, 199, 248,
-    7, 119, 250, 209, 199, 248, 8, 119, 203, 65, 194, 42, 30, 248, 7, 42, 95, 86, 107,
-    98, 35, 229, 125, 248, 5, 119, 225, 124, 248, 4, 119, 248, 7, 42, 95, 86, 33, 12, 0,
-    25, 229, 125, 248, 7, 119, 225, 124, 248, 6, 119, 175, 245, 51, 17, 0, 0, 213, 248,
-    6, 42, 95, 42, 87, 213, 42, 95, 86, 213, 30, 2, 33, 32, 75, 205, 8, 0, 232, 7, 248,
-    7, 123, 34, 114, 248, 7, 126, 248, 0, 119, 248, 8, 126, 248, 1, 119, 250, 208, 199,
-    248, 7, 119, 250, 209, 199, 248, 8, 119, 248, 1, 58, 182, 202, 42, 30, 209, 213, 33,
-    47, 0, 25, 77, 68, 10, 79, 248, 7, 42, 95, 86, 33, 34, 0, 25, 93, 84, 26, 161, 202,
-    42, 30, 209, 213, 33, 37, 0, 25, 77, 68, 10, 248, 2, 119, 126, 183, 40, 94, 209, 213,
-    33, 45, 0, 25, 77, 68, 35, 58, 110, 7, 48, 80, 248, 7, 42, 95, 86, 33, 33, 0, 25, 93,
-    84, 26, 248, 3, 34, 175, 34, 121, 34, 112, 209, 213, 33, 38, 0, 25, 229, 125, 248, 9,
-    119, 225, 124, 248, 8, 50, 42, 95, 58, 87, 26, 34, 19, 26, 119, 248, 3, 42, 95, 42,
-    87, 213, 62, 1, 245, 51, 42, 95, 42, 87, 213, 42, 95, 86, 213, 248, 9, 126, 245, 51,
-    30, 3, 33, 144, 93, 205, 8, 0, 232, 8, 250, 208, 199, 248, 7, 119, 250, 209, 199,
-    248, 8, 50, 42, 78, 111, 97, 126, 15, 230, 1, 32, 88, 248, 7, 42, 95, 86, 33, 35, 0,
-    25, 42, 79, 70, 248, 7, 121, 34, 112, 33, 211, 199, 58, 182, 40, 12, 42, 95, 86, 33,
-    35, 0, 25, 121, 34, 112, 24, 6, 33, 203, 199, 121, 34, 112, 33, 208, 199, 42, 70,
-    198, 35, 79, 48, 1, 4, 33, 205, 199, 42, 2, 3, 126, 2, 250, 208, 199, 234, 205, 199,
-    250, 209, 199, 234, 206, 199, 248, 7, 126, 234, 208, 199, 248, 8, 126, 234, 209, 199,
-    195, 122, 27, 248, 7, 42, 95, 86, 107, 98, 35, 229, 125, 248, 7, 119, 225, 124, 248,
-    6, 50, 42, 95, 58, 87, 26, 34, 19, 26, 119, 203, 62, 43, 203, 30, 35, 203, 62, 43,
-    203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 42, 119, 33, 216, 199,
-    78, 248, 6, 58, 43, 145, 198, 8, 119, 248, 7, 42, 95, 86, 33, 3, 0, 25, 229, 125,
-    248, 7, 119, 225, 124, 248, 6, 50, 42, 95, 58, 87, 26, 34, 19, 26, 119, 203, 62, 43,
-    203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203,
-    30, 42, 119, 33, 218, 199, 78, 248, 6, 58, 145, 198, 8, 50, 62, 160, 150, 56, 7, 35,
-    62, 144, 150, 210, 86, 31, 248, 7, 42, 95, 86, 33, 35, 0, 25, 229, 125, 248, 7, 119,
-    225, 124, 248, 6, 50, 42, 95, 86, 26, 248, 1, 34, 19, 26, 50, 42, 35, 50, 42, 35,
-    119, 33, 211, 199, 58, 182, 40, 39, 126, 248, 5, 119, 250, 211, 199, 248, 6, 50, 42,
-    95, 86, 33, 35, 0, 25, 229, 125, 248, 9, 119, 225, 124, 248, 8, 50, 42, 95, 86, 248,
-    1, 42, 18, 19, 126, 18, 24, 12, 248, 1, 126, 234, 203, 199, 248, 2, 126, 234, 204,
-    199, 250, 208, 199, 248, 5, 119, 250, 209, 199, 248, 6, 50, 42, 95, 86, 33, 35, 0,
-    25, 229, 125, 248, 9, 119, 225, 124, 248, 8, 50, 42, 95, 86, 33, 205, 199, 42, 18,
-    19, 126, 18, 250, 208, 199, 234, 205, 199, 250, 209, 199, 234, 206, 199, 248, 3, 126,
-    234, 208, 199, 248, 4, 126, 234, 209, 199, 195, 122, 27, 248, 7, 42, 95, 86, 33, 16,
-    0, 25, 77, 68, 10, 224, 144, 10, 234, 0, 32, 33, 208, 199, 42, 79, 70, 33, 17, 0, 9,
-    42, 110, 95, 85, 250, 14, 197, 248, 6, 119, 33, 20, 0, 9, 126, 234, 29, 218, 107, 98,
-    35, 35, 35, 93, 84, 26, 248, 2, 34, 19, 26, 119, 33, 9, 0, 9, 110, 38, 0, 41, 77, 68,
-    248, 2, 42, 102, 111, 9, 229, 125, 248, 9, 119, 225, 124, 248, 8, 50, 42, 95, 58, 87,
-    26, 34, 19, 26, 50, 126, 234, 27, 218, 248, 8, 126, 234, 28, 218, 248, 5, 58, 87, 42,
-    35, 95, 213, 126, 245, 51, 205, 162, 13, 232, 3, 123, 33, 14, 197, 134, 119, 33, 208,
-    199, 42, 79, 70, 33, 210, 199, 121, 34, 112, 33, 35, 0, 9, 93, 84, 26, 33, 208, 199,
-    34, 19, 26, 119, 195, 122, 27, 33, 207, 199, 126, 224, 144, 17, 0, 32, 126, 18, 232,
-    9, 201, 232, 249, 250, 203, 199, 234, 208, 199, 250, 204, 199, 234, 209, 199, 175,
-    33, 210, 199, 34, 119, 240, 144, 234, 207, 199, 33, 209, 199, 58, 182, 202, 101, 33,
-    42, 79, 70, 105, 96, 35, 42, 102, 111, 203, 60, 203, 29, 203, 60, 203, 29, 203, 60,
-    203, 29, 203, 60, 203, 29, 125, 33, 216, 199, 94, 147, 198, 8, 248, 2, 119, 105, 96,
-    35, 35, 35, 42, 102, 111, 203, 60, 203, 29, 203, 60, 203, 29, 203, 60, 203, 29, 203,
-    60, 203, 29, 125, 33, 218, 199, 94, 147, 198, 8, 248, 3, 50, 62, 160, 150, 56, 6, 35,
-    62, 144, 150, 48, 94, 33, 35, 0, 9, 93, 84, 26, 248, 5, 34, 19, 26, 119, 248, 5, 42,
-    79, 70, 33, 211, 199, 58, 182, 40, 17, 42, 86, 198, 35, 95, 48, 1, 20, 248, 5, 42,
-    18, 19, 126, 18, 24, 12, 248, 5, 126, 234, 203, 199, 248, 6, 126, 234, 204, 199, 33,
-    208, 199, 42, 86, 198, 35, 95, 48, 1, 20, 33, 205, 199, 42, 18, 19, 126, 18, 250,
-    208, 199, 234, 205, 199, 250, 209, 199, 234, 206, 199, 33, 208, 199, 121, 34, 112,
-    195, 25, 32, 33, 16, 0, 9, 77, 68, 10, 224, 144, 10, 234, 0, 32, 33, 208, 199, 42,
-    79, 70, 33, 17, 0, 9, 42, 110, 95, 85, 250, 14, 197, 248, 4, 119, 33, 20, 0, 9, 126,
-    234, 29, 218, 107, 98, 35, 35, 35, 93, 84, 26, 248, 0, 34, 19, 26, 119, 33, 9, 0, 9,
-    110, 38, 0, 41, 77, 68, 225, 229, 9, 229, 125, 248, 7, 119, 225, 124, 248, 6, 50, 42,
-    95, 58, 87, 26, 34, 19, 26, 50, 126, 234, 27, 218, 248, 6, 126, 234, 28, 218, 248, 3,
-    58, 87, 42, 35, 95, 213, 126, 245, 51, 205, 162, 13, 232, 3, 123, 33, 14, 197, 134,
-    119, 33, 208, 199, 42, 79, 70, 33, 210, 199, 121, 34, 112, 33, 35, 0, 9, 93, 84, 26,
-    33, 208, 199, 34, 19, 26, 119, 195, 25, 32, 33, 207, 199, 126, 224, 144, 17, 0, 32,
-    126, 18, 232, 7, 201, 240, 144, 79, 33, 231, 199, 126, 214, 7, 48, 4, 70, 175, 24, 3,
-    6, 7, 175, 33, 41, 197, 126, 224, 144, 17, 0, 32, 126, 18, 197, 250, 50, 197, 245,
-    51, 33, 42, 197, 42, 95, 86, 213, 62, 1, 245, 51, 197, 51, 250, 230, 199, 103, 250,
-    229, 199, 111, 229, 205, 151, 34, 232, 7, 193, 33, 229, 199, 126, 128, 119, 33, 231,
-    199, 126, 144, 119, 121, 224, 144, 33, 0, 32, 113, 201, 240, 144, 79, 33, 41, 197,
-    126, 224, 144, 17, 0, 32, 126, 18, 62, 23, 33, 50, 197, 150, 48, 4, 62, 23, 24, 3,
-    250, 50, 197, 197, 33, 50, 197, 102, 229, 51, 33, 42, 197, 94, 35, 86, 213, 38, 1,
-    229, 51, 245, 51, 248, 10, 58, 87, 94, 213, 205, 151, 34, 232, 7, 193, 121, 224, 144,
-    33, 0, 32, 113, 201, 240, 144, 79, 33, 41, 197, 126, 224, 144, 17, 0, 32, 126, 18,
-    197, 250, 50, 197, 245, 51, 33, 42, 197, 42, 95, 86, 213, 248, 9, 58, 87, 30, 1, 213,
-    58, 87, 94, 213, 205, 151, 34, 232, 7, 193, 121, 224, 144, 33, 0, 32, 113, 201, 240,
-    144, 79, 33, 228, 199, 126, 214, 7, 48, 4, 70, 175, 24, 3, 6, 7, 175, 33, 41, 197,
-    126, 224, 144, 17, 0, 32, 126, 18, 126, 224, 144, 17, 0, 32, 126, 18, 197, 250, 50,
-    197, 245, 51, 33, 42, 197, 42, 95, 86, 213, 14, 1, 197, 250, 227, 199, 103, 250, 226,
-    199, 111, 229, 205, 151, 34, 232, 7, 193, 33, 227, 199, 126, 128, 119, 33, 228, 199,
-    126, 144, 119, 121, 224, 144, 33, 0, 32, 113, 201, 248, 2, 42, 71, 78, 248, 8, 126,
-    248, 4, 150, 234, 240, 199, 134, 22, 0, 95, 121, 33, 0, 0, 203, 63, 48, 1, 25, 203,
-    35, 203, 18, 183, 32, 244, 120, 133, 111, 140, 149, 103, 84, 93, 248, 6, 42, 102,
-    111, 25, 68, 77, 248, 2, 42, 230, 31, 87, 126, 230, 31, 95, 248, 5, 58, 102, 111, 24,
-    54, 248, 2, 42, 79, 42, 71, 240, 144, 245, 42, 224, 144, 234, 0, 32, 42, 35, 35, 150,
-    234, 240, 199, 43, 43, 42, 230, 31, 87, 42, 230, 31, 95, 42, 110, 103, 205, 7, 35,
-    241, 224, 144, 234, 0, 32, 201, 229, 240, 64, 203, 119, 40, 9, 24, 12, 229, 240, 64,
-    203, 95, 32, 5, 33, 0, 152, 24, 3, 33, 0, 156, 197, 203, 51, 203, 3, 123, 230, 3,
-    132, 71, 62, 224, 163, 130, 79, 225, 209, 213, 197, 240, 65, 230, 2, 32, 250, 42, 2,
-    121, 230, 224, 95, 121, 60, 230, 31, 179, 79, 21, 32, 235, 250, 240, 199, 133, 111,
-    140, 149, 103, 193, 209, 29, 200, 213, 120, 230, 252, 95, 62, 32, 129, 79, 136, 145,
-    230, 3, 179, 71, 197, 24, 205, 33, 242, 199, 42, 95, 182, 200, 86, 33, 244, 199, 175,
-    182, 40, 3, 53, 95, 201, 98, 107, 240, 144, 95, 250, 241, 199, 60, 40, 243, 61, 224,
-    144, 234, 0, 32, 22, 15, 126, 203, 55, 162, 234, 244, 199, 42, 162, 87, 202, 39, 36,
-    42, 71, 230, 7, 254, 5, 56, 90, 254, 7, 40, 80, 175, 234, 26, 255, 14, 48, 42, 226,
-    12, 42, 226, 12, 42, 226, 12, 42, 226, 12, 42, 226, 12, 42, 226, 12, 42, 226, 12, 42,
-    226, 12, 42, 226, 12, 42, 226, 12, 42, 226, 12, 42, 226, 12, 42, 226, 12, 42, 226,
-    12, 42, 226, 12, 42, 226, 12, 120, 254, 6, 32, 69, 62, 128, 224, 26, 62, 254, 224,
-    27, 62, 32, 224, 28, 175, 224, 29, 62, 199, 224, 30, 24, 48, 33, 0, 0, 85, 24, 47,
-    79, 135, 135, 129, 198, 16, 79, 203, 32, 48, 2, 42, 226, 12, 203, 32, 48, 2, 42, 226,
-    12, 203, 32, 48, 2, 42, 226, 12, 203, 32, 48, 2, 42, 226, 12, 203, 32, 48, 2, 42,
-    226, 12, 21, 194, 151, 35, 20, 125, 234, 242, 199, 124, 234, 243, 199, 123, 224, 144,
-    234, 0, 32, 90, 201, 33, 22, 200, 126, 183, 40, 41, 53, 33, 23, 200, 42, 79, 70, 248,
-    2, 126, 2, 33, 23, 200, 52, 32, 2, 35, 52, 33, 22, 200, 126, 183, 194, 29, 2, 33, 23,
-    200, 54, 246, 35, 54, 199, 33, 25, 200, 54, 1, 201, 248, 2, 126, 234, 22, 200, 33,
-    23, 200, 54, 246, 35, 54, 199, 195, 29, 2, 250, 30, 198, 214, 4, 32, 31, 33, 245,
-    199, 54, 0, 33, 26, 200, 54, 0, 33, 22, 200, 54, 0, 33, 23, 200, 54, 246, 35, 54,
-    199, 33, 30, 198, 54, 0, 30, 0, 201, 250, 31, 198, 183, 40, 56, 250, 26, 200, 183,
-    40, 45, 33, 31, 198, 54, 0, 250, 26, 200, 61, 32, 5, 33, 32, 198, 54, 2, 33, 27, 200,
-    110, 250, 28, 200, 103, 70, 33, 27, 200, 52, 32, 2, 35, 52, 197, 51, 205, 10, 2, 51,
-    33, 26, 200, 53, 24, 5, 33, 29, 200, 54, 1, 30, 1, 201, 14, 0, 250, 30, 201, 33, 31,
-    201, 150, 40, 58, 1, 162, 2, 121, 33, 32, 201, 134, 79, 48, 1, 4, 10, 33, 31, 218,
-    166, 32, 37, 250, 32, 201, 183, 32, 5, 1, 2, 0, 24, 3, 1, 1, 0, 250, 30, 201, 33, 31,
-    201, 150, 48, 8, 33, 30, 201, 126, 129, 119, 24, 6, 33, 30, 201, 126, 145, 119, 14,
-    1, 250, 28, 201, 33, 29, 201, 150, 40, 58, 1, 162, 2, 121, 33, 32, 201, 134, 79, 48,
-    1, 4, 10, 33, 31, 218, 166, 32, 37, 250, 32, 201, 183, 32, 5, 1, 2, 0, 24, 3, 1, 1,
-    0, 250, 28, 201, 33, 29, 201, 150, 48, 8, 33, 28, 201, 126, 129, 119, 24, 6, 33, 28,
-    201, 126, 145, 119, 14, 1, 121, 183, 192, 250, 33, 201, 183, 192, 250, 27, 198, 230,
-    48, 40, 7, 250, 28, 198, 230, 48, 40, 8, 250, 31, 218, 33, 34, 201, 166, 192, 30, 1,
-    33, 101, 68, 205, 8, 0, 250, 41, 201, 183, 32, 6, 250, 39, 201, 183, 32, 6, 250, 33,
-    201, 183, 40, 230, 123, 183, 200, 250, 75, 202, 60, 200, 33, 74, 202, 78, 33, 76,
-    202, 42, 95, 86, 33, 75, 202, 70, 62, 4, 33, 71, 198, 150, 216, 33, 241, 199, 54,
-    255, 33, 71, 198, 54, 4, 250, 63, 198, 111, 15, 48, 7, 175, 224, 18, 62, 192, 224,
-    20, 203, 77, 40, 7, 175, 224, 23, 62, 192, 224, 25, 203, 85, 40, 3, 175, 224, 28,
-    203, 93, 40, 7, 175, 224, 33, 62, 192, 224, 35, 62, 255, 224, 37, 33, 63, 198, 113,
-    33, 241, 199, 54, 255, 33, 244, 199, 54, 0, 33, 242, 199, 123, 34, 114, 33, 241, 199,
-    112, 201, 59, 240, 144, 248, 0, 119, 248, 7, 42, 79, 70, 89, 80, 19, 19, 26, 224,
-    144, 26, 234, 0, 32, 105, 96, 42, 102, 111, 229, 205, 92, 13, 225, 105, 96, 42, 102,
-    111, 126, 230, 3, 95, 22, 0, 123, 135, 203, 18, 135, 203, 18, 135, 203, 18, 135, 203,
-    18, 133, 95, 122, 140, 87, 123, 2, 3, 122, 2, 248, 0, 126, 224, 144, 17, 0, 32, 126,
-    18, 51, 201, 232, 240, 240, 144, 248, 0, 119, 248, 22, 126, 248, 1, 119, 248, 23,
-    126, 248, 2, 50, 42, 79, 70, 3, 3, 10, 224, 144, 10, 234, 0, 32, 248, 1, 42, 95, 86,
-    26, 79, 19, 26, 71, 248, 12, 121, 34, 112, 248, 24, 126, 30, 0, 135, 203, 19, 111,
-    99, 9, 229, 125, 248, 5, 119, 225, 124, 248, 4, 119, 248, 14, 54, 43, 35, 54, 201,
-    248, 1, 42, 95, 86, 33, 8, 0, 25, 229, 125, 248, 7, 119, 225, 124, 248, 6, 119, 248,
-    3, 42, 95, 86, 26, 248, 7, 119, 248, 3, 42, 95, 86, 107, 98, 35, 229, 125, 248, 10,
-    119, 225, 124, 248, 9, 50, 43, 126, 183, 202, 148, 40, 248, 14, 42, 95, 86, 107, 98,
-    35, 229, 125, 248, 12, 119, 225, 124, 248, 11, 119, 248, 7, 126, 214, 37, 194, 85,
-    40, 248, 12, 42, 95, 86, 26, 111, 19, 26, 103, 77, 68, 203, 33, 203, 16, 203, 124,
-    40, 13, 248, 5, 42, 95, 86, 26, 111, 19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 93, 84,
-    26, 248, 3, 34, 19, 26, 119, 248, 8, 42, 79, 70, 10, 95, 105, 96, 35, 229, 125, 248,
-    10, 119, 225, 124, 248, 9, 119, 123, 254, 37, 202, 48, 40, 214, 68, 40, 29, 248, 3,
-    126, 248, 9, 119, 123, 254, 99, 202, 232, 39, 254, 100, 40, 85, 254, 102, 202, 23,
-    40, 214, 116, 202, 253, 39, 195, 53, 40, 248, 8, 42, 79, 70, 10, 198, 208, 234, 84,
-    202, 248, 14, 42, 95, 86, 213, 248, 5, 42, 95, 86, 213, 30, 5, 33, 98, 76, 205, 8, 0,
-    232, 4, 250, 56, 202, 183, 40, 12, 213, 248, 16, 42, 102, 111, 229, 205, 239, 53,
-    225, 209, 22, 0, 248, 14, 42, 102, 111, 25, 229, 125, 248, 16, 119, 225, 124, 248,
-    15, 119, 195, 120, 40, 33, 84, 202, 54, 0, 248, 14, 42, 95, 86, 213, 248, 5, 42, 95,
-    86, 213, 30, 5, 33, 98, 76, 205, 8, 0, 232, 4, 250, 56, 202, 183, 40, 12, 213, 248,
-    16, 42, 102, 111, 229, 205, 239, 53, 225, 209, 22, 0, 248, 14, 42, 102, 111, 25, 229,
-    125, 248, 16, 119, 225, 124, 248, 15, 119, 195, 120, 40, 248, 14, 42, 95, 86, 248, 9,
-    42, 18, 126, 248, 14, 119, 248, 11, 126, 248, 15, 119, 24, 123, 248, 14, 42, 102,
-    111, 54, 1, 248, 10, 42, 95, 58, 43, 87, 126, 60, 60, 18, 19, 248, 14, 123, 34, 114,
-    24, 97, 248, 14, 42, 102, 111, 54, 2, 248, 10, 42, 95, 58, 43, 87, 126, 60, 18, 19,
-    248, 14, 123, 34, 114, 24, 72, 248, 8, 42, 79, 70, 89, 80, 27, 26, 79, 19, 248, 3,
-    123, 34, 114, 248, 14, 42, 102, 111, 113, 248, 10, 126, 248, 14, 119, 248, 11, 126,
-    248, 15, 119, 195, 186, 38, 248, 7, 42, 79, 126, 248, 3, 119, 248, 9, 126, 248, 4,
-    119, 248, 14, 42, 102, 111, 113, 248, 10, 126, 248, 14, 119, 248, 11, 126, 248, 15,
-    119, 195, 186, 38, 3, 248, 3, 121, 34, 112, 248, 12, 42, 95, 86, 33, 2, 0, 25, 229,
-    125, 248, 14, 119, 225, 124, 248, 13, 119, 195, 186, 38, 248, 14, 42, 102, 111, 54,
-    0, 248, 0, 126, 224, 144, 17, 0, 32, 42, 18, 42, 95, 86, 248, 8, 42, 18, 19, 126, 18,
-    232, 16, 201, 232, 246, 248, 16, 42, 95, 42, 87, 42, 79, 70, 203, 33, 203, 16, 33, 8,
-    0, 25, 229, 125, 248, 10, 119, 225, 124, 248, 9, 119, 248, 19, 203, 126, 40, 22, 248,
-    8, 42, 95, 86, 26, 111, 19, 26, 103, 9, 93, 84, 26, 248, 0, 34, 19, 26, 119, 24, 13,
-    33, 152, 203, 9, 93, 84, 26, 248, 0, 34, 19, 26, 119, 248, 21, 126, 183, 40, 32, 248,
-    8, 42, 95, 86, 26, 79, 19, 26, 71, 248, 21, 94, 22, 0, 107, 98, 41, 121, 149, 79,
-    120, 156, 71, 248, 8, 42, 102, 111, 113, 35, 112, 240, 144, 248, 2, 119, 248, 16,
-    126, 248, 3, 119, 248, 17, 126, 248, 4, 50, 42, 79, 70, 3, 3, 10, 224, 144, 10, 234,
-    0, 32, 248, 3, 42, 95, 42, 87, 26, 79, 19, 26, 113, 35, 119, 248, 20, 126, 248, 9,
-    119, 248, 9, 126, 183, 40, 71, 248, 5, 42, 95, 42, 87, 26, 34, 19, 26, 119, 248, 5,
-    42, 79, 70, 3, 3, 248, 0, 126, 248, 7, 150, 32, 33, 248, 1, 126, 248, 8, 150, 32, 25,
-    105, 96, 42, 79, 70, 248, 3, 42, 102, 111, 121, 34, 112, 248, 2, 126, 224, 144, 17,
-    0, 32, 126, 18, 24, 35, 3, 3, 248, 5, 121, 34, 112, 248, 9, 53, 24, 179, 248, 2, 126,
-    224, 144, 17, 0, 32, 126, 18, 248, 5, 42, 79, 70, 248, 3, 42, 102, 111, 121, 34, 112,
-    232, 10, 201, 232, 240, 248, 27, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248,
-    22, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 175, 245, 51,
-    229, 248, 28, 42, 95, 58, 43, 87, 213, 126, 245, 51, 30, 3, 33, 144, 93, 205, 8, 0,
-    232, 8, 213, 248, 29, 126, 183, 202, 215, 42, 248, 1, 58, 182, 202, 215, 42, 35, 35,
-    240, 144, 119, 248, 22, 126, 248, 3, 119, 248, 23, 126, 248, 4, 50, 42, 79, 70, 3, 3,
-    10, 224, 144, 10, 234, 0, 32, 248, 29, 126, 248, 15, 119, 248, 27, 126, 248, 5, 119,
-    248, 28, 126, 248, 6, 50, 203, 38, 35, 203, 22, 17, 152, 203, 58, 110, 103, 25, 229,
-    125, 248, 9, 119, 225, 124, 248, 8, 119, 248, 3, 42, 95, 86, 33, 8, 0, 25, 229, 125,
-    248, 11, 119, 225, 124, 248, 10, 119, 248, 15, 126, 183, 40, 112, 248, 3, 42, 95, 86,
-    26, 111, 19, 26, 103, 42, 203, 126, 40, 21, 248, 9, 42, 95, 86, 26, 79, 19, 26, 71,
-    248, 5, 42, 102, 111, 9, 42, 79, 126, 24, 9, 248, 7, 42, 95, 86, 26, 79, 19, 26, 248,
-    11, 113, 35, 119, 209, 213, 33, 8, 0, 25, 77, 68, 89, 80, 26, 248, 13, 34, 19, 26,
-    50, 42, 95, 86, 19, 19, 123, 2, 3, 122, 2, 248, 11, 42, 79, 42, 71, 42, 102, 111,
-    121, 34, 112, 248, 3, 42, 95, 86, 26, 79, 19, 26, 71, 3, 3, 58, 110, 103, 121, 34,
-    112, 248, 15, 53, 24, 138, 248, 2, 126, 224, 144, 17, 0, 32, 126, 18, 232, 16, 201,
-    232, 243, 240, 144, 248, 0, 119, 248, 19, 126, 248, 1, 119, 248, 20, 126, 248, 2, 50,
-    42, 79, 70, 3, 3, 10, 224, 144, 10, 234, 0, 32, 248, 1, 42, 95, 86, 33, 8, 0, 25,
-    229, 125, 248, 5, 119, 225, 124, 248, 4, 50, 42, 95, 42, 87, 26, 34, 19, 26, 119,
-    248, 1, 42, 95, 86, 26, 248, 11, 34, 19, 26, 50, 42, 79, 70, 3, 248, 1, 42, 102, 111,
-    121, 34, 112, 248, 11, 42, 95, 86, 26, 119, 203, 126, 202, 67, 45, 126, 214, 251, 40,
-    34, 248, 12, 126, 214, 252, 202, 202, 43, 248, 12, 126, 214, 253, 202, 83, 44, 248,
-    12, 126, 214, 254, 202, 187, 44, 248, 12, 126, 60, 202, 240, 44, 195, 33, 45, 248, 1,
-    42, 95, 86, 26, 111, 19, 26, 103, 42, 79, 70, 203, 120, 40, 22, 203, 33, 203, 16,
-    248, 5, 42, 102, 111, 9, 229, 125, 248, 13, 119, 225, 124, 248, 12, 119, 24, 15, 203,
-    33, 203, 16, 33, 152, 203, 9, 77, 124, 248, 11, 113, 35, 119, 248, 3, 42, 95, 86, 26,
-    79, 19, 26, 71, 11, 11, 58, 110, 103, 121, 34, 112, 105, 96, 42, 79, 70, 248, 11, 42,
-    102, 111, 121, 34, 112, 248, 1, 42, 95, 86, 26, 79, 19, 26, 71, 3, 3, 58, 110, 103,
-    121, 34, 112, 195, 21, 43, 248, 1, 42, 95, 86, 26, 111, 19, 26, 103, 42, 79, 70, 203,
-    120, 40, 14, 203, 33, 203, 16, 248, 5, 42, 102, 111, 9, 77, 68, 24, 9, 105, 96, 41,
-    17, 152, 203, 25, 77, 68, 105, 96, 42, 79, 70, 248, 3, 42, 95, 86, 26, 248, 9, 34,
-    19, 26, 119, 203, 120, 40, 22, 203, 33, 203, 16, 248, 5, 42, 102, 111, 9, 229, 125,
-    248, 13, 119, 225, 124, 248, 12, 119, 24, 15, 121, 135, 203, 16, 198, 152, 79, 120,
-    206, 203, 248, 11, 113, 35, 119, 248, 11, 42, 95, 86, 26, 79, 19, 26, 71, 248, 9, 42,
-    102, 111, 121, 34, 112, 248, 1, 42, 95, 86, 26, 79, 19, 26, 71, 3, 3, 58, 110, 103,
-    121, 34, 112, 195, 46, 45, 248, 1, 42, 95, 86, 26, 111, 19, 26, 103, 42, 79, 70, 248,
-    3, 42, 95, 86, 26, 248, 9, 34, 19, 26, 119, 203, 120, 40, 22, 203, 33, 203, 16, 248,
-    5, 42, 102, 111, 9, 229, 125, 248, 13, 119, 225, 124, 248, 12, 119, 24, 15, 121, 135,
-    203, 16, 198, 152, 79, 120, 206, 203, 248, 11, 113, 35, 119, 248, 11, 42, 95, 86, 26,
-    79, 19, 26, 71, 248, 9, 42, 102, 111, 121, 34, 112, 248, 1, 42, 95, 86, 26, 79, 19,
-    26, 71, 3, 3, 58, 110, 103, 121, 34, 112, 24, 115, 248, 3, 42, 95, 86, 26, 248, 11,
-    34, 19, 26, 119, 248, 1, 42, 95, 86, 26, 111, 19, 26, 103, 42, 79, 70, 248, 11, 42,
-    102, 111, 121, 34, 112, 248, 1, 42, 95, 86, 26, 79, 19, 26, 71, 3, 3, 58, 110, 103,
-    121, 34, 112, 24, 62, 248, 1, 42, 95, 86, 26, 248, 11, 34, 19, 26, 50, 42, 79, 70, 3,
-    248, 1, 42, 102, 111, 121, 34, 112, 248, 11, 42, 95, 86, 26, 79, 248, 3, 42, 95, 86,
-    26, 111, 19, 26, 103, 121, 7, 159, 113, 35, 119, 24, 13, 248, 0, 126, 224, 144, 17,
-    0, 32, 126, 18, 195, 25, 50, 248, 3, 42, 95, 86, 26, 79, 19, 26, 71, 3, 3, 58, 110,
-    103, 121, 34, 112, 195, 21, 43, 248, 3, 42, 95, 86, 26, 79, 19, 26, 71, 17, 4, 0,
-    121, 147, 95, 120, 154, 248, 8, 50, 123, 34, 43, 42, 95, 86, 33, 2, 0, 25, 229, 125,
-    248, 11, 119, 225, 124, 248, 10, 34, 35, 126, 61, 202, 213, 46, 248, 12, 126, 214, 2,
-    202, 1, 47, 248, 12, 126, 214, 3, 202, 59, 47, 248, 12, 126, 214, 4, 202, 119, 47,
-    248, 12, 126, 214, 5, 202, 177, 47, 248, 12, 126, 214, 6, 202, 237, 47, 248, 12, 126,
-    214, 7, 202, 27, 48, 248, 12, 126, 214, 8, 202, 71, 48, 248, 12, 126, 214, 9, 202,
-    124, 48, 248, 12, 126, 214, 37, 202, 174, 46, 248, 12, 126, 214, 38, 202, 151, 48,
-    248, 12, 126, 214, 42, 202, 96, 46, 248, 12, 126, 214, 43, 40, 74, 248, 12, 126, 214,
-    45, 40, 100, 248, 12, 126, 214, 47, 202, 135, 46, 248, 12, 126, 214, 64, 202, 166,
-    49, 248, 12, 126, 214, 77, 202, 91, 49, 248, 12, 126, 214, 81, 202, 219, 49, 248, 12,
-    126, 214, 94, 202, 223, 48, 248, 12, 126, 214, 109, 202, 3, 49, 248, 12, 126, 214,
-    124, 202, 187, 48, 248, 12, 126, 214, 126, 202, 194, 49, 195, 248, 49, 248, 7, 42,
-    95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26, 103, 9, 77, 68, 248, 7,
-    42, 102, 111, 121, 34, 112, 195, 4, 50, 248, 7, 42, 95, 42, 87, 26, 79, 19, 26, 71,
-    42, 95, 86, 26, 111, 19, 26, 103, 121, 149, 79, 120, 156, 71, 248, 7, 42, 102, 111,
-    121, 34, 112, 195, 4, 50, 248, 7, 42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26,
-    111, 19, 26, 103, 229, 197, 205, 105, 53, 232, 4, 75, 66, 248, 7, 42, 102, 111, 121,
-    34, 112, 195, 4, 50, 248, 7, 42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111,
-    19, 26, 103, 229, 197, 205, 140, 56, 232, 4, 75, 66, 248, 7, 42, 102, 111, 121, 34,
-    112, 195, 4, 50, 248, 7, 42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19,
-    26, 103, 229, 197, 205, 157, 56, 232, 4, 75, 66, 248, 7, 42, 102, 111, 121, 34, 112,
-    195, 4, 50, 248, 7, 42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26,
-    103, 125, 145, 32, 6, 124, 144, 62, 1, 40, 1, 175, 79, 6, 0, 248, 7, 42, 102, 111,
-    121, 34, 112, 195, 4, 50, 248, 7, 42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26,
-    111, 19, 26, 103, 92, 80, 121, 149, 120, 156, 203, 123, 40, 7, 203, 122, 32, 8, 191,
-    24, 5, 203, 122, 40, 1, 55, 62, 0, 23, 79, 6, 0, 248, 7, 42, 102, 111, 121, 34, 112,
-    195, 4, 50, 248, 7, 42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26,
-    103, 88, 84, 125, 145, 124, 152, 203, 123, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203,
-    122, 40, 1, 55, 62, 0, 23, 238, 1, 79, 6, 0, 248, 7, 42, 102, 111, 121, 34, 112, 195,
-    4, 50, 248, 7, 42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26, 103,
-    88, 84, 125, 145, 124, 152, 203, 123, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122,
-    40, 1, 55, 62, 0, 23, 79, 6, 0, 248, 7, 42, 102, 111, 121, 34, 112, 195, 4, 50, 248,
-    7, 42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26, 103, 92, 80, 121,
-    149, 120, 156, 203, 123, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 62,
-    0, 23, 238, 1, 79, 6, 0, 248, 7, 42, 102, 111, 121, 34, 112, 195, 4, 50, 248, 7, 42,
-    95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26, 103, 125, 145, 32, 6,
-    124, 144, 62, 1, 40, 1, 175, 238, 1, 79, 6, 0, 248, 7, 42, 102, 111, 121, 34, 112,
-    195, 4, 50, 248, 7, 42, 79, 58, 71, 42, 95, 86, 26, 111, 19, 26, 181, 40, 12, 248, 9,
-    42, 95, 86, 26, 111, 19, 26, 181, 32, 4, 30, 0, 24, 2, 30, 1, 22, 0, 123, 2, 3, 122,
-    2, 195, 4, 50, 248, 7, 126, 248, 11, 119, 248, 8, 126, 248, 12, 119, 248, 7, 42, 95,
-    86, 26, 79, 19, 26, 177, 32, 14, 35, 42, 95, 86, 26, 79, 19, 26, 177, 32, 3, 79, 24,
-    2, 14, 1, 6, 0, 248, 11, 42, 102, 111, 121, 34, 112, 195, 4, 50, 248, 9, 42, 95, 86,
-    26, 79, 19, 26, 177, 214, 1, 62, 0, 23, 79, 6, 0, 58, 110, 103, 121, 34, 112, 195,
-    21, 43, 248, 7, 42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26, 245,
-    121, 165, 79, 241, 160, 71, 248, 7, 42, 102, 111, 121, 34, 112, 195, 4, 50, 248, 7,
-    42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26, 103, 121, 181, 79,
-    120, 180, 71, 248, 7, 42, 102, 111, 121, 34, 112, 195, 4, 50, 248, 7, 42, 95, 42, 87,
-    26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26, 245, 121, 173, 79, 241, 168, 71,
-    248, 7, 42, 102, 111, 121, 34, 112, 195, 4, 50, 248, 7, 126, 248, 11, 119, 248, 8,
-    126, 248, 12, 119, 248, 7, 42, 95, 42, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111,
-    19, 26, 103, 92, 80, 121, 149, 120, 156, 203, 123, 40, 7, 203, 122, 32, 8, 191, 24,
-    5, 203, 122, 40, 1, 55, 48, 7, 248, 9, 121, 34, 112, 24, 11, 248, 9, 42, 95, 58, 87,
-    26, 34, 19, 26, 119, 248, 11, 42, 95, 86, 248, 9, 42, 18, 19, 126, 18, 195, 4, 50,
-    248, 7, 126, 248, 11, 119, 248, 8, 126, 248, 12, 119, 248, 7, 42, 95, 42, 87, 26, 79,
-    19, 26, 71, 42, 95, 86, 26, 111, 19, 26, 103, 88, 84, 125, 145, 124, 152, 203, 123,
-    40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 56, 10, 248, 9, 42, 95, 86,
-    26, 79, 19, 26, 71, 248, 11, 42, 102, 111, 121, 34, 112, 24, 94, 248, 9, 42, 95, 86,
-    26, 79, 19, 26, 71, 197, 205, 143, 53, 225, 75, 66, 248, 9, 42, 102, 111, 121, 34,
-    112, 195, 21, 43, 248, 9, 42, 95, 86, 26, 79, 19, 26, 245, 121, 47, 79, 241, 47, 71,
-    58, 110, 103, 121, 34, 112, 195, 21, 43, 248, 9, 42, 95, 86, 26, 79, 19, 26, 71, 197,
-    205, 110, 24, 225, 75, 6, 0, 248, 9, 42, 102, 111, 121, 34, 112, 195, 21, 43, 248, 0,
-    126, 224, 144, 17, 0, 32, 126, 18, 24, 21, 248, 3, 42, 95, 86, 26, 79, 19, 26, 71,
-    11, 11, 58, 110, 103, 121, 34, 112, 195, 21, 43, 232, 13, 201, 232, 253, 248, 11, 42,
-    79, 70, 203, 33, 203, 16, 203, 126, 40, 22, 248, 9, 42, 95, 86, 33, 8, 0, 25, 42,
-    110, 129, 79, 125, 136, 248, 0, 113, 35, 119, 24, 11, 33, 152, 203, 9, 77, 124, 248,
-    0, 113, 35, 119, 240, 144, 248, 2, 119, 248, 14, 126, 224, 144, 17, 0, 32, 58, 18,
-    126, 183, 32, 11, 35, 35, 42, 79, 70, 10, 79, 6, 0, 24, 10, 248, 15, 42, 79, 70, 105,
-    96, 42, 79, 70, 225, 229, 121, 34, 112, 248, 2, 126, 224, 144, 17, 0, 32, 126, 18,
-    232, 3, 201, 248, 6, 42, 102, 111, 229, 248, 10, 42, 95, 42, 102, 111, 205, 8, 0,
-    232, 2, 201, 248, 2, 42, 102, 111, 35, 35, 58, 95, 58, 110, 103, 240, 144, 245, 123,
-    224, 144, 234, 0, 32, 42, 95, 183, 40, 77, 197, 229, 38, 0, 107, 41, 41, 43, 17, 95,
-    3, 25, 58, 95, 58, 234, 151, 203, 58, 71, 78, 225, 83, 203, 58, 48, 3, 42, 245, 51,
-    40, 9, 42, 245, 51, 42, 245, 51, 21, 32, 247, 197, 68, 77, 248, 8, 25, 42, 102, 111,
-    113, 77, 124, 35, 112, 71, 225, 197, 213, 213, 250, 151, 203, 224, 144, 234, 0, 32,
-    231, 225, 57, 249, 232, 4, 193, 30, 1, 241, 224, 144, 234, 0, 32, 201, 59, 59, 250,
-    144, 203, 183, 32, 17, 33, 140, 203, 34, 119, 250, 136, 203, 234, 142, 203, 250, 137,
-    203, 234, 143, 203, 33, 156, 217, 54, 1, 33, 157, 217, 54, 16, 33, 143, 203, 58, 182,
-    202, 91, 52, 33, 146, 203, 54, 0, 33, 142, 203, 42, 79, 70, 33, 16, 0, 9, 54, 0, 33,
-    142, 203, 42, 79, 70, 33, 15, 0, 9, 126, 183, 32, 22, 33, 142, 203, 42, 95, 86, 213,
-    205, 153, 50, 225, 123, 33, 142, 203, 78, 35, 70, 183, 194, 15, 52, 33, 17, 0, 9, 94,
-    33, 144, 203, 126, 147, 119, 33, 13, 0, 9, 42, 79, 70, 120, 177, 40, 13, 105, 96, 35,
-    58, 94, 87, 203, 250, 123, 2, 3, 122, 2, 33, 141, 203, 58, 182, 40, 28, 42, 79, 70,
-    3, 3, 3, 51, 51, 197, 33, 142, 203, 110, 250, 143, 203, 103, 35, 35, 35, 42, 79, 70,
-    225, 229, 121, 34, 112, 33, 142, 203, 42, 79, 70, 3, 3, 3, 250, 136, 203, 33, 142,
-    203, 150, 32, 19, 250, 137, 203, 33, 143, 203, 150, 32, 10, 89, 80, 26, 33, 136, 203,
-    34, 19, 26, 119, 33, 138, 203, 42, 2, 3, 126, 2, 250, 142, 203, 234, 138, 203, 250,
-    143, 203, 234, 139, 203, 33, 141, 203, 58, 182, 40, 19, 42, 79, 70, 3, 3, 3, 89, 80,
-    26, 33, 142, 203, 34, 19, 26, 119, 195, 42, 51, 250, 136, 203, 234, 142, 203, 250,
-    137, 203, 234, 143, 203, 195, 42, 51, 250, 146, 203, 183, 40, 4, 30, 3, 24, 86, 33,
-    16, 0, 9, 93, 84, 26, 183, 32, 17, 250, 157, 217, 248, 1, 119, 33, 157, 217, 53, 248,
-    1, 126, 183, 194, 42, 51, 250, 144, 203, 183, 32, 33, 26, 33, 156, 217, 166, 119, 33,
-    140, 203, 121, 34, 112, 3, 3, 3, 89, 80, 26, 33, 142, 203, 34, 19, 26, 119, 33, 157,
-    217, 54, 16, 195, 42, 51, 33, 137, 203, 58, 182, 32, 3, 95, 24, 10, 250, 156, 217,
-    183, 30, 1, 32, 2, 30, 2, 51, 51, 201, 197, 248, 4, 42, 87, 94, 248, 8, 58, 71, 58,
-    102, 111, 205, 155, 54, 193, 201, 250, 191, 217, 111, 95, 250, 192, 217, 87, 203, 37,
-    23, 203, 37, 23, 203, 37, 23, 203, 37, 23, 103, 123, 133, 111, 124, 138, 103, 125,
-    198, 147, 234, 191, 217, 87, 124, 206, 92, 234, 192, 217, 95, 201, 248, 2, 42, 234,
-    191, 217, 126, 234, 192, 217, 201, 240, 64, 230, 16, 194, 7, 53, 197, 248, 7, 58, 71,
-    58, 79, 58, 95, 110, 229, 175, 179, 32, 5, 17, 0, 16, 24, 9, 38, 0, 107, 41, 41, 41,
-    41, 84, 93, 225, 125, 7, 159, 103, 41, 41, 41, 41, 197, 1, 0, 144, 9, 193, 203, 92,
-    40, 6, 203, 100, 40, 2, 203, 164, 240, 65, 230, 2, 32, 250, 42, 2, 3, 27, 122, 179,
-    32, 232, 193, 201, 197, 248, 7, 58, 71, 58, 79, 58, 95, 110, 229, 175, 179, 32, 5,
-    17, 0, 16, 24, 9, 38, 0, 107, 41, 41, 41, 41, 84, 93, 225, 111, 41, 41, 41, 41, 197,
-    1, 0, 128, 9, 68, 77, 225, 205, 241, 54, 193, 201, 33, 3, 0, 68, 57, 94, 43, 78, 24,
-    22, 33, 2, 0, 68, 57, 94, 35, 78, 24, 12, 33, 2, 0, 57, 42, 123, 110, 77, 125, 23,
-    159, 71, 123, 23, 159, 87, 24, 25, 33, 2, 0, 57, 42, 95, 78, 175, 87, 71, 24, 13, 33,
-    2, 0, 57, 42, 95, 42, 87, 42, 102, 111, 68, 77, 33, 0, 0, 120, 6, 16, 183, 32, 3, 6,
-    8, 121, 41, 203, 17, 23, 48, 1, 25, 5, 32, 246, 93, 84, 201, 248, 3, 58, 87, 94, 135,
-    208, 175, 147, 95, 62, 0, 154, 87, 201, 197, 248, 4, 42, 87, 94, 248, 9, 58, 71, 58,
-    79, 58, 102, 111, 205, 227, 55, 193, 201, 248, 6, 86, 24, 10, 22, 144, 240, 64, 230,
-    16, 40, 2, 22, 128, 197, 248, 4, 42, 95, 42, 79, 42, 102, 111, 203, 51, 123, 230, 15,
-    130, 87, 123, 230, 240, 95, 203, 90, 40, 2, 203, 162, 6, 16, 240, 65, 230, 2, 32,
-    250, 42, 18, 19, 5, 32, 244, 13, 32, 233, 193, 201, 248, 2, 42, 102, 111, 197, 68,
-    77, 17, 0, 0, 42, 183, 40, 3, 19, 24, 249, 203, 58, 203, 27, 123, 178, 40, 19, 43,
-    43, 20, 28, 24, 7, 126, 245, 10, 50, 241, 2, 3, 29, 32, 246, 21, 32, 243, 193, 248,
-    2, 94, 35, 86, 201, 240, 144, 245, 33, 57, 54, 229, 33, 7, 218, 42, 102, 111, 250, 9,
-    218, 224, 144, 234, 0, 32, 233, 241, 224, 144, 234, 0, 32, 201, 248, 2, 42, 95, 42,
-    87, 42, 102, 111, 201, 245, 229, 197, 213, 33, 11, 218, 229, 42, 102, 111, 180, 40,
-    21, 231, 33, 13, 218, 42, 102, 111, 180, 40, 11, 231, 33, 15, 218, 42, 102, 111, 180,
-    196, 32, 0, 225, 209, 193, 225, 240, 65, 230, 2, 32, 250, 241, 217, 197, 248, 4, 78,
-    35, 70, 205, 132, 54, 193, 201, 33, 11, 218, 195, 42, 14, 197, 248, 4, 78, 35, 70,
-    205, 149, 54, 193, 201, 33, 11, 218, 195, 10, 14, 229, 240, 64, 230, 64, 40, 9, 24,
-    12, 229, 240, 64, 230, 8, 32, 5, 33, 0, 152, 24, 3, 33, 0, 156, 197, 203, 51, 203, 3,
-    123, 230, 3, 132, 71, 62, 224, 163, 130, 79, 225, 209, 213, 197, 240, 65, 230, 2, 32,
-    250, 124, 2, 121, 230, 224, 95, 121, 60, 230, 31, 179, 79, 21, 32, 235, 193, 209, 29,
-    200, 213, 120, 230, 252, 95, 62, 32, 129, 79, 136, 145, 230, 3, 179, 71, 197, 24,
-    213, 20, 28, 24, 9, 240, 65, 230, 2, 32, 250, 10, 34, 3, 29, 32, 244, 21, 32, 241,
-    201, 197, 248, 9, 58, 87, 58, 95, 58, 71, 58, 79, 58, 110, 103, 205, 241, 54, 193,
-    201, 197, 248, 4, 42, 87, 94, 248, 8, 58, 71, 58, 102, 111, 205, 164, 54, 193, 201,
-    245, 213, 22, 127, 205, 58, 55, 183, 32, 248, 21, 32, 247, 209, 241, 201, 62, 32,
-    224, 0, 240, 0, 240, 0, 230, 15, 95, 62, 16, 224, 0, 240, 0, 240, 0, 240, 0, 240, 0,
-    240, 0, 240, 0, 230, 15, 203, 55, 179, 47, 95, 62, 48, 224, 0, 123, 201, 248, 2, 86,
-    205, 58, 55, 162, 40, 250, 201, 233, 248, 7, 58, 87, 58, 95, 178, 40, 52, 197, 58,
-    71, 58, 79, 58, 110, 103, 203, 58, 203, 27, 48, 3, 10, 34, 3, 203, 58, 203, 27, 48,
-    6, 10, 34, 3, 10, 34, 3, 20, 28, 24, 12, 10, 34, 3, 10, 34, 3, 10, 34, 3, 10, 34, 3,
-    29, 32, 241, 21, 32, 238, 193, 248, 2, 42, 95, 86, 201, 248, 7, 58, 87, 58, 95, 178,
-    40, 36, 43, 58, 245, 58, 110, 103, 241, 203, 58, 203, 27, 48, 1, 34, 203, 58, 203,
-    27, 48, 2, 34, 34, 20, 28, 24, 4, 34, 34, 34, 34, 29, 32, 249, 21, 32, 246, 248, 2,
-    42, 95, 86, 201, 229, 240, 64, 230, 64, 40, 9, 24, 12, 229, 240, 64, 230, 8, 32, 5,
-    33, 0, 152, 24, 3, 33, 0, 156, 197, 203, 51, 203, 3, 123, 230, 3, 132, 71, 62, 224,
-    163, 130, 79, 225, 209, 213, 197, 240, 65, 230, 2, 32, 250, 250, 54, 218, 134, 2, 35,
-    121, 230, 224, 95, 121, 60, 230, 31, 179, 79, 21, 32, 231, 193, 209, 29, 200, 213,
-    120, 230, 252, 95, 62, 32, 129, 79, 136, 145, 230, 3, 179, 71, 197, 24, 209, 248, 3,
-    94, 43, 78, 6, 0, 205, 230, 56, 89, 80, 201, 248, 3, 94, 43, 78, 6, 0, 195, 230, 56,
-    33, 3, 0, 84, 57, 58, 95, 78, 121, 7, 159, 71, 205, 234, 56, 89, 80, 201, 33, 3, 0,
-    84, 57, 58, 95, 78, 121, 7, 159, 71, 205, 234, 56, 201, 248, 3, 94, 43, 110, 77, 205,
-    226, 56, 89, 80, 201, 248, 3, 94, 43, 110, 77, 205, 226, 56, 201, 248, 5, 58, 87, 58,
-    95, 58, 110, 103, 68, 77, 205, 234, 56, 89, 80, 201, 248, 5, 58, 87, 58, 95, 58, 110,
-    103, 68, 77, 205, 234, 56, 201, 248, 3, 94, 43, 110, 77, 205, 32, 57, 89, 80, 201,
-    248, 3, 94, 43, 110, 77, 205, 32, 57, 201, 248, 5, 58, 87, 58, 95, 58, 110, 103, 68,
-    77, 205, 35, 57, 89, 80, 201, 248, 5, 58, 87, 58, 95, 58, 110, 103, 68, 77, 205, 35,
-    57, 201, 121, 7, 159, 71, 123, 7, 159, 87, 120, 245, 170, 245, 203, 122, 40, 6, 151,
-    147, 95, 159, 146, 87, 203, 120, 40, 6, 151, 145, 79, 159, 144, 71, 205, 35, 57, 56,
-    22, 241, 230, 128, 40, 6, 151, 145, 79, 159, 144, 71, 241, 230, 128, 200, 151, 147,
-    95, 159, 146, 87, 201, 241, 241, 201, 6, 0, 80, 123, 178, 32, 7, 1, 0, 0, 80, 89, 55,
-    201, 105, 96, 1, 0, 0, 183, 62, 16, 245, 203, 21, 203, 20, 203, 17, 203, 16, 197,
-    121, 155, 79, 120, 154, 71, 63, 56, 8, 193, 241, 61, 183, 32, 231, 24, 9, 51, 51,
-    241, 61, 55, 32, 222, 24, 0, 80, 89, 203, 21, 77, 203, 20, 68, 183, 201, 197, 248, 4,
-    42, 87, 94, 248, 9, 58, 71, 58, 79, 58, 102, 111, 205, 236, 55, 193, 201, 0, 0, 136,
-    51, 2, 0, 4, 0, 1, 0, 228, 127, 0, 0, 0, 0, 0, 0, 150, 0, 15, 31, 16, 192, 0, 1, 25,
-    0, 33, 120, 57, 17, 30, 218, 205, 234, 13, 175, 33, 30, 198, 14, 3, 239, 62, 0, 234,
-    30, 198, 224, 2, 62, 102, 224, 1, 62, 128, 224, 2, 33, 83, 202, 54, 0, 201, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 33, 224, 223, 17, 240, 223, 26, 34, 19, 26, 34,
-    19, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26, 34,
-    19, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26, 34,
-    19, 250, 42, 201, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
-    201, 248, 2, 42, 183, 17, 255, 255, 200, 95, 122, 203, 63, 29, 32, 251, 94, 28, 24,
-    4, 55, 31, 203, 26, 29, 32, 249, 95, 201, 248, 2, 42, 183, 17, 255, 255, 200, 95,
-    122, 203, 39, 29, 32, 251, 94, 28, 24, 4, 55, 23, 203, 18, 29, 32, 249, 95, 201, 33,
-    56, 202, 54, 0, 33, 66, 202, 54, 0, 250, 161, 5, 234, 65, 202, 33, 162, 5, 42, 79,
-    70, 250, 65, 202, 245, 51, 17, 8, 0, 213, 197, 17, 57, 202, 213, 205, 35, 19, 232, 7,
-    33, 36, 201, 54, 0, 33, 37, 201, 54, 0, 33, 38, 201, 54, 0, 33, 40, 201, 54, 1, 33,
-    42, 201, 54, 0, 175, 33, 42, 202, 34, 119, 33, 52, 202, 54, 0, 33, 48, 202, 54, 204,
-    33, 49, 202, 54, 0, 33, 50, 202, 54, 204, 33, 51, 202, 54, 0, 33, 31, 201, 54, 144,
-    33, 30, 201, 54, 144, 33, 29, 201, 54, 0, 33, 28, 201, 54, 0, 33, 32, 201, 54, 1, 33,
-    33, 201, 54, 1, 33, 39, 201, 54, 1, 33, 34, 201, 54, 0, 205, 101, 2, 33, 67, 202,
-    123, 34, 114, 33, 33, 0, 25, 77, 124, 33, 46, 202, 113, 35, 119, 33, 44, 202, 113,
-    35, 119, 205, 101, 2, 33, 69, 202, 123, 34, 114, 33, 71, 202, 54, 20, 33, 72, 202,
-    54, 8, 33, 73, 202, 54, 201, 33, 75, 202, 54, 255, 30, 1, 33, 75, 65, 195, 8, 0, 6,
-    5, 197, 51, 17, 197, 81, 213, 62, 9, 191, 245, 205, 112, 18, 232, 5, 6, 5, 197, 51,
-    17, 181, 81, 213, 33, 203, 1, 229, 205, 112, 18, 232, 5, 1, 224, 223, 89, 80, 33, 16,
-    0, 229, 108, 229, 213, 205, 176, 55, 232, 6, 197, 33, 201, 1, 229, 205, 183, 53, 232,
-    4, 89, 80, 33, 16, 0, 229, 46, 255, 229, 213, 205, 176, 55, 232, 6, 197, 33, 202, 1,
-    229, 205, 183, 53, 232, 4, 201, 248, 9, 126, 183, 200, 205, 101, 2, 248, 7, 110, 38,
-    0, 41, 41, 41, 41, 41, 25, 77, 68, 248, 6, 110, 38, 0, 9, 77, 68, 89, 80, 197, 248,
-    10, 126, 191, 245, 213, 205, 45, 3, 232, 4, 193, 248, 9, 53, 200, 62, 1, 248, 9, 150,
-    48, 37, 94, 29, 123, 183, 40, 31, 33, 32, 0, 9, 77, 68, 121, 80, 197, 213, 248, 12,
-    102, 229, 51, 38, 195, 229, 51, 95, 213, 205, 45, 3, 232, 4, 209, 193, 29, 24, 221,
-    33, 32, 0, 9, 77, 68, 248, 8, 102, 46, 198, 229, 197, 205, 45, 3, 232, 4, 201, 250,
-    52, 202, 183, 40, 20, 250, 49, 202, 234, 51, 202, 250, 48, 202, 234, 50, 202, 33, 48,
-    202, 52, 32, 2, 54, 204, 33, 52, 202, 54, 0, 33, 42, 201, 78, 6, 0, 17, 32, 0, 213,
-    197, 17, 224, 223, 213, 205, 176, 55, 232, 6, 201, 248, 6, 126, 234, 48, 202, 234,
-    50, 202, 248, 7, 126, 234, 49, 202, 234, 51, 202, 33, 52, 202, 54, 0, 33, 42, 201,
-    78, 6, 0, 17, 32, 0, 213, 197, 17, 224, 223, 213, 205, 176, 55, 232, 6, 201, 232,
-    253, 250, 57, 202, 15, 48, 33, 33, 65, 202, 70, 33, 59, 202, 42, 102, 111, 17, 58,
-    202, 26, 229, 248, 7, 166, 225, 95, 22, 0, 25, 197, 51, 229, 205, 7, 19, 232, 3, 24,
-    3, 248, 5, 94, 248, 0, 115, 33, 63, 202, 42, 79, 70, 248, 0, 110, 30, 0, 99, 41, 41,
-    41, 41, 9, 77, 124, 248, 1, 113, 35, 119, 250, 57, 202, 33, 65, 202, 78, 203, 79,
-    202, 20, 68, 203, 87, 40, 5, 250, 42, 201, 24, 1, 175, 234, 55, 202, 33, 61, 202, 42,
-    70, 95, 80, 248, 0, 110, 38, 0, 25, 93, 84, 121, 245, 51, 213, 205, 7, 19, 232, 3,
-    75, 248, 0, 113, 250, 56, 202, 183, 32, 89, 250, 52, 202, 234, 54, 202, 197, 250, 52,
-    202, 71, 197, 205, 74, 64, 225, 193, 33, 53, 202, 115, 197, 213, 250, 65, 202, 245,
-    51, 248, 6, 42, 102, 111, 229, 33, 224, 223, 229, 205, 170, 2, 232, 5, 209, 193, 33,
-    52, 202, 126, 129, 79, 62, 8, 185, 210, 180, 67, 150, 246, 128, 234, 54, 202, 33, 53,
-    202, 114, 250, 65, 202, 245, 51, 248, 2, 42, 95, 86, 213, 17, 240, 223, 213, 205,
-    170, 2, 232, 5, 24, 104, 62, 8, 33, 52, 202, 150, 95, 121, 147, 48, 4, 123, 145, 24,
-    4, 121, 147, 203, 255, 234, 54, 202, 250, 52, 202, 245, 51, 248, 1, 126, 245, 51,
-    205, 102, 64, 225, 33, 53, 202, 115, 213, 250, 65, 202, 245, 51, 248, 4, 42, 79, 70,
-    197, 1, 224, 223, 197, 205, 170, 2, 232, 5, 209, 250, 52, 202, 248, 0, 134, 79, 62,
-    8, 145, 48, 30, 62, 16, 145, 234, 54, 202, 33, 53, 202, 114, 250, 65, 202, 245, 51,
-    248, 2, 42, 95, 86, 213, 17, 240, 223, 213, 205, 170, 2, 232, 5, 248, 0, 126, 33, 52,
-    202, 134, 119, 17, 224, 223, 213, 62, 1, 245, 51, 250, 48, 202, 245, 51, 205, 183,
-    53, 232, 4, 62, 7, 33, 52, 202, 150, 48, 58, 205, 0, 64, 33, 52, 202, 126, 198, 248,
-    119, 250, 49, 202, 234, 51, 202, 250, 48, 202, 234, 50, 202, 33, 48, 202, 52, 32, 2,
-    54, 204, 250, 52, 202, 183, 40, 18, 17, 224, 223, 213, 62, 1, 245, 51, 250, 48, 202,
-    245, 51, 205, 183, 53, 232, 4, 30, 1, 24, 82, 30, 0, 24, 78, 250, 52, 202, 183, 40,
-    22, 250, 49, 202, 234, 51, 202, 250, 48, 202, 234, 50, 202, 33, 48, 202, 52, 126,
-    183, 32, 2, 54, 204, 121, 245, 51, 248, 2, 42, 95, 86, 213, 62, 1, 245, 51, 250, 48,
-    202, 245, 51, 205, 112, 18, 232, 5, 250, 49, 202, 234, 51, 202, 250, 48, 202, 234,
-    50, 202, 33, 48, 202, 52, 32, 2, 54, 204, 33, 52, 202, 54, 0, 30, 1, 232, 3, 201, 59,
-    59, 250, 40, 201, 183, 40, 19, 250, 27, 198, 230, 48, 40, 12, 250, 28, 198, 230, 48,
-    32, 5, 33, 41, 201, 54, 1, 250, 41, 201, 183, 32, 13, 33, 35, 201, 126, 183, 40, 6,
-    53, 30, 0, 195, 200, 72, 33, 43, 202, 58, 182, 32, 108, 1, 162, 2, 121, 33, 39, 201,
-    134, 79, 48, 1, 4, 10, 234, 34, 201, 250, 66, 202, 234, 78, 202, 250, 42, 201, 234,
-    79, 202, 250, 56, 202, 234, 80, 202, 250, 40, 201, 234, 81, 202, 250, 39, 201, 234,
-    82, 202, 33, 42, 202, 54, 43, 35, 54, 201, 250, 36, 201, 15, 56, 45, 250, 67, 202,
-    198, 33, 234, 46, 202, 250, 68, 202, 206, 0, 234, 47, 202, 250, 56, 202, 61, 32, 11,
-    33, 46, 202, 126, 198, 17, 34, 126, 206, 0, 119, 250, 46, 202, 234, 44, 202, 250, 47,
-    202, 234, 45, 202, 205, 17, 66, 33, 42, 202, 110, 250, 43, 202, 103, 78, 62, 13, 145,
-    218, 92, 72, 6, 0, 33, 30, 69, 9, 9, 9, 233, 195, 72, 69, 195, 162, 69, 195, 198, 69,
-    195, 44, 70, 195, 127, 70, 195, 84, 72, 195, 2, 71, 195, 115, 71, 195, 156, 71, 195,
-    188, 72, 195, 50, 72, 195, 92, 72, 195, 92, 72, 195, 185, 71, 175, 33, 42, 202, 34,
-    119, 33, 33, 201, 54, 1, 250, 66, 202, 33, 78, 202, 150, 40, 41, 33, 66, 202, 78, 6,
-    0, 105, 96, 41, 9, 77, 68, 33, 161, 5, 9, 42, 71, 234, 65, 202, 42, 79, 126, 197, 51,
-    17, 8, 0, 213, 71, 197, 17, 57, 202, 213, 205, 35, 19, 232, 7, 250, 79, 202, 234, 42,
-    201, 250, 80, 202, 234, 56, 202, 250, 81, 202, 234, 40, 201, 250, 82, 202, 234, 39,
-    201, 30, 0, 195, 200, 72, 33, 42, 202, 52, 32, 2, 35, 52, 33, 42, 202, 42, 79, 70,
-    10, 61, 230, 7, 234, 39, 201, 198, 162, 79, 62, 2, 206, 0, 71, 10, 234, 34, 201, 195,
-    188, 72, 33, 42, 202, 52, 32, 2, 35, 52, 33, 42, 202, 42, 79, 70, 10, 61, 33, 78,
-    202, 119, 78, 6, 0, 105, 96, 41, 9, 1, 161, 5, 9, 77, 68, 250, 57, 202, 248, 1, 119,
-    10, 234, 65, 202, 105, 96, 35, 78, 35, 70, 245, 51, 17, 8, 0, 213, 197, 17, 57, 202,
-    213, 205, 35, 19, 232, 7, 33, 52, 202, 126, 183, 202, 188, 72, 229, 248, 3, 203, 78,
-    225, 202, 188, 72, 250, 57, 202, 203, 79, 194, 188, 72, 33, 44, 202, 52, 194, 188,
-    72, 35, 52, 195, 188, 72, 33, 42, 202, 52, 32, 2, 35, 52, 33, 42, 202, 110, 250, 43,
-    202, 103, 78, 6, 0, 11, 121, 33, 67, 202, 134, 35, 79, 120, 142, 71, 33, 42, 202, 52,
-    32, 2, 35, 52, 33, 42, 202, 110, 250, 43, 202, 103, 110, 38, 0, 43, 41, 41, 41, 41,
-    41, 9, 77, 124, 33, 46, 202, 113, 35, 119, 33, 44, 202, 113, 35, 119, 250, 52, 202,
-    183, 202, 188, 72, 205, 17, 66, 195, 188, 72, 33, 42, 202, 52, 32, 2, 35, 52, 33, 42,
-    202, 110, 250, 43, 202, 103, 78, 89, 175, 87, 145, 203, 123, 40, 7, 203, 122, 32, 8,
-    191, 24, 5, 203, 122, 40, 1, 55, 48, 1, 13, 33, 42, 202, 52, 32, 2, 35, 52, 33, 42,
-    202, 110, 250, 43, 202, 103, 110, 93, 175, 87, 149, 203, 123, 40, 7, 203, 122, 32, 8,
-    191, 24, 5, 203, 122, 40, 1, 55, 48, 1, 45, 121, 7, 159, 71, 125, 7, 159, 103, 41,
-    41, 41, 41, 41, 9, 77, 68, 121, 33, 44, 202, 134, 35, 79, 120, 142, 71, 33, 46, 202,
-    121, 34, 112, 33, 44, 202, 121, 34, 112, 250, 52, 202, 183, 202, 188, 72, 205, 17,
-    66, 195, 188, 72, 33, 41, 201, 126, 183, 40, 13, 54, 0, 33, 40, 201, 54, 0, 250, 27,
-    198, 234, 28, 198, 250, 39, 201, 183, 32, 12, 33, 42, 202, 52, 194, 188, 72, 35, 52,
-    195, 188, 72, 250, 28, 198, 6, 0, 47, 79, 120, 47, 71, 250, 27, 198, 30, 0, 161, 79,
-    123, 160, 71, 33, 42, 202, 52, 32, 2, 35, 52, 33, 42, 202, 42, 95, 86, 26, 30, 0,
-    161, 79, 123, 160, 177, 40, 15, 250, 81, 202, 234, 40, 201, 250, 27, 198, 234, 28,
-    198, 195, 188, 72, 33, 42, 202, 42, 95, 58, 87, 27, 123, 34, 114, 30, 0, 195, 200,
-    72, 33, 42, 202, 52, 32, 2, 35, 52, 33, 42, 202, 42, 79, 70, 10, 15, 48, 7, 175, 248,
-    0, 34, 119, 24, 7, 248, 0, 62, 255, 34, 175, 119, 248, 0, 126, 234, 42, 201, 195,
-    188, 72, 33, 42, 202, 52, 32, 2, 35, 52, 33, 42, 202, 42, 79, 70, 10, 15, 48, 3, 175,
-    24, 2, 62, 1, 234, 56, 202, 195, 188, 72, 33, 44, 202, 42, 198, 32, 79, 126, 206, 0,
-    71, 33, 69, 202, 42, 95, 86, 33, 72, 202, 110, 38, 0, 41, 41, 41, 41, 41, 25, 125,
-    230, 224, 111, 43, 125, 145, 124, 152, 48, 45, 250, 73, 202, 103, 250, 72, 202, 111,
-    229, 250, 71, 202, 245, 51, 33, 69, 202, 42, 95, 86, 213, 30, 5, 33, 77, 68, 205, 8,
-    0, 232, 5, 250, 46, 202, 234, 44, 202, 250, 47, 202, 234, 45, 202, 24, 23, 33, 46,
-    202, 42, 198, 32, 79, 126, 206, 0, 71, 33, 44, 202, 121, 34, 112, 33, 46, 202, 121,
-    34, 112, 250, 52, 202, 183, 202, 188, 72, 205, 17, 66, 195, 188, 72, 33, 46, 202, 42,
-    198, 32, 79, 126, 206, 0, 71, 33, 44, 202, 121, 34, 112, 33, 46, 202, 121, 34, 112,
-    250, 52, 202, 183, 40, 109, 205, 17, 66, 24, 104, 33, 42, 202, 52, 32, 2, 35, 52, 33,
-    42, 202, 42, 79, 70, 10, 245, 51, 205, 113, 66, 51, 123, 183, 40, 44, 250, 50, 202,
-    33, 44, 202, 78, 35, 70, 245, 51, 197, 205, 46, 2, 232, 3, 250, 56, 202, 183, 32, 10,
-    33, 44, 202, 52, 32, 15, 35, 52, 24, 11, 33, 44, 202, 42, 95, 58, 87, 27, 123, 34,
-    114, 250, 52, 202, 183, 40, 17, 250, 48, 202, 33, 44, 202, 78, 35, 70, 245, 51, 197,
-    205, 46, 2, 232, 3, 33, 42, 202, 52, 32, 2, 35, 52, 30, 1, 24, 12, 33, 42, 202, 52,
-    194, 6, 69, 35, 52, 195, 6, 69, 51, 51, 201, 232, 230, 248, 35, 126, 248, 6, 119,
-    229, 203, 86, 225, 40, 5, 248, 37, 78, 24, 3, 1, 1, 0, 248, 7, 113, 78, 6, 0, 11,
-    105, 96, 41, 9, 41, 77, 68, 248, 32, 42, 102, 111, 9, 77, 68, 248, 0, 125, 84, 248,
-    8, 34, 122, 50, 42, 95, 86, 248, 34, 126, 245, 51, 33, 6, 0, 229, 197, 213, 205, 35,
-    19, 232, 7, 248, 8, 42, 95, 86, 107, 98, 35, 229, 125, 248, 12, 119, 225, 124, 248,
-    11, 50, 42, 95, 58, 43, 43, 87, 26, 71, 42, 95, 86, 26, 38, 203, 104, 229, 245, 51,
-    205, 61, 2, 232, 3, 248, 6, 126, 230, 2, 248, 12, 34, 54, 0, 248, 8, 42, 95, 86, 33,
-    3, 0, 25, 229, 125, 248, 16, 119, 225, 124, 248, 15, 119, 248, 8, 42, 95, 86, 33, 2,
-    0, 25, 229, 125, 248, 18, 119, 225, 124, 248, 17, 119, 248, 8, 42, 95, 86, 33, 5, 0,
-    25, 229, 125, 248, 20, 119, 225, 124, 248, 19, 119, 248, 8, 42, 95, 86, 33, 4, 0, 25,
-    229, 125, 248, 22, 119, 225, 124, 248, 21, 119, 248, 8, 126, 248, 22, 119, 248, 9,
-    126, 248, 23, 119, 205, 221, 22, 205, 224, 36, 14, 192, 240, 146, 145, 32, 7, 33, 53,
-    218, 54, 223, 24, 5, 33, 53, 218, 54, 192, 33, 14, 197, 54, 0, 205, 241, 19, 30, 4,
-    33, 3, 84, 205, 8, 0, 205, 115, 14, 205, 0, 32, 62, 40, 245, 51, 250, 14, 197, 245,
-    51, 205, 228, 12, 225, 250, 53, 218, 224, 146, 33, 31, 218, 52, 205, 53, 14, 250, 27,
-    198, 248, 24, 119, 250, 28, 198, 248, 25, 119, 229, 43, 203, 86, 225, 40, 20, 229,
-    248, 27, 203, 86, 225, 32, 12, 248, 20, 42, 95, 86, 26, 248, 25, 119, 195, 176, 74,
-    229, 248, 26, 203, 94, 225, 40, 20, 229, 248, 27, 203, 94, 225, 32, 12, 248, 18, 42,
-    95, 86, 26, 248, 25, 119, 195, 176, 74, 229, 248, 26, 203, 78, 225, 40, 19, 229, 248,
-    27, 203, 78, 225, 32, 11, 248, 16, 42, 95, 86, 26, 248, 25, 119, 24, 108, 229, 248,
-    26, 203, 70, 225, 40, 19, 229, 248, 27, 203, 70, 225, 32, 11, 248, 14, 42, 95, 86,
-    26, 248, 25, 119, 24, 81, 229, 248, 26, 203, 102, 225, 40, 44, 229, 248, 27, 203,
-    102, 225, 32, 36, 248, 7, 126, 248, 36, 150, 32, 14, 248, 6, 126, 230, 1, 40, 7, 175,
-    248, 24, 34, 119, 24, 8, 248, 7, 126, 248, 24, 34, 54, 0, 248, 24, 94, 195, 33, 75,
-    229, 248, 26, 203, 110, 225, 202, 158, 73, 229, 248, 27, 203, 110, 225, 194, 158, 73,
-    248, 13, 58, 182, 202, 158, 73, 30, 0, 24, 113, 248, 25, 126, 183, 202, 158, 73, 126,
-    248, 7, 119, 248, 10, 42, 95, 58, 43, 43, 87, 26, 71, 42, 95, 86, 26, 38, 196, 104,
-    229, 245, 51, 205, 61, 2, 232, 3, 248, 25, 78, 6, 0, 105, 96, 41, 9, 41, 77, 68, 248,
-    32, 42, 102, 111, 9, 77, 68, 121, 198, 250, 79, 120, 206, 255, 71, 248, 22, 42, 95,
-    86, 248, 34, 126, 245, 51, 33, 6, 0, 229, 197, 213, 205, 35, 19, 232, 7, 248, 10, 42,
-    95, 58, 43, 43, 87, 26, 71, 42, 95, 86, 26, 38, 203, 104, 229, 245, 51, 205, 61, 2,
-    232, 3, 195, 158, 73, 232, 26, 201, 232, 253, 248, 9, 78, 121, 230, 1, 248, 0, 34,
-    54, 0, 6, 0, 248, 1, 58, 182, 40, 20, 250, 28, 201, 33, 29, 201, 150, 32, 9, 250, 30,
-    201, 33, 31, 201, 150, 40, 2, 6, 1, 203, 73, 40, 8, 250, 33, 201, 183, 32, 2, 6, 1,
-    250, 27, 198, 248, 2, 119, 33, 28, 198, 126, 203, 81, 40, 14, 229, 248, 4, 203, 102,
-    225, 40, 4, 203, 103, 40, 2, 6, 1, 203, 89, 40, 14, 229, 248, 4, 203, 110, 225, 40,
-    4, 203, 111, 40, 2, 6, 1, 203, 97, 40, 14, 250, 27, 198, 183, 40, 6, 250, 28, 198,
-    183, 40, 2, 6, 1, 120, 183, 40, 83, 197, 205, 224, 36, 193, 30, 192, 240, 146, 147,
-    32, 7, 33, 53, 218, 54, 223, 24, 5, 33, 53, 218, 54, 192, 33, 14, 197, 54, 0, 197,
-    205, 241, 19, 30, 4, 33, 3, 84, 205, 8, 0, 205, 115, 14, 205, 0, 32, 193, 62, 40,
-    245, 51, 250, 14, 197, 245, 51, 205, 228, 12, 225, 250, 53, 218, 224, 146, 33, 31,
-    218, 52, 205, 53, 14, 197, 205, 221, 22, 193, 120, 183, 194, 49, 75, 232, 3, 201,
-    104, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    1, 0, 15, 0, 127, 0, 0, 0, 0, 0, 0, 0, 3, 0, 31, 0, 255, 0, 255, 0, 255, 0, 0, 0, 7,
-    0, 63, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 254, 0, 240, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 252, 0, 192, 0, 0, 0, 0, 0, 255, 0, 255, 0, 248, 0, 128, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 63,
-    0, 31, 0, 15, 0, 7, 0, 3, 0, 1, 0, 1, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 224, 0, 240, 0, 248, 0, 252, 0, 254, 0, 254, 0, 255, 0, 255, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 7, 0, 159, 0, 1, 0, 3, 0, 15, 0, 63, 0, 127, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 254, 0, 252, 0, 255, 0, 255, 0, 252,
-    0, 248, 0, 224, 0, 128, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 63, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 254, 0, 240, 0, 0, 0, 255, 0, 255, 0, 255, 0, 252, 0, 224,
-    0, 0, 0, 0, 0, 0, 0, 255, 0, 248, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 31,
-    31, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    255, 0, 127, 0, 63, 0, 31, 0, 15, 0, 7, 0, 7, 0, 7, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 254, 0, 248, 0, 224, 0, 192, 0, 0, 0, 240, 0, 192, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 254, 0, 240, 0, 0, 0, 0, 0, 255, 0, 255, 0, 252, 0,
-    224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
-    0, 3, 0, 3, 0, 3, 0, 3, 31, 31, 31, 31, 31, 25, 31, 25, 31, 25, 31, 25, 31, 25, 31,
-    31, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0,
-    0, 0, 0, 0, 3, 0, 7, 0, 31, 0, 127, 0, 255, 255, 15, 0, 63, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 252, 0, 240,
-    0, 224, 0, 224, 0, 240, 0, 248, 0, 252, 0, 254, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 240, 0,
-    254, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27,
-    0, 27, 0, 27, 0, 27, 0, 27, 0, 27, 0, 27, 0, 27, 31, 31, 31, 25, 31, 25, 31, 25, 31,
-    25, 31, 31, 31, 31, 31, 25, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 254, 0, 252, 0, 255, 255, 255,
-    0, 253, 0, 248, 0, 224, 0, 128, 0, 0, 0, 0, 0, 0, 0, 255, 0, 255, 0, 255, 0, 127, 0,
-    127, 0, 63, 0, 31, 0, 15, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 0, 0, 128, 0, 128, 0, 192, 0, 224, 0, 240, 0, 248, 0, 252, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 128, 0, 240,
-    0, 254, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0, 0, 0, 0, 0, 0, 128, 0, 240, 0,
-    252, 0, 255, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 240, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 27, 0, 27, 0, 27, 0, 27, 0, 27, 0, 27, 4, 3, 12, 3, 31, 25, 31, 25, 31, 25, 31,
-    25, 127, 25, 255, 25, 255, 25, 255, 25, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 227, 255,
-    227, 255, 227, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 48, 0, 54, 0, 54, 7, 0, 3, 0,
-    1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 127, 0,
-    63, 0, 31, 0, 254, 0, 254, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0, 0,
-    0, 0, 0, 0, 128, 0, 192, 0, 224, 0, 240, 0, 248, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 255, 0, 255, 0, 31, 0, 3, 0, 0, 0, 0, 0, 0, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 31, 0, 7, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 252, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 0, 0, 128, 0, 224, 0, 252, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0,
-    0, 0, 0, 3, 0, 7, 0, 159, 0, 255, 0, 255, 0, 255, 0, 60, 3, 252, 3, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 25, 255, 25, 255, 25, 255, 25, 255, 25, 255, 25,
-    255, 25, 255, 25, 255, 255, 255, 255, 255, 248, 254, 248, 248, 248, 248, 248, 248,
-    248, 248, 248, 255, 255, 255, 255, 131, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 227, 255, 227,
-    255, 227, 255, 227, 255, 227, 255, 227, 255, 227, 255, 227, 255, 0, 54, 0, 54, 0, 54,
-    0, 54, 0, 54, 0, 54, 0, 54, 0, 54, 0, 192, 0, 192, 0, 192, 0, 216, 0, 216, 0, 192, 0,
-    192, 0, 192, 15, 0, 7, 0, 7, 0, 3, 0, 1, 0, 0, 0, 0, 0, 0, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 127, 0, 63, 0, 248, 0, 252, 0, 254, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0, 192, 0, 224, 0, 224, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 255, 0, 31, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 63, 0, 31, 0, 127, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 25, 255, 25,
-    255, 25, 255, 25, 255, 25, 255, 25, 255, 25, 255, 25, 248, 248, 248, 248, 248, 248,
-    248, 248, 248, 248, 248, 248, 248, 248, 252, 248, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 255, 255, 255, 255, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 227, 255, 227,
-    255, 227, 255, 227, 255, 227, 255, 227, 255, 227, 255, 227, 255, 0, 54, 0, 54, 0, 54,
-    0, 54, 0, 48, 0, 48, 0, 48, 0, 48, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 31, 0, 15, 0, 7, 0, 3, 0, 1,
-    0, 0, 0, 0, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 127, 0, 240,
-    0, 248, 0, 252, 0, 254, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 128, 0, 128, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 15, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 7, 0, 31, 0, 63,
-    0, 3, 0, 15, 0, 63, 0, 127, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 252, 0, 240, 0, 255, 0, 255, 0, 255, 0, 231, 0, 129, 0, 0, 0, 0, 0, 0, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 63, 0, 7, 0, 1, 0, 255, 25, 255, 25, 255, 25,
-    255, 25, 255, 25, 255, 25, 255, 25, 255, 25, 255, 248, 255, 248, 255, 248, 255, 248,
-    255, 248, 255, 248, 255, 248, 255, 248, 0, 0, 224, 0, 251, 3, 255, 3, 255, 3, 255, 3,
-    255, 3, 255, 3, 3, 3, 3, 3, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 227, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 127, 0, 63, 0, 31, 0, 15, 0, 7, 0, 3, 0, 1, 0, 7, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 224, 0, 243, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 127, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0,
-    0, 3, 0, 7, 0, 31, 0, 127, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 252, 0, 255, 0, 255, 0, 254, 0, 248, 0, 224, 0, 192, 0, 0, 0, 0, 0, 192, 0, 128,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255,
-    255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 63, 25, 31, 25, 31,
-    25, 31, 25, 31, 25, 31, 31, 255, 255, 255, 255, 255, 248, 255, 248, 255, 248, 255,
-    248, 255, 248, 249, 248, 248, 248, 248, 248, 255, 3, 255, 3, 255, 3, 255, 3, 255, 0,
-    255, 0, 63, 0, 15, 0, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 255, 0, 255, 0,
-    255, 0, 248, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0, 0, 0, 0,
-    192, 0, 248, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0,
-    248, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 15, 0, 127, 0, 255, 0, 0, 0, 1, 0, 15,
-    0, 127, 0, 255, 0, 255, 0, 255, 0, 255, 0, 63, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 254, 0, 248, 0, 252, 0, 254, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 254, 0, 252, 0, 240, 0, 192, 0, 0,
-    0, 240, 0, 224, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 254, 255, 248, 248, 248, 248, 248,
-    248, 248, 248, 248, 248, 248, 248, 56, 249, 56, 249, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 128, 0, 128, 255, 0, 127, 0, 15, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 127, 0, 15, 0, 63, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    248, 0, 192, 0, 192, 0, 240, 0, 255, 0, 252, 0, 224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    255, 0, 255, 0, 127, 0, 63, 0, 31, 0, 31, 0, 15, 0, 7, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 254, 0, 248, 0, 224, 0, 128, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 254, 255, 254,
-    255, 254, 255, 254, 255, 254, 255, 254, 255, 254, 255, 56, 249, 56, 249, 56, 249, 56,
-    249, 56, 249, 62, 249, 62, 249, 62, 249, 0, 128, 0, 128, 3, 176, 15, 176, 73, 182,
-    73, 182, 73, 182, 73, 182, 15, 0, 127, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 248, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 224, 0, 0, 0, 0, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 127, 0, 15, 0, 3,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 254, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0, 0, 192, 0, 240, 0, 254, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 3, 0, 1, 0, 0, 0, 0, 0, 192, 0, 240, 0, 254, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 127, 0, 127, 0, 63, 0, 31, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 254, 255,
-    254, 255, 254, 255, 254, 255, 254, 255, 254, 255, 254, 255, 62, 249, 62, 249, 62,
-    249, 62, 249, 62, 249, 62, 249, 62, 249, 62, 249, 73, 182, 73, 182, 73, 182, 79, 176,
-    79, 176, 79, 176, 127, 128, 124, 128, 255, 0, 255, 0, 255, 0, 255, 0, 254, 0, 240, 0,
-    128, 0, 0, 0, 255, 0, 255, 0, 248, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 224, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 31, 0, 3, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 255, 0, 255, 0, 255, 0, 127, 0, 31, 0, 3, 0, 0, 0, 0, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 127, 0, 31, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 247, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 15, 0, 127, 0, 3, 3, 3, 3,
-    3, 3, 7, 3, 63, 3, 255, 3, 255, 3, 255, 3, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 254, 255, 254, 255, 254, 255, 254, 255, 254, 255, 254,
-    255, 254, 255, 255, 255, 62, 249, 63, 248, 60, 248, 56, 248, 56, 248, 56, 248, 56,
-    248, 248, 248, 96, 128, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 2, 2, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 224, 224, 19, 19, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 227, 227, 16, 16,
-    16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108, 108, 146, 146, 164, 164, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 222, 222, 98, 98, 66, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 28, 36, 36,
-    68, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 28, 34, 34, 64, 64, 0, 0, 0, 0, 0, 0, 8, 8,
-    8, 8, 63, 63, 16, 16, 16, 16, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0,
-    255, 0, 31, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    31, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 31, 0, 255, 0, 255, 0, 255, 0, 3, 0, 31,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 3, 255, 3, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 255, 255, 255, 255, 0, 255, 0, 255, 0, 252, 0, 224, 0, 0, 0, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 240, 240, 240, 240, 240, 240, 240, 240, 240, 240, 240, 240, 248, 248, 248, 248,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 7, 7, 0, 0, 0, 0,
-    4, 4, 4, 4, 4, 4, 4, 4, 36, 36, 199, 199, 0, 0, 0, 0, 17, 17, 17, 17, 33, 33, 33, 33,
-    66, 66, 130, 130, 0, 0, 0, 0, 36, 36, 36, 36, 40, 40, 40, 40, 72, 72, 78, 78, 0, 0,
-    1, 1, 66, 66, 66, 66, 131, 131, 133, 133, 140, 140, 240, 240, 128, 128, 0, 0, 132,
-    132, 132, 132, 4, 4, 8, 8, 152, 152, 238, 238, 0, 0, 0, 0, 128, 128, 128, 128, 128,
-    128, 128, 128, 132, 132, 248, 248, 0, 0, 0, 0, 16, 16, 16, 16, 32, 32, 32, 32, 34,
-    34, 28, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 254, 0, 240, 0, 128, 0, 255, 0, 255, 0, 254, 0, 240, 0, 128, 0, 0,
-    0, 0, 0, 0, 0, 248, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 240, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 192, 192, 192, 128, 128, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 6, 6, 4, 4, 7, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 255, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 255, 0, 255, 0, 0, 0, 0, 0, 0, 0, 1, 0, 127, 0,
-    255, 0, 255, 0, 255, 0, 0, 0, 0, 0, 3, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0,
-    0, 15, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 252, 0, 224, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 1, 1, 3, 3, 6, 6, 12, 12, 24, 24, 16, 16, 16, 16, 0, 0, 240, 240, 184, 184, 8,
-    8, 0, 0, 1, 1, 1, 1, 3, 3, 0, 0, 30, 30, 51, 51, 97, 97, 193, 193, 131, 131, 3, 3, 2,
-    2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 112, 112, 80, 80, 80, 80,
-    112, 112, 48, 48, 240, 240, 191, 152, 0, 0, 3, 3, 2, 2, 2, 2, 3, 3, 3, 3, 127, 6,
-    255, 60, 0, 0, 128, 128, 128, 128, 128, 128, 135, 128, 255, 128, 255, 129, 255, 193,
-    0, 0, 31, 31, 59, 59, 111, 97, 255, 193, 255, 129, 255, 131, 255, 134, 0, 0, 7, 7,
-    63, 9, 255, 129, 255, 129, 255, 129, 255, 3, 255, 3, 129, 128, 255, 252, 255, 128,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 4, 255, 63, 255, 76, 255, 8, 255, 8,
-    255, 24, 255, 24, 255, 24, 255, 0, 255, 192, 255, 0, 255, 0, 255, 1, 255, 1, 255, 3,
-    255, 3, 255, 0, 255, 62, 255, 118, 255, 195, 255, 131, 255, 3, 255, 6, 255, 28, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 224, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 192, 0, 0, 0, 255, 0, 255, 0, 255, 0, 255, 0, 254, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 255, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 15, 0, 255, 0, 255, 0, 48, 48, 48, 48, 48, 48, 48, 48, 63, 48, 255,
-    57, 255, 31, 255, 0, 2, 2, 2, 2, 3, 2, 127, 2, 255, 50, 255, 227, 255, 129, 255, 0,
-    2, 2, 7, 6, 255, 6, 255, 14, 255, 12, 255, 181, 255, 231, 255, 0, 15, 7, 255, 0, 255,
-    0, 255, 0, 255, 24, 255, 14, 255, 135, 255, 0, 255, 24, 255, 24, 255, 24, 255, 20,
-    255, 62, 255, 248, 255, 224, 255, 0, 255, 48, 255, 0, 255, 0, 255, 0, 255, 193, 255,
-    119, 255, 63, 255, 0, 255, 193, 255, 193, 255, 207, 255, 153, 255, 241, 255, 192,
-    255, 0, 255, 0, 255, 60, 255, 224, 255, 0, 255, 0, 255, 131, 255, 206, 252, 120, 0,
-    0, 255, 2, 255, 2, 255, 6, 255, 6, 255, 6, 251, 3, 3, 3, 0, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 248, 24, 112, 112, 224, 224, 0, 0, 255, 16, 255, 16, 255, 48, 240, 48, 48,
-    48, 57, 57, 31, 31, 0, 0, 255, 2, 255, 7, 30, 30, 50, 50, 99, 99, 193, 193, 0, 0, 0,
-    0, 248, 240, 192, 192, 0, 0, 2, 2, 6, 6, 156, 156, 240, 240, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 127, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 248, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 224, 0, 0, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 192, 0, 0, 0, 0, 0, 255, 0, 255, 0, 255, 0,
-    252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 255, 0, 248, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    255, 0, 224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 128, 128, 128,
-    128, 128, 128, 128, 128, 128, 128, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 1, 1, 1, 1, 1, 1, 1,
-    1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 128, 128, 128, 128,
-    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 3, 0, 3, 0, 63, 0, 63, 0, 60, 3, 0, 0, 192, 0,
-    192, 0, 192, 0, 195, 0, 195, 0, 195, 0, 3, 192, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 192,
-    0, 192, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
-    0, 63, 0, 63, 0, 63, 0, 63, 192, 0, 0, 0, 0, 0, 0, 0, 0, 252, 0, 252, 0, 252, 0, 252,
-    3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0,
-    63, 0, 63, 0, 63, 192, 0, 0, 0, 0, 0, 0, 0, 0, 254, 0, 254, 0, 254, 0, 254, 1, 0, 1,
-    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 225, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
-    128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 3, 60, 3, 60, 3, 63, 0, 63,
-    0, 63, 0, 63, 0, 3, 0, 3, 192, 3, 192, 3, 192, 195, 60, 195, 60, 195, 60, 195, 60,
-    252, 0, 192, 0, 192, 0, 192, 0, 192, 0, 192, 0, 192, 0, 192, 0, 3, 0, 3, 0, 3, 0, 3,
-    0, 60, 0, 60, 0, 60, 0, 60, 0, 252, 0, 192, 0, 192, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 3, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 254, 195, 0, 195, 0, 195, 0,
-    60, 0, 60, 0, 60, 0, 60, 0, 60, 0, 192, 0, 192, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3,
-    3, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 248, 225, 1, 225, 1, 225, 1, 31, 1,
-    31, 1, 31, 1, 31, 1, 31, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 128,
-    128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 252,
-    0, 252, 0, 252, 0, 60, 0, 60, 0, 60, 0, 60, 0, 63, 0, 3, 0, 3, 0, 3, 0, 31, 0, 31, 0,
-    31, 0, 31, 0, 255, 0, 252, 0, 252, 0, 252, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    0, 0, 0, 0, 0, 0, 224, 0, 224, 0, 224, 0, 224, 0, 255, 0, 254, 254, 254, 254, 254,
-    254, 254, 254, 254, 254, 254, 254, 0, 0, 255, 0, 60, 0, 60, 0, 60, 0, 60, 0, 60, 0,
-    60, 0, 60, 0, 255, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 0, 0, 255, 0, 248, 248,
-    248, 248, 248, 248, 249, 248, 249, 248, 249, 248, 1, 0, 255, 0, 31, 1, 31, 1, 31, 1,
-    255, 1, 255, 1, 255, 1, 255, 1, 255, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 63, 0, 63, 0, 63, 0, 3, 0, 3, 0, 3, 0, 3, 0, 0, 3, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 63, 192, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 1, 255, 1, 255, 1, 255, 1, 255, 1, 255, 1, 255, 1, 255, 1, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128,
-    128, 128, 128, 128, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    63, 192, 63, 192, 63, 192, 7, 56, 7, 56, 7, 56, 7, 56, 7, 56, 255, 0, 255, 120, 255,
-    120, 255, 120, 255, 120, 255, 7, 255, 7, 255, 7, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 255, 255, 255, 255, 255, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 255,
-    255, 255, 255, 255, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 255, 255, 255, 255,
-    255, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 192, 255, 192, 255, 192, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 1, 255, 1, 255, 1, 255,
-    1, 225, 31, 225, 31, 225, 31, 225, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 128, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 3, 0, 3, 0, 60, 7, 56, 0, 63, 0, 63, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 3, 255, 7, 255, 0, 255, 0, 255, 0, 255, 0, 0, 255, 0,
-    255, 0, 255, 255, 255, 255, 0, 255, 0, 255, 0, 255, 0, 63, 192, 63, 192, 0, 255, 255,
-    255, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 255, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 192, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 0, 255, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 0,
-    255, 225, 31, 225, 31, 225, 31, 225, 31, 129, 121, 129, 121, 129, 121, 1, 249, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 128, 128, 128, 128, 128, 128, 128,
-    128, 128, 128, 128, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0,
-    0, 0, 0, 0, 0, 0, 3, 0, 3, 0, 3, 0, 3, 255, 255, 0, 0, 0, 60, 0, 60, 0, 252, 0, 192,
-    0, 192, 0, 192, 255, 255, 0, 0, 0, 3, 0, 3, 0, 63, 0, 60, 0, 60, 0, 60, 255, 255, 0,
-    0, 0, 255, 0, 255, 0, 255, 0, 7, 0, 7, 0, 7, 255, 255, 0, 0, 0, 255, 0, 255, 0, 255,
-    0, 128, 0, 128, 0, 128, 255, 255, 0, 0, 0, 255, 0, 255, 0, 255, 0, 0, 0, 0, 0, 0,
-    255, 255, 0, 0, 0, 255, 0, 255, 0, 255, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 255, 0,
-    255, 0, 255, 0, 1, 0, 1, 0, 1, 255, 255, 0, 0, 0, 255, 0, 255, 0, 255, 0, 231, 0,
-    231, 0, 231, 255, 255, 0, 0, 1, 159, 1, 159, 1, 159, 1, 159, 1, 129, 1, 129, 255,
-    255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 249, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 2, 0, 2, 0, 2, 0, 2, 0, 14, 0, 6, 0, 6, 0, 14, 0, 12,
-    0, 12, 0, 12, 0, 20, 0, 9, 0, 9, 0, 17, 0, 18, 0, 18, 0, 18, 0, 34, 0, 36, 0, 140, 0,
-    140, 0, 148, 0, 185, 0, 169, 0, 73, 0, 73, 0, 10, 0, 198, 0, 198, 0, 202, 0, 92, 0,
-    84, 0, 36, 0, 36, 0, 4, 0, 130, 0, 68, 0, 72, 0, 88, 0, 112, 0, 32, 0, 32, 0, 64, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 16, 0, 16, 0, 24, 0, 7, 0, 1,
-    0, 0, 0, 1, 0, 72, 0, 16, 0, 16, 0, 32, 0, 32, 0, 32, 0, 160, 0, 33, 0, 204, 0, 76,
-    0, 74, 0, 138, 0, 146, 0, 146, 0, 145, 0, 17, 0, 36, 0, 40, 0, 72, 0, 80, 0, 83, 0,
-    80, 0, 144, 0, 144, 0, 32, 0, 32, 0, 0, 0, 0, 0, 192, 0, 64, 0, 64, 0, 128, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 20, 0, 231, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 196, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 8, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 35, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 33, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 252, 252, 36,
-    36, 36, 36, 71, 71, 68, 68, 72, 72, 72, 72, 73, 73, 94, 94, 96, 96, 160, 160, 184,
-    184, 160, 160, 160, 160, 192, 192, 120, 120, 7, 7, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 9,
-    9, 14, 14, 14, 14, 146, 146, 18, 18, 18, 18, 29, 29, 21, 21, 38, 38, 38, 38, 65, 65,
-    66, 66, 194, 194, 195, 195, 66, 66, 196, 196, 68, 68, 71, 71, 206, 206, 81, 81, 80,
-    80, 136, 136, 70, 70, 66, 66, 98, 98, 188, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 97, 0, 33, 30, 197, 54, 0, 33, 31, 197, 54, 0,
-    33, 32, 197, 54, 0, 33, 33, 197, 54, 0, 250, 190, 217, 214, 16, 32, 31, 33, 186, 192,
-    42, 70, 175, 33, 186, 192, 34, 112, 33, 188, 192, 42, 70, 14, 0, 33, 128, 0, 9, 77,
-    68, 33, 188, 192, 121, 34, 112, 201, 33, 186, 192, 42, 79, 70, 203, 56, 203, 25, 203,
-    56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25,
-    203, 56, 203, 25, 175, 203, 24, 65, 203, 24, 31, 33, 186, 192, 34, 112, 33, 188, 192,
-    42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25,
-    203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 175, 203, 24, 65, 203, 24, 31,
-    33, 188, 192, 34, 112, 201, 232, 251, 248, 0, 54, 4, 250, 190, 217, 214, 16, 32, 61,
-    33, 186, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203,
-    56, 203, 25, 121, 230, 15, 32, 34, 33, 188, 192, 42, 79, 70, 203, 56, 203, 25, 203,
-    56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 121, 230, 15, 79, 6, 0, 121, 214, 8,
-    176, 40, 64, 250, 190, 217, 214, 8, 194, 141, 127, 33, 186, 192, 42, 79, 70, 203, 56,
-    203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 121, 230, 7, 194, 141,
-    127, 33, 188, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25,
-    203, 56, 203, 25, 121, 230, 7, 194, 141, 127, 33, 7, 197, 54, 0, 175, 245, 51, 17,
-    186, 192, 213, 17, 191, 192, 213, 30, 4, 33, 178, 94, 205, 8, 0, 232, 5, 123, 183,
-    194, 253, 127, 250, 29, 198, 248, 4, 119, 229, 203, 78, 225, 32, 17, 33, 27, 198, 78,
-    33, 29, 198, 126, 183, 194, 75, 123, 203, 73, 202, 75, 123, 33, 7, 197, 54, 1, 248,
-    0, 54, 3, 33, 188, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203,
-    25, 203, 56, 203, 25, 250, 193, 192, 95, 7, 159, 248, 3, 113, 35, 112, 87, 58, 110,
-    103, 25, 77, 68, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 194, 192,
-    95, 7, 159, 87, 248, 3, 42, 102, 111, 25, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27,
-    203, 58, 203, 27, 123, 60, 248, 2, 34, 17, 186, 192, 26, 34, 19, 26, 119, 203, 62,
-    43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43,
-    203, 30, 250, 191, 192, 71, 7, 159, 94, 35, 86, 104, 103, 25, 93, 84, 203, 58, 203,
-    27, 203, 58, 203, 27, 203, 58, 203, 27, 248, 3, 115, 248, 2, 126, 145, 202, 124, 126,
-    248, 3, 42, 61, 119, 33, 50, 197, 150, 48, 57, 121, 33, 51, 197, 150, 48, 50, 33, 47,
-    197, 70, 33, 50, 197, 110, 38, 0, 89, 22, 0, 197, 229, 213, 205, 105, 53, 232, 4,
-    193, 123, 33, 48, 197, 134, 35, 95, 122, 142, 87, 248, 4, 110, 38, 0, 25, 93, 84,
-    197, 51, 213, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 91, 40, 8, 33, 7, 197, 54, 0,
-    195, 124, 126, 12, 24, 164, 229, 248, 6, 203, 70, 225, 32, 13, 33, 29, 198, 126, 183,
-    194, 85, 124, 203, 65, 202, 85, 124, 33, 7, 197, 54, 1, 248, 0, 54, 1, 33, 188, 192,
-    42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25,
-    250, 193, 192, 95, 7, 159, 248, 3, 113, 35, 112, 87, 58, 110, 103, 25, 77, 68, 203,
-    56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 194, 192, 95, 7, 159, 87, 248,
-    3, 42, 102, 111, 25, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27,
-    123, 60, 248, 2, 34, 17, 186, 192, 26, 34, 19, 26, 119, 203, 62, 43, 203, 30, 35,
-    203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 250, 192,
-    192, 71, 7, 159, 94, 35, 86, 104, 103, 25, 93, 84, 203, 58, 203, 27, 203, 58, 203,
-    27, 203, 58, 203, 27, 248, 3, 115, 248, 2, 126, 145, 202, 124, 126, 248, 3, 42, 60,
-    119, 33, 50, 197, 150, 48, 57, 121, 33, 51, 197, 150, 48, 50, 33, 47, 197, 70, 33,
-    50, 197, 110, 38, 0, 89, 22, 0, 197, 229, 213, 205, 105, 53, 232, 4, 193, 123, 33,
-    48, 197, 134, 35, 95, 122, 142, 87, 248, 4, 110, 38, 0, 25, 93, 84, 197, 51, 213,
-    205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 83, 40, 8, 33, 7, 197, 54, 0, 195, 124, 126,
-    12, 24, 164, 229, 248, 6, 203, 86, 225, 32, 13, 33, 29, 198, 126, 183, 194, 106, 125,
-    203, 81, 202, 106, 125, 33, 7, 197, 54, 1, 248, 0, 54, 2, 33, 186, 192, 42, 79, 70,
-    203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 191,
-    192, 95, 7, 159, 248, 3, 113, 35, 112, 87, 58, 110, 103, 25, 77, 68, 203, 56, 203,
-    25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 192, 192, 95, 7, 159, 87, 248, 3, 42,
-    102, 111, 25, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 123, 60,
-    248, 1, 34, 35, 17, 188, 192, 26, 34, 19, 26, 119, 203, 62, 43, 203, 30, 35, 203, 62,
-    43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 250, 193, 192, 71,
-    7, 159, 94, 35, 86, 104, 103, 25, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203,
-    58, 203, 27, 248, 2, 115, 248, 1, 126, 145, 202, 124, 126, 248, 2, 42, 61, 119, 121,
-    33, 50, 197, 150, 48, 66, 248, 3, 126, 33, 51, 197, 150, 48, 57, 250, 47, 197, 248,
-    4, 119, 33, 50, 197, 94, 22, 0, 248, 3, 126, 6, 0, 197, 213, 95, 80, 213, 205, 105,
-    53, 232, 4, 193, 123, 33, 48, 197, 134, 35, 95, 122, 142, 87, 105, 38, 0, 25, 93, 84,
-    248, 4, 126, 245, 51, 213, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 75, 40, 8, 33, 7,
-    197, 54, 0, 195, 124, 126, 12, 24, 154, 229, 248, 6, 203, 94, 225, 32, 12, 250, 29,
-    198, 183, 194, 124, 126, 203, 89, 202, 124, 126, 33, 7, 197, 54, 1, 248, 0, 54, 0,
-    33, 186, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203,
-    56, 203, 25, 250, 191, 192, 95, 7, 159, 248, 3, 113, 35, 112, 87, 58, 110, 103, 25,
-    77, 68, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 192, 192, 95, 7,
-    159, 87, 248, 3, 42, 102, 111, 25, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203,
-    58, 203, 27, 123, 60, 248, 1, 34, 35, 17, 188, 192, 26, 34, 19, 26, 119, 203, 62, 43,
-    203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203,
-    30, 250, 194, 192, 71, 7, 159, 94, 35, 86, 104, 103, 25, 93, 84, 203, 58, 203, 27,
-    203, 58, 203, 27, 203, 58, 203, 27, 248, 2, 115, 248, 1, 126, 145, 40, 94, 248, 2,
-    42, 60, 119, 121, 33, 50, 197, 150, 48, 67, 248, 3, 126, 33, 51, 197, 150, 48, 58,
-    250, 47, 197, 248, 4, 119, 33, 50, 197, 94, 22, 0, 248, 3, 126, 6, 0, 197, 213, 95,
-    80, 213, 205, 105, 53, 232, 4, 193, 123, 33, 48, 197, 134, 35, 95, 122, 142, 87, 105,
-    38, 0, 25, 93, 84, 248, 4, 126, 245, 51, 213, 205, 7, 19, 232, 3, 123, 24, 2, 62, 15,
-    15, 48, 7, 33, 7, 197, 54, 0, 24, 3, 12, 24, 156, 248, 0, 126, 214, 4, 40, 26, 250,
-    7, 197, 245, 51, 248, 1, 126, 245, 51, 17, 185, 192, 213, 30, 2, 33, 101, 70, 205, 8,
-    0, 232, 4, 24, 13, 17, 185, 192, 213, 30, 2, 33, 6, 70, 205, 8, 0, 225, 250, 31, 218,
-    230, 1, 71, 14, 0, 120, 61, 177, 32, 30, 175, 245, 51, 30, 2, 33, 135, 72, 205, 8, 0,
-    51, 122, 179, 40, 14, 33, 47, 0, 25, 126, 183, 40, 6, 33, 9, 197, 123, 34, 114, 250,
-    7, 197, 183, 40, 46, 175, 245, 51, 250, 190, 217, 245, 51, 30, 2, 33, 160, 71, 205,
-    8, 0, 225, 122, 179, 40, 25, 33, 9, 197, 123, 34, 114, 1, 197, 192, 17, 196, 192, 26,
-    2, 1, 198, 192, 26, 60, 2, 33, 7, 197, 54, 0, 250, 27, 198, 14, 0, 245, 230, 16, 95,
-    22, 0, 241, 163, 111, 121, 162, 181, 40, 113, 33, 28, 198, 78, 6, 0, 121, 163, 79,
-    120, 162, 177, 32, 99, 62, 1, 245, 51, 250, 190, 217, 245, 51, 30, 2, 33, 160, 71,
-    205, 8, 0, 225, 122, 179, 40, 77, 33, 47, 0, 25, 126, 183, 32, 69, 1, 190, 192, 10,
-    198, 2, 230, 3, 213, 38, 0, 229, 51, 245, 51, 213, 30, 2, 33, 101, 70, 205, 8, 0,
-    232, 4, 209, 33, 7, 197, 54, 0, 33, 37, 0, 25, 126, 183, 40, 29, 35, 78, 35, 70, 17,
-    0, 0, 213, 38, 1, 229, 51, 17, 0, 0, 213, 197, 245, 51, 30, 3, 33, 144, 93, 205, 8,
-    0, 232, 8, 250, 7, 197, 183, 40, 106, 250, 200, 192, 248, 2, 119, 250, 190, 192, 248,
-    4, 119, 33, 186, 192, 42, 79, 70, 248, 4, 126, 22, 0, 135, 203, 18, 95, 33, 196, 25,
-    25, 229, 125, 248, 5, 119, 225, 124, 248, 4, 50, 42, 95, 58, 43, 87, 26, 197, 102,
-    229, 51, 245, 51, 205, 65, 53, 225, 193, 107, 98, 9, 77, 68, 33, 186, 192, 121, 34,
-    112, 33, 188, 192, 42, 79, 70, 248, 3, 42, 95, 58, 43, 87, 19, 26, 197, 102, 229, 51,
-    245, 51, 205, 65, 53, 225, 193, 107, 98, 9, 77, 68, 33, 188, 192, 121, 34, 112, 232,
-    5, 201, 175, 33, 1, 197, 34, 119, 175, 33, 253, 196, 34, 119, 175, 33, 255, 196, 34,
-    119, 33, 7, 197, 54, 0, 33, 8, 197, 54, 0, 175, 33, 9, 197, 34, 119, 175, 33, 11,
-    197, 34, 119, 17, 68, 4, 213, 17, 0, 0, 213, 17, 185, 192, 213, 205, 176, 55, 232, 6,
-    201, 2, 1, 0, 255, 254, 253, 252, 251, 250, 251, 252, 253, 254, 255, 0, 0, 0, 0, 7,
-    0, 8, 2, 7, 128, 0, 0, 0, 17, 185, 192, 213, 30, 2, 33, 6, 70, 205, 8, 0, 225, 33,
-    185, 192, 203, 150, 203, 158, 201, 232, 248, 248, 14, 126, 248, 0, 119, 248, 15, 126,
-    248, 1, 119, 193, 197, 105, 96, 126, 230, 1, 248, 7, 119, 183, 202, 216, 65, 248, 0,
-    126, 214, 185, 32, 9, 35, 126, 214, 192, 202, 216, 65, 24, 0, 248, 0, 126, 248, 6,
-    119, 248, 1, 126, 248, 7, 50, 42, 102, 111, 203, 134, 209, 213, 33, 48, 0, 25, 229,
-    125, 248, 4, 119, 225, 124, 248, 3, 50, 42, 95, 86, 26, 248, 6, 34, 19, 26, 119, 209,
-    213, 33, 50, 0, 25, 77, 68, 248, 7, 58, 182, 40, 70, 105, 96, 42, 102, 111, 180, 40,
-    62, 17, 48, 0, 25, 93, 84, 248, 6, 42, 18, 19, 126, 18, 248, 2, 42, 95, 86, 26, 248,
-    6, 34, 19, 26, 50, 42, 95, 86, 33, 50, 0, 25, 229, 125, 248, 6, 119, 225, 124, 248,
-    5, 34, 89, 80, 26, 34, 19, 26, 50, 43, 43, 42, 95, 42, 87, 42, 18, 19, 126, 18, 24,
-    57, 248, 7, 58, 182, 40, 28, 42, 95, 86, 33, 50, 0, 25, 93, 84, 175, 18, 19, 18, 248,
-    2, 42, 95, 86, 26, 33, 253, 196, 34, 19, 26, 119, 24, 23, 105, 96, 42, 102, 111, 180,
-    40, 9, 17, 48, 0, 25, 175, 34, 119, 24, 6, 175, 33, 253, 196, 34, 119, 175, 2, 3, 2,
-    248, 2, 42, 95, 86, 33, 1, 197, 42, 18, 19, 126, 18, 58, 182, 40, 15, 42, 70, 198,
-    50, 79, 48, 1, 4, 248, 0, 42, 2, 3, 126, 2, 248, 0, 126, 234, 1, 197, 248, 1, 126,
-    234, 2, 197, 209, 213, 33, 43, 0, 25, 229, 125, 248, 8, 119, 225, 124, 248, 7, 50,
-    42, 95, 58, 87, 26, 34, 19, 26, 119, 229, 203, 126, 225, 32, 14, 248, 6, 126, 245,
-    51, 30, 3, 33, 213, 94, 205, 8, 0, 51, 209, 213, 33, 45, 0, 25, 229, 125, 248, 8,
-    119, 225, 124, 248, 7, 50, 42, 95, 58, 87, 26, 34, 19, 26, 119, 229, 203, 126, 225,
-    32, 14, 248, 6, 126, 245, 51, 30, 3, 33, 59, 95, 205, 8, 0, 51, 232, 8, 201, 232,
-    248, 248, 14, 42, 79, 70, 105, 96, 126, 230, 1, 194, 23, 67, 105, 96, 126, 203, 55,
-    7, 218, 23, 67, 105, 96, 203, 198, 197, 197, 30, 2, 33, 6, 70, 205, 8, 0, 225, 193,
-    33, 48, 0, 9, 51, 51, 93, 84, 213, 26, 248, 6, 34, 19, 26, 119, 33, 50, 0, 9, 229,
-    125, 248, 4, 119, 225, 124, 248, 3, 119, 248, 7, 58, 182, 40, 75, 248, 2, 42, 95, 86,
-    26, 111, 19, 26, 103, 181, 40, 62, 17, 48, 0, 25, 93, 84, 248, 6, 42, 18, 19, 58, 18,
-    209, 213, 26, 34, 19, 26, 50, 42, 95, 86, 33, 50, 0, 25, 229, 125, 248, 6, 119, 225,
-    124, 248, 5, 50, 43, 43, 42, 95, 86, 26, 248, 6, 34, 19, 26, 50, 43, 43, 42, 95, 42,
-    87, 42, 18, 19, 126, 18, 24, 59, 248, 7, 58, 182, 40, 25, 42, 95, 86, 33, 50, 0, 25,
-    93, 84, 175, 18, 19, 18, 209, 213, 26, 33, 1, 197, 34, 19, 26, 119, 24, 28, 248, 2,
-    42, 95, 86, 26, 111, 19, 26, 103, 181, 40, 9, 17, 48, 0, 25, 175, 34, 119, 24, 6,
-    175, 33, 1, 197, 34, 119, 248, 2, 42, 102, 111, 175, 34, 119, 209, 213, 33, 253, 196,
-    42, 18, 19, 126, 18, 58, 182, 40, 10, 42, 95, 86, 33, 50, 0, 25, 121, 34, 112, 33,
-    253, 196, 121, 34, 112, 33, 43, 0, 9, 93, 84, 175, 34, 54, 128, 33, 40, 0, 9, 126,
-    248, 5, 119, 183, 40, 39, 35, 123, 34, 114, 33, 41, 0, 9, 42, 110, 95, 85, 197, 175,
-    245, 51, 248, 9, 42, 102, 111, 229, 213, 248, 12, 126, 245, 51, 30, 3, 33, 144, 93,
-    205, 8, 0, 232, 6, 193, 33, 45, 0, 9, 175, 34, 54, 128, 232, 8, 201, 232, 250, 250,
-    1, 197, 234, 21, 197, 250, 2, 197, 234, 22, 197, 33, 22, 197, 58, 182, 202, 14, 68,
-    42, 79, 70, 105, 96, 35, 35, 35, 42, 102, 95, 84, 203, 58, 203, 27, 203, 58, 203, 27,
-    203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203,
-    27, 248, 5, 115, 33, 48, 0, 9, 93, 84, 26, 248, 0, 34, 19, 26, 119, 248, 13, 126,
-    248, 5, 150, 194, 255, 67, 105, 96, 35, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203,
-    25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56,
-    203, 25, 248, 2, 121, 34, 175, 50, 42, 79, 70, 3, 248, 12, 126, 248, 4, 34, 175, 50,
-    42, 145, 126, 152, 86, 120, 203, 127, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122,
-    40, 1, 55, 48, 66, 248, 4, 42, 95, 86, 33, 23, 0, 25, 77, 68, 248, 2, 42, 145, 126,
-    152, 86, 120, 203, 127, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 48,
-    29, 193, 197, 197, 33, 21, 197, 42, 95, 86, 213, 30, 2, 33, 219, 65, 205, 8, 0, 225,
-    193, 33, 21, 197, 121, 34, 112, 195, 40, 67, 248, 0, 126, 234, 21, 197, 248, 1, 126,
-    234, 22, 197, 195, 40, 67, 232, 6, 201, 232, 249, 250, 1, 197, 234, 23, 197, 250, 2,
-    197, 234, 24, 197, 33, 24, 197, 58, 182, 202, 95, 69, 42, 79, 70, 105, 96, 35, 93,
-    84, 26, 248, 5, 34, 19, 26, 119, 248, 5, 42, 95, 58, 43, 87, 203, 58, 203, 27, 203,
-    58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27,
-    203, 58, 203, 27, 115, 105, 96, 35, 35, 35, 93, 84, 26, 248, 0, 34, 19, 26, 119, 209,
-    213, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58,
-    203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 248, 2, 115, 248, 5, 42, 95, 86, 203,
-    58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 33, 7, 0, 9, 126,
-    111, 7, 159, 103, 25, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27,
-    248, 5, 115, 209, 213, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58,
-    203, 27, 33, 8, 0, 9, 126, 111, 7, 159, 103, 25, 93, 84, 203, 58, 203, 27, 203, 58,
-    203, 27, 203, 58, 203, 27, 248, 6, 115, 33, 48, 0, 9, 42, 79, 70, 248, 13, 126, 248,
-    4, 150, 56, 97, 35, 126, 248, 13, 150, 56, 90, 35, 94, 22, 0, 33, 21, 0, 25, 229,
-    125, 248, 5, 119, 225, 124, 248, 4, 34, 35, 58, 34, 54, 0, 248, 3, 93, 84, 248, 5,
-    26, 19, 150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203,
-    122, 40, 1, 55, 56, 35, 248, 2, 126, 248, 14, 150, 56, 27, 197, 33, 23, 197, 42, 95,
-    86, 213, 30, 2, 33, 219, 65, 205, 8, 0, 225, 193, 33, 23, 197, 121, 34, 112, 195, 31,
-    68, 33, 23, 197, 121, 34, 112, 195, 31, 68, 232, 7, 201, 232, 251, 248, 11, 42, 79,
-    70, 33, 12, 0, 9, 51, 51, 93, 84, 213, 26, 248, 4, 119, 33, 13, 0, 9, 229, 125, 248,
-    4, 119, 225, 124, 248, 3, 119, 248, 13, 126, 248, 4, 150, 32, 16, 248, 2, 42, 95, 42,
-    87, 26, 119, 248, 14, 126, 248, 4, 150, 40, 23, 33, 11, 0, 9, 77, 68, 248, 13, 126,
-    2, 209, 213, 126, 18, 248, 2, 42, 95, 86, 248, 14, 126, 18, 232, 5, 201, 59, 59, 248,
-    8, 42, 95, 86, 213, 33, 11, 0, 25, 209, 51, 51, 229, 33, 12, 0, 25, 78, 33, 13, 0,
-    25, 94, 22, 0, 105, 38, 0, 123, 149, 71, 122, 156, 248, 10, 94, 22, 0, 197, 104, 103,
-    229, 213, 205, 157, 56, 232, 4, 193, 123, 129, 79, 225, 229, 113, 51, 51, 201, 248,
-    6, 42, 79, 70, 33, 11, 0, 9, 94, 33, 12, 0, 9, 78, 123, 145, 95, 201, 248, 6, 42, 79,
-    70, 33, 5, 0, 9, 94, 33, 16, 0, 9, 115, 33, 18, 0, 9, 203, 35, 22, 0, 25, 93, 84, 19,
-    26, 60, 86, 245, 51, 213, 51, 197, 30, 2, 33, 98, 69, 205, 8, 0, 232, 4, 201, 248, 6,
-    42, 79, 70, 33, 5, 0, 9, 126, 198, 4, 95, 33, 16, 0, 9, 115, 33, 18, 0, 9, 203, 35,
-    22, 0, 25, 93, 84, 19, 26, 60, 86, 245, 51, 213, 51, 197, 30, 2, 33, 98, 69, 205, 8,
-    0, 232, 4, 201, 248, 6, 42, 79, 70, 33, 5, 0, 9, 93, 84, 248, 8, 42, 18, 126, 183,
-    40, 41, 43, 126, 198, 4, 95, 33, 16, 0, 9, 115, 33, 18, 0, 9, 203, 35, 22, 0, 25, 93,
-    84, 19, 26, 60, 86, 245, 51, 213, 51, 197, 30, 2, 33, 98, 69, 205, 8, 0, 232, 4, 201,
-    248, 8, 94, 33, 16, 0, 9, 115, 33, 18, 0, 9, 85, 203, 35, 106, 22, 0, 25, 93, 84, 19,
-    26, 60, 86, 245, 51, 213, 51, 197, 30, 2, 33, 98, 69, 205, 8, 0, 232, 4, 201, 232,
-    252, 250, 253, 196, 248, 0, 119, 250, 254, 196, 248, 1, 119, 248, 1, 58, 182, 202,
-    154, 71, 248, 12, 126, 183, 32, 15, 248, 0, 42, 78, 111, 97, 126, 203, 55, 15, 230,
-    1, 202, 134, 71, 193, 197, 3, 105, 96, 42, 102, 95, 84, 203, 58, 203, 27, 203, 58,
-    203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203,
-    58, 203, 27, 248, 3, 115, 105, 96, 35, 35, 42, 79, 70, 203, 56, 203, 25, 203, 56,
-    203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203,
-    56, 203, 25, 248, 11, 126, 145, 40, 16, 6, 0, 3, 248, 11, 94, 22, 0, 123, 145, 32,
-    53, 122, 144, 32, 49, 248, 10, 126, 248, 3, 150, 40, 37, 248, 3, 78, 6, 0, 89, 80,
-    19, 248, 10, 126, 248, 2, 34, 175, 50, 126, 147, 32, 5, 35, 126, 146, 40, 12, 11,
-    248, 2, 126, 145, 32, 9, 35, 126, 144, 32, 4, 209, 213, 24, 23, 209, 213, 33, 48, 0,
-    25, 77, 68, 89, 80, 26, 248, 0, 34, 19, 26, 119, 195, 216, 70, 17, 0, 0, 232, 4, 201,
-    232, 244, 248, 0, 125, 84, 248, 10, 34, 114, 33, 186, 192, 42, 79, 70, 248, 10, 42,
-    102, 111, 121, 34, 112, 248, 0, 125, 84, 248, 4, 34, 122, 50, 42, 79, 70, 3, 3, 17,
-    188, 192, 26, 248, 10, 34, 19, 26, 50, 42, 2, 3, 126, 2, 248, 18, 78, 6, 0, 121, 135,
-    203, 16, 135, 203, 16, 135, 203, 16, 135, 203, 16, 248, 6, 34, 112, 250, 190, 192,
-    248, 9, 119, 248, 4, 42, 79, 70, 89, 80, 26, 248, 10, 34, 19, 26, 50, 43, 94, 22, 0,
-    203, 35, 203, 18, 33, 196, 25, 25, 229, 125, 248, 10, 119, 225, 124, 248, 9, 50, 42,
-    95, 86, 26, 95, 7, 159, 87, 197, 248, 8, 42, 102, 111, 229, 213, 205, 105, 53, 232,
-    4, 193, 248, 10, 42, 102, 111, 25, 93, 84, 105, 96, 123, 34, 114, 3, 3, 89, 80, 26,
-    248, 10, 34, 19, 26, 119, 248, 8, 42, 95, 86, 19, 26, 95, 7, 159, 87, 197, 248, 8,
-    42, 102, 111, 229, 213, 205, 105, 53, 232, 4, 193, 248, 10, 42, 102, 111, 25, 93, 84,
-    123, 2, 3, 122, 2, 17, 185, 192, 248, 4, 42, 79, 70, 248, 19, 126, 245, 51, 213, 197,
-    17, 191, 192, 213, 30, 2, 33, 32, 75, 205, 8, 0, 232, 19, 201, 232, 240, 17, 235,
-    192, 26, 248, 0, 34, 19, 26, 119, 248, 1, 58, 182, 202, 26, 75, 209, 213, 33, 50, 0,
-    25, 229, 125, 248, 4, 119, 225, 124, 248, 3, 119, 248, 22, 126, 183, 32, 37, 248, 0,
-    126, 248, 14, 119, 248, 1, 126, 248, 15, 50, 42, 95, 86, 26, 203, 55, 15, 230, 1, 32,
-    14, 248, 2, 42, 95, 86, 26, 248, 0, 34, 19, 26, 119, 24, 190, 209, 213, 107, 98, 35,
-    229, 125, 248, 8, 119, 225, 124, 248, 7, 119, 209, 213, 33, 6, 0, 25, 229, 125, 248,
-    6, 119, 225, 124, 248, 5, 34, 42, 95, 58, 43, 43, 87, 26, 79, 19, 26, 71, 203, 56,
-    203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 42, 95, 86, 26, 95, 7,
-    159, 248, 14, 113, 35, 112, 87, 58, 110, 103, 25, 229, 125, 248, 14, 119, 225, 124,
-    248, 13, 119, 33, 186, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56,
-    203, 25, 203, 56, 203, 25, 250, 192, 192, 95, 7, 159, 87, 248, 8, 121, 34, 120, 50,
-    42, 102, 111, 25, 77, 68, 248, 12, 121, 150, 35, 120, 158, 56, 77, 248, 4, 42, 79,
-    70, 3, 10, 79, 7, 159, 71, 248, 14, 42, 102, 111, 9, 229, 125, 248, 12, 119, 225,
-    124, 248, 11, 119, 250, 191, 192, 248, 14, 34, 7, 159, 50, 58, 43, 119, 248, 15, 58,
-    43, 50, 42, 95, 86, 248, 8, 42, 102, 111, 25, 229, 125, 248, 16, 119, 225, 124, 248,
-    15, 119, 248, 10, 93, 84, 248, 14, 26, 19, 150, 35, 26, 158, 48, 7, 248, 15, 54, 0,
-    195, 1, 75, 248, 6, 42, 95, 86, 33, 2, 0, 25, 229, 125, 248, 16, 119, 225, 124, 248,
-    15, 50, 42, 95, 58, 87, 26, 34, 19, 26, 50, 58, 43, 119, 248, 15, 58, 43, 119, 203,
-    62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43,
-    203, 30, 248, 4, 42, 95, 86, 33, 2, 0, 25, 229, 125, 248, 16, 119, 225, 124, 248, 15,
-    50, 42, 95, 86, 26, 119, 58, 34, 7, 159, 119, 248, 12, 126, 248, 6, 119, 248, 13,
-    126, 248, 7, 119, 248, 14, 42, 95, 86, 248, 6, 42, 102, 111, 25, 229, 125, 248, 10,
-    119, 225, 124, 248, 9, 119, 17, 188, 192, 26, 248, 14, 34, 19, 26, 50, 58, 43, 119,
-    248, 15, 58, 43, 119, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62,
-    43, 203, 30, 35, 203, 62, 43, 203, 30, 250, 194, 192, 248, 15, 119, 58, 34, 7, 159,
-    119, 248, 12, 58, 43, 119, 248, 13, 58, 43, 119, 248, 14, 58, 43, 119, 248, 15, 58,
-    43, 50, 42, 95, 86, 248, 10, 42, 102, 111, 25, 229, 125, 248, 16, 119, 225, 124, 248,
-    15, 119, 248, 14, 93, 84, 248, 8, 26, 19, 150, 35, 26, 158, 56, 101, 248, 4, 42, 95,
-    86, 33, 3, 0, 25, 229, 125, 248, 16, 119, 225, 124, 248, 15, 50, 42, 95, 86, 26, 119,
-    58, 34, 7, 159, 50, 42, 95, 86, 248, 6, 42, 102, 111, 25, 229, 125, 248, 14, 119,
-    225, 124, 248, 13, 119, 250, 193, 192, 248, 15, 119, 58, 34, 7, 159, 50, 126, 248, 8,
-    119, 248, 15, 126, 248, 9, 50, 42, 95, 42, 87, 42, 102, 111, 25, 229, 125, 248, 16,
-    119, 225, 124, 248, 15, 119, 248, 12, 93, 84, 248, 14, 26, 19, 150, 35, 26, 158, 48,
-    6, 248, 15, 54, 0, 24, 4, 248, 15, 54, 1, 248, 15, 126, 183, 40, 4, 209, 213, 24, 18,
-    248, 2, 42, 95, 86, 26, 248, 0, 34, 19, 26, 119, 195, 147, 72, 17, 0, 0, 232, 16,
-    201, 232, 233, 248, 0, 54, 185, 35, 54, 192, 248, 31, 126, 248, 2, 119, 248, 32, 126,
-    248, 3, 119, 248, 29, 126, 248, 4, 119, 248, 30, 126, 248, 5, 50, 42, 95, 86, 107,
-    98, 35, 229, 125, 248, 8, 119, 225, 124, 248, 7, 119, 248, 2, 42, 95, 86, 33, 2, 0,
-    25, 229, 125, 248, 10, 119, 225, 124, 248, 9, 119, 248, 4, 42, 95, 86, 33, 3, 0, 25,
-    229, 125, 248, 12, 119, 225, 124, 248, 11, 119, 248, 4, 42, 79, 70, 3, 3, 10, 248,
-    12, 119, 248, 1, 58, 182, 202, 57, 77, 209, 213, 33, 50, 0, 25, 229, 125, 248, 15,
-    119, 225, 124, 248, 14, 119, 248, 33, 126, 248, 0, 150, 32, 8, 248, 34, 126, 248, 1,
-    150, 40, 20, 248, 35, 126, 183, 32, 28, 248, 0, 42, 78, 111, 97, 126, 203, 55, 15,
-    230, 1, 32, 14, 248, 13, 42, 95, 86, 26, 248, 0, 34, 19, 26, 119, 24, 183, 209, 213,
-    107, 98, 35, 229, 125, 248, 19, 119, 225, 124, 248, 18, 119, 209, 213, 33, 6, 0, 25,
-    229, 125, 248, 17, 119, 225, 124, 248, 16, 34, 42, 95, 58, 43, 43, 87, 26, 79, 19,
-    26, 71, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 42,
-    95, 86, 26, 95, 7, 159, 248, 19, 113, 35, 112, 87, 58, 110, 103, 25, 229, 125, 248,
-    23, 119, 225, 124, 248, 22, 119, 248, 2, 42, 95, 86, 26, 79, 19, 26, 71, 203, 56,
-    203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 248, 6, 42, 95, 86,
-    26, 111, 7, 159, 103, 9, 93, 84, 248, 21, 123, 150, 35, 122, 158, 56, 48, 248, 15,
-    42, 95, 86, 19, 26, 95, 7, 159, 87, 248, 19, 42, 102, 111, 25, 229, 125, 248, 23,
-    119, 225, 124, 248, 22, 119, 248, 4, 42, 95, 86, 26, 111, 7, 159, 103, 9, 77, 68,
-    248, 21, 42, 145, 126, 152, 48, 4, 175, 195, 35, 77, 248, 17, 42, 102, 111, 35, 35,
-    42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25,
-    248, 15, 42, 95, 42, 87, 19, 19, 26, 95, 7, 159, 87, 121, 34, 120, 50, 42, 102, 111,
-    25, 229, 125, 248, 21, 119, 225, 124, 248, 20, 119, 248, 8, 42, 95, 42, 87, 26, 79,
-    19, 26, 71, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25,
-    42, 95, 86, 26, 95, 7, 159, 87, 248, 21, 121, 34, 120, 50, 42, 102, 111, 25, 77, 68,
-    248, 19, 121, 150, 35, 120, 158, 56, 41, 248, 15, 42, 79, 42, 71, 3, 3, 3, 10, 79, 7,
-    159, 71, 42, 102, 111, 9, 77, 68, 248, 12, 126, 95, 7, 159, 87, 248, 21, 42, 102,
-    111, 25, 93, 84, 121, 147, 120, 154, 48, 3, 175, 24, 2, 62, 1, 183, 40, 4, 209, 213,
-    24, 18, 248, 13, 42, 95, 86, 26, 248, 0, 34, 19, 26, 119, 195, 130, 75, 17, 0, 0,
-    232, 23, 201, 59, 250, 8, 197, 183, 32, 116, 33, 10, 197, 58, 182, 40, 109, 42, 79,
-    70, 33, 47, 0, 9, 78, 121, 183, 40, 105, 250, 222, 192, 248, 0, 119, 183, 40, 34, 6,
-    0, 33, 223, 192, 42, 102, 95, 84, 197, 62, 1, 245, 51, 1, 0, 0, 197, 213, 248, 7,
-    126, 245, 51, 30, 3, 33, 144, 93, 205, 8, 0, 232, 8, 33, 9, 197, 42, 95, 86, 33, 37,
-    0, 25, 70, 120, 183, 40, 32, 33, 38, 0, 25, 42, 102, 111, 17, 0, 0, 213, 62, 1, 245,
-    51, 17, 0, 0, 213, 229, 197, 51, 30, 3, 33, 144, 93, 205, 8, 0, 232, 8, 33, 8, 197,
-    54, 20, 24, 8, 33, 8, 197, 126, 183, 40, 1, 53, 175, 33, 9, 197, 34, 119, 51, 201,
-    232, 240, 248, 22, 126, 248, 12, 119, 248, 23, 126, 248, 13, 119, 203, 62, 43, 203,
-    30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30,
-    248, 26, 126, 248, 10, 119, 248, 27, 126, 248, 11, 119, 248, 28, 126, 248, 8, 119,
-    248, 29, 126, 248, 9, 119, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203,
-    62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 248, 24, 126, 248, 6, 119, 248, 25, 126,
-    248, 7, 119, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203,
-    30, 35, 203, 62, 43, 203, 30, 248, 10, 42, 95, 86, 33, 2, 0, 25, 229, 125, 248, 4,
-    119, 225, 124, 248, 3, 119, 248, 10, 42, 95, 86, 33, 3, 0, 25, 229, 125, 248, 6, 119,
-    225, 124, 248, 5, 119, 248, 30, 126, 61, 40, 45, 248, 10, 42, 95, 86, 107, 98, 35,
-    229, 125, 248, 16, 119, 225, 124, 248, 15, 119, 248, 30, 126, 214, 2, 202, 167, 80,
-    248, 30, 126, 214, 3, 202, 185, 82, 248, 30, 126, 214, 4, 202, 40, 84, 195, 178, 85,
-    248, 10, 126, 248, 14, 119, 248, 11, 126, 248, 15, 119, 248, 10, 42, 95, 86, 26, 119,
-    58, 34, 7, 159, 34, 42, 95, 86, 248, 10, 42, 102, 111, 25, 51, 51, 229, 248, 0, 126,
-    248, 12, 119, 248, 1, 126, 248, 13, 119, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203,
-    30, 35, 203, 46, 43, 203, 30, 43, 43, 42, 95, 86, 248, 8, 42, 102, 111, 25, 51, 51,
-    229, 248, 0, 126, 248, 8, 119, 248, 1, 126, 248, 9, 119, 203, 62, 43, 203, 30, 35,
-    203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 42, 95, 86, 33, 1, 0, 123, 149, 95,
-    122, 156, 248, 11, 50, 115, 248, 2, 42, 95, 86, 26, 248, 9, 119, 58, 34, 7, 159, 50,
-    126, 248, 2, 119, 248, 9, 126, 248, 3, 50, 42, 95, 86, 248, 6, 42, 102, 111, 25, 229,
-    125, 248, 10, 119, 225, 124, 248, 9, 119, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203,
-    30, 35, 203, 46, 43, 203, 30, 248, 4, 42, 95, 86, 26, 119, 58, 34, 7, 159, 50, 42,
-    95, 42, 87, 42, 102, 111, 25, 229, 125, 248, 4, 119, 225, 124, 248, 3, 50, 126, 248,
-    6, 119, 248, 3, 126, 248, 7, 119, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35,
-    203, 62, 43, 203, 30, 42, 95, 86, 107, 98, 35, 51, 51, 229, 248, 12, 126, 248, 4,
-    119, 248, 13, 126, 248, 5, 119, 248, 10, 126, 248, 4, 150, 32, 9, 248, 11, 126, 248,
-    5, 150, 202, 159, 80, 248, 8, 58, 43, 119, 248, 9, 58, 43, 119, 248, 0, 126, 248, 6,
-    150, 32, 9, 248, 1, 126, 248, 7, 150, 202, 135, 80, 248, 6, 126, 248, 2, 34, 35, 58,
-    119, 33, 50, 197, 150, 48, 66, 248, 2, 126, 33, 51, 197, 150, 48, 57, 33, 47, 197,
-    70, 33, 50, 197, 94, 22, 0, 248, 2, 126, 14, 0, 197, 213, 95, 81, 213, 205, 105, 53,
-    232, 4, 193, 123, 33, 48, 197, 134, 35, 95, 122, 142, 87, 248, 3, 110, 38, 0, 25, 93,
-    84, 197, 51, 213, 205, 7, 19, 232, 3, 248, 3, 115, 24, 4, 248, 3, 54, 15, 229, 248,
-    5, 203, 94, 225, 40, 84, 248, 12, 42, 95, 86, 107, 98, 35, 229, 125, 248, 12, 119,
-    225, 124, 248, 11, 50, 42, 35, 50, 42, 35, 119, 62, 7, 248, 12, 203, 38, 35, 203, 22,
-    61, 32, 246, 35, 42, 95, 86, 26, 119, 58, 34, 7, 159, 119, 62, 4, 248, 14, 203, 38,
-    35, 203, 22, 61, 32, 246, 43, 43, 43, 42, 95, 42, 87, 42, 102, 111, 123, 149, 95,
-    122, 156, 248, 11, 50, 115, 42, 95, 86, 195, 183, 85, 248, 6, 52, 194, 186, 79, 35,
-    52, 195, 186, 79, 248, 4, 42, 95, 58, 87, 27, 123, 34, 122, 50, 126, 248, 12, 119,
-    248, 5, 126, 248, 13, 119, 195, 159, 79, 248, 28, 42, 95, 86, 195, 183, 85, 248, 14,
-    126, 248, 10, 119, 248, 15, 126, 248, 11, 119, 248, 14, 42, 95, 86, 26, 119, 58, 34,
-    7, 159, 50, 126, 248, 0, 119, 248, 15, 126, 248, 1, 119, 248, 12, 42, 95, 86, 225,
-    229, 25, 229, 125, 248, 16, 119, 225, 124, 248, 15, 119, 203, 46, 43, 203, 30, 35,
-    203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 209, 213, 248, 8, 42, 102, 111, 25,
-    229, 125, 248, 14, 119, 225, 124, 248, 13, 50, 126, 248, 8, 119, 248, 13, 126, 248,
-    9, 119, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 42,
-    95, 86, 107, 98, 35, 229, 125, 248, 14, 119, 225, 124, 248, 13, 119, 248, 2, 42, 95,
-    86, 26, 248, 9, 119, 58, 34, 7, 159, 50, 126, 248, 2, 119, 248, 9, 126, 248, 3, 50,
-    42, 95, 86, 248, 6, 42, 102, 111, 25, 229, 125, 248, 10, 119, 225, 124, 248, 9, 119,
-    203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 248, 4, 42,
-    95, 86, 26, 119, 58, 34, 7, 159, 50, 42, 95, 42, 87, 42, 102, 111, 25, 229, 125, 248,
-    4, 119, 225, 124, 248, 3, 50, 126, 248, 6, 119, 248, 3, 126, 248, 7, 119, 203, 62,
-    43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 42, 95, 86, 107, 98,
-    35, 51, 51, 229, 248, 14, 126, 248, 4, 119, 248, 15, 126, 248, 5, 119, 248, 12, 126,
-    248, 4, 150, 32, 9, 248, 13, 126, 248, 5, 150, 202, 177, 82, 248, 8, 58, 43, 119,
-    248, 9, 58, 43, 119, 248, 0, 126, 248, 6, 150, 32, 9, 248, 1, 126, 248, 7, 150, 202,
-    155, 82, 248, 6, 126, 248, 2, 34, 35, 58, 119, 33, 50, 197, 150, 48, 66, 248, 2, 126,
-    33, 51, 197, 150, 48, 57, 33, 47, 197, 70, 33, 50, 197, 94, 22, 0, 248, 2, 126, 14,
-    0, 197, 213, 95, 81, 213, 205, 105, 53, 232, 4, 193, 123, 33, 48, 197, 134, 35, 95,
-    122, 142, 87, 248, 3, 110, 38, 0, 25, 93, 84, 197, 51, 213, 205, 7, 19, 232, 3, 248,
-    3, 115, 24, 4, 248, 3, 54, 15, 229, 248, 5, 203, 86, 225, 40, 84, 62, 7, 248, 14,
-    203, 38, 35, 203, 22, 61, 32, 246, 248, 10, 42, 95, 42, 35, 87, 26, 119, 58, 34, 7,
-    159, 50, 42, 95, 86, 107, 98, 35, 229, 125, 248, 12, 119, 225, 124, 248, 11, 50, 42,
-    35, 50, 42, 35, 119, 62, 4, 248, 12, 203, 38, 35, 203, 22, 61, 32, 246, 35, 42, 95,
-    86, 248, 12, 42, 102, 111, 123, 149, 95, 122, 156, 248, 11, 50, 115, 42, 95, 86, 195,
-    183, 85, 248, 6, 52, 194, 206, 81, 35, 52, 195, 206, 81, 248, 4, 52, 32, 2, 35, 52,
-    248, 4, 126, 248, 14, 119, 248, 5, 126, 248, 15, 119, 195, 179, 81, 248, 28, 42, 95,
-    86, 195, 183, 85, 248, 2, 58, 43, 119, 248, 3, 58, 43, 34, 42, 95, 86, 26, 79, 7,
-    159, 71, 248, 6, 42, 102, 111, 9, 93, 84, 248, 2, 123, 34, 114, 203, 46, 43, 203, 30,
-    35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 248, 8, 42, 102, 111, 9, 77, 68,
-    203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 17, 1, 0, 121, 147, 95, 120,
-    154, 248, 5, 50, 115, 248, 10, 42, 95, 42, 87, 26, 79, 7, 159, 71, 42, 102, 111, 9,
-    77, 68, 248, 6, 121, 34, 112, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35,
-    203, 46, 43, 203, 30, 248, 14, 42, 95, 86, 26, 79, 7, 159, 71, 248, 12, 42, 102, 111,
-    9, 77, 68, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 3, 248, 8, 121, 34,
-    112, 248, 2, 42, 95, 86, 248, 4, 126, 147, 32, 6, 35, 126, 146, 202, 32, 84, 248, 6,
-    126, 248, 14, 119, 248, 7, 126, 248, 15, 119, 248, 8, 126, 248, 14, 150, 32, 9, 248,
-    9, 126, 248, 15, 150, 202, 23, 84, 248, 13, 123, 34, 126, 248, 10, 119, 33, 50, 197,
-    150, 48, 78, 248, 13, 126, 33, 51, 197, 150, 48, 69, 250, 47, 197, 248, 11, 119, 33,
-    50, 197, 78, 6, 0, 248, 13, 58, 34, 175, 50, 213, 197, 42, 79, 70, 197, 205, 105, 53,
-    232, 4, 75, 66, 209, 121, 33, 48, 197, 134, 35, 79, 120, 142, 71, 248, 10, 110, 38,
-    0, 9, 77, 68, 213, 248, 13, 126, 245, 51, 197, 205, 7, 19, 232, 3, 75, 209, 248, 13,
-    113, 24, 4, 248, 13, 54, 15, 229, 248, 15, 203, 78, 225, 40, 34, 248, 2, 42, 79, 70,
-    3, 175, 203, 24, 65, 203, 24, 31, 79, 209, 213, 26, 111, 7, 159, 103, 41, 41, 41, 41,
-    121, 149, 95, 120, 156, 87, 195, 183, 85, 248, 14, 52, 194, 113, 83, 35, 52, 195,
-    113, 83, 27, 248, 2, 123, 34, 114, 195, 89, 83, 248, 28, 42, 95, 86, 195, 183, 85,
-    248, 4, 126, 248, 0, 119, 248, 5, 126, 248, 1, 119, 248, 4, 42, 95, 42, 87, 26, 79,
-    7, 159, 71, 42, 102, 111, 9, 93, 84, 248, 2, 123, 34, 114, 203, 46, 43, 203, 30, 35,
-    203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 248, 8, 42, 102, 111, 9, 77, 68, 203,
-    56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 3, 248, 4, 121, 34, 112, 248, 10,
-    42, 95, 42, 87, 26, 79, 7, 159, 71, 42, 102, 111, 9, 77, 68, 203, 40, 203, 25, 203,
-    40, 203, 25, 203, 40, 203, 25, 248, 14, 42, 95, 86, 26, 95, 7, 159, 87, 248, 12, 42,
-    102, 111, 25, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 19, 248,
-    6, 123, 34, 114, 248, 2, 42, 95, 86, 248, 4, 126, 147, 32, 6, 35, 126, 146, 202, 171,
-    85, 248, 14, 121, 34, 112, 248, 6, 126, 248, 14, 150, 32, 9, 248, 7, 126, 248, 15,
-    150, 202, 162, 85, 248, 13, 123, 34, 126, 248, 8, 119, 33, 50, 197, 150, 48, 121,
-    248, 13, 126, 33, 51, 197, 150, 48, 112, 250, 47, 197, 248, 9, 119, 250, 50, 197,
-    248, 10, 34, 175, 34, 35, 58, 34, 54, 0, 197, 213, 248, 14, 42, 102, 111, 229, 248,
-    18, 42, 102, 111, 229, 205, 105, 53, 232, 4, 248, 16, 123, 34, 114, 209, 193, 213,
-    248, 14, 42, 95, 86, 33, 48, 197, 42, 102, 111, 25, 209, 229, 125, 248, 12, 119, 225,
-    124, 248, 11, 50, 213, 42, 95, 86, 248, 10, 110, 38, 0, 25, 209, 229, 125, 248, 14,
-    119, 225, 124, 248, 13, 119, 213, 248, 11, 126, 245, 51, 248, 15, 42, 102, 111, 229,
-    205, 7, 19, 232, 3, 107, 209, 125, 24, 2, 62, 15, 15, 48, 46, 248, 2, 42, 78, 135,
-    203, 17, 135, 203, 17, 135, 203, 17, 135, 203, 17, 135, 203, 17, 135, 203, 17, 135,
-    203, 17, 209, 213, 245, 26, 111, 7, 159, 103, 35, 241, 41, 41, 41, 41, 149, 95, 121,
-    156, 87, 24, 32, 248, 14, 52, 194, 204, 84, 35, 52, 195, 204, 84, 19, 248, 2, 123,
-    34, 114, 195, 187, 84, 248, 28, 42, 95, 86, 24, 5, 248, 28, 42, 95, 86, 232, 16, 201,
-    232, 229, 248, 33, 126, 248, 2, 119, 248, 34, 126, 248, 3, 50, 42, 95, 86, 33, 16, 0,
-    25, 54, 1, 248, 35, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 30, 248, 2, 42, 95,
-    86, 33, 8, 0, 25, 229, 125, 248, 27, 119, 225, 124, 248, 26, 50, 42, 95, 86, 26, 111,
-    19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 125, 76, 248, 25, 34, 121, 50, 1, 185, 192,
-    42, 95, 86, 26, 95, 22, 0, 107, 98, 41, 25, 41, 41, 25, 41, 41, 9, 77, 124, 248, 4,
-    113, 35, 50, 43, 43, 42, 95, 86, 33, 18, 0, 25, 229, 125, 248, 8, 119, 225, 124, 248,
-    7, 50, 42, 95, 58, 43, 43, 87, 26, 79, 42, 95, 86, 107, 98, 35, 229, 125, 248, 10,
-    119, 225, 124, 248, 9, 119, 248, 25, 42, 95, 86, 33, 6, 0, 25, 229, 125, 248, 12,
-    119, 225, 124, 248, 11, 119, 248, 25, 42, 95, 86, 33, 2, 0, 25, 229, 125, 248, 14,
-    119, 225, 124, 248, 13, 119, 248, 4, 42, 95, 86, 33, 6, 0, 25, 229, 125, 248, 16,
-    119, 225, 124, 248, 15, 119, 248, 25, 42, 95, 86, 33, 4, 0, 25, 229, 125, 248, 18,
-    119, 225, 124, 248, 17, 119, 248, 8, 42, 95, 86, 33, 2, 0, 25, 229, 125, 248, 20,
-    119, 225, 124, 248, 19, 119, 121, 183, 194, 228, 89, 248, 4, 42, 71, 78, 104, 97,
-    203, 182, 248, 4, 42, 95, 86, 213, 30, 2, 33, 52, 70, 205, 8, 0, 225, 248, 8, 42, 95,
-    86, 26, 79, 19, 26, 71, 121, 230, 240, 79, 58, 110, 103, 121, 34, 112, 248, 18, 42,
-    95, 86, 26, 79, 19, 26, 71, 121, 230, 240, 79, 58, 110, 103, 121, 34, 112, 248, 10,
-    42, 95, 86, 26, 203, 87, 40, 13, 248, 6, 42, 95, 86, 26, 246, 3, 79, 58, 110, 103,
-    113, 248, 10, 42, 95, 86, 26, 248, 6, 94, 35, 86, 245, 26, 248, 28, 119, 241, 15, 48,
-    13, 248, 26, 78, 203, 193, 248, 6, 42, 102, 111, 113, 24, 11, 248, 26, 78, 203, 201,
-    248, 6, 42, 102, 111, 113, 248, 10, 42, 95, 86, 26, 79, 203, 73, 202, 253, 88, 248,
-    18, 42, 95, 86, 26, 248, 25, 34, 19, 26, 119, 248, 8, 42, 95, 86, 26, 71, 19, 26,
-    248, 0, 112, 35, 119, 248, 25, 126, 248, 21, 119, 248, 26, 126, 248, 22, 119, 203,
-    65, 202, 44, 88, 248, 12, 42, 95, 86, 26, 79, 19, 26, 71, 89, 80, 248, 0, 126, 147,
-    32, 5, 35, 126, 146, 40, 59, 248, 0, 123, 150, 35, 122, 158, 48, 5, 17, 1, 0, 24, 3,
-    17, 2, 0, 123, 245, 51, 197, 248, 17, 42, 95, 86, 213, 248, 26, 42, 95, 86, 213, 248,
-    7, 42, 95, 86, 213, 30, 2, 33, 202, 77, 205, 8, 0, 232, 9, 75, 66, 248, 12, 42, 102,
-    111, 121, 34, 112, 248, 18, 42, 95, 58, 43, 43, 87, 26, 79, 19, 26, 71, 42, 95, 86,
-    26, 248, 23, 34, 19, 26, 34, 121, 34, 112, 248, 23, 42, 79, 42, 71, 126, 145, 32, 6,
-    35, 126, 144, 202, 253, 88, 248, 25, 121, 150, 35, 120, 158, 48, 4, 6, 3, 24, 2, 6,
-    4, 248, 12, 42, 95, 86, 26, 79, 19, 26, 197, 51, 248, 24, 94, 35, 86, 213, 248, 17,
-    94, 35, 86, 213, 248, 30, 94, 35, 86, 213, 71, 197, 30, 2, 33, 202, 77, 205, 8, 0,
-    232, 9, 75, 66, 248, 16, 42, 102, 111, 121, 34, 112, 195, 253, 88, 248, 16, 42, 95,
-    86, 26, 248, 23, 34, 19, 26, 50, 42, 35, 50, 42, 35, 119, 248, 21, 126, 248, 25, 150,
-    32, 8, 248, 22, 126, 248, 26, 150, 40, 66, 248, 25, 93, 84, 248, 21, 26, 19, 150, 35,
-    26, 158, 48, 4, 62, 3, 24, 2, 62, 4, 245, 51, 248, 24, 42, 95, 86, 213, 248, 17, 42,
-    95, 86, 213, 248, 26, 42, 95, 86, 213, 248, 7, 42, 95, 86, 213, 30, 2, 33, 202, 77,
-    205, 8, 0, 232, 9, 75, 66, 248, 16, 42, 102, 111, 121, 34, 112, 248, 8, 42, 95, 86,
-    26, 79, 19, 26, 71, 248, 12, 42, 95, 86, 26, 248, 23, 34, 19, 26, 34, 121, 34, 112,
-    248, 23, 42, 79, 42, 71, 126, 145, 32, 5, 35, 126, 144, 40, 69, 248, 25, 121, 150,
-    35, 120, 158, 48, 5, 1, 1, 0, 24, 3, 1, 2, 0, 65, 248, 16, 42, 95, 86, 26, 79, 19,
-    26, 197, 51, 248, 24, 94, 35, 86, 213, 248, 17, 94, 35, 86, 213, 71, 197, 248, 32,
-    42, 95, 86, 213, 30, 2, 33, 202, 77, 205, 8, 0, 232, 9, 75, 66, 248, 12, 42, 102,
-    111, 121, 34, 112, 248, 8, 42, 95, 86, 26, 248, 21, 34, 19, 26, 119, 248, 12, 42, 95,
-    86, 26, 79, 19, 26, 71, 248, 21, 126, 248, 24, 50, 43, 126, 248, 25, 119, 248, 6, 42,
-    95, 86, 26, 248, 26, 50, 43, 126, 145, 32, 5, 35, 126, 144, 40, 13, 248, 26, 78, 203,
-    241, 248, 6, 42, 102, 111, 113, 24, 11, 248, 26, 78, 203, 201, 248, 6, 42, 102, 111,
-    113, 248, 18, 42, 95, 42, 35, 87, 26, 34, 19, 26, 119, 248, 16, 42, 95, 86, 26, 79,
-    19, 26, 71, 248, 21, 126, 248, 24, 50, 43, 126, 248, 25, 119, 248, 6, 42, 95, 86, 26,
-    248, 26, 50, 43, 126, 145, 32, 5, 35, 126, 144, 40, 13, 248, 26, 78, 203, 249, 248,
-    6, 42, 102, 111, 113, 24, 11, 248, 26, 78, 203, 193, 248, 6, 42, 102, 111, 113, 248,
-    8, 42, 95, 86, 26, 79, 19, 26, 71, 248, 12, 42, 95, 86, 26, 111, 19, 26, 103, 125,
-    145, 124, 152, 48, 13, 248, 6, 42, 95, 86, 26, 79, 203, 209, 58, 110, 103, 113, 248,
-    18, 42, 95, 58, 43, 43, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26, 103,
-    125, 145, 124, 152, 48, 13, 248, 6, 42, 95, 86, 26, 79, 203, 217, 58, 110, 103, 113,
-    248, 4, 42, 78, 111, 97, 126, 7, 7, 210, 151, 90, 248, 8, 42, 95, 86, 26, 248, 23,
-    34, 19, 26, 119, 248, 12, 42, 95, 86, 26, 79, 19, 26, 248, 23, 94, 35, 86, 35, 71,
-    123, 230, 128, 34, 114, 123, 145, 122, 152, 48, 28, 248, 23, 126, 230, 127, 40, 21,
-    248, 25, 42, 95, 86, 33, 128, 0, 25, 77, 68, 248, 12, 42, 102, 111, 121, 34, 112, 24,
-    12, 248, 12, 42, 95, 86, 248, 25, 42, 18, 19, 126, 18, 248, 18, 42, 95, 86, 26, 248,
-    23, 34, 19, 26, 119, 248, 16, 42, 95, 86, 26, 79, 19, 26, 248, 23, 94, 35, 86, 35,
-    71, 123, 230, 128, 34, 114, 123, 145, 122, 152, 48, 28, 248, 23, 126, 230, 127, 40,
-    21, 248, 25, 42, 95, 86, 33, 128, 0, 25, 77, 68, 248, 16, 42, 102, 111, 121, 34, 112,
-    24, 12, 248, 16, 42, 95, 86, 248, 25, 42, 18, 19, 126, 18, 248, 4, 42, 71, 78, 104,
-    97, 203, 182, 248, 6, 42, 95, 86, 26, 248, 26, 119, 230, 65, 79, 248, 4, 42, 95, 86,
-    33, 15, 0, 25, 229, 125, 248, 22, 119, 225, 124, 248, 21, 119, 121, 214, 65, 194,
-    150, 92, 229, 248, 28, 203, 86, 225, 62, 3, 32, 2, 62, 1, 234, 83, 202, 248, 20, 42,
-    95, 42, 87, 26, 119, 250, 83, 202, 248, 23, 119, 248, 8, 42, 79, 70, 248, 23, 42, 34,
-    89, 80, 26, 34, 19, 26, 119, 248, 23, 110, 38, 0, 41, 17, 196, 25, 25, 126, 197, 248,
-    24, 102, 229, 51, 245, 51, 205, 65, 53, 225, 193, 248, 25, 42, 102, 111, 25, 93, 84,
-    105, 96, 123, 34, 114, 3, 3, 89, 80, 26, 248, 25, 34, 19, 26, 50, 43, 110, 38, 0, 41,
-    17, 196, 25, 25, 35, 126, 197, 248, 24, 102, 229, 51, 245, 51, 205, 65, 53, 225, 193,
-    248, 25, 42, 102, 111, 25, 93, 84, 123, 2, 3, 122, 2, 248, 10, 42, 95, 86, 26, 203,
-    79, 202, 3, 92, 175, 245, 51, 248, 5, 42, 95, 86, 213, 248, 11, 42, 95, 86, 213, 248,
-    19, 42, 95, 86, 213, 30, 2, 33, 32, 75, 205, 8, 0, 232, 7, 122, 179, 202, 3, 92, 248,
-    20, 42, 95, 86, 26, 248, 26, 119, 250, 83, 202, 198, 2, 230, 3, 248, 25, 119, 248, 8,
-    42, 79, 70, 248, 25, 126, 248, 22, 34, 89, 80, 26, 34, 19, 26, 34, 110, 38, 0, 41,
-    17, 196, 25, 25, 126, 197, 248, 28, 102, 229, 51, 245, 51, 205, 65, 53, 225, 193,
-    248, 23, 42, 102, 111, 25, 93, 84, 105, 96, 123, 34, 114, 3, 3, 89, 80, 26, 248, 24,
-    34, 19, 26, 119, 248, 22, 94, 22, 0, 203, 35, 203, 18, 33, 197, 25, 25, 93, 84, 26,
-    197, 248, 28, 102, 229, 51, 245, 51, 205, 65, 53, 225, 193, 248, 24, 42, 102, 111,
-    25, 93, 84, 123, 2, 3, 122, 2, 248, 6, 42, 102, 111, 54, 0, 248, 4, 42, 95, 86, 213,
-    30, 2, 33, 6, 70, 205, 8, 0, 225, 195, 84, 95, 248, 6, 42, 95, 86, 26, 203, 103, 32,
-    34, 79, 203, 225, 248, 6, 42, 102, 111, 113, 62, 1, 245, 51, 250, 83, 202, 245, 51,
-    248, 6, 42, 95, 86, 213, 30, 2, 33, 101, 70, 205, 8, 0, 232, 4, 250, 83, 202, 214, 3,
-    32, 28, 248, 8, 42, 95, 86, 26, 79, 19, 26, 71, 248, 12, 42, 95, 86, 26, 111, 19, 26,
-    103, 93, 84, 123, 145, 122, 152, 48, 34, 250, 83, 202, 61, 32, 62, 248, 8, 42, 95,
-    86, 26, 79, 19, 26, 71, 248, 12, 42, 95, 86, 26, 111, 19, 26, 103, 93, 84, 121, 147,
-    120, 154, 56, 34, 77, 68, 248, 8, 42, 102, 111, 121, 34, 112, 248, 6, 42, 95, 58, 87,
-    26, 203, 207, 245, 42, 102, 111, 241, 119, 230, 190, 79, 248, 6, 42, 102, 111, 113,
-    248, 6, 42, 95, 86, 26, 79, 230, 130, 214, 130, 194, 26, 95, 203, 89, 40, 5, 17, 2,
-    0, 24, 3, 17, 0, 0, 33, 83, 202, 115, 248, 20, 42, 95, 42, 87, 26, 119, 250, 83, 202,
-    248, 23, 119, 248, 8, 42, 79, 70, 248, 23, 42, 34, 89, 80, 26, 34, 19, 26, 119, 248,
-    23, 110, 38, 0, 41, 17, 196, 25, 25, 126, 197, 248, 24, 102, 229, 51, 245, 51, 205,
-    65, 53, 225, 193, 248, 25, 42, 102, 111, 25, 93, 84, 105, 96, 123, 34, 114, 3, 3, 89,
-    80, 26, 248, 25, 34, 19, 26, 50, 43, 110, 38, 0, 41, 17, 196, 25, 25, 35, 126, 197,
-    248, 24, 102, 229, 51, 245, 51, 205, 65, 53, 225, 193, 248, 25, 42, 102, 111, 25, 93,
-    84, 123, 2, 3, 122, 2, 248, 10, 42, 95, 86, 26, 203, 79, 202, 133, 94, 175, 245, 51,
-    248, 5, 42, 95, 86, 213, 248, 11, 42, 95, 86, 213, 248, 19, 42, 95, 86, 213, 30, 2,
-    33, 32, 75, 205, 8, 0, 232, 7, 122, 179, 202, 133, 94, 248, 20, 42, 95, 86, 26, 248,
-    26, 119, 250, 83, 202, 198, 2, 230, 3, 79, 248, 8, 126, 248, 24, 119, 248, 9, 126,
-    248, 25, 119, 248, 17, 113, 248, 24, 42, 95, 86, 26, 248, 18, 34, 19, 26, 119, 248,
-    22, 121, 34, 175, 50, 58, 43, 119, 248, 23, 58, 43, 50, 203, 38, 35, 203, 22, 43, 42,
-    95, 86, 33, 196, 25, 25, 229, 125, 248, 24, 119, 225, 124, 248, 23, 50, 42, 95, 86,
-    26, 119, 248, 26, 126, 245, 51, 248, 24, 126, 245, 51, 205, 65, 53, 225, 248, 22,
-    123, 34, 114, 248, 22, 58, 43, 119, 248, 23, 58, 43, 50, 42, 95, 86, 248, 18, 42,
-    102, 111, 25, 229, 125, 248, 24, 119, 225, 124, 248, 23, 34, 42, 95, 86, 248, 22, 42,
-    18, 19, 42, 18, 42, 95, 86, 33, 2, 0, 25, 229, 125, 248, 24, 119, 225, 124, 248, 23,
-    50, 42, 95, 86, 26, 248, 20, 34, 19, 26, 119, 248, 17, 126, 248, 24, 34, 175, 50,
-    203, 38, 35, 203, 22, 43, 42, 95, 86, 33, 196, 25, 25, 229, 125, 248, 20, 119, 225,
-    124, 248, 19, 50, 42, 95, 86, 107, 98, 35, 229, 125, 248, 26, 119, 225, 124, 248, 25,
-    50, 42, 95, 86, 26, 34, 58, 87, 94, 213, 205, 65, 53, 225, 248, 25, 123, 34, 114,
-    248, 25, 126, 248, 18, 119, 248, 26, 126, 248, 19, 50, 42, 95, 42, 87, 42, 102, 111,
-    25, 229, 125, 248, 27, 119, 225, 124, 248, 26, 119, 248, 22, 42, 95, 42, 35, 87, 42,
-    18, 19, 126, 18, 248, 6, 42, 102, 111, 54, 0, 248, 4, 42, 95, 86, 213, 30, 2, 33, 6,
-    70, 205, 8, 0, 225, 195, 84, 95, 248, 6, 42, 95, 86, 26, 203, 111, 32, 34, 79, 203,
-    233, 248, 6, 42, 102, 111, 113, 62, 1, 245, 51, 250, 83, 202, 245, 51, 248, 6, 42,
-    95, 86, 213, 30, 2, 33, 101, 70, 205, 8, 0, 232, 4, 250, 83, 202, 214, 2, 32, 29,
-    248, 18, 42, 95, 58, 43, 43, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26,
-    103, 93, 84, 123, 145, 122, 152, 48, 35, 250, 83, 202, 183, 32, 63, 248, 18, 42, 95,
-    58, 43, 43, 87, 26, 79, 19, 26, 71, 42, 95, 86, 26, 111, 19, 26, 103, 93, 84, 121,
-    147, 120, 154, 56, 34, 77, 68, 248, 18, 42, 102, 111, 121, 34, 112, 248, 6, 42, 95,
-    58, 87, 26, 203, 199, 245, 42, 102, 111, 241, 119, 230, 125, 79, 248, 6, 42, 102,
-    111, 113, 248, 6, 42, 95, 86, 26, 230, 192, 32, 24, 248, 6, 42, 102, 111, 54, 0, 248,
-    4, 42, 95, 86, 213, 30, 2, 33, 6, 70, 205, 8, 0, 225, 24, 24, 248, 2, 42, 95, 86, 26,
-    79, 19, 26, 71, 121, 198, 253, 79, 120, 206, 255, 71, 58, 110, 103, 121, 34, 112,
-    232, 27, 201, 248, 8, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42, 95,
-    86, 33, 8, 0, 25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 10, 79, 6, 0, 105,
-    96, 41, 9, 41, 41, 9, 41, 41, 17, 185, 192, 25, 203, 246, 201, 248, 8, 42, 79, 70,
-    203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111,
-    9, 24, 4, 33, 152, 203, 9, 93, 84, 1, 185, 192, 26, 95, 22, 0, 107, 98, 41, 25, 41,
-    41, 25, 41, 41, 9, 93, 84, 121, 147, 32, 10, 120, 146, 32, 6, 123, 98, 111, 203, 150,
-    201, 107, 98, 203, 158, 213, 30, 2, 33, 219, 65, 205, 8, 0, 225, 201, 248, 8, 42, 79,
-    70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42, 102,
-    111, 9, 24, 4, 33, 152, 203, 9, 93, 84, 1, 185, 192, 26, 95, 22, 0, 107, 98, 41, 25,
-    41, 41, 25, 41, 41, 9, 93, 84, 121, 147, 32, 9, 120, 146, 32, 5, 26, 246, 4, 18, 201,
-    107, 98, 203, 222, 213, 30, 2, 33, 106, 64, 205, 8, 0, 225, 201, 248, 8, 42, 79, 70,
-    203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111,
-    9, 24, 4, 33, 152, 203, 9, 77, 68, 10, 79, 6, 0, 105, 96, 41, 9, 41, 41, 9, 41, 41,
-    17, 185, 192, 25, 1, 43, 0, 9, 42, 203, 126, 192, 245, 51, 30, 3, 33, 213, 94, 205,
-    8, 0, 51, 201, 248, 8, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42,
-    95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 10, 79, 6, 0,
-    105, 96, 41, 9, 41, 41, 9, 41, 41, 17, 185, 192, 25, 77, 68, 175, 245, 51, 248, 11,
-    126, 245, 51, 197, 30, 2, 33, 101, 70, 205, 8, 0, 232, 4, 201, 232, 252, 248, 10, 42,
-    95, 42, 87, 42, 79, 70, 203, 33, 203, 16, 33, 8, 0, 25, 51, 51, 229, 248, 13, 203,
-    126, 40, 10, 209, 213, 26, 111, 19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 125, 76, 248,
-    2, 34, 113, 248, 14, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 11, 209, 213, 26,
-    111, 19, 26, 103, 9, 77, 24, 5, 33, 152, 203, 9, 77, 105, 78, 248, 2, 42, 95, 86, 26,
-    95, 22, 0, 107, 98, 41, 25, 41, 41, 25, 41, 41, 17, 185, 192, 25, 93, 84, 33, 16, 0,
-    25, 113, 33, 18, 0, 25, 69, 203, 33, 104, 6, 0, 9, 77, 68, 3, 10, 60, 70, 245, 51,
-    197, 51, 213, 30, 2, 33, 98, 69, 205, 8, 0, 232, 8, 201, 232, 250, 248, 14, 42, 79,
-    70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 12, 42, 95, 86, 33, 8, 0, 25, 42, 102,
-    111, 9, 24, 4, 33, 152, 203, 9, 125, 76, 248, 0, 34, 113, 1, 185, 192, 209, 213, 26,
-    95, 22, 0, 107, 98, 41, 25, 41, 41, 25, 41, 41, 9, 77, 124, 248, 2, 113, 35, 50, 42,
-    79, 70, 3, 225, 229, 35, 35, 42, 110, 95, 85, 123, 2, 3, 122, 2, 248, 2, 42, 95, 86,
-    33, 3, 0, 25, 229, 125, 248, 6, 119, 225, 124, 248, 5, 119, 209, 213, 33, 4, 0, 25,
-    42, 79, 70, 248, 4, 42, 102, 111, 121, 34, 112, 232, 6, 201, 232, 252, 248, 12, 42,
-    79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 10, 42, 95, 86, 33, 8, 0, 25, 42,
-    102, 111, 9, 24, 4, 33, 152, 203, 9, 125, 76, 248, 0, 34, 113, 1, 185, 192, 209, 213,
-    26, 95, 22, 0, 107, 98, 41, 25, 41, 41, 25, 41, 41, 9, 77, 124, 248, 2, 113, 35, 119,
-    193, 197, 3, 3, 58, 110, 103, 35, 42, 110, 95, 85, 123, 2, 3, 122, 2, 209, 213, 33,
-    4, 0, 25, 77, 68, 248, 2, 42, 102, 111, 35, 35, 35, 42, 110, 95, 85, 123, 2, 3, 122,
-    2, 232, 4, 201, 232, 252, 248, 10, 42, 95, 42, 87, 42, 79, 70, 203, 33, 203, 16, 33,
-    8, 0, 25, 51, 51, 229, 248, 13, 203, 126, 40, 10, 209, 213, 26, 111, 19, 26, 103, 9,
-    24, 4, 33, 152, 203, 9, 93, 84, 1, 185, 192, 26, 95, 22, 0, 107, 98, 41, 25, 41, 41,
-    25, 41, 41, 9, 77, 124, 248, 2, 113, 35, 119, 248, 14, 42, 79, 70, 203, 33, 203, 16,
-    203, 126, 40, 12, 209, 213, 26, 111, 19, 26, 103, 9, 77, 68, 24, 6, 33, 152, 203, 9,
-    77, 68, 248, 2, 42, 95, 86, 33, 5, 0, 25, 93, 84, 26, 95, 22, 0, 123, 2, 3, 122, 2,
-    232, 4, 201, 232, 252, 248, 10, 42, 95, 42, 87, 42, 79, 70, 203, 33, 203, 16, 33, 8,
-    0, 25, 51, 51, 229, 248, 13, 203, 126, 40, 10, 209, 213, 26, 111, 19, 26, 103, 9, 24,
-    4, 33, 152, 203, 9, 93, 84, 1, 185, 192, 26, 95, 22, 0, 107, 98, 41, 25, 41, 41, 25,
-    41, 41, 9, 77, 124, 248, 2, 113, 35, 119, 248, 14, 42, 79, 70, 203, 33, 203, 16, 203,
-    126, 40, 12, 209, 213, 26, 111, 19, 26, 103, 9, 77, 68, 24, 6, 33, 152, 203, 9, 77,
-    68, 248, 2, 42, 95, 86, 33, 5, 0, 25, 93, 84, 26, 95, 33, 204, 25, 22, 0, 25, 94, 22,
-    0, 123, 2, 3, 122, 2, 232, 4, 201, 232, 250, 248, 12, 42, 79, 70, 33, 18, 0, 9, 51,
-    51, 93, 84, 213, 26, 183, 194, 197, 99, 248, 14, 126, 248, 2, 119, 248, 15, 126, 248,
-    3, 50, 203, 38, 35, 203, 22, 248, 15, 203, 126, 40, 28, 33, 8, 0, 9, 93, 84, 26, 248,
-    4, 34, 19, 26, 119, 248, 4, 42, 95, 86, 248, 2, 42, 102, 111, 25, 93, 124, 24, 11,
-    17, 152, 203, 248, 2, 42, 102, 111, 25, 93, 124, 248, 4, 115, 35, 119, 225, 229, 54,
-    1, 248, 4, 42, 95, 86, 26, 95, 22, 0, 107, 98, 41, 25, 41, 41, 25, 41, 41, 17, 185,
-    192, 25, 93, 84, 33, 11, 197, 123, 34, 114, 33, 13, 197, 54, 1, 197, 248, 18, 42,
-    245, 51, 42, 95, 86, 213, 30, 3, 33, 210, 75, 205, 8, 0, 232, 3, 193, 250, 13, 197,
-    214, 60, 32, 12, 225, 229, 54, 0, 175, 33, 11, 197, 34, 119, 24, 26, 33, 16, 0, 9,
-    54, 1, 33, 13, 197, 52, 105, 96, 42, 102, 198, 250, 95, 124, 206, 255, 87, 123, 2, 3,
-    122, 2, 232, 6, 201, 248, 8, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 6,
-    42, 95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 10, 79, 6,
-    0, 105, 96, 41, 9, 41, 41, 9, 41, 41, 17, 185, 192, 25, 77, 68, 33, 6, 0, 9, 93, 84,
-    248, 10, 126, 18, 33, 7, 0, 9, 93, 84, 248, 11, 126, 18, 33, 8, 0, 9, 93, 84, 248,
-    12, 126, 18, 33, 9, 0, 9, 77, 68, 248, 13, 126, 2, 201, 59, 59, 248, 10, 42, 79, 70,
-    203, 33, 203, 16, 203, 126, 40, 15, 248, 8, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111,
-    9, 24, 4, 33, 152, 203, 9, 77, 68, 10, 79, 6, 0, 105, 96, 41, 9, 41, 41, 9, 41, 41,
-    17, 185, 192, 25, 77, 68, 33, 10, 0, 9, 126, 197, 248, 14, 102, 229, 51, 248, 16, 94,
-    35, 86, 213, 245, 51, 30, 3, 33, 174, 65, 205, 8, 0, 232, 4, 193, 33, 34, 0, 9, 93,
-    84, 248, 12, 126, 18, 33, 35, 0, 9, 93, 84, 248, 13, 126, 248, 0, 119, 248, 14, 126,
-    248, 1, 50, 42, 18, 19, 126, 18, 33, 18, 0, 9, 197, 229, 17, 0, 0, 213, 248, 18, 42,
-    245, 51, 42, 95, 86, 213, 205, 36, 21, 232, 7, 193, 33, 6, 0, 9, 197, 229, 248, 16,
-    42, 245, 51, 42, 95, 86, 213, 30, 3, 33, 12, 66, 205, 8, 0, 232, 5, 193, 33, 18, 0,
-    9, 93, 84, 33, 16, 0, 9, 126, 135, 111, 38, 0, 25, 93, 84, 19, 26, 60, 86, 245, 51,
-    213, 51, 197, 30, 2, 33, 98, 69, 205, 8, 0, 232, 6, 201, 59, 59, 248, 10, 42, 79, 70,
-    203, 33, 203, 16, 203, 126, 40, 15, 248, 8, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111,
-    9, 24, 4, 33, 152, 203, 9, 93, 84, 1, 185, 192, 26, 95, 22, 0, 107, 98, 41, 25, 41,
-    41, 25, 41, 41, 9, 77, 68, 51, 51, 197, 248, 14, 42, 79, 42, 71, 3, 3, 110, 38, 0,
-    41, 41, 41, 41, 9, 77, 68, 209, 213, 33, 10, 0, 25, 93, 84, 26, 248, 12, 134, 35,
-    102, 229, 51, 197, 248, 20, 102, 229, 51, 245, 51, 205, 140, 18, 232, 7, 201, 248, 8,
-    42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42,
-    102, 111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 10, 79, 6, 0, 105, 96, 41, 9, 41, 41, 9,
-    41, 41, 17, 185, 192, 25, 1, 14, 0, 9, 77, 68, 248, 10, 126, 2, 201, 248, 8, 42, 79,
-    70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42, 102,
-    111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 10, 79, 6, 0, 105, 96, 41, 9, 41, 41, 9, 41,
-    41, 17, 185, 192, 25, 1, 15, 0, 9, 77, 68, 248, 10, 126, 2, 201, 248, 8, 42, 79, 70,
-    203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111,
-    9, 24, 4, 33, 152, 203, 9, 77, 68, 10, 95, 22, 0, 107, 98, 41, 25, 41, 41, 25, 41,
-    41, 17, 185, 192, 25, 3, 3, 10, 245, 51, 229, 30, 2, 33, 182, 69, 205, 8, 0, 232, 3,
-    201, 248, 8, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42, 95, 86, 33,
-    8, 0, 25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 10, 95, 22, 0, 107, 98,
-    41, 25, 41, 41, 25, 41, 41, 17, 185, 192, 25, 3, 3, 197, 229, 30, 2, 33, 243, 69,
-    205, 8, 0, 225, 193, 22, 0, 123, 2, 3, 122, 2, 201, 232, 253, 248, 11, 42, 79, 70,
-    203, 33, 203, 16, 203, 126, 40, 15, 248, 9, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111,
-    9, 24, 4, 33, 152, 203, 9, 77, 68, 10, 79, 6, 0, 105, 96, 41, 9, 41, 41, 9, 41, 41,
-    17, 185, 192, 25, 77, 68, 33, 18, 0, 9, 51, 51, 229, 33, 34, 0, 9, 126, 248, 2, 119,
-    33, 35, 0, 9, 42, 110, 95, 85, 197, 248, 2, 42, 102, 111, 229, 248, 17, 42, 102, 111,
-    229, 248, 8, 126, 245, 51, 213, 205, 36, 21, 232, 7, 193, 33, 18, 0, 9, 93, 84, 33,
-    16, 0, 9, 126, 135, 111, 38, 0, 25, 93, 84, 19, 26, 60, 86, 245, 51, 213, 51, 197,
-    30, 2, 33, 98, 69, 205, 8, 0, 232, 7, 201, 232, 251, 248, 11, 42, 95, 42, 87, 42, 79,
-    70, 203, 33, 203, 16, 33, 8, 0, 25, 51, 51, 229, 248, 14, 203, 126, 40, 10, 209, 213,
-    26, 111, 19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 93, 84, 1, 185, 192, 26, 95, 22, 0,
-    107, 98, 41, 25, 41, 41, 25, 41, 41, 9, 77, 124, 248, 2, 113, 35, 119, 248, 15, 42,
-    79, 70, 203, 33, 203, 16, 203, 126, 40, 10, 209, 213, 26, 111, 19, 26, 103, 9, 24, 4,
-    33, 152, 203, 9, 126, 229, 248, 6, 119, 225, 35, 35, 42, 79, 70, 248, 2, 42, 95, 86,
-    33, 10, 0, 25, 93, 84, 26, 197, 248, 6, 102, 229, 51, 197, 245, 51, 30, 3, 33, 174,
-    65, 205, 8, 0, 232, 4, 193, 248, 2, 42, 95, 86, 33, 34, 0, 25, 93, 84, 248, 4, 126,
-    18, 107, 98, 35, 121, 88, 34, 115, 248, 2, 42, 95, 86, 33, 18, 0, 25, 93, 84, 197,
-    213, 17, 0, 0, 213, 248, 10, 126, 245, 51, 197, 205, 36, 21, 232, 7, 193, 248, 2, 42,
-    95, 86, 33, 6, 0, 25, 229, 248, 6, 126, 245, 51, 197, 30, 3, 33, 12, 66, 205, 8, 0,
-    232, 5, 248, 2, 42, 95, 86, 33, 18, 0, 25, 77, 68, 248, 2, 42, 95, 86, 33, 16, 0, 25,
-    93, 84, 26, 135, 111, 38, 0, 9, 77, 68, 3, 10, 60, 70, 245, 51, 197, 51, 248, 4, 42,
-    95, 86, 213, 30, 2, 33, 98, 69, 205, 8, 0, 232, 9, 201, 59, 59, 248, 10, 42, 79, 70,
-    203, 33, 203, 16, 203, 126, 40, 15, 248, 8, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111,
-    9, 24, 4, 33, 152, 203, 9, 78, 6, 0, 105, 96, 41, 9, 41, 41, 9, 41, 41, 125, 198,
-    185, 79, 124, 206, 192, 248, 0, 113, 35, 119, 248, 13, 78, 203, 65, 40, 16, 209, 213,
-    248, 12, 126, 230, 1, 7, 230, 2, 111, 26, 230, 253, 181, 18, 203, 73, 40, 18, 209,
-    213, 248, 12, 126, 15, 230, 1, 7, 7, 230, 4, 111, 26, 230, 251, 181, 18, 203, 81, 40,
-    17, 209, 213, 248, 12, 126, 15, 15, 203, 55, 230, 16, 111, 26, 230, 239, 181, 18,
-    203, 89, 40, 21, 209, 213, 248, 12, 126, 15, 15, 15, 230, 1, 203, 55, 7, 230, 32,
-    111, 26, 230, 223, 181, 18, 203, 97, 40, 18, 193, 197, 248, 12, 126, 203, 55, 230, 1,
-    15, 230, 128, 111, 10, 230, 127, 181, 2, 51, 51, 201, 250, 27, 198, 234, 28, 198,
-    248, 8, 126, 234, 36, 201, 33, 41, 201, 54, 0, 33, 35, 201, 54, 0, 33, 33, 201, 54,
-    0, 248, 9, 126, 254, 52, 208, 198, 204, 38, 0, 229, 51, 245, 51, 30, 1, 33, 69, 66,
-    205, 8, 0, 225, 201, 248, 8, 126, 183, 40, 10, 205, 101, 2, 33, 67, 202, 123, 34,
-    114, 201, 205, 109, 2, 33, 67, 202, 123, 34, 114, 201, 248, 9, 58, 135, 135, 135, 79,
-    126, 135, 135, 135, 33, 31, 201, 113, 33, 30, 201, 113, 234, 29, 201, 234, 28, 201,
-    201, 248, 8, 126, 183, 40, 14, 35, 126, 245, 51, 30, 1, 33, 36, 75, 205, 8, 0, 51,
-    201, 14, 0, 248, 9, 70, 203, 64, 40, 20, 250, 28, 201, 33, 29, 201, 150, 32, 9, 250,
-    30, 201, 33, 31, 201, 150, 40, 2, 14, 1, 203, 72, 40, 8, 250, 33, 201, 183, 32, 2,
-    14, 1, 250, 27, 198, 33, 28, 198, 94, 203, 80, 40, 10, 203, 103, 40, 4, 203, 99, 40,
-    2, 14, 1, 203, 88, 40, 10, 203, 111, 40, 4, 203, 107, 40, 2, 14, 1, 203, 96, 40, 14,
-    250, 27, 198, 183, 40, 6, 250, 28, 198, 183, 40, 2, 14, 1, 121, 183, 200, 248, 6, 42,
-    79, 70, 33, 16, 0, 9, 54, 1, 105, 96, 42, 102, 198, 253, 95, 124, 206, 255, 87, 123,
-    2, 3, 122, 2, 201, 248, 10, 126, 60, 32, 8, 250, 37, 201, 248, 10, 119, 24, 13, 248,
-    10, 126, 214, 254, 32, 6, 250, 38, 201, 248, 10, 119, 248, 10, 58, 79, 58, 135, 135,
-    135, 71, 126, 135, 135, 135, 95, 33, 31, 201, 112, 33, 29, 201, 115, 121, 214, 253,
-    32, 9, 33, 30, 201, 112, 33, 28, 201, 115, 201, 33, 32, 201, 113, 201, 205, 101, 2,
-    248, 9, 110, 38, 0, 41, 41, 41, 41, 41, 25, 77, 68, 248, 8, 110, 38, 0, 9, 93, 84,
-    33, 69, 202, 123, 34, 114, 248, 10, 126, 234, 71, 202, 248, 11, 126, 234, 72, 202,
-    248, 12, 126, 183, 40, 5, 1, 201, 0, 24, 3, 1, 202, 0, 33, 73, 202, 113, 201, 248,
-    12, 126, 183, 40, 4, 175, 111, 24, 4, 62, 255, 46, 0, 234, 42, 201, 248, 13, 126,
-    245, 230, 2, 79, 6, 0, 241, 15, 48, 71, 197, 248, 13, 58, 87, 58, 95, 213, 58, 87,
-    94, 213, 30, 1, 33, 162, 65, 205, 8, 0, 232, 4, 193, 120, 177, 200, 248, 11, 58, 87,
-    21, 21, 58, 79, 13, 13, 58, 60, 70, 4, 248, 12, 102, 229, 51, 213, 51, 97, 229, 51,
-    245, 51, 197, 51, 248, 11, 42, 95, 86, 213, 30, 2, 33, 26, 106, 205, 8, 0, 232, 7,
-    201, 248, 12, 126, 183, 40, 5, 17, 201, 0, 24, 3, 17, 202, 0, 123, 245, 51, 248, 12,
-    58, 87, 58, 95, 213, 58, 87, 94, 213, 205, 114, 52, 232, 5, 120, 177, 200, 248, 12,
-    58, 87, 58, 95, 213, 58, 87, 58, 95, 213, 58, 43, 245, 51, 42, 95, 86, 213, 30, 2,
-    33, 26, 106, 205, 8, 0, 232, 7, 201, 248, 8, 126, 214, 20, 48, 45, 35, 126, 214, 18,
-    48, 39, 62, 18, 150, 43, 71, 62, 20, 150, 248, 11, 102, 229, 51, 248, 11, 102, 104,
-    229, 245, 51, 175, 15, 245, 248, 12, 42, 95, 86, 213, 30, 2, 33, 87, 106, 205, 8, 0,
-    232, 8, 248, 9, 58, 135, 135, 135, 79, 126, 135, 135, 135, 33, 31, 201, 113, 33, 30,
-    201, 113, 234, 29, 201, 234, 28, 201, 201, 232, 247, 248, 15, 126, 248, 0, 119, 248,
-    16, 126, 248, 1, 119, 248, 17, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 27, 209,
-    213, 33, 8, 0, 25, 229, 125, 248, 9, 119, 225, 124, 248, 8, 50, 42, 95, 86, 26, 111,
-    19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 125, 76, 248, 7, 34, 121, 50, 126, 248, 2,
-    119, 248, 8, 126, 248, 3, 119, 248, 20, 126, 183, 202, 56, 108, 248, 7, 42, 95, 86,
-    26, 248, 4, 34, 19, 26, 119, 248, 20, 126, 248, 7, 34, 54, 0, 248, 7, 93, 84, 248, 4,
-    26, 19, 150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203,
-    122, 40, 1, 55, 62, 0, 23, 248, 6, 119, 183, 40, 6, 35, 42, 79, 70, 24, 5, 248, 4,
-    42, 79, 70, 121, 214, 1, 120, 23, 63, 31, 222, 128, 48, 9, 248, 7, 62, 1, 34, 175,
-    119, 24, 17, 248, 6, 126, 183, 32, 11, 248, 4, 126, 248, 7, 50, 43, 126, 248, 8, 119,
-    248, 7, 78, 209, 213, 19, 19, 26, 71, 209, 213, 26, 111, 19, 26, 93, 87, 121, 245,
-    51, 248, 21, 58, 245, 51, 126, 245, 51, 197, 51, 213, 30, 1, 33, 203, 72, 205, 8, 0,
-    232, 6, 75, 6, 0, 24, 3, 1, 0, 0, 248, 2, 42, 102, 111, 121, 34, 112, 209, 213, 26,
-    248, 7, 34, 19, 26, 119, 248, 20, 78, 6, 0, 105, 96, 41, 9, 41, 77, 68, 248, 7, 42,
-    102, 111, 9, 77, 68, 225, 229, 121, 34, 112, 232, 9, 201, 248, 8, 78, 33, 66, 202,
-    113, 6, 0, 105, 96, 41, 9, 1, 161, 5, 9, 77, 68, 10, 234, 65, 202, 105, 96, 35, 42,
-    79, 70, 250, 65, 202, 245, 51, 17, 8, 0, 213, 197, 17, 57, 202, 213, 205, 35, 19,
-    232, 7, 201, 205, 101, 2, 248, 9, 110, 38, 0, 41, 41, 41, 41, 41, 25, 77, 68, 248, 8,
-    110, 38, 0, 9, 77, 68, 248, 12, 126, 183, 40, 5, 17, 201, 0, 24, 3, 17, 202, 0, 123,
-    245, 51, 248, 12, 58, 87, 94, 213, 197, 30, 5, 33, 77, 68, 205, 8, 0, 232, 5, 201,
-    250, 50, 197, 245, 51, 248, 14, 126, 245, 51, 205, 93, 53, 225, 248, 12, 126, 6, 0,
-    131, 79, 120, 138, 71, 121, 33, 42, 197, 134, 35, 79, 120, 142, 71, 248, 11, 58, 87,
-    58, 95, 213, 58, 87, 94, 213, 250, 50, 197, 103, 250, 41, 197, 111, 229, 197, 205,
-    218, 34, 232, 8, 201, 232, 253, 248, 11, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40,
-    15, 248, 9, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 77,
-    68, 33, 10, 0, 9, 126, 197, 33, 50, 197, 102, 229, 51, 245, 51, 205, 93, 53, 225,
-    193, 33, 8, 0, 9, 110, 38, 0, 25, 93, 84, 33, 6, 0, 9, 126, 248, 0, 119, 33, 4, 0, 9,
-    126, 248, 1, 119, 105, 96, 35, 35, 126, 248, 2, 119, 10, 79, 33, 42, 197, 42, 131,
-    95, 126, 138, 87, 248, 0, 42, 245, 51, 42, 245, 51, 70, 197, 250, 50, 197, 103, 250,
-    41, 197, 111, 229, 213, 205, 218, 34, 232, 11, 201, 232, 244, 248, 18, 42, 95, 86,
-    248, 22, 42, 79, 70, 203, 33, 203, 16, 33, 8, 0, 25, 229, 125, 248, 12, 119, 225,
-    124, 248, 11, 119, 248, 23, 203, 126, 40, 13, 248, 10, 42, 95, 86, 26, 111, 19, 26,
-    103, 9, 24, 4, 33, 152, 203, 9, 126, 248, 3, 119, 248, 24, 42, 79, 70, 203, 33, 203,
-    16, 203, 126, 40, 13, 248, 10, 42, 95, 86, 26, 111, 19, 26, 103, 9, 24, 4, 33, 152,
-    203, 9, 126, 248, 4, 119, 248, 26, 126, 248, 5, 119, 248, 27, 42, 79, 70, 89, 80,
-    248, 5, 126, 245, 51, 213, 205, 7, 19, 232, 3, 248, 6, 123, 50, 89, 80, 19, 126, 245,
-    51, 213, 205, 7, 19, 232, 3, 248, 7, 115, 248, 20, 126, 248, 8, 119, 248, 21, 126,
-    248, 9, 50, 203, 38, 35, 203, 22, 248, 21, 203, 126, 40, 24, 248, 10, 42, 95, 58, 87,
-    26, 34, 19, 26, 50, 42, 95, 86, 248, 8, 42, 102, 111, 25, 93, 84, 24, 11, 17, 152,
-    203, 248, 8, 42, 102, 111, 25, 93, 84, 26, 234, 54, 218, 33, 8, 0, 9, 93, 84, 248, 0,
-    77, 68, 248, 10, 121, 34, 112, 248, 5, 126, 245, 51, 213, 248, 13, 42, 95, 86, 213,
-    205, 224, 18, 232, 5, 10, 105, 96, 35, 78, 35, 70, 245, 51, 197, 248, 10, 58, 87, 58,
-    43, 95, 213, 58, 87, 94, 213, 205, 196, 18, 232, 7, 33, 54, 218, 54, 0, 232, 12, 201,
-    248, 8, 126, 234, 75, 202, 248, 9, 126, 234, 76, 202, 248, 10, 126, 234, 77, 202,
-    248, 11, 126, 234, 74, 202, 201, 128, 0, 0, 0, 175, 110, 1, 0, 0, 179, 110, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 232, 249, 175, 33, 160, 217, 34, 119, 250, 180, 217,
-    234, 162, 217, 250, 181, 217, 234, 163, 217, 62, 2, 33, 162, 217, 203, 38, 35, 203,
-    22, 61, 32, 245, 1, 185, 192, 33, 5, 0, 9, 126, 254, 2, 40, 3, 183, 32, 2, 54, 1, 33,
-    186, 192, 42, 102, 95, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203,
-    58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 248, 0, 115, 33,
-    188, 192, 42, 110, 95, 203, 61, 203, 27, 203, 61, 203, 27, 203, 61, 203, 27, 203, 61,
-    203, 27, 203, 61, 203, 27, 203, 61, 203, 27, 203, 61, 203, 27, 33, 158, 217, 54, 0,
-    29, 248, 0, 126, 33, 50, 197, 150, 48, 60, 123, 33, 51, 197, 150, 48, 53, 250, 47,
-    197, 248, 6, 119, 33, 50, 197, 110, 38, 0, 84, 197, 229, 213, 205, 105, 53, 232, 4,
-    193, 123, 33, 48, 197, 134, 35, 95, 122, 142, 87, 248, 0, 110, 38, 0, 25, 93, 84,
-    248, 6, 126, 245, 51, 213, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 99, 202, 81, 112,
-    250, 192, 192, 248, 1, 34, 7, 159, 34, 250, 191, 192, 34, 7, 159, 50, 43, 43, 42, 95,
-    42, 87, 42, 102, 111, 123, 149, 95, 122, 156, 248, 6, 50, 123, 34, 43, 42, 95, 86,
-    203, 42, 203, 27, 248, 0, 126, 22, 0, 135, 203, 18, 135, 203, 18, 135, 203, 18, 213,
-    95, 33, 4, 0, 25, 209, 229, 125, 248, 3, 119, 225, 124, 248, 2, 34, 22, 0, 42, 102,
-    111, 25, 229, 125, 248, 7, 119, 225, 124, 248, 6, 119, 248, 1, 42, 95, 86, 248, 5,
-    42, 102, 111, 123, 149, 95, 122, 156, 248, 4, 50, 115, 42, 94, 35, 135, 203, 19, 135,
-    203, 19, 135, 203, 19, 135, 203, 19, 34, 123, 50, 17, 186, 192, 42, 18, 19, 126, 18,
-    33, 201, 192, 54, 6, 250, 216, 192, 60, 33, 215, 192, 102, 197, 245, 51, 229, 51, 17,
-    185, 192, 213, 30, 2, 33, 98, 69, 205, 8, 0, 232, 4, 193, 1, 197, 192, 17, 196, 192,
-    26, 2, 1, 198, 192, 26, 60, 2, 33, 159, 217, 54, 1, 24, 15, 33, 159, 217, 54, 0, 197,
-    30, 2, 33, 6, 70, 205, 8, 0, 225, 33, 30, 197, 54, 0, 33, 31, 197, 54, 0, 33, 32,
-    197, 54, 4, 33, 33, 197, 54, 16, 33, 31, 218, 54, 0, 232, 7, 201, 232, 237, 250, 192,
-    192, 79, 7, 159, 71, 250, 191, 192, 248, 0, 34, 7, 159, 34, 209, 213, 121, 147, 95,
-    120, 154, 71, 75, 203, 40, 203, 25, 121, 34, 54, 0, 250, 27, 198, 248, 18, 119, 250,
-    47, 197, 248, 4, 119, 250, 50, 197, 248, 5, 34, 54, 0, 248, 2, 126, 248, 7, 119, 248,
-    3, 126, 248, 8, 119, 248, 18, 126, 230, 4, 248, 9, 34, 54, 0, 248, 18, 126, 230, 8,
-    248, 11, 34, 54, 0, 248, 18, 126, 230, 2, 248, 13, 34, 54, 0, 248, 18, 126, 230, 1,
-    248, 15, 34, 54, 0, 250, 159, 217, 183, 202, 235, 115, 33, 186, 192, 42, 79, 70, 203,
-    56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 248, 0, 42, 94,
-    129, 79, 123, 136, 71, 248, 7, 42, 102, 111, 9, 77, 68, 203, 56, 203, 25, 203, 56,
-    203, 25, 203, 56, 203, 25, 248, 18, 113, 175, 33, 162, 217, 34, 119, 248, 18, 126,
-    33, 50, 197, 150, 62, 0, 23, 79, 248, 10, 58, 182, 202, 201, 113, 17, 188, 192, 26,
-    248, 16, 34, 19, 26, 119, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203,
-    62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 250, 193, 192, 71, 7, 159, 94, 35, 86,
-    104, 103, 25, 35, 93, 203, 60, 203, 27, 203, 60, 203, 27, 203, 60, 203, 27, 121, 183,
-    40, 52, 123, 33, 51, 197, 150, 48, 45, 22, 0, 248, 5, 42, 79, 70, 197, 213, 205, 105,
-    53, 232, 4, 123, 33, 48, 197, 134, 35, 79, 122, 142, 71, 248, 18, 110, 38, 0, 9, 77,
-    68, 248, 4, 126, 245, 51, 197, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 99, 202, 207,
-    115, 175, 33, 168, 217, 150, 234, 162, 217, 159, 33, 169, 217, 150, 234, 163, 217,
-    195, 207, 115, 248, 12, 58, 182, 202, 89, 114, 17, 188, 192, 26, 248, 16, 34, 19, 26,
-    119, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35,
-    203, 62, 43, 203, 30, 250, 194, 192, 71, 7, 159, 94, 35, 86, 104, 103, 25, 35, 93,
-    203, 60, 203, 27, 203, 60, 203, 27, 203, 60, 203, 27, 121, 183, 40, 52, 123, 33, 51,
-    197, 150, 48, 45, 22, 0, 248, 5, 42, 79, 70, 197, 213, 205, 105, 53, 232, 4, 123, 33,
-    48, 197, 134, 35, 79, 122, 142, 71, 248, 18, 110, 38, 0, 9, 77, 68, 248, 4, 126, 245,
-    51, 197, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 99, 202, 207, 115, 250, 168, 217,
-    234, 162, 217, 250, 169, 217, 234, 163, 217, 195, 207, 115, 248, 14, 58, 182, 202,
-    21, 115, 33, 159, 217, 54, 0, 33, 188, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56,
-    203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 193, 192, 111, 7, 159, 103, 9, 93,
-    84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 248, 17, 115, 250, 194,
-    192, 111, 7, 159, 103, 9, 77, 203, 60, 203, 25, 203, 60, 203, 25, 203, 60, 203, 25,
-    12, 248, 17, 126, 145, 202, 207, 115, 248, 18, 126, 61, 248, 15, 119, 33, 50, 197,
-    150, 48, 68, 248, 17, 126, 33, 51, 197, 150, 48, 59, 250, 47, 197, 248, 16, 119, 33,
-    50, 197, 94, 22, 0, 248, 17, 126, 6, 0, 197, 213, 95, 80, 213, 205, 105, 53, 232, 4,
-    193, 123, 33, 48, 197, 134, 35, 95, 122, 142, 87, 248, 15, 110, 38, 0, 25, 93, 84,
-    248, 16, 126, 245, 51, 213, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 91, 40, 8, 33,
-    159, 217, 54, 1, 195, 207, 115, 248, 17, 52, 24, 149, 248, 16, 58, 182, 202, 207,
-    115, 33, 159, 217, 54, 0, 33, 188, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203,
-    25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 193, 192, 111, 7, 159, 103, 9, 93, 84,
-    203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 248, 17, 115, 250, 194, 192,
-    111, 7, 159, 103, 9, 77, 203, 60, 203, 25, 203, 60, 203, 25, 203, 60, 203, 25, 12,
-    248, 17, 126, 145, 40, 99, 248, 18, 126, 60, 248, 15, 119, 33, 50, 197, 150, 48, 68,
-    248, 17, 126, 33, 51, 197, 150, 48, 59, 250, 47, 197, 248, 16, 119, 33, 50, 197, 94,
-    22, 0, 248, 17, 126, 6, 0, 197, 213, 95, 80, 213, 205, 105, 53, 232, 4, 193, 123, 33,
-    48, 197, 134, 35, 95, 122, 142, 87, 248, 15, 110, 38, 0, 25, 93, 84, 248, 16, 126,
-    245, 51, 213, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 83, 40, 7, 33, 159, 217, 54, 1,
-    24, 5, 248, 17, 52, 24, 151, 33, 188, 192, 42, 79, 70, 33, 163, 217, 94, 123, 7, 159,
-    87, 107, 98, 9, 77, 68, 33, 188, 192, 121, 34, 112, 195, 28, 126, 248, 18, 58, 230,
-    32, 34, 54, 0, 248, 14, 58, 182, 202, 35, 117, 17, 0, 0, 33, 164, 217, 42, 102, 111,
-    123, 149, 95, 122, 156, 248, 16, 50, 115, 248, 18, 58, 182, 202, 155, 116, 250, 160,
-    217, 33, 174, 217, 150, 33, 160, 217, 34, 126, 33, 175, 217, 158, 234, 161, 217, 17,
-    0, 0, 33, 170, 217, 42, 102, 111, 123, 149, 95, 122, 156, 248, 18, 50, 115, 17, 160,
-    217, 26, 19, 150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5,
-    203, 122, 40, 1, 55, 56, 55, 248, 15, 93, 84, 33, 160, 217, 26, 19, 150, 35, 26, 158,
-    26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 48, 10,
-    248, 15, 42, 35, 50, 42, 35, 119, 24, 12, 250, 160, 217, 248, 17, 119, 250, 161, 217,
-    248, 18, 119, 248, 17, 126, 234, 160, 217, 248, 18, 126, 234, 161, 217, 195, 150,
-    118, 250, 160, 217, 33, 172, 217, 150, 33, 160, 217, 34, 126, 33, 173, 217, 158, 234,
-    161, 217, 17, 0, 0, 33, 166, 217, 42, 102, 111, 123, 149, 95, 122, 156, 248, 18, 50,
-    115, 17, 160, 217, 26, 19, 150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32,
-    8, 191, 24, 5, 203, 122, 40, 1, 55, 56, 55, 248, 15, 93, 84, 33, 160, 217, 26, 19,
-    150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40,
-    1, 55, 48, 10, 248, 15, 42, 35, 50, 42, 35, 119, 24, 12, 250, 160, 217, 248, 17, 119,
-    250, 161, 217, 248, 18, 119, 248, 17, 126, 234, 160, 217, 248, 18, 126, 234, 161,
-    217, 195, 150, 118, 248, 16, 58, 182, 202, 25, 118, 250, 164, 217, 248, 15, 119, 250,
-    165, 217, 248, 16, 34, 35, 58, 182, 40, 113, 250, 160, 217, 33, 174, 217, 134, 33,
-    160, 217, 34, 126, 33, 175, 217, 142, 234, 161, 217, 17, 160, 217, 33, 164, 217, 26,
-    19, 150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122,
-    40, 1, 55, 48, 7, 248, 15, 42, 95, 126, 24, 46, 17, 170, 217, 33, 160, 217, 26, 19,
-    150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40,
-    1, 55, 48, 8, 33, 170, 217, 42, 95, 126, 24, 6, 33, 160, 217, 42, 95, 126, 33, 160,
-    217, 115, 35, 119, 195, 150, 118, 250, 160, 217, 33, 172, 217, 134, 33, 160, 217, 34,
-    126, 33, 173, 217, 142, 234, 161, 217, 17, 160, 217, 33, 164, 217, 26, 19, 150, 35,
-    26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55,
-    48, 6, 248, 15, 42, 70, 24, 44, 17, 166, 217, 33, 160, 217, 26, 19, 150, 35, 26, 158,
-    26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 48, 7, 33,
-    166, 217, 42, 70, 24, 5, 33, 160, 217, 42, 70, 33, 160, 217, 34, 112, 195, 150, 118,
-    250, 158, 217, 183, 40, 119, 33, 161, 217, 203, 126, 40, 54, 43, 126, 33, 176, 217,
-    134, 33, 160, 217, 34, 126, 33, 177, 217, 142, 33, 161, 217, 50, 175, 150, 35, 62, 0,
-    158, 62, 0, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55,
-    48, 66, 175, 33, 160, 217, 34, 119, 24, 58, 33, 160, 217, 175, 150, 35, 62, 0, 158,
-    62, 0, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 48, 28,
-    250, 160, 217, 33, 176, 217, 150, 33, 160, 217, 34, 126, 33, 177, 217, 158, 33, 161,
-    217, 119, 203, 126, 40, 4, 43, 175, 34, 119, 248, 10, 58, 182, 202, 128, 119, 33,
-    186, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56,
-    203, 25, 250, 191, 192, 111, 7, 159, 103, 9, 77, 68, 248, 7, 42, 102, 111, 9, 77, 68,
-    203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 248, 18, 113, 33, 188, 192, 42,
-    79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250,
-    193, 192, 111, 7, 159, 103, 9, 77, 203, 60, 203, 25, 203, 60, 203, 25, 203, 60, 203,
-    25, 248, 18, 126, 33, 50, 197, 150, 48, 52, 121, 33, 51, 197, 150, 48, 45, 6, 0, 248,
-    5, 42, 95, 86, 213, 197, 205, 105, 53, 232, 4, 123, 33, 48, 197, 134, 35, 79, 122,
-    142, 71, 248, 18, 110, 38, 0, 9, 77, 68, 248, 4, 126, 245, 51, 197, 205, 7, 19, 232,
-    3, 24, 2, 30, 15, 203, 99, 202, 103, 120, 248, 18, 110, 38, 0, 41, 41, 41, 1, 4, 0,
-    9, 77, 68, 250, 191, 192, 95, 7, 159, 87, 248, 2, 42, 102, 111, 25, 93, 84, 121, 147,
-    111, 120, 154, 103, 41, 41, 41, 41, 77, 68, 33, 186, 192, 121, 34, 112, 33, 159, 217,
-    54, 1, 175, 33, 160, 217, 34, 119, 195, 103, 120, 248, 12, 58, 182, 202, 103, 120,
-    33, 186, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203,
-    56, 203, 25, 250, 191, 192, 111, 7, 159, 103, 9, 77, 68, 248, 7, 42, 102, 111, 9, 77,
-    68, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 248, 18, 113, 33, 188, 192,
-    42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25,
-    250, 194, 192, 111, 7, 159, 103, 9, 77, 203, 60, 203, 25, 203, 60, 203, 25, 203, 60,
-    203, 25, 12, 248, 18, 126, 33, 50, 197, 150, 48, 52, 121, 33, 51, 197, 150, 48, 45,
-    6, 0, 248, 5, 42, 95, 86, 213, 197, 205, 105, 53, 232, 4, 123, 33, 48, 197, 134, 35,
-    79, 122, 142, 71, 248, 18, 110, 38, 0, 9, 77, 68, 248, 4, 126, 245, 51, 197, 205, 7,
-    19, 232, 3, 24, 2, 30, 15, 203, 99, 40, 58, 248, 18, 110, 38, 0, 41, 41, 41, 1, 4, 0,
-    9, 77, 68, 250, 191, 192, 95, 7, 159, 87, 248, 2, 42, 102, 111, 25, 93, 84, 121, 147,
-    111, 120, 154, 103, 41, 41, 41, 41, 77, 68, 33, 186, 192, 121, 34, 112, 33, 159, 217,
-    54, 1, 175, 33, 160, 217, 34, 119, 250, 27, 198, 203, 103, 40, 27, 33, 163, 217, 203,
-    126, 40, 20, 43, 126, 33, 182, 217, 134, 33, 162, 217, 34, 126, 33, 183, 217, 142,
-    234, 163, 217, 24, 19, 250, 162, 217, 33, 180, 217, 134, 33, 162, 217, 34, 126, 33,
-    181, 217, 142, 234, 163, 217, 1, 186, 192, 17, 188, 192, 26, 248, 17, 34, 19, 26, 50,
-    126, 248, 13, 119, 248, 18, 126, 248, 14, 119, 203, 62, 43, 203, 30, 35, 203, 62, 43,
-    203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 250, 193, 192, 95, 7,
-    159, 87, 126, 248, 16, 50, 43, 126, 248, 17, 50, 42, 102, 111, 25, 93, 84, 203, 58,
-    203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 248, 18, 123, 50, 250, 194, 192, 95, 7,
-    159, 87, 58, 110, 103, 25, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203,
-    27, 123, 60, 248, 11, 119, 33, 161, 217, 94, 123, 7, 159, 87, 248, 16, 123, 34, 114,
-    33, 160, 217, 175, 150, 35, 62, 0, 158, 62, 0, 87, 203, 126, 40, 7, 203, 122, 32, 8,
-    191, 24, 5, 203, 122, 40, 1, 55, 210, 119, 122, 33, 187, 192, 58, 94, 87, 248, 16,
-    42, 102, 111, 25, 93, 124, 248, 12, 115, 35, 50, 126, 248, 16, 119, 248, 13, 126,
-    248, 17, 119, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203, 30, 35, 203, 62, 43, 203,
-    30, 35, 203, 62, 43, 203, 30, 250, 192, 192, 95, 7, 159, 87, 42, 102, 111, 25, 93,
-    84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 248, 14, 115, 248, 11, 126,
-    248, 18, 150, 202, 61, 122, 248, 14, 126, 33, 50, 197, 150, 48, 71, 248, 18, 126, 33,
-    51, 197, 150, 48, 62, 250, 47, 197, 248, 15, 119, 33, 50, 197, 94, 22, 0, 248, 18,
-    58, 43, 34, 175, 50, 197, 213, 42, 95, 86, 213, 205, 105, 53, 232, 4, 193, 123, 33,
-    48, 197, 134, 35, 95, 122, 142, 87, 248, 14, 110, 38, 0, 25, 93, 84, 248, 15, 126,
-    245, 51, 213, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 83, 40, 78, 248, 14, 58, 30, 0,
-    135, 203, 19, 135, 203, 19, 135, 203, 19, 34, 123, 34, 250, 192, 192, 34, 7, 159, 50,
-    43, 43, 42, 95, 42, 87, 42, 102, 111, 123, 149, 95, 122, 156, 248, 18, 50, 115, 62,
-    4, 248, 17, 203, 38, 35, 203, 22, 61, 32, 246, 43, 42, 95, 86, 33, 1, 0, 123, 149,
-    95, 122, 156, 248, 13, 50, 115, 175, 33, 160, 217, 34, 119, 24, 6, 248, 18, 52, 195,
-    138, 121, 33, 52, 197, 42, 198, 240, 95, 126, 206, 255, 87, 203, 35, 203, 18, 203,
-    35, 203, 18, 203, 35, 203, 18, 203, 35, 203, 18, 248, 12, 123, 150, 35, 122, 158, 56,
-    5, 248, 12, 42, 95, 86, 248, 17, 123, 34, 122, 50, 17, 186, 192, 42, 18, 19, 126, 18,
-    195, 192, 123, 250, 161, 217, 203, 127, 202, 192, 123, 33, 187, 192, 58, 94, 87, 248,
-    16, 42, 102, 111, 25, 93, 124, 248, 16, 115, 35, 50, 58, 43, 119, 248, 17, 58, 43,
-    119, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35,
-    203, 46, 43, 203, 30, 250, 191, 192, 95, 7, 159, 87, 42, 102, 111, 25, 93, 84, 203,
-    42, 203, 27, 203, 42, 203, 27, 203, 42, 203, 27, 248, 18, 86, 248, 11, 126, 146, 202,
-    165, 123, 123, 33, 50, 197, 150, 48, 114, 122, 33, 51, 197, 150, 48, 107, 250, 47,
-    197, 248, 18, 119, 250, 50, 197, 248, 12, 34, 175, 34, 122, 34, 54, 0, 197, 213, 248,
-    16, 42, 102, 111, 229, 248, 20, 42, 102, 111, 229, 205, 105, 53, 232, 4, 248, 18,
-    123, 34, 114, 209, 193, 213, 248, 16, 42, 95, 86, 33, 48, 197, 42, 102, 111, 25, 209,
-    229, 125, 248, 14, 119, 225, 124, 248, 13, 119, 213, 22, 0, 58, 110, 103, 25, 209,
-    229, 125, 248, 16, 119, 225, 124, 248, 15, 119, 213, 248, 20, 126, 245, 51, 248, 17,
-    42, 102, 111, 229, 205, 7, 19, 232, 3, 107, 209, 24, 2, 46, 15, 203, 93, 40, 78, 22,
-    0, 19, 122, 203, 35, 143, 203, 35, 143, 203, 35, 143, 248, 13, 115, 35, 34, 250, 191,
-    192, 34, 7, 159, 50, 43, 43, 42, 95, 42, 87, 42, 102, 111, 123, 149, 95, 122, 156,
-    248, 18, 50, 123, 34, 43, 42, 95, 58, 43, 87, 203, 35, 203, 18, 203, 35, 203, 18,
-    203, 35, 203, 18, 203, 35, 203, 18, 19, 123, 34, 114, 175, 33, 160, 217, 34, 119, 24,
-    4, 20, 195, 205, 122, 248, 17, 203, 126, 40, 4, 175, 95, 24, 4, 248, 16, 42, 94, 248,
-    17, 34, 123, 50, 17, 186, 192, 42, 18, 19, 126, 18, 33, 158, 217, 54, 0, 105, 96, 42,
-    79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250,
-    191, 192, 111, 7, 159, 103, 9, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58,
-    203, 27, 248, 18, 115, 250, 192, 192, 111, 7, 159, 103, 9, 203, 60, 203, 29, 203, 60,
-    203, 29, 203, 60, 203, 29, 125, 60, 248, 14, 119, 33, 163, 217, 78, 121, 7, 159, 71,
-    248, 16, 121, 34, 112, 33, 162, 217, 175, 150, 35, 62, 0, 158, 62, 0, 87, 203, 126,
-    40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 210, 17, 125, 33, 188, 192,
-    42, 79, 70, 248, 16, 42, 102, 111, 9, 77, 124, 248, 16, 113, 35, 50, 42, 79, 70, 203,
-    56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 194, 192,
-    111, 7, 159, 103, 9, 77, 203, 60, 203, 25, 203, 60, 203, 25, 203, 60, 203, 25, 248,
-    14, 126, 248, 18, 150, 202, 3, 125, 248, 18, 126, 33, 50, 197, 150, 48, 63, 121, 33,
-    51, 197, 150, 48, 56, 250, 47, 197, 248, 15, 119, 33, 50, 197, 94, 22, 0, 105, 38, 0,
-    197, 213, 229, 205, 105, 53, 232, 4, 193, 123, 33, 48, 197, 134, 35, 95, 122, 142,
-    87, 248, 18, 110, 38, 0, 25, 93, 84, 248, 15, 126, 245, 51, 213, 205, 7, 19, 232, 3,
-    123, 24, 2, 62, 15, 15, 48, 52, 105, 38, 0, 41, 41, 41, 77, 68, 250, 194, 192, 95, 7,
-    159, 87, 121, 147, 111, 120, 154, 103, 41, 41, 41, 41, 77, 68, 17, 1, 0, 121, 147,
-    95, 120, 154, 248, 17, 50, 115, 33, 158, 217, 54, 1, 175, 33, 162, 217, 34, 119, 24,
-    6, 248, 18, 52, 195, 115, 124, 248, 16, 42, 79, 70, 33, 188, 192, 121, 34, 112, 195,
-    235, 125, 250, 163, 217, 203, 127, 202, 235, 125, 33, 188, 192, 42, 79, 70, 248, 16,
-    42, 102, 111, 9, 77, 124, 248, 15, 113, 35, 50, 42, 79, 70, 203, 56, 203, 25, 203,
-    56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 193, 192, 111, 7, 159, 103, 9,
-    77, 68, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 248, 17, 121, 34, 78,
-    248, 14, 126, 145, 202, 224, 125, 121, 33, 50, 197, 150, 48, 66, 248, 17, 126, 33,
-    51, 197, 150, 48, 57, 250, 47, 197, 248, 18, 119, 33, 50, 197, 94, 22, 0, 248, 17,
-    126, 6, 0, 197, 213, 95, 80, 213, 205, 105, 53, 232, 4, 193, 123, 33, 48, 197, 134,
-    35, 95, 122, 142, 87, 105, 38, 0, 25, 93, 84, 248, 18, 126, 245, 51, 213, 205, 7, 19,
-    232, 3, 24, 2, 30, 15, 203, 75, 40, 44, 248, 17, 110, 44, 38, 0, 41, 41, 41, 77, 68,
-    250, 193, 192, 95, 7, 159, 87, 121, 147, 111, 120, 154, 103, 41, 41, 41, 41, 35, 77,
-    68, 248, 15, 121, 34, 112, 175, 33, 162, 217, 34, 119, 24, 4, 12, 195, 90, 125, 248,
-    15, 42, 79, 70, 33, 188, 192, 121, 34, 112, 17, 162, 217, 33, 184, 217, 26, 19, 150,
-    35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1,
-    55, 48, 7, 33, 162, 217, 42, 70, 24, 5, 33, 184, 217, 42, 70, 33, 162, 217, 34, 112,
-    250, 27, 198, 15, 15, 230, 1, 40, 9, 250, 28, 198, 15, 15, 230, 1, 40, 3, 175, 24, 2,
-    62, 1, 245, 51, 17, 186, 192, 213, 17, 191, 192, 213, 30, 4, 33, 178, 94, 205, 8, 0,
-    232, 5, 123, 183, 194, 252, 127, 248, 18, 54, 1, 175, 245, 51, 30, 2, 33, 135, 72,
-    205, 8, 0, 51, 122, 179, 40, 16, 33, 47, 0, 25, 126, 183, 40, 8, 33, 9, 197, 123, 34,
-    114, 24, 101, 250, 27, 198, 38, 0, 245, 230, 16, 79, 6, 0, 241, 161, 111, 124, 160,
-    181, 40, 82, 250, 28, 198, 46, 0, 161, 79, 125, 160, 177, 32, 70, 122, 179, 32, 13,
-    33, 8, 1, 229, 30, 2, 33, 160, 71, 205, 8, 0, 225, 122, 179, 40, 49, 33, 47, 0, 25,
-    126, 183, 32, 41, 33, 37, 0, 25, 126, 183, 40, 33, 35, 78, 35, 70, 17, 0, 0, 213, 38,
-    1, 229, 51, 17, 0, 0, 213, 197, 245, 51, 30, 3, 33, 144, 93, 205, 8, 0, 232, 8, 248,
-    18, 54, 0, 33, 27, 198, 94, 22, 0, 123, 230, 16, 79, 6, 0, 123, 161, 95, 122, 160,
-    179, 40, 45, 250, 28, 198, 30, 0, 161, 79, 123, 160, 177, 32, 33, 250, 158, 217, 183,
-    40, 27, 248, 18, 126, 183, 40, 21, 175, 33, 178, 217, 150, 234, 162, 217, 159, 33,
-    179, 217, 150, 234, 163, 217, 33, 158, 217, 54, 0, 250, 159, 217, 183, 40, 54, 33,
-    201, 192, 54, 6, 250, 216, 192, 60, 33, 215, 192, 70, 245, 51, 197, 51, 17, 185, 192,
-    213, 30, 2, 33, 98, 69, 205, 8, 0, 232, 4, 33, 163, 217, 58, 182, 194, 252, 127, 1,
-    196, 192, 10, 234, 197, 192, 10, 60, 234, 198, 192, 195, 252, 127, 250, 158, 217,
-    183, 40, 93, 250, 161, 217, 203, 127, 40, 21, 33, 3, 1, 229, 17, 185, 192, 213, 30,
-    2, 33, 101, 70, 205, 8, 0, 232, 4, 195, 252, 127, 33, 160, 217, 175, 150, 35, 62, 0,
-    158, 62, 0, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55,
-    48, 20, 33, 1, 1, 229, 17, 185, 192, 213, 30, 2, 33, 101, 70, 205, 8, 0, 232, 4, 24,
-    86, 17, 185, 192, 213, 30, 2, 33, 6, 70, 205, 8, 0, 225, 24, 71, 250, 190, 192, 214,
-    3, 32, 33, 33, 201, 192, 54, 0, 250, 204, 192, 60, 33, 203, 192, 70, 245, 51, 197,
-    51, 17, 185, 192, 213, 30, 2, 33, 98, 69, 205, 8, 0, 232, 4, 24, 31, 33, 201, 192,
-    54, 2, 250, 208, 192, 60, 33, 207, 192, 70, 245, 51, 197, 51, 17, 185, 192, 213, 30,
-    2, 33, 98, 69, 205, 8, 0, 232, 4, 232, 19, 201, 255, 33, 57, 197, 54, 0, 33, 59, 197,
-    54, 0, 33, 126, 197, 54, 62, 35, 54, 197, 201, 2, 181, 110, 59, 248, 9, 126, 183,
-    202, 237, 64, 43, 58, 182, 202, 237, 64, 248, 7, 42, 79, 42, 71, 89, 80, 126, 245,
-    51, 213, 205, 7, 19, 232, 3, 3, 3, 123, 214, 128, 122, 222, 0, 48, 23, 123, 183, 202,
-    237, 64, 248, 9, 102, 229, 51, 197, 103, 46, 0, 229, 205, 112, 18, 232, 5, 195, 237,
-    64, 197, 213, 248, 13, 126, 245, 51, 197, 33, 0, 128, 229, 205, 112, 18, 232, 5, 209,
-    193, 123, 198, 128, 95, 122, 206, 255, 87, 33, 0, 8, 9, 77, 68, 123, 214, 128, 122,
-    222, 0, 48, 64, 248, 0, 123, 119, 214, 65, 122, 222, 0, 48, 28, 126, 183, 40, 95, 94,
-    62, 192, 147, 248, 9, 102, 229, 51, 197, 248, 3, 102, 229, 51, 245, 51, 205, 112, 18,
-    232, 5, 24, 71, 248, 0, 126, 183, 40, 65, 248, 9, 126, 245, 51, 197, 248, 3, 102, 46,
-    128, 229, 205, 112, 18, 232, 5, 24, 46, 197, 213, 248, 13, 126, 245, 51, 197, 33,
-    128, 128, 229, 205, 112, 18, 232, 5, 209, 193, 123, 198, 128, 95, 120, 198, 8, 71,
-    123, 183, 40, 15, 248, 9, 102, 229, 51, 197, 103, 46, 0, 229, 205, 140, 18, 232, 5,
-    51, 201, 232, 239, 248, 23, 42, 95, 86, 248, 2, 77, 68, 51, 51, 197, 197, 248, 27,
-    126, 245, 51, 33, 14, 0, 229, 213, 248, 7, 42, 95, 86, 213, 205, 35, 19, 232, 7, 193,
-    33, 8, 0, 9, 126, 234, 41, 197, 33, 9, 0, 9, 42, 110, 95, 85, 33, 42, 197, 123, 34,
-    114, 33, 11, 0, 9, 126, 234, 44, 197, 33, 12, 0, 9, 42, 110, 95, 85, 33, 45, 197,
-    123, 34, 114, 10, 234, 50, 197, 89, 80, 19, 26, 234, 51, 197, 33, 50, 197, 110, 38,
-    0, 41, 41, 41, 93, 84, 33, 52, 197, 123, 34, 114, 250, 52, 197, 198, 96, 234, 220,
-    199, 250, 53, 197, 206, 255, 234, 221, 199, 33, 51, 197, 110, 38, 0, 41, 41, 41, 93,
-    84, 33, 54, 197, 123, 34, 114, 250, 54, 197, 198, 112, 234, 222, 199, 250, 55, 197,
-    206, 255, 234, 223, 199, 89, 80, 19, 19, 26, 248, 16, 119, 105, 96, 35, 35, 35, 42,
-    79, 70, 248, 16, 126, 245, 51, 197, 30, 3, 33, 22, 64, 205, 8, 0, 232, 20, 201, 232,
-    251, 248, 12, 42, 86, 198, 13, 95, 48, 1, 20, 248, 0, 77, 68, 248, 3, 121, 34, 112,
-    248, 14, 126, 245, 51, 213, 248, 6, 42, 95, 86, 213, 205, 224, 18, 232, 5, 10, 248,
-    3, 119, 105, 96, 35, 42, 79, 70, 248, 11, 126, 248, 4, 50, 89, 80, 126, 245, 51, 213,
-    205, 7, 19, 232, 3, 123, 183, 40, 22, 3, 3, 213, 248, 5, 102, 229, 51, 197, 245, 51,
-    248, 10, 126, 245, 51, 205, 140, 18, 232, 5, 209, 232, 5, 201, 248, 6, 42, 86, 198,
-    9, 95, 48, 1, 20, 35, 35, 42, 79, 58, 43, 71, 126, 245, 51, 33, 4, 0, 229, 213, 197,
-    205, 35, 19, 232, 7, 201, 232, 249, 248, 17, 58, 43, 71, 42, 95, 86, 107, 98, 213,
-    197, 51, 229, 205, 7, 19, 232, 3, 75, 209, 248, 0, 113, 19, 19, 19, 248, 13, 126,
-    248, 5, 119, 248, 14, 126, 248, 6, 119, 121, 183, 40, 77, 203, 65, 40, 48, 248, 1,
-    123, 34, 114, 248, 5, 58, 43, 119, 248, 6, 58, 43, 119, 197, 213, 197, 51, 33, 8, 0,
-    229, 248, 8, 42, 102, 111, 229, 248, 12, 42, 102, 111, 229, 205, 35, 19, 232, 7, 209,
-    193, 33, 8, 0, 25, 93, 84, 203, 57, 213, 248, 7, 42, 95, 86, 33, 8, 0, 25, 209, 229,
-    125, 248, 7, 119, 225, 124, 248, 6, 119, 24, 175, 248, 0, 94, 232, 7, 201, 232, 171,
-    248, 91, 42, 79, 70, 248, 2, 125, 84, 248, 64, 34, 122, 50, 42, 95, 86, 197, 248, 95,
-    126, 245, 51, 33, 59, 0, 229, 197, 213, 205, 35, 19, 232, 7, 193, 17, 38, 197, 248,
-    93, 126, 18, 33, 39, 197, 121, 34, 112, 248, 64, 42, 79, 70, 3, 3, 10, 234, 60, 197,
-    248, 64, 42, 79, 70, 3, 3, 3, 10, 248, 80, 119, 42, 34, 175, 50, 42, 95, 86, 107, 98,
-    35, 229, 125, 248, 85, 119, 225, 124, 248, 84, 50, 42, 214, 21, 126, 222, 0, 86, 62,
-    0, 203, 127, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 48, 8, 248, 80,
-    126, 60, 79, 7, 24, 2, 14, 21, 33, 57, 197, 113, 248, 64, 42, 95, 86, 33, 4, 0, 25,
-    77, 68, 10, 254, 31, 56, 2, 62, 31, 234, 49, 218, 248, 64, 42, 95, 86, 33, 5, 0, 25,
-    77, 68, 10, 254, 5, 56, 2, 62, 5, 234, 58, 197, 248, 64, 42, 95, 86, 33, 6, 0, 25,
-    77, 68, 10, 254, 64, 56, 2, 62, 64, 234, 56, 197, 248, 64, 42, 95, 86, 33, 14, 0, 25,
-    77, 68, 10, 234, 47, 197, 248, 64, 42, 95, 86, 33, 15, 0, 25, 42, 79, 126, 33, 48,
-    197, 113, 35, 119, 30, 1, 33, 75, 65, 205, 8, 0, 248, 64, 42, 95, 86, 33, 11, 0, 25,
-    77, 68, 10, 71, 248, 64, 42, 95, 86, 33, 12, 0, 25, 42, 79, 126, 197, 51, 71, 197,
-    30, 3, 33, 239, 64, 205, 8, 0, 232, 3, 248, 64, 42, 95, 86, 33, 17, 0, 25, 77, 68,
-    10, 248, 82, 119, 248, 64, 42, 95, 86, 33, 18, 0, 25, 77, 68, 89, 80, 26, 248, 83,
-    34, 19, 26, 50, 43, 42, 245, 51, 42, 95, 86, 213, 17, 160, 223, 213, 30, 3, 33, 44,
-    66, 205, 8, 0, 232, 5, 248, 83, 42, 79, 58, 43, 71, 3, 126, 245, 51, 197, 205, 7, 19,
-    232, 3, 123, 234, 72, 198, 248, 64, 42, 95, 86, 33, 20, 0, 25, 77, 68, 10, 248, 82,
-    119, 248, 64, 42, 95, 86, 33, 21, 0, 25, 77, 68, 89, 80, 26, 248, 83, 34, 19, 26, 50,
-    43, 42, 245, 51, 42, 95, 86, 213, 17, 75, 198, 213, 30, 3, 33, 44, 66, 205, 8, 0,
-    232, 5, 248, 83, 42, 79, 58, 43, 71, 3, 126, 245, 51, 197, 205, 7, 19, 232, 3, 248,
-    83, 123, 34, 114, 248, 83, 126, 234, 73, 198, 1, 74, 198, 248, 84, 126, 2, 248, 64,
-    42, 95, 86, 33, 41, 0, 25, 229, 125, 248, 85, 119, 225, 124, 248, 84, 50, 17, 18, 0,
-    213, 42, 95, 86, 213, 17, 139, 198, 213, 205, 109, 55, 232, 6, 248, 64, 42, 95, 86,
-    33, 42, 0, 25, 77, 68, 10, 183, 32, 15, 250, 60, 197, 214, 5, 62, 2, 40, 1, 175, 234,
-    61, 197, 24, 5, 33, 61, 197, 54, 1, 30, 4, 33, 112, 79, 205, 8, 0, 250, 60, 197, 214,
-    5, 202, 122, 69, 248, 64, 42, 95, 86, 33, 8, 0, 25, 229, 125, 248, 81, 119, 225, 124,
-    248, 80, 50, 42, 79, 70, 17, 3, 0, 213, 197, 17, 219, 192, 213, 205, 109, 55, 232, 6,
-    248, 79, 42, 95, 86, 26, 79, 248, 64, 42, 95, 86, 33, 9, 0, 25, 229, 125, 248, 85,
-    119, 225, 124, 248, 84, 50, 42, 95, 86, 26, 111, 19, 26, 103, 93, 84, 33, 195, 192,
-    54, 0, 121, 245, 51, 213, 175, 245, 51, 30, 3, 33, 174, 65, 205, 8, 0, 232, 4, 75,
-    248, 64, 42, 95, 86, 33, 7, 0, 25, 93, 84, 26, 87, 145, 48, 1, 81, 248, 81, 122, 50,
-    43, 42, 95, 86, 26, 71, 248, 83, 42, 95, 86, 26, 79, 19, 26, 17, 203, 192, 213, 17,
-    0, 0, 213, 197, 51, 71, 197, 205, 36, 21, 232, 7, 248, 79, 42, 95, 86, 26, 71, 248,
-    83, 42, 95, 86, 26, 79, 19, 26, 17, 191, 192, 213, 197, 51, 71, 197, 30, 3, 33, 12,
-    66, 205, 8, 0, 232, 5, 24, 43, 248, 81, 54, 104, 33, 195, 192, 54, 104, 17, 3, 0,
-    213, 17, 19, 64, 213, 17, 219, 192, 213, 205, 109, 55, 232, 6, 17, 16, 0, 213, 17, 0,
-    0, 213, 17, 203, 192, 213, 205, 176, 55, 232, 6, 248, 64, 42, 95, 86, 33, 29, 0, 25,
-    229, 125, 248, 68, 119, 225, 124, 248, 67, 50, 43, 43, 42, 95, 86, 33, 30, 0, 25,
-    229, 125, 248, 70, 119, 225, 124, 248, 69, 119, 250, 56, 197, 183, 202, 121, 70, 248,
-    66, 126, 248, 75, 119, 248, 67, 126, 248, 76, 119, 248, 68, 42, 95, 86, 26, 79, 19,
-    26, 71, 248, 61, 125, 84, 248, 77, 34, 122, 50, 42, 95, 86, 107, 98, 35, 229, 125,
-    248, 81, 119, 225, 124, 248, 80, 119, 248, 84, 54, 0, 250, 56, 197, 248, 84, 150, 40,
-    108, 248, 84, 126, 214, 64, 40, 101, 248, 75, 42, 95, 86, 26, 248, 82, 113, 35, 112,
-    248, 77, 94, 35, 86, 245, 51, 248, 83, 42, 102, 111, 229, 213, 205, 224, 18, 232, 5,
-    17, 128, 197, 248, 84, 110, 38, 0, 25, 93, 84, 248, 81, 126, 18, 248, 77, 42, 95, 86,
-    26, 248, 83, 119, 248, 79, 42, 95, 86, 26, 111, 19, 26, 93, 197, 248, 85, 102, 229,
-    51, 87, 213, 248, 86, 126, 245, 51, 30, 3, 33, 174, 65, 205, 8, 0, 232, 4, 123, 193,
-    248, 81, 134, 119, 3, 3, 3, 248, 84, 52, 24, 140, 248, 94, 126, 183, 202, 113, 74,
-    30, 5, 33, 30, 64, 205, 8, 0, 248, 64, 42, 95, 86, 33, 26, 0, 25, 229, 125, 248, 85,
-    119, 225, 124, 248, 84, 50, 17, 3, 0, 213, 42, 95, 86, 213, 17, 222, 192, 213, 205,
-    109, 55, 232, 6, 33, 7, 197, 54, 0, 175, 33, 253, 196, 34, 119, 175, 33, 1, 197, 34,
-    119, 33, 185, 192, 203, 134, 33, 255, 196, 62, 185, 34, 62, 192, 50, 42, 79, 70, 33,
-    50, 0, 9, 175, 34, 119, 33, 255, 196, 42, 70, 198, 48, 79, 48, 1, 4, 33, 1, 197, 42,
-    2, 3, 126, 2, 58, 182, 40, 38, 126, 248, 79, 119, 250, 2, 197, 248, 80, 50, 42, 95,
-    86, 33, 50, 0, 25, 229, 125, 248, 85, 119, 225, 124, 248, 84, 50, 42, 95, 86, 33,
-    255, 196, 42, 18, 19, 126, 18, 250, 255, 196, 234, 1, 197, 250, 0, 197, 234, 2, 197,
-    17, 185, 192, 213, 30, 2, 33, 219, 65, 205, 8, 0, 225, 250, 57, 197, 183, 202, 66,
-    73, 248, 64, 42, 95, 86, 33, 32, 0, 25, 77, 68, 10, 71, 33, 57, 197, 110, 38, 0, 43,
-    93, 84, 41, 25, 41, 41, 25, 41, 41, 229, 125, 248, 85, 119, 225, 124, 248, 84, 119,
-    248, 64, 42, 95, 86, 33, 33, 0, 25, 42, 79, 126, 197, 51, 248, 84, 94, 35, 86, 213,
-    71, 197, 17, 237, 192, 213, 205, 35, 19, 232, 7, 250, 57, 197, 61, 248, 82, 119, 248,
-    66, 126, 248, 70, 119, 248, 67, 126, 248, 71, 119, 248, 68, 126, 248, 72, 119, 248,
-    69, 126, 248, 73, 119, 248, 83, 54, 237, 35, 54, 192, 248, 82, 126, 183, 202, 66, 73,
-    35, 42, 95, 86, 33, 17, 0, 25, 229, 125, 248, 81, 119, 225, 124, 248, 80, 50, 42, 95,
-    58, 43, 87, 26, 119, 248, 83, 42, 95, 86, 33, 10, 0, 25, 51, 51, 229, 248, 83, 42,
-    95, 86, 33, 34, 0, 25, 229, 125, 248, 76, 119, 225, 124, 248, 75, 50, 42, 95, 86,
-    107, 98, 35, 229, 125, 248, 78, 119, 225, 124, 248, 77, 34, 126, 183, 40, 69, 209,
-    213, 248, 81, 126, 18, 248, 74, 42, 95, 86, 26, 248, 78, 50, 43, 42, 95, 42, 87, 26,
-    79, 19, 26, 102, 229, 51, 71, 197, 248, 84, 126, 245, 51, 30, 3, 33, 174, 65, 205, 8,
-    0, 232, 4, 248, 78, 123, 34, 42, 95, 86, 26, 119, 58, 43, 150, 48, 3, 42, 35, 119,
-    248, 81, 58, 134, 35, 119, 24, 55, 248, 70, 42, 95, 42, 87, 26, 71, 42, 95, 42, 87,
-    26, 79, 19, 26, 94, 35, 86, 213, 33, 56, 197, 102, 104, 229, 71, 197, 205, 91, 19,
-    232, 6, 123, 33, 56, 197, 150, 48, 9, 33, 128, 197, 22, 0, 25, 78, 24, 3, 1, 0, 0,
-    225, 229, 113, 248, 83, 42, 95, 86, 33, 18, 0, 25, 77, 68, 248, 74, 42, 95, 86, 26,
-    248, 80, 119, 248, 76, 42, 95, 86, 26, 111, 19, 26, 93, 87, 197, 1, 0, 0, 197, 248,
-    84, 126, 245, 51, 213, 205, 36, 21, 232, 7, 248, 83, 42, 71, 78, 104, 97, 203, 134,
-    248, 83, 42, 95, 86, 33, 50, 0, 25, 77, 68, 175, 2, 3, 2, 248, 83, 42, 95, 86, 33,
-    48, 0, 25, 77, 68, 33, 1, 197, 42, 2, 3, 126, 2, 58, 182, 40, 37, 126, 248, 77, 119,
-    250, 2, 197, 248, 78, 50, 42, 95, 86, 33, 50, 0, 25, 229, 125, 248, 81, 119, 225,
-    124, 248, 80, 50, 42, 95, 86, 248, 83, 42, 18, 19, 126, 18, 248, 83, 126, 234, 1,
-    197, 248, 84, 126, 234, 2, 197, 248, 83, 42, 78, 111, 97, 126, 15, 230, 1, 32, 19,
-    248, 83, 126, 248, 79, 119, 248, 84, 126, 248, 80, 50, 42, 95, 86, 26, 7, 48, 15,
-    248, 83, 42, 95, 86, 213, 30, 2, 33, 219, 65, 205, 8, 0, 225, 248, 82, 53, 35, 42,
-    95, 86, 33, 52, 0, 25, 229, 125, 248, 85, 119, 225, 124, 248, 84, 119, 195, 158, 71,
-    250, 58, 197, 183, 202, 175, 74, 248, 64, 42, 95, 86, 33, 38, 0, 25, 77, 68, 10, 248,
-    80, 119, 33, 58, 197, 78, 6, 0, 105, 96, 41, 41, 9, 41, 9, 41, 9, 229, 125, 248, 83,
-    119, 225, 124, 248, 82, 119, 248, 64, 42, 95, 86, 33, 39, 0, 25, 229, 125, 248, 85,
-    119, 225, 124, 248, 84, 50, 42, 95, 58, 87, 26, 34, 19, 26, 119, 248, 80, 42, 245,
-    51, 42, 95, 42, 87, 213, 42, 95, 86, 213, 17, 88, 199, 213, 205, 35, 19, 232, 7, 250,
-    58, 197, 248, 84, 119, 248, 66, 126, 248, 82, 119, 248, 67, 126, 248, 83, 119, 248,
-    68, 126, 248, 78, 119, 248, 69, 126, 248, 79, 34, 62, 88, 34, 54, 199, 248, 84, 126,
-    183, 202, 175, 74, 248, 80, 42, 95, 86, 33, 4, 0, 25, 229, 125, 248, 75, 119, 225,
-    124, 248, 74, 119, 248, 82, 42, 95, 86, 26, 248, 75, 119, 248, 78, 42, 95, 86, 26,
-    248, 76, 34, 19, 26, 119, 248, 73, 42, 95, 86, 213, 250, 56, 197, 245, 51, 248, 78,
-    42, 245, 51, 42, 95, 86, 213, 205, 91, 19, 232, 6, 248, 77, 115, 248, 80, 42, 95, 86,
-    33, 8, 0, 25, 229, 125, 248, 76, 119, 225, 124, 248, 75, 34, 35, 126, 33, 56, 197,
-    150, 48, 30, 248, 77, 94, 22, 0, 33, 128, 197, 25, 229, 125, 248, 78, 119, 225, 124,
-    248, 77, 50, 42, 95, 86, 26, 119, 58, 34, 54, 0, 24, 5, 175, 248, 76, 34, 119, 248,
-    76, 58, 79, 58, 110, 103, 113, 248, 84, 53, 248, 80, 42, 95, 86, 33, 23, 0, 25, 229,
-    125, 248, 82, 119, 225, 124, 248, 81, 119, 195, 199, 73, 250, 253, 196, 248, 81, 119,
-    250, 254, 196, 248, 82, 119, 248, 82, 58, 182, 40, 44, 42, 95, 86, 213, 30, 2, 33, 6,
-    70, 205, 8, 0, 225, 248, 81, 42, 95, 86, 33, 48, 0, 25, 229, 125, 248, 85, 119, 225,
-    124, 248, 84, 50, 42, 95, 86, 26, 248, 81, 34, 19, 26, 119, 24, 206, 250, 49, 218,
-    183, 40, 76, 248, 64, 42, 95, 86, 33, 35, 0, 25, 77, 68, 10, 248, 80, 119, 250, 49,
-    218, 14, 0, 135, 203, 17, 135, 203, 17, 135, 203, 17, 248, 81, 34, 113, 248, 64, 42,
-    95, 86, 33, 36, 0, 25, 77, 68, 89, 80, 26, 248, 83, 34, 19, 26, 119, 248, 80, 42,
-    245, 51, 42, 95, 42, 87, 213, 42, 95, 86, 213, 17, 33, 200, 213, 205, 35, 19, 232, 7,
-    30, 4, 33, 227, 83, 205, 8, 0, 30, 4, 33, 2, 93, 205, 8, 0, 175, 33, 11, 197, 34,
-    119, 248, 94, 126, 183, 40, 100, 248, 64, 42, 95, 86, 33, 23, 0, 25, 229, 125, 248,
-    85, 119, 225, 124, 248, 84, 119, 248, 64, 42, 95, 86, 33, 24, 0, 25, 77, 68, 89, 80,
-    26, 248, 81, 34, 19, 26, 119, 58, 182, 40, 57, 248, 83, 42, 95, 86, 26, 119, 175,
-    245, 51, 17, 0, 0, 213, 248, 84, 42, 95, 42, 35, 87, 213, 126, 245, 51, 30, 3, 33,
-    144, 93, 205, 8, 0, 232, 6, 248, 83, 123, 34, 114, 248, 84, 58, 182, 35, 214, 1, 62,
-    0, 23, 119, 238, 1, 119, 94, 24, 2, 30, 0, 232, 85, 201, 33, 151, 5, 42, 79, 70, 33,
-    186, 192, 121, 34, 112, 33, 153, 5, 42, 79, 70, 33, 188, 192, 121, 34, 112, 1, 190,
-    192, 250, 155, 5, 2, 1, 200, 192, 250, 159, 5, 2, 1, 199, 192, 250, 160, 5, 2, 33,
-    196, 192, 54, 0, 33, 197, 192, 54, 0, 33, 198, 192, 54, 2, 33, 185, 192, 203, 142,
-    33, 232, 192, 54, 1, 33, 185, 192, 203, 238, 201, 248, 8, 58, 43, 245, 51, 42, 95,
-    86, 213, 33, 124, 4, 229, 205, 140, 18, 232, 5, 201, 248, 6, 126, 183, 40, 23, 14, 0,
-    121, 214, 8, 208, 6, 0, 105, 96, 41, 41, 9, 17, 192, 197, 25, 175, 34, 119, 12, 24,
-    235, 17, 8, 0, 213, 17, 0, 0, 213, 17, 232, 197, 213, 205, 176, 55, 232, 6, 17, 40,
-    0, 213, 17, 0, 0, 213, 17, 192, 197, 213, 205, 176, 55, 232, 6, 201, 248, 6, 126,
-    183, 40, 23, 14, 0, 121, 214, 4, 200, 6, 0, 105, 96, 41, 41, 9, 17, 240, 197, 25,
-    175, 34, 119, 12, 24, 235, 17, 8, 0, 213, 17, 0, 0, 213, 17, 4, 198, 213, 205, 176,
-    55, 232, 6, 17, 20, 0, 213, 17, 0, 0, 213, 17, 240, 197, 213, 205, 176, 55, 232, 6,
-    201, 232, 250, 248, 12, 126, 248, 0, 119, 248, 13, 126, 248, 1, 119, 209, 213, 33,
-    16, 0, 25, 54, 1, 248, 14, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 27, 209, 213,
-    33, 8, 0, 25, 229, 125, 248, 6, 119, 225, 124, 248, 5, 50, 42, 95, 86, 26, 111, 19,
-    26, 103, 9, 24, 4, 33, 152, 203, 9, 77, 68, 33, 34, 197, 126, 230, 252, 119, 89, 80,
-    26, 248, 2, 34, 19, 26, 119, 3, 3, 250, 26, 197, 248, 2, 150, 32, 47, 250, 27, 197,
-    248, 3, 150, 32, 39, 89, 80, 26, 248, 4, 34, 19, 26, 119, 250, 28, 197, 248, 4, 150,
-    32, 22, 250, 29, 197, 248, 5, 150, 32, 14, 248, 17, 126, 230, 3, 33, 34, 197, 78,
-    177, 119, 195, 182, 77, 250, 31, 218, 248, 16, 166, 194, 162, 77, 248, 2, 93, 84, 33,
-    26, 197, 26, 19, 150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24,
-    5, 203, 122, 40, 1, 55, 48, 13, 33, 26, 197, 42, 95, 58, 87, 27, 123, 34, 114, 24,
-    39, 17, 26, 197, 248, 2, 26, 19, 150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122,
-    32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 48, 8, 33, 26, 197, 52, 32, 2, 35, 52, 105,
-    96, 42, 79, 70, 33, 28, 197, 121, 150, 35, 120, 158, 120, 87, 203, 126, 40, 7, 203,
-    122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 48, 13, 33, 28, 197, 42, 95, 58, 87, 27,
-    123, 34, 114, 24, 35, 33, 28, 197, 42, 145, 126, 152, 86, 120, 203, 127, 40, 7, 203,
-    122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 48, 8, 33, 28, 197, 52, 32, 2, 35, 52,
-    209, 213, 26, 79, 19, 26, 71, 121, 198, 251, 79, 120, 206, 255, 71, 225, 229, 121,
-    34, 112, 232, 6, 201, 248, 8, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 6,
-    42, 95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 89, 80,
-    26, 33, 26, 197, 34, 19, 26, 119, 3, 3, 89, 80, 26, 33, 28, 197, 34, 19, 26, 119, 33,
-    34, 197, 126, 230, 252, 119, 30, 4, 33, 3, 84, 195, 8, 0, 232, 249, 248, 15, 126,
-    183, 40, 23, 43, 43, 42, 79, 70, 33, 8, 0, 9, 42, 79, 70, 33, 163, 192, 42, 95, 86,
-    123, 2, 3, 122, 2, 250, 163, 192, 248, 2, 119, 250, 164, 192, 248, 3, 119, 248, 13,
-    126, 248, 4, 119, 248, 14, 126, 248, 5, 50, 42, 95, 86, 33, 8, 0, 25, 42, 79, 102,
-    105, 42, 79, 70, 248, 2, 42, 95, 58, 43, 87, 123, 145, 95, 122, 152, 50, 115, 248,
-    16, 42, 79, 70, 89, 80, 26, 248, 2, 34, 19, 26, 119, 248, 0, 93, 84, 248, 2, 26, 19,
-    150, 35, 26, 158, 48, 122, 3, 3, 105, 96, 35, 58, 203, 70, 40, 48, 197, 205, 132, 52,
-    123, 193, 230, 15, 248, 6, 119, 94, 62, 10, 87, 150, 203, 123, 40, 7, 203, 122, 32,
-    8, 191, 24, 5, 203, 122, 40, 1, 55, 48, 6, 248, 6, 126, 198, 246, 119, 248, 6, 126,
-    198, 251, 234, 224, 199, 105, 96, 42, 79, 70, 203, 73, 40, 40, 205, 132, 52, 123,
-    230, 15, 79, 89, 62, 10, 87, 145, 203, 123, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203,
-    122, 40, 1, 55, 48, 4, 121, 198, 246, 79, 121, 198, 251, 234, 225, 199, 248, 4, 42,
-    95, 86, 33, 16, 0, 25, 54, 1, 30, 0, 24, 12, 33, 225, 199, 54, 0, 33, 224, 199, 54,
-    0, 30, 1, 232, 7, 201, 232, 252, 248, 10, 126, 248, 0, 119, 248, 11, 126, 248, 1,
-    119, 209, 213, 33, 8, 0, 25, 77, 68, 89, 80, 26, 248, 2, 34, 19, 26, 50, 42, 95, 86,
-    19, 19, 123, 2, 3, 122, 2, 209, 213, 26, 79, 19, 26, 71, 58, 110, 103, 121, 34, 112,
-    209, 213, 248, 12, 42, 18, 19, 126, 18, 232, 4, 201, 232, 252, 248, 10, 126, 248, 0,
-    119, 248, 11, 126, 248, 1, 119, 209, 213, 33, 8, 0, 25, 229, 125, 248, 4, 119, 225,
-    124, 248, 3, 50, 42, 95, 86, 26, 79, 19, 26, 71, 11, 11, 58, 110, 103, 121, 34, 112,
-    105, 96, 42, 79, 70, 225, 229, 121, 34, 112, 248, 12, 126, 183, 40, 30, 248, 2, 42,
-    95, 86, 26, 79, 19, 26, 71, 248, 12, 110, 38, 0, 41, 121, 149, 79, 120, 156, 71, 248,
-    2, 42, 102, 111, 121, 34, 112, 232, 4, 201, 232, 250, 248, 12, 126, 248, 0, 119, 248,
-    13, 126, 248, 1, 119, 209, 213, 33, 8, 0, 25, 229, 125, 248, 6, 119, 225, 124, 248,
-    5, 50, 42, 95, 86, 26, 79, 19, 26, 71, 33, 2, 0, 9, 229, 125, 248, 4, 119, 225, 124,
-    248, 3, 34, 42, 95, 86, 248, 2, 42, 18, 19, 126, 18, 209, 213, 26, 111, 19, 26, 93,
-    87, 123, 2, 3, 122, 2, 248, 2, 42, 79, 42, 71, 3, 3, 42, 102, 111, 121, 34, 112, 193,
-    197, 3, 3, 10, 248, 4, 34, 175, 50, 43, 43, 42, 95, 42, 87, 42, 18, 19, 126, 18, 209,
-    213, 248, 15, 42, 18, 19, 58, 43, 18, 126, 2, 232, 6, 201, 232, 252, 248, 10, 126,
-    248, 0, 119, 248, 11, 126, 248, 1, 119, 209, 213, 33, 8, 0, 25, 229, 125, 248, 4,
-    119, 225, 124, 248, 3, 50, 42, 95, 86, 26, 79, 19, 26, 71, 11, 11, 58, 110, 103, 121,
-    34, 112, 209, 213, 19, 19, 10, 18, 11, 11, 248, 2, 42, 102, 111, 121, 34, 112, 105,
-    96, 42, 79, 70, 225, 229, 121, 34, 112, 248, 12, 126, 183, 40, 30, 248, 2, 42, 95,
-    86, 26, 79, 19, 26, 71, 248, 12, 110, 38, 0, 41, 121, 149, 79, 120, 156, 71, 248, 2,
-    42, 102, 111, 121, 34, 112, 232, 4, 201, 59, 59, 248, 8, 42, 70, 198, 8, 79, 48, 1,
-    4, 89, 80, 26, 248, 0, 34, 19, 26, 119, 209, 213, 19, 19, 123, 2, 3, 122, 2, 209,
-    213, 248, 10, 42, 18, 19, 126, 18, 51, 51, 201, 59, 59, 248, 8, 42, 79, 70, 33, 8, 0,
-    9, 51, 51, 229, 248, 10, 126, 183, 40, 24, 209, 213, 26, 79, 19, 26, 71, 94, 22, 0,
-    107, 98, 41, 121, 149, 79, 120, 156, 71, 225, 229, 121, 34, 112, 209, 213, 26, 111,
-    19, 26, 103, 42, 79, 70, 89, 80, 51, 51, 201, 232, 252, 248, 10, 126, 248, 0, 119,
-    248, 11, 126, 248, 1, 119, 248, 12, 42, 79, 70, 203, 33, 203, 16, 209, 213, 33, 8, 0,
-    25, 229, 125, 248, 4, 119, 225, 124, 248, 3, 119, 248, 13, 203, 126, 40, 15, 248, 2,
-    42, 95, 86, 26, 111, 19, 26, 103, 9, 77, 68, 24, 8, 33, 152, 203, 9, 93, 84, 75, 66,
-    105, 96, 35, 58, 110, 181, 40, 23, 209, 213, 248, 14, 42, 18, 19, 126, 18, 105, 96,
-    35, 58, 94, 87, 27, 123, 2, 3, 122, 2, 24, 39, 248, 16, 126, 183, 40, 33, 248, 2, 42,
-    95, 86, 26, 79, 19, 26, 71, 248, 16, 94, 22, 0, 203, 35, 203, 18, 121, 147, 79, 120,
-    154, 71, 248, 2, 42, 102, 111, 121, 34, 112, 232, 4, 201, 248, 6, 42, 79, 42, 71, 42,
-    2, 3, 126, 2, 201, 232, 250, 248, 14, 126, 183, 40, 23, 43, 43, 42, 79, 70, 33, 8, 0,
-    9, 42, 79, 70, 33, 163, 192, 42, 95, 86, 123, 2, 3, 122, 2, 250, 163, 192, 248, 0,
-    119, 250, 164, 192, 248, 1, 119, 248, 12, 126, 248, 2, 119, 248, 13, 126, 248, 3, 50,
-    42, 95, 86, 33, 8, 0, 25, 42, 79, 102, 105, 42, 79, 70, 209, 213, 123, 145, 95, 122,
-    152, 248, 5, 50, 115, 248, 15, 42, 79, 70, 105, 96, 42, 79, 70, 248, 4, 42, 145, 126,
-    152, 48, 16, 43, 43, 43, 42, 95, 86, 33, 16, 0, 25, 54, 1, 30, 0, 24, 2, 30, 1, 232,
-    6, 201, 232, 243, 248, 19, 126, 248, 2, 119, 248, 20, 126, 248, 3, 119, 248, 25, 42,
-    79, 70, 203, 33, 203, 16, 248, 2, 42, 95, 86, 33, 8, 0, 25, 229, 125, 248, 6, 119,
-    225, 124, 248, 5, 119, 248, 26, 203, 126, 40, 15, 248, 4, 42, 95, 86, 26, 111, 19,
-    26, 103, 9, 77, 124, 24, 6, 33, 152, 203, 9, 124, 77, 248, 6, 113, 35, 119, 248, 2,
-    42, 95, 86, 33, 5, 0, 25, 229, 125, 248, 10, 119, 225, 124, 248, 9, 50, 42, 95, 86,
-    26, 248, 0, 34, 19, 26, 119, 248, 22, 42, 79, 70, 248, 2, 42, 95, 86, 33, 7, 0, 25,
-    229, 125, 248, 12, 119, 225, 124, 248, 11, 119, 248, 0, 126, 145, 32, 21, 35, 126,
-    144, 32, 16, 248, 10, 42, 95, 42, 87, 26, 119, 248, 21, 126, 248, 12, 150, 40, 21,
-    248, 8, 42, 102, 111, 121, 34, 112, 248, 10, 42, 95, 86, 248, 21, 126, 18, 22, 1, 24,
-    2, 22, 0, 33, 7, 218, 121, 34, 112, 248, 21, 126, 234, 9, 218, 1, 35, 54, 248, 19,
-    42, 95, 126, 245, 248, 8, 42, 102, 111, 241, 229, 213, 51, 87, 213, 105, 96, 205,
-    108, 55, 232, 5, 123, 183, 40, 53, 248, 24, 126, 183, 40, 30, 248, 4, 42, 95, 86, 26,
-    79, 19, 26, 71, 248, 24, 110, 38, 0, 41, 121, 149, 79, 120, 156, 71, 248, 4, 42, 102,
-    111, 121, 34, 112, 248, 8, 42, 102, 111, 175, 34, 119, 248, 10, 42, 102, 111, 54, 0,
-    24, 24, 248, 2, 42, 95, 86, 26, 79, 19, 26, 71, 121, 198, 249, 79, 120, 206, 255, 71,
-    58, 110, 103, 121, 34, 112, 232, 13, 201, 232, 252, 248, 10, 42, 79, 42, 71, 126,
-    248, 0, 119, 248, 13, 126, 248, 1, 50, 203, 38, 35, 203, 22, 248, 13, 203, 126, 40,
-    23, 33, 8, 0, 9, 93, 84, 26, 248, 2, 34, 19, 26, 119, 248, 2, 42, 95, 86, 225, 229,
-    25, 24, 6, 17, 152, 203, 225, 229, 25, 42, 102, 175, 180, 32, 22, 105, 96, 42, 102,
-    198, 253, 95, 124, 206, 255, 87, 105, 96, 123, 34, 114, 33, 16, 0, 9, 54, 1, 232, 4,
-    201, 248, 8, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42, 95, 86, 33,
-    8, 0, 25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 126, 245, 51, 30, 3, 33, 213, 94,
-    205, 8, 0, 51, 201, 232, 247, 248, 15, 126, 248, 0, 119, 248, 16, 126, 248, 1, 119,
-    248, 18, 42, 79, 70, 203, 33, 203, 16, 209, 213, 33, 8, 0, 25, 229, 125, 248, 4, 119,
-    225, 124, 248, 3, 119, 248, 19, 203, 126, 40, 22, 248, 2, 42, 95, 86, 26, 111, 19,
-    26, 103, 9, 93, 84, 26, 248, 4, 34, 19, 26, 119, 24, 13, 33, 152, 203, 9, 93, 84, 26,
-    248, 4, 34, 19, 26, 119, 248, 20, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 16,
-    248, 2, 42, 95, 86, 26, 111, 19, 26, 103, 9, 42, 79, 70, 24, 7, 33, 152, 203, 9, 42,
-    79, 70, 248, 6, 54, 0, 62, 6, 248, 17, 150, 218, 186, 84, 248, 4, 126, 145, 32, 7,
-    35, 126, 144, 62, 1, 40, 1, 175, 248, 7, 119, 248, 4, 42, 145, 126, 152, 86, 120,
-    203, 127, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 62, 0, 23, 248, 8,
-    119, 248, 4, 121, 150, 35, 120, 158, 120, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191,
-    24, 5, 203, 122, 40, 1, 55, 62, 0, 23, 79, 248, 17, 94, 22, 0, 33, 131, 84, 25, 25,
-    233, 24, 53, 24, 10, 24, 14, 24, 19, 24, 25, 24, 28, 24, 35, 248, 7, 58, 119, 24, 35,
-    248, 8, 58, 43, 119, 24, 28, 121, 238, 1, 248, 6, 119, 24, 20, 248, 6, 113, 24, 15,
-    248, 8, 58, 43, 238, 1, 119, 24, 6, 248, 7, 58, 238, 1, 119, 248, 6, 126, 183, 40, 9,
-    209, 213, 248, 22, 42, 18, 19, 126, 18, 248, 24, 126, 183, 40, 30, 248, 2, 42, 95,
-    86, 26, 79, 19, 26, 71, 248, 24, 110, 38, 0, 41, 121, 149, 79, 120, 156, 71, 248, 2,
-    42, 102, 111, 121, 34, 112, 232, 9, 201, 232, 249, 248, 13, 126, 248, 0, 119, 248,
-    14, 126, 248, 1, 119, 248, 16, 42, 79, 70, 203, 33, 203, 16, 209, 213, 33, 8, 0, 25,
-    229, 125, 248, 4, 119, 225, 124, 248, 3, 119, 248, 17, 203, 126, 40, 16, 248, 2, 42,
-    95, 86, 26, 111, 19, 26, 103, 9, 42, 79, 70, 24, 7, 33, 152, 203, 9, 42, 79, 70, 248,
-    4, 54, 0, 62, 6, 248, 15, 150, 218, 206, 85, 248, 18, 126, 145, 32, 7, 35, 126, 144,
-    62, 1, 40, 1, 175, 248, 5, 119, 248, 18, 121, 150, 35, 120, 158, 120, 87, 203, 126,
-    40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 62, 0, 23, 248, 6, 119, 248,
-    18, 42, 145, 126, 152, 86, 120, 203, 127, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203,
-    122, 40, 1, 55, 62, 0, 23, 79, 248, 15, 94, 22, 0, 33, 151, 85, 25, 25, 233, 24, 53,
-    24, 10, 24, 14, 24, 19, 24, 25, 24, 28, 24, 35, 248, 5, 58, 119, 24, 35, 248, 6, 58,
-    43, 119, 24, 28, 121, 238, 1, 248, 4, 119, 24, 20, 248, 4, 113, 24, 15, 248, 6, 58,
-    43, 238, 1, 119, 24, 6, 248, 5, 58, 238, 1, 119, 248, 4, 126, 183, 40, 9, 209, 213,
-    248, 20, 42, 18, 19, 126, 18, 248, 22, 126, 183, 40, 30, 248, 2, 42, 95, 86, 26, 79,
-    19, 26, 71, 248, 22, 110, 38, 0, 41, 121, 149, 79, 120, 156, 71, 248, 2, 42, 102,
-    111, 121, 34, 112, 232, 7, 201, 232, 252, 248, 10, 42, 79, 70, 33, 8, 0, 9, 51, 51,
-    93, 84, 213, 26, 248, 2, 34, 19, 26, 119, 248, 12, 42, 79, 70, 203, 33, 203, 16, 203,
-    126, 40, 10, 248, 2, 42, 102, 111, 9, 77, 68, 24, 6, 33, 152, 203, 9, 77, 68, 105,
-    96, 42, 79, 70, 248, 2, 42, 102, 111, 121, 34, 112, 209, 213, 26, 79, 19, 26, 71, 3,
-    3, 225, 229, 121, 34, 112, 232, 4, 201, 232, 252, 248, 10, 42, 95, 42, 87, 42, 79,
-    70, 203, 33, 203, 16, 33, 8, 0, 25, 51, 51, 93, 84, 213, 26, 248, 2, 34, 19, 26, 119,
-    248, 13, 203, 126, 40, 10, 248, 2, 42, 102, 111, 9, 77, 68, 24, 6, 33, 152, 203, 9,
-    77, 68, 105, 96, 42, 79, 70, 248, 12, 121, 34, 112, 203, 33, 203, 16, 203, 126, 40,
-    10, 248, 2, 42, 102, 111, 9, 77, 68, 24, 6, 33, 152, 203, 9, 77, 68, 105, 96, 42, 79,
-    70, 248, 2, 42, 102, 111, 121, 34, 112, 209, 213, 26, 79, 19, 26, 71, 3, 3, 225, 229,
-    121, 34, 112, 232, 4, 201, 232, 252, 248, 10, 42, 79, 70, 33, 8, 0, 9, 51, 51, 93,
-    84, 213, 26, 248, 2, 34, 19, 26, 119, 248, 13, 203, 126, 40, 28, 248, 2, 42, 79, 70,
-    121, 214, 152, 79, 120, 222, 203, 71, 203, 56, 203, 25, 248, 12, 42, 94, 129, 79,
-    123, 136, 71, 24, 5, 248, 12, 42, 79, 70, 248, 2, 42, 102, 111, 121, 34, 112, 209,
-    213, 26, 79, 19, 26, 71, 3, 3, 225, 229, 121, 34, 112, 232, 4, 201, 59, 59, 248, 8,
-    42, 70, 198, 8, 79, 48, 1, 4, 89, 80, 26, 248, 0, 34, 19, 26, 119, 248, 10, 126, 95,
-    7, 159, 87, 203, 35, 203, 18, 225, 229, 25, 93, 84, 123, 2, 3, 122, 2, 51, 51, 201,
-    232, 252, 248, 10, 42, 95, 42, 87, 42, 79, 70, 203, 33, 203, 16, 33, 8, 0, 25, 51,
-    51, 229, 248, 13, 203, 126, 40, 17, 209, 213, 26, 111, 19, 26, 103, 9, 77, 124, 248,
-    2, 113, 35, 119, 24, 11, 33, 152, 203, 9, 77, 124, 248, 2, 113, 35, 119, 248, 14, 42,
-    79, 70, 203, 33, 203, 16, 203, 126, 40, 10, 209, 213, 26, 111, 19, 26, 103, 9, 24, 4,
-    33, 152, 203, 9, 42, 79, 70, 248, 2, 42, 102, 111, 121, 34, 112, 232, 4, 201, 248, 8,
-    42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 17, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42,
-    102, 111, 9, 77, 68, 24, 6, 33, 152, 203, 9, 77, 68, 248, 10, 42, 2, 3, 126, 2, 201,
-    232, 250, 248, 12, 126, 248, 0, 119, 248, 13, 126, 248, 1, 119, 248, 14, 42, 79, 70,
-    203, 33, 203, 16, 209, 213, 33, 8, 0, 25, 229, 125, 248, 6, 119, 225, 124, 248, 5,
-    119, 248, 15, 203, 126, 40, 20, 248, 4, 42, 95, 86, 26, 111, 19, 26, 103, 9, 77, 124,
-    248, 2, 113, 35, 119, 24, 11, 33, 152, 203, 9, 93, 84, 248, 2, 123, 34, 114, 248, 16,
-    42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 13, 248, 4, 42, 95, 86, 26, 111, 19, 26,
-    103, 9, 24, 25, 209, 213, 33, 10, 0, 25, 229, 125, 248, 6, 119, 225, 124, 248, 5, 50,
-    42, 95, 86, 26, 111, 19, 26, 103, 9, 42, 79, 70, 248, 2, 42, 102, 111, 121, 34, 112,
-    232, 6, 201, 248, 8, 126, 15, 48, 3, 205, 53, 14, 64, 201, 248, 6, 42, 79, 70, 33,
-    16, 0, 9, 54, 1, 201, 248, 8, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 17, 248, 6,
-    42, 95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 77, 68, 24, 6, 33, 152, 203, 9, 77, 68,
-    248, 10, 42, 95, 86, 26, 95, 22, 0, 123, 2, 3, 122, 2, 201, 248, 8, 42, 79, 70, 203,
-    33, 203, 16, 203, 126, 40, 17, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 77,
-    68, 24, 6, 33, 152, 203, 9, 77, 68, 248, 10, 42, 95, 86, 26, 95, 7, 159, 87, 123, 2,
-    3, 122, 2, 201, 59, 59, 248, 10, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 22, 248,
-    8, 42, 95, 86, 33, 8, 0, 25, 42, 110, 129, 79, 125, 136, 248, 0, 113, 35, 119, 24,
-    11, 33, 152, 203, 9, 77, 124, 248, 0, 113, 35, 119, 248, 12, 42, 79, 70, 105, 96, 42,
-    79, 70, 225, 229, 121, 34, 112, 51, 51, 201, 248, 10, 42, 79, 70, 203, 33, 203, 16,
-    203, 126, 40, 17, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 77, 68, 24, 6,
-    33, 152, 203, 9, 77, 68, 248, 8, 42, 95, 86, 10, 18, 201, 248, 10, 42, 79, 70, 203,
-    33, 203, 16, 203, 126, 40, 17, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 77,
-    68, 24, 6, 33, 152, 203, 9, 77, 68, 248, 8, 42, 95, 86, 10, 18, 201, 59, 59, 248, 12,
-    42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 17, 248, 8, 42, 95, 86, 33, 8, 0, 25, 42,
-    102, 111, 9, 77, 68, 24, 6, 33, 152, 203, 9, 77, 68, 248, 10, 126, 248, 0, 119, 248,
-    11, 126, 248, 1, 119, 105, 96, 42, 79, 70, 225, 229, 121, 34, 112, 51, 51, 201, 248,
-    8, 42, 79, 42, 71, 126, 2, 201, 248, 8, 42, 79, 42, 71, 42, 2, 3, 126, 2, 201, 248,
-    8, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 16, 248, 6, 42, 95, 86, 33, 8, 0, 25,
-    42, 102, 111, 9, 77, 24, 5, 33, 152, 203, 9, 77, 105, 42, 79, 70, 197, 205, 175, 52,
-    225, 201, 232, 252, 205, 132, 52, 123, 248, 18, 166, 35, 79, 122, 166, 71, 248, 16,
-    121, 150, 35, 120, 158, 56, 12, 248, 16, 42, 95, 86, 121, 147, 95, 120, 154, 71, 75,
-    248, 16, 121, 150, 35, 120, 158, 56, 12, 248, 16, 42, 95, 86, 121, 147, 95, 120, 154,
-    71, 75, 248, 12, 126, 248, 0, 119, 248, 13, 126, 248, 1, 50, 203, 38, 35, 203, 22,
-    248, 13, 203, 126, 40, 30, 248, 10, 42, 95, 86, 33, 8, 0, 25, 93, 84, 26, 248, 2, 34,
-    19, 26, 119, 248, 2, 42, 95, 86, 225, 229, 25, 93, 84, 24, 8, 17, 152, 203, 225, 229,
-    25, 93, 84, 248, 14, 42, 102, 111, 9, 77, 68, 121, 18, 19, 120, 18, 232, 4, 201, 248,
-    6, 42, 79, 70, 33, 17, 0, 9, 52, 33, 144, 203, 52, 201, 248, 6, 42, 79, 70, 33, 17,
-    0, 9, 126, 183, 200, 61, 119, 33, 144, 203, 53, 201, 59, 59, 248, 10, 126, 234, 146,
-    203, 248, 11, 126, 234, 147, 203, 248, 8, 42, 79, 70, 89, 80, 19, 19, 26, 234, 148,
-    203, 105, 96, 35, 58, 94, 87, 33, 149, 203, 123, 34, 114, 89, 80, 26, 248, 0, 34, 19,
-    26, 119, 209, 213, 248, 11, 110, 38, 0, 25, 93, 84, 123, 2, 3, 122, 2, 51, 51, 201,
-    232, 252, 248, 10, 42, 95, 42, 87, 42, 79, 70, 203, 33, 203, 16, 33, 8, 0, 25, 51,
-    51, 229, 248, 13, 203, 126, 40, 10, 209, 213, 26, 111, 19, 26, 103, 9, 24, 4, 33,
-    152, 203, 9, 42, 102, 79, 68, 203, 33, 203, 16, 203, 124, 40, 17, 209, 213, 26, 111,
-    19, 26, 103, 9, 77, 124, 248, 2, 113, 35, 119, 24, 11, 33, 152, 203, 9, 77, 124, 248,
-    2, 113, 35, 119, 248, 14, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 10, 209, 213,
-    26, 111, 19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 42, 79, 70, 248, 2, 42, 102, 111,
-    121, 34, 112, 232, 4, 201, 232, 252, 248, 10, 42, 95, 42, 87, 42, 79, 70, 203, 33,
-    203, 16, 33, 8, 0, 25, 51, 51, 229, 248, 13, 203, 126, 40, 17, 209, 213, 26, 111, 19,
-    26, 103, 9, 77, 124, 248, 2, 113, 35, 119, 24, 11, 33, 152, 203, 9, 77, 124, 248, 2,
-    113, 35, 119, 248, 14, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 10, 209, 213, 26,
-    111, 19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 42, 102, 79, 68, 203, 33, 203, 16, 203,
-    124, 40, 10, 209, 213, 26, 111, 19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 42, 79, 70,
-    248, 2, 42, 102, 111, 121, 34, 112, 232, 4, 201, 248, 8, 42, 79, 70, 203, 33, 203,
-    16, 203, 126, 40, 15, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 24, 4, 33,
-    152, 203, 9, 229, 250, 145, 203, 225, 14, 0, 34, 113, 33, 145, 203, 54, 0, 201, 59,
-    59, 248, 14, 126, 248, 0, 119, 248, 15, 126, 248, 1, 50, 203, 38, 35, 203, 22, 248,
-    10, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 8, 42, 95, 86, 33, 8, 0, 25,
-    42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 209, 213, 213, 248, 14, 42, 95, 86,
-    213, 197, 205, 176, 55, 232, 8, 201, 232, 250, 248, 18, 126, 248, 0, 119, 248, 19,
-    126, 248, 1, 50, 203, 38, 35, 203, 22, 248, 12, 42, 95, 86, 248, 16, 42, 79, 70, 203,
-    33, 203, 16, 33, 8, 0, 25, 229, 125, 248, 4, 119, 225, 124, 248, 3, 119, 248, 17,
-    203, 126, 40, 15, 248, 2, 42, 95, 86, 26, 111, 19, 26, 103, 9, 77, 124, 24, 6, 33,
-    152, 203, 9, 124, 77, 248, 4, 113, 35, 119, 248, 14, 42, 79, 70, 203, 33, 203, 16,
-    203, 126, 40, 14, 248, 2, 42, 95, 86, 26, 111, 19, 26, 103, 9, 77, 24, 5, 33, 152,
-    203, 9, 77, 68, 209, 213, 213, 248, 6, 42, 95, 86, 213, 197, 205, 109, 55, 232, 12,
-    201, 232, 251, 248, 11, 126, 183, 40, 34, 17, 0, 14, 213, 17, 0, 0, 213, 17, 152,
-    203, 213, 205, 176, 55, 232, 6, 17, 48, 1, 213, 17, 0, 0, 213, 17, 88, 202, 213, 205,
-    176, 55, 232, 6, 1, 152, 209, 33, 138, 203, 62, 88, 34, 54, 202, 175, 33, 136, 203,
-    34, 119, 17, 0, 0, 248, 2, 62, 117, 34, 62, 203, 34, 54, 16, 248, 4, 126, 183, 40,
-    79, 43, 58, 110, 103, 35, 35, 35, 123, 34, 114, 248, 2, 42, 95, 86, 33, 10, 0, 25,
-    93, 84, 121, 18, 19, 120, 18, 248, 2, 42, 95, 86, 33, 12, 0, 25, 93, 84, 248, 4, 126,
-    18, 33, 128, 0, 9, 77, 68, 248, 2, 58, 43, 119, 248, 3, 58, 43, 34, 42, 95, 86, 33,
-    19, 0, 123, 149, 95, 122, 156, 248, 3, 50, 123, 34, 35, 209, 213, 53, 24, 171, 33,
-    144, 203, 54, 0, 33, 145, 203, 54, 0, 175, 33, 140, 203, 34, 119, 250, 136, 203, 234,
-    142, 203, 250, 137, 203, 234, 143, 203, 232, 5, 201, 232, 247, 33, 139, 203, 58, 182,
-    32, 6, 17, 0, 0, 195, 210, 94, 33, 138, 203, 42, 79, 70, 51, 51, 197, 33, 3, 0, 9,
-    229, 125, 248, 7, 119, 225, 124, 248, 6, 50, 42, 95, 86, 26, 33, 138, 203, 34, 19,
-    26, 119, 89, 80, 248, 16, 42, 18, 19, 58, 43, 18, 89, 80, 19, 19, 126, 18, 33, 8, 0,
-    9, 229, 125, 248, 4, 119, 225, 124, 248, 3, 119, 33, 10, 0, 9, 93, 84, 26, 248, 7,
-    34, 19, 26, 119, 248, 2, 42, 95, 86, 248, 7, 42, 18, 19, 126, 18, 33, 13, 0, 9, 93,
-    84, 248, 18, 42, 18, 19, 126, 18, 58, 182, 40, 18, 42, 95, 86, 33, 12, 0, 9, 126,
-    248, 7, 34, 175, 50, 42, 18, 19, 126, 18, 33, 15, 0, 9, 54, 0, 33, 17, 0, 9, 54, 0,
-    33, 18, 0, 9, 54, 0, 33, 7, 0, 9, 54, 0, 248, 5, 42, 102, 111, 175, 34, 119, 33, 137,
-    203, 58, 182, 40, 37, 42, 79, 70, 3, 3, 3, 248, 7, 121, 34, 120, 50, 42, 95, 86, 26,
-    79, 19, 26, 71, 177, 32, 236, 248, 7, 42, 95, 86, 248, 0, 42, 18, 19, 126, 18, 24, 6,
-    33, 136, 203, 121, 34, 112, 248, 20, 126, 183, 40, 93, 248, 20, 125, 84, 248, 7, 34,
-    122, 50, 42, 95, 86, 107, 98, 35, 229, 125, 248, 6, 119, 225, 124, 248, 5, 119, 248,
-    20, 126, 248, 8, 119, 248, 8, 126, 183, 40, 56, 248, 2, 42, 95, 86, 26, 248, 6, 34,
-    19, 26, 50, 42, 79, 70, 3, 3, 248, 2, 42, 102, 111, 121, 34, 112, 248, 4, 42, 79, 58,
-    71, 3, 3, 121, 34, 112, 11, 11, 105, 96, 42, 79, 70, 248, 6, 42, 102, 111, 121, 34,
-    112, 248, 8, 53, 24, 194, 209, 213, 232, 9, 201, 250, 136, 203, 234, 152, 217, 250,
-    137, 203, 234, 153, 217, 33, 153, 217, 58, 182, 40, 80, 42, 79, 70, 33, 12, 0, 9, 94,
-    248, 6, 126, 147, 32, 51, 33, 13, 0, 9, 42, 79, 70, 120, 177, 40, 26, 105, 96, 35,
-    58, 94, 87, 203, 250, 123, 2, 3, 122, 2, 33, 152, 217, 42, 79, 70, 33, 13, 0, 9, 175,
-    34, 119, 33, 152, 217, 42, 79, 70, 33, 15, 0, 9, 54, 1, 94, 201, 3, 3, 3, 89, 80, 26,
-    33, 152, 217, 34, 19, 26, 119, 24, 169, 30, 0, 201, 250, 136, 203, 234, 154, 217,
-    250, 137, 203, 234, 155, 217, 33, 155, 217, 58, 182, 40, 39, 42, 79, 70, 33, 12, 0,
-    9, 94, 248, 6, 126, 147, 32, 10, 33, 13, 0, 9, 175, 34, 119, 30, 1, 201, 3, 3, 3, 89,
-    80, 26, 33, 154, 217, 34, 19, 26, 119, 24, 210, 30, 0, 201, 186, 0, 254, 255, 51,
-    252, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 207, 48, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 243, 12, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 231, 24, 223, 32, 217, 38, 219, 36,
-    219, 36, 217, 38, 219, 36, 217, 38, 217, 38, 255, 0, 239, 16, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 248, 7, 223,
-    63, 255, 0, 223, 32, 255, 0, 255, 0, 240, 15, 191, 127, 255, 255, 255, 255, 255, 0,
-    255, 0, 255, 0, 255, 0, 253, 255, 255, 255, 255, 255, 255, 255, 255, 0, 255, 0, 255,
-    0, 255, 0, 193, 255, 255, 255, 255, 255, 255, 255, 255, 0, 255, 0, 255, 0, 255, 0,
-    240, 255, 255, 255, 252, 255, 207, 255, 255, 0, 255, 0, 255, 0, 255, 0, 31, 224, 254,
-    255, 227, 255, 127, 255, 231, 24, 224, 31, 224, 31, 224, 31, 236, 19, 0, 255, 255,
-    255, 131, 255, 255, 0, 15, 240, 127, 128, 255, 0, 255, 0, 63, 192, 247, 248, 253,
-    254, 219, 36, 219, 36, 219, 36, 219, 36, 219, 36, 219, 36, 219, 36, 219, 36, 255, 0,
-    255, 0, 255, 0, 255, 0, 248, 7, 127, 255, 127, 255, 255, 127, 255, 0, 255, 0, 255, 0,
-    241, 15, 255, 255, 255, 255, 255, 255, 253, 255, 255, 63, 255, 63, 255, 127, 255,
-    255, 255, 255, 255, 255, 141, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255,
-    239, 255, 187, 255, 239, 255, 255, 255, 255, 255, 255, 255, 251, 255, 63, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 254, 255, 255, 255, 159, 255, 255, 255, 255, 255,
-    254, 255, 231, 255, 251, 255, 223, 255, 255, 255, 255, 255, 252, 255, 167, 255, 63,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 63, 255, 255, 255, 254, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 247, 255, 239,
-    255, 223, 255, 254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 219, 36, 219, 36, 223, 32, 223, 32, 223, 32, 223, 32, 223, 32, 223, 32,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 254, 1, 244, 15, 140, 127, 255, 0, 255, 0,
-    255, 0, 254, 1, 255, 3, 62, 199, 23, 239, 247, 15, 191, 127, 252, 63, 151, 127, 223,
-    255, 119, 255, 255, 255, 253, 255, 251, 255, 223, 255, 255, 255, 223, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 127, 255, 253, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 63, 255, 255, 255, 253, 255, 255, 255, 241, 255,
-    231, 255, 255, 255, 255, 255, 255, 255, 63, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 191, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    231, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    191, 255, 191, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 223, 32, 223, 32, 223, 32, 223, 32, 223, 32, 223, 32, 223, 32, 223, 32, 255, 0,
-    255, 0, 255, 0, 255, 0, 254, 1, 225, 31, 135, 127, 228, 63, 9, 254, 11, 252, 19, 236,
-    34, 221, 28, 255, 255, 255, 255, 255, 255, 255, 255, 15, 143, 127, 143, 127, 63, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 179, 255, 239, 255, 255, 255, 191, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 255, 255, 255, 255,
-    255, 255, 103, 255, 255, 255, 255, 255, 207, 255, 255, 255, 127, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 252, 255, 251, 255, 247, 255, 223, 255, 255, 255,
-    63, 255, 255, 255, 127, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 239,
-    255, 255, 255, 252, 3, 241, 15, 247, 15, 243, 31, 223, 63, 191, 127, 60, 255, 19,
-    255, 255, 63, 63, 255, 255, 255, 255, 255, 255, 255, 255, 255, 63, 255, 255, 255,
-    254, 255, 255, 255, 255, 255, 255, 255, 255, 255, 175, 223, 183, 207, 193, 255, 63,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 253, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 254, 255, 253, 255, 251, 255, 255, 255, 239, 255, 255, 255, 255, 255, 191, 255,
-    255, 255, 127, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 1, 255, 3, 255, 7, 252, 3, 247, 15, 239, 31, 223, 63, 191, 255,
-    255, 255, 255, 255, 255, 255, 225, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 207, 255, 255, 255, 251, 255, 252, 255,
-    255, 255, 255, 255, 247, 255, 183, 255, 255, 255, 255, 255, 253, 255, 255, 255, 191,
-    255, 255, 255, 127, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    223, 255, 247, 15, 255, 15, 255, 31, 255, 63, 255, 63, 255, 127, 248, 127, 121, 255,
-    239, 255, 255, 255, 255, 255, 255, 255, 254, 255, 253, 254, 255, 252, 251, 252, 255,
-    255, 255, 255, 152, 255, 0, 255, 120, 135, 255, 0, 255, 0, 255, 0, 255, 255, 255,
-    255, 127, 255, 127, 255, 127, 255, 223, 63, 223, 63, 202, 63, 239, 255, 255, 255,
-    255, 255, 251, 255, 251, 255, 254, 255, 255, 255, 253, 255, 63, 255, 255, 255, 255,
-    255, 127, 255, 255, 255, 255, 255, 214, 255, 255, 255, 223, 32, 223, 32, 223, 32,
-    223, 32, 223, 32, 223, 32, 222, 33, 222, 33, 125, 243, 238, 241, 238, 241, 254, 225,
-    255, 225, 221, 227, 223, 225, 254, 193, 247, 248, 255, 240, 239, 240, 239, 240, 255,
-    224, 255, 224, 223, 224, 223, 224, 231, 31, 240, 15, 242, 13, 224, 31, 240, 15, 240,
-    15, 240, 15, 224, 31, 127, 255, 191, 255, 223, 255, 3, 255, 133, 127, 3, 255, 3, 255,
-    7, 255, 255, 255, 255, 255, 255, 255, 255, 255, 127, 255, 191, 255, 255, 255, 238,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 239, 255,
-    255, 255, 231, 255, 127, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 222,
-    33, 223, 32, 223, 32, 223, 32, 223, 32, 223, 32, 219, 36, 219, 36, 254, 193, 222,
-    225, 222, 225, 253, 227, 255, 227, 127, 227, 122, 229, 99, 252, 255, 224, 255, 224,
-    255, 224, 255, 224, 127, 224, 127, 224, 127, 224, 88, 231, 248, 7, 255, 0, 255, 0,
-    255, 0, 255, 0, 254, 1, 254, 1, 76, 179, 1, 255, 133, 123, 131, 127, 131, 127, 9,
-    247, 1, 255, 5, 251, 192, 63, 251, 255, 255, 255, 255, 255, 255, 255, 255, 255, 63,
-    255, 63, 255, 33, 255, 219, 36, 217, 38, 217, 38, 217, 38, 217, 38, 216, 39, 220, 35,
-    220, 35, 255, 120, 239, 120, 143, 120, 139, 124, 248, 63, 221, 63, 223, 63, 255, 31,
-    67, 255, 87, 239, 224, 255, 255, 224, 255, 224, 251, 228, 252, 227, 255, 225, 224,
-    255, 248, 255, 62, 255, 143, 127, 207, 63, 207, 63, 63, 255, 255, 255, 0, 255, 0,
-    255, 7, 255, 15, 255, 239, 159, 247, 143, 115, 143, 243, 15, 7, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 252, 255, 252, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 87, 255, 63, 255, 127, 255, 255, 255, 255, 255, 255, 255, 254,
-    255, 255, 255, 253, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 127, 255,
-    255, 255, 191, 255, 191, 255, 255, 255, 220, 35, 217, 38, 221, 34, 220, 35, 220, 35,
-    216, 39, 219, 36, 223, 32, 255, 31, 255, 31, 239, 31, 255, 15, 247, 15, 255, 7, 251,
-    7, 255, 3, 255, 224, 255, 224, 255, 224, 255, 224, 255, 224, 239, 240, 239, 240, 111,
-    240, 190, 127, 231, 24, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 245, 15, 230,
-    31, 231, 31, 239, 31, 203, 63, 207, 63, 223, 63, 223, 63, 255, 255, 255, 255, 220,
-    255, 252, 255, 250, 255, 253, 255, 253, 255, 253, 255, 255, 255, 255, 255, 127, 255,
-    0, 255, 2, 253, 0, 255, 0, 255, 8, 247, 255, 255, 255, 255, 191, 255, 127, 255, 63,
-    255, 159, 127, 30, 255, 255, 255, 255, 255, 239, 255, 255, 255, 239, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 220, 35, 217, 38, 217, 38, 219, 36, 219, 36, 223, 32,
-    223, 32, 223, 32, 255, 1, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 240, 63, 240, 247, 24, 247, 8, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 252, 3, 254, 1, 223, 63, 159, 127, 151, 127, 159,
-    127, 47, 255, 63, 255, 255, 255, 127, 255, 253, 255, 255, 255, 255, 255, 187, 255,
-    255, 255, 63, 255, 247, 255, 255, 255, 1, 255, 73, 255, 143, 255, 151, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 254, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 0, 255, 0, 255, 0, 12, 243, 0, 255, 0, 255, 0, 255, 4,
-    251, 255, 0, 248, 7, 224, 31, 2, 253, 0, 255, 25, 231, 187, 71, 27, 231, 255, 0, 31,
-    224, 51, 252, 78, 255, 187, 255, 254, 255, 255, 255, 255, 255, 223, 32, 219, 36, 223,
-    32, 223, 32, 159, 224, 223, 224, 47, 240, 211, 252, 255, 0, 254, 1, 236, 19, 192, 63,
-    195, 60, 199, 56, 199, 63, 196, 59, 223, 63, 223, 63, 23, 255, 207, 255, 123, 255,
-    249, 7, 119, 143, 63, 255, 223, 255, 251, 255, 239, 255, 254, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 79, 255, 254, 255,
-    251, 255, 255, 255, 247, 255, 247, 255, 255, 255, 223, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 252, 255,
-    223, 224, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 127, 128, 255,
-    0, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 160, 255, 255, 0, 255, 0, 255,
-    255, 255, 255, 223, 255, 159, 255, 255, 255, 3, 255, 255, 0, 255, 0, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 127, 255, 159, 127, 0, 255, 49, 255,
-    111, 255, 127, 255, 255, 255, 255, 255, 255, 255, 255, 255, 27, 231, 195, 255, 161,
-    255, 245, 251, 209, 255, 249, 255, 255, 255, 251, 255, 255, 255, 191, 255, 254, 255,
-    255, 255, 255, 255, 124, 255, 191, 255, 191, 255, 253, 254, 183, 254, 251, 255, 221,
-    255, 254, 255, 239, 255, 255, 255, 247, 255, 255, 0, 255, 0, 255, 0, 127, 128, 191,
-    192, 127, 192, 191, 224, 175, 240, 207, 48, 239, 16, 238, 17, 252, 3, 241, 14, 244,
-    11, 233, 30, 249, 30, 227, 31, 253, 3, 249, 7, 63, 255, 127, 255, 15, 255, 240, 15,
-    240, 15, 255, 255, 63, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 191,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 255, 254,
-    254, 255, 255, 252, 247, 248, 255, 224, 255, 192, 127, 128, 255, 0, 255, 0, 255, 0,
-    247, 8, 255, 0, 255, 0, 254, 1, 255, 0, 249, 6, 224, 31, 255, 0, 119, 136, 223, 32,
-    159, 96, 238, 17, 240, 15, 248, 7, 240, 15, 254, 1, 228, 27, 0, 255, 128, 127, 0,
-    255, 0, 255, 0, 255, 0, 255, 240, 15, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 40,
-    223, 8, 255, 39, 223, 1, 255, 1, 255, 120, 135, 127, 128, 31, 224, 0, 255, 12, 243,
-    255, 255, 255, 255, 255, 255, 255, 255, 159, 127, 227, 31, 1, 255, 244, 255, 255,
-    255, 223, 255, 255, 255, 255, 255, 255, 255, 247, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 251, 255, 255, 255, 253, 255, 255, 255, 254, 255, 255, 255, 223, 240, 223,
-    248, 235, 252, 245, 254, 183, 254, 255, 255, 218, 255, 123, 255, 255, 0, 255, 0, 255,
-    0, 255, 0, 193, 63, 249, 255, 243, 255, 103, 255, 249, 30, 221, 62, 188, 127, 222,
-    255, 223, 255, 159, 255, 255, 255, 149, 255, 225, 31, 1, 255, 211, 47, 7, 255, 191,
-    255, 255, 255, 255, 255, 255, 255, 191, 255, 127, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 159, 255, 255, 252, 251, 252, 255, 248, 247, 248, 254, 241, 237, 242,
-    249, 230, 249, 230, 254, 1, 252, 3, 252, 3, 248, 7, 248, 7, 244, 15, 245, 14, 244,
-    15, 246, 9, 254, 1, 254, 1, 246, 9, 247, 8, 251, 4, 56, 199, 63, 192, 204, 51, 12,
-    243, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 128, 127, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 1, 255, 29, 254, 36, 255, 188, 127, 63, 255, 125, 255,
-    63, 255, 127, 255, 255, 255, 1, 255, 14, 255, 27, 255, 63, 255, 63, 255, 191, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255, 255,
-    255, 255, 255, 159, 255, 199, 255, 243, 255, 248, 255, 254, 255, 255, 255, 255, 255,
-    253, 255, 255, 255, 255, 255, 255, 255, 255, 255, 127, 255, 159, 255, 207, 255, 225,
-    255, 255, 255, 255, 255, 255, 255, 110, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    191, 255, 251, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 254, 255, 255, 255, 191, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 127, 255, 255, 255, 191, 255, 255, 255, 119, 255, 125,
-    255, 254, 255, 255, 255, 253, 255, 253, 255, 255, 255, 247, 255, 207, 255, 207, 255,
-    255, 255, 207, 255, 255, 255, 255, 255, 125, 255, 253, 255, 190, 255, 255, 255, 251,
-    255, 255, 255, 223, 255, 255, 255, 237, 255, 253, 255, 227, 255, 119, 248, 59, 252,
-    46, 255, 243, 255, 223, 255, 248, 255, 253, 255, 255, 255, 63, 255, 207, 63, 231, 31,
-    15, 255, 143, 255, 159, 255, 255, 255, 209, 238, 245, 206, 245, 206, 237, 222, 171,
-    222, 235, 158, 107, 158, 109, 158, 236, 31, 232, 31, 202, 61, 208, 63, 144, 127, 180,
-    123, 128, 127, 133, 122, 123, 132, 60, 195, 62, 193, 28, 227, 60, 195, 92, 163, 124,
-    131, 62, 193, 128, 127, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 5,
-    251, 1, 255, 1, 255, 1, 255, 3, 255, 7, 255, 7, 255, 15, 255, 127, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 249, 255, 119, 255, 189,
-    255, 223, 255, 239, 255, 255, 255, 253, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    247, 255, 255, 255, 251, 255, 255, 255, 255, 255, 223, 255, 239, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 247, 255, 255, 255,
-    187, 255, 237, 255, 255, 255, 255, 255, 247, 255, 255, 255, 251, 255, 251, 255, 251,
-    255, 251, 255, 251, 255, 251, 255, 255, 255, 255, 255, 127, 255, 191, 255, 191, 255,
-    191, 255, 223, 255, 223, 255, 255, 255, 254, 255, 255, 255, 255, 255, 255, 255, 159,
-    255, 255, 255, 223, 255, 252, 255, 254, 255, 127, 255, 127, 255, 62, 255, 125, 255,
-    57, 255, 51, 255, 255, 255, 255, 255, 255, 255, 63, 255, 255, 255, 131, 255, 166,
-    223, 254, 255, 255, 255, 253, 255, 249, 255, 207, 255, 255, 255, 255, 255, 191, 255,
-    191, 255, 101, 158, 87, 188, 211, 60, 195, 60, 195, 60, 198, 57, 198, 57, 198, 57,
-    163, 124, 3, 252, 15, 240, 92, 163, 28, 227, 120, 135, 240, 15, 224, 31, 218, 37,
-    216, 39, 24, 231, 24, 231, 0, 255, 12, 243, 56, 199, 56, 199, 0, 255, 1, 254, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 37, 255, 7, 255, 31, 255, 15, 255, 15, 255,
-    175, 127, 31, 255, 31, 255, 255, 255, 251, 255, 255, 255, 239, 255, 255, 255, 251,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 127, 255, 251, 255, 251, 255, 255, 255,
-    254, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
-    16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36,
-    37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57,
-    58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78,
-    79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 95, 12, 97,
-    98, 99, 100, 101, 62, 102, 103, 104, 105, 95, 106, 95, 95, 107, 108, 109, 2, 110,
-    111, 112, 113, 114, 103, 115, 116, 95, 106, 117, 95, 118, 119, 120, 121, 122, 123,
-    124, 125, 126, 127, 128, 129, 95, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139,
-    140, 141, 132, 142, 95, 95, 95, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152,
-    153, 154, 155, 156, 157, 158, 159, 160, 161, 162, 163, 164, 165, 166, 88, 167, 168,
-    169, 170, 171, 172, 173, 174, 175, 176, 177, 178, 179, 180, 181, 182, 183, 184, 185,
-    186, 156, 157, 158, 159, 187, 188, 189, 190, 191, 192, 193, 194, 195, 196, 197, 198,
-    199, 200, 201, 202, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 64, 64,
-    64, 64, 64, 64, 64, 0, 0, 64, 64, 0, 0, 0, 0, 108, 108, 36, 36, 72, 72, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 36, 36, 126, 126, 36, 36, 36, 36, 126, 126, 36, 36, 0, 0, 16, 16,
-    56, 56, 84, 84, 80, 80, 56, 56, 20, 20, 84, 84, 56, 56, 0, 0, 0, 0, 36, 36, 8, 8, 16,
-    16, 36, 36, 0, 0, 0, 0, 0, 0, 48, 48, 72, 72, 80, 80, 32, 32, 84, 84, 72, 72, 52, 52,
-    0, 0, 24, 24, 8, 8, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 8, 16, 16, 16, 16, 16,
-    16, 16, 16, 8, 8, 0, 0, 0, 0, 16, 16, 8, 8, 8, 8, 8, 8, 8, 8, 16, 16, 0, 0, 0, 0, 0,
-    0, 16, 16, 84, 84, 56, 56, 84, 84, 16, 16, 0, 0, 0, 0, 0, 0, 16, 16, 16, 16, 124,
-    124, 16, 16, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 96, 32, 32, 64, 64, 0, 0, 0,
-    0, 0, 0, 0, 0, 124, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    64, 64, 0, 0, 0, 0, 2, 2, 4, 4, 8, 8, 16, 16, 32, 32, 64, 64, 0, 0, 0, 0, 60, 60, 98,
-    98, 82, 82, 74, 74, 70, 70, 60, 60, 0, 0, 0, 0, 24, 24, 40, 40, 8, 8, 8, 8, 8, 8, 60,
-    60, 0, 0, 0, 0, 60, 60, 66, 66, 2, 2, 60, 60, 64, 64, 126, 126, 0, 0, 0, 0, 124, 124,
-    2, 2, 12, 12, 2, 2, 2, 2, 126, 126, 0, 0, 0, 0, 64, 64, 64, 64, 72, 72, 126, 126, 8,
-    8, 8, 8, 0, 0, 0, 0, 126, 126, 64, 64, 124, 124, 2, 2, 2, 2, 124, 124, 0, 0, 0, 0,
-    60, 60, 64, 64, 124, 124, 66, 66, 66, 66, 60, 60, 0, 0, 0, 0, 126, 126, 2, 2, 4, 4,
-    8, 8, 16, 16, 32, 32, 0, 0, 0, 0, 60, 60, 66, 66, 60, 60, 66, 66, 66, 66, 60, 60, 0,
-    0, 0, 0, 60, 60, 66, 66, 66, 66, 62, 62, 2, 2, 60, 60, 0, 0, 0, 0, 24, 24, 24, 24, 0,
-    0, 0, 0, 24, 24, 24, 24, 0, 0, 0, 0, 24, 24, 24, 24, 0, 0, 24, 24, 24, 24, 8, 8, 16,
-    16, 0, 0, 0, 0, 12, 12, 48, 48, 64, 64, 48, 48, 12, 12, 0, 0, 0, 0, 0, 0, 0, 0, 124,
-    124, 0, 0, 124, 124, 0, 0, 0, 0, 0, 0, 0, 0, 96, 96, 24, 24, 4, 4, 24, 24, 96, 96, 0,
-    0, 0, 0, 48, 48, 72, 72, 8, 8, 48, 48, 0, 0, 32, 32, 0, 0, 0, 0, 28, 28, 34, 34, 77,
-    77, 85, 85, 85, 85, 46, 46, 0, 0, 0, 0, 60, 60, 66, 66, 66, 66, 126, 126, 66, 66, 66,
-    66, 0, 0, 0, 0, 124, 124, 66, 66, 124, 124, 66, 66, 66, 66, 124, 124, 0, 0, 0, 0, 60,
-    60, 66, 66, 64, 64, 64, 64, 66, 66, 60, 60, 0, 0, 0, 0, 124, 124, 66, 66, 66, 66, 66,
-    66, 66, 66, 124, 124, 0, 0, 0, 0, 126, 126, 64, 64, 124, 124, 64, 64, 64, 64, 126,
-    126, 0, 0, 0, 0, 126, 126, 64, 64, 64, 64, 124, 124, 64, 64, 64, 64, 0, 0, 0, 0, 60,
-    60, 66, 66, 64, 64, 78, 78, 66, 66, 62, 62, 0, 0, 0, 0, 66, 66, 66, 66, 66, 66, 126,
-    126, 66, 66, 66, 66, 0, 0, 0, 0, 124, 124, 16, 16, 16, 16, 16, 16, 16, 16, 124, 124,
-    0, 0, 0, 0, 124, 124, 16, 16, 16, 16, 16, 16, 16, 16, 96, 96, 0, 0, 0, 0, 68, 68, 72,
-    72, 80, 80, 112, 112, 72, 72, 68, 68, 0, 0, 0, 0, 64, 64, 64, 64, 64, 64, 64, 64, 64,
-    64, 126, 126, 0, 0, 0, 0, 66, 66, 102, 102, 90, 90, 66, 66, 66, 66, 66, 66, 0, 0, 0,
-    0, 66, 66, 98, 98, 82, 82, 74, 74, 70, 70, 66, 66, 0, 0, 0, 0, 60, 60, 66, 66, 66,
-    66, 66, 66, 66, 66, 60, 60, 0, 0, 0, 0, 124, 124, 66, 66, 66, 66, 124, 124, 64, 64,
-    64, 64, 0, 0, 0, 0, 60, 60, 66, 66, 66, 66, 74, 74, 68, 68, 58, 58, 0, 0, 0, 0, 124,
-    124, 66, 66, 66, 66, 124, 124, 66, 66, 66, 66, 0, 0, 0, 0, 62, 62, 64, 64, 60, 60, 2,
-    2, 66, 66, 60, 60, 0, 0, 0, 0, 124, 124, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 0,
-    0, 0, 0, 66, 66, 66, 66, 66, 66, 66, 66, 66, 66, 60, 60, 0, 0, 0, 0, 68, 68, 68, 68,
-    68, 68, 40, 40, 40, 40, 16, 16, 0, 0, 0, 0, 66, 66, 66, 66, 66, 66, 90, 90, 102, 102,
-    66, 66, 0, 0, 0, 0, 66, 66, 36, 36, 24, 24, 24, 24, 36, 36, 66, 66, 0, 0, 0, 0, 68,
-    68, 40, 40, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 0, 0, 124, 124, 4, 4, 8, 8, 16, 16,
-    32, 32, 124, 124, 0, 0, 0, 0, 24, 24, 16, 16, 16, 16, 16, 16, 16, 16, 24, 24, 0, 0,
-    0, 0, 64, 64, 32, 32, 16, 16, 8, 8, 4, 4, 2, 2, 0, 0, 0, 0, 24, 24, 8, 8, 8, 8, 8, 8,
-    8, 8, 24, 24, 0, 0, 0, 0, 16, 16, 40, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 60, 60, 0, 0, 0, 0, 32, 32, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 60, 60, 68, 68, 68, 68, 76, 76, 52, 52, 0, 0, 0, 0, 64, 64, 120,
-    120, 68, 68, 68, 68, 68, 68, 120, 120, 0, 0, 0, 0, 0, 0, 60, 60, 64, 64, 64, 64, 64,
-    64, 60, 60, 0, 0, 0, 0, 4, 4, 60, 60, 68, 68, 68, 68, 68, 68, 60, 60, 0, 0, 0, 0, 0,
-    0, 56, 56, 68, 68, 124, 124, 64, 64, 60, 60, 0, 0, 0, 0, 56, 56, 64, 64, 64, 64, 120,
-    120, 64, 64, 64, 64, 0, 0, 0, 0, 0, 0, 56, 56, 68, 68, 68, 68, 60, 60, 4, 4, 120,
-    120, 0, 0, 64, 64, 120, 120, 68, 68, 68, 68, 68, 68, 68, 68, 0, 0, 0, 0, 16, 16, 0,
-    0, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 0, 0, 8, 8, 0, 0, 8, 8, 8, 8, 8, 8, 8, 8,
-    48, 48, 0, 0, 64, 64, 68, 68, 72, 72, 112, 112, 72, 72, 68, 68, 0, 0, 0, 0, 16, 16,
-    16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 0, 0, 0, 0, 108, 108, 84, 84, 68, 68,
-    68, 68, 68, 68, 0, 0, 0, 0, 0, 0, 88, 88, 100, 100, 68, 68, 68, 68, 68, 68, 0, 0, 0,
-    0, 0, 0, 56, 56, 68, 68, 68, 68, 68, 68, 56, 56, 0, 0, 0, 0, 0, 0, 120, 120, 68, 68,
-    68, 68, 68, 68, 120, 120, 64, 64, 0, 0, 0, 0, 60, 60, 68, 68, 68, 68, 68, 68, 60, 60,
-    4, 4, 0, 0, 0, 0, 88, 88, 100, 100, 64, 64, 64, 64, 64, 64, 0, 0, 0, 0, 0, 0, 60, 60,
-    64, 64, 56, 56, 4, 4, 120, 120, 0, 0, 0, 0, 64, 64, 64, 64, 120, 120, 64, 64, 64, 64,
-    56, 56, 0, 0, 0, 0, 0, 0, 68, 68, 68, 68, 68, 68, 76, 76, 52, 52, 0, 0, 0, 0, 0, 0,
-    68, 68, 68, 68, 40, 40, 40, 40, 16, 16, 0, 0, 0, 0, 0, 0, 68, 68, 68, 68, 68, 68, 84,
-    84, 108, 108, 0, 0, 0, 0, 0, 0, 68, 68, 40, 40, 16, 16, 40, 40, 68, 68, 0, 0, 0, 0,
-    0, 0, 68, 68, 68, 68, 68, 68, 40, 40, 16, 16, 96, 96, 0, 0, 0, 0, 124, 124, 8, 8, 16,
-    16, 32, 32, 124, 124, 0, 0, 0, 0, 8, 8, 16, 16, 16, 16, 32, 32, 16, 16, 16, 16, 8, 8,
-    16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 16, 16, 8, 8,
-    8, 8, 4, 4, 8, 8, 8, 8, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 50, 50, 76, 76, 0, 0, 0, 0,
-    85, 85, 170, 170, 85, 85, 170, 170, 85, 85, 170, 170, 85, 85, 170, 170, 0, 0, 56, 56,
-    64, 64, 112, 112, 112, 112, 64, 64, 56, 56, 0, 0, 0, 0, 0, 0, 12, 12, 16, 16, 56, 56,
-    16, 16, 96, 96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108, 108, 36, 36, 72, 72, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 84, 0, 0, 0, 0, 0, 0, 32, 32, 112, 112, 32, 32, 32,
-    32, 0, 0, 0, 0, 0, 0, 0, 0, 32, 32, 112, 112, 32, 32, 112, 112, 32, 32, 0, 0, 0, 0,
-    0, 0, 72, 72, 16, 16, 32, 32, 74, 74, 0, 0, 0, 0, 40, 40, 16, 16, 60, 60, 64, 64, 56,
-    56, 4, 4, 120, 120, 0, 0, 0, 0, 0, 0, 8, 8, 16, 16, 32, 32, 16, 16, 8, 8, 0, 0, 0, 0,
-    62, 62, 72, 72, 78, 78, 72, 72, 72, 72, 62, 62, 0, 0, 40, 40, 16, 16, 124, 124, 8, 8,
-    16, 16, 32, 32, 124, 124, 0, 0, 0, 0, 6, 6, 4, 4, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    96, 96, 32, 32, 64, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 54, 36, 36, 18, 18, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 24, 60, 60, 60, 60, 24, 24, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 56, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 124, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 20, 20, 40, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 122, 46,
-    46, 42, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 32, 16, 16, 8, 8, 16, 16, 32, 32, 0, 0,
-    0, 0, 0, 0, 0, 0, 60, 60, 74, 74, 76, 76, 62, 62, 0, 0, 0, 0, 40, 40, 0, 0, 68, 68,
-    40, 40, 16, 16, 16, 16, 16, 16, 0, 0, 64, 64, 0, 0, 64, 64, 64, 64, 64, 64, 64, 64,
-    0, 0, 0, 0, 16, 16, 60, 60, 80, 80, 80, 80, 80, 80, 60, 60, 16, 16, 0, 0, 56, 56, 68,
-    68, 64, 64, 240, 240, 64, 64, 124, 124, 0, 0, 0, 0, 0, 0, 68, 68, 56, 56, 40, 40, 56,
-    56, 68, 68, 0, 0, 68, 68, 40, 40, 16, 16, 124, 124, 16, 16, 124, 124, 16, 16, 0, 0,
-    16, 16, 16, 16, 16, 16, 0, 0, 0, 0, 16, 16, 16, 16, 16, 16, 28, 28, 32, 32, 120, 120,
-    68, 68, 56, 56, 8, 8, 112, 112, 0, 0, 0, 0, 36, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 60, 60, 66, 66, 153, 153, 161, 161, 161, 161, 153, 153, 66, 66, 60, 60, 56, 56,
-    72, 72, 88, 88, 104, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 20, 40, 40, 80, 80,
-    40, 40, 20, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 60, 4, 4, 0, 0, 0, 0, 60, 60, 66,
-    66, 185, 185, 165, 165, 185, 185, 165, 165, 66, 66, 60, 60, 0, 0, 0, 0, 0, 0, 120,
-    120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 48, 72, 72, 72, 72, 48, 48, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 16, 16, 56, 56, 16, 16, 0, 0, 56, 56, 0, 0, 48, 48, 72, 72, 16, 16, 32,
-    32, 120, 120, 0, 0, 0, 0, 0, 0, 112, 112, 8, 8, 56, 56, 8, 8, 112, 112, 0, 0, 0, 0,
-    0, 0, 4, 4, 8, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 64, 72, 72, 72, 72,
-    72, 72, 116, 116, 64, 64, 0, 0, 0, 0, 58, 58, 122, 122, 122, 122, 58, 58, 10, 10, 10,
-    10, 0, 0, 0, 0, 0, 0, 0, 0, 24, 24, 24, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 8, 8, 4, 4, 8, 8, 0, 0, 96, 96, 32, 32, 32, 32, 112, 112, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 40, 40, 20, 20, 10, 10, 20, 20, 40, 40, 0, 0, 0, 0, 16, 16, 0, 0, 48, 48,
-    64, 64, 72, 72, 48, 48, 0, 0, 32, 32, 16, 16, 0, 0, 60, 60, 66, 66, 126, 126, 66, 66,
-    0, 0, 4, 4, 8, 8, 0, 0, 60, 60, 66, 66, 126, 126, 66, 66, 0, 0, 24, 24, 36, 36, 0, 0,
-    60, 60, 66, 66, 126, 126, 66, 66, 0, 0, 20, 20, 40, 40, 0, 0, 60, 60, 66, 66, 126,
-    126, 66, 66, 0, 0, 0, 0, 36, 36, 0, 0, 60, 60, 66, 66, 126, 126, 66, 66, 0, 0, 24,
-    24, 24, 24, 0, 0, 60, 60, 66, 66, 126, 126, 66, 66, 0, 0, 0, 0, 30, 30, 24, 24, 40,
-    40, 62, 62, 72, 72, 78, 78, 0, 0, 0, 0, 60, 60, 66, 66, 64, 64, 64, 64, 66, 66, 60,
-    60, 8, 8, 32, 32, 16, 16, 0, 0, 126, 126, 64, 64, 124, 124, 64, 64, 126, 126, 4, 4,
-    8, 8, 0, 0, 126, 126, 64, 64, 124, 124, 64, 64, 126, 126, 20, 20, 40, 40, 0, 0, 126,
-    126, 64, 64, 124, 124, 64, 64, 126, 126, 0, 0, 36, 36, 0, 0, 126, 126, 64, 64, 124,
-    124, 64, 64, 126, 126, 32, 32, 16, 16, 0, 0, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16,
-    8, 8, 16, 16, 0, 0, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 40, 40, 0, 0, 16,
-    16, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 40, 40, 0, 0, 16, 16, 16, 16, 16, 16, 16,
-    16, 16, 16, 0, 0, 124, 124, 66, 66, 66, 66, 242, 242, 66, 66, 124, 124, 0, 0, 20, 20,
-    40, 40, 0, 0, 98, 98, 82, 82, 74, 74, 70, 70, 0, 0, 32, 32, 16, 16, 0, 0, 60, 60, 66,
-    66, 66, 66, 60, 60, 0, 0, 4, 4, 8, 8, 0, 0, 60, 60, 66, 66, 66, 66, 60, 60, 0, 0, 24,
-    24, 36, 36, 0, 0, 60, 60, 66, 66, 66, 66, 60, 60, 0, 0, 20, 20, 40, 40, 0, 0, 60, 60,
-    66, 66, 66, 66, 60, 60, 0, 0, 0, 0, 36, 36, 0, 0, 60, 60, 66, 66, 66, 66, 60, 60, 0,
-    0, 0, 0, 62, 62, 70, 70, 74, 74, 82, 82, 98, 98, 124, 124, 0, 0, 32, 32, 16, 16, 66,
-    66, 66, 66, 66, 66, 66, 66, 60, 60, 0, 0, 4, 4, 8, 8, 66, 66, 66, 66, 66, 66, 66, 66,
-    60, 60, 0, 0, 24, 24, 36, 36, 0, 0, 66, 66, 66, 66, 66, 66, 60, 60, 0, 0, 0, 0, 36,
-    36, 0, 0, 66, 66, 66, 66, 66, 66, 60, 60, 0, 0, 4, 4, 8, 8, 68, 68, 40, 40, 16, 16,
-    16, 16, 16, 16, 0, 0, 0, 0, 64, 64, 112, 112, 72, 72, 72, 72, 112, 112, 64, 64, 0, 0,
-    0, 0, 120, 120, 68, 68, 72, 72, 68, 68, 68, 68, 88, 88, 0, 0, 32, 32, 16, 16, 0, 0,
-    60, 60, 68, 68, 76, 76, 52, 52, 0, 0, 4, 4, 8, 8, 0, 0, 60, 60, 68, 68, 76, 76, 52,
-    52, 0, 0, 24, 24, 36, 36, 0, 0, 60, 60, 68, 68, 76, 76, 52, 52, 0, 0, 20, 20, 40, 40,
-    0, 0, 60, 60, 68, 68, 76, 76, 52, 52, 0, 0, 0, 0, 36, 36, 0, 0, 60, 60, 68, 68, 76,
-    76, 52, 52, 0, 0, 24, 24, 24, 24, 0, 0, 60, 60, 68, 68, 76, 76, 52, 52, 0, 0, 0, 0,
-    0, 0, 0, 0, 60, 60, 74, 74, 92, 92, 46, 46, 0, 0, 0, 0, 0, 0, 60, 60, 64, 64, 64, 64,
-    64, 64, 60, 60, 8, 8, 32, 32, 16, 16, 0, 0, 56, 56, 68, 68, 124, 124, 64, 64, 60, 60,
-    4, 4, 8, 8, 0, 0, 56, 56, 68, 68, 124, 124, 64, 64, 60, 60, 24, 24, 36, 36, 0, 0, 56,
-    56, 68, 68, 124, 124, 64, 64, 60, 60, 0, 0, 36, 36, 0, 0, 56, 56, 68, 68, 124, 124,
-    64, 64, 60, 60, 0, 0, 10, 10, 4, 4, 58, 58, 68, 68, 68, 68, 56, 56, 0, 0, 20, 20, 40,
-    40, 0, 0, 88, 88, 100, 100, 68, 68, 68, 68, 0, 0, 32, 32, 16, 16, 0, 0, 56, 56, 68,
-    68, 68, 68, 56, 56, 0, 0, 8, 8, 16, 16, 0, 0, 56, 56, 68, 68, 68, 68, 56, 56, 0, 0,
-    16, 16, 40, 40, 0, 0, 56, 56, 68, 68, 68, 68, 56, 56, 0, 0, 20, 20, 40, 40, 0, 0, 56,
-    56, 68, 68, 68, 68, 56, 56, 0, 0, 0, 0, 40, 40, 0, 0, 56, 56, 68, 68, 68, 68, 56, 56,
-    0, 0, 0, 0, 0, 0, 16, 16, 0, 0, 124, 124, 0, 0, 16, 16, 0, 0, 0, 0, 0, 0, 60, 60, 76,
-    76, 84, 84, 100, 100, 120, 120, 0, 0, 32, 32, 16, 16, 0, 0, 68, 68, 68, 68, 76, 76,
-    52, 52, 0, 0, 8, 8, 16, 16, 0, 0, 68, 68, 68, 68, 76, 76, 52, 52, 0, 0, 16, 16, 40,
-    40, 0, 0, 68, 68, 68, 68, 76, 76, 52, 52, 0, 0, 0, 0, 40, 40, 0, 0, 68, 68, 68, 68,
-    76, 76, 52, 52, 0, 0, 8, 8, 16, 16, 0, 0, 68, 68, 68, 68, 40, 40, 16, 16, 96, 96, 0,
-    0, 64, 64, 96, 96, 80, 80, 80, 80, 96, 96, 64, 64, 0, 0, 0, 0, 40, 40, 0, 0, 68, 68,
-    68, 68, 40, 40, 16, 16, 96, 96, 1, 255, 26, 107, 0, 0, 26, 108, 33, 30, 197, 54, 0,
-    33, 31, 197, 54, 0, 33, 32, 197, 54, 8, 33, 33, 197, 54, 8, 201, 232, 239, 248, 4,
-    175, 34, 54, 4, 33, 7, 197, 54, 0, 33, 29, 198, 94, 33, 27, 198, 86, 122, 230, 2, 79,
-    6, 0, 122, 230, 1, 248, 11, 34, 175, 34, 122, 230, 4, 34, 175, 34, 122, 230, 8, 34,
-    54, 0, 203, 75, 32, 10, 250, 29, 198, 183, 32, 10, 120, 177, 40, 6, 248, 5, 54, 3,
-    24, 64, 203, 67, 32, 12, 250, 29, 198, 183, 32, 12, 248, 12, 58, 182, 40, 6, 248, 5,
-    54, 1, 24, 42, 203, 83, 32, 12, 250, 29, 198, 183, 32, 12, 248, 14, 58, 182, 40, 6,
-    248, 5, 54, 2, 24, 20, 203, 91, 32, 12, 250, 29, 198, 183, 32, 10, 248, 16, 58, 182,
-    40, 4, 248, 5, 54, 0, 120, 177, 40, 35, 33, 7, 197, 54, 1, 248, 14, 58, 182, 40, 6,
-    248, 4, 54, 224, 24, 91, 248, 16, 58, 182, 40, 6, 248, 4, 54, 160, 24, 79, 248, 4,
-    54, 192, 24, 73, 248, 12, 58, 182, 40, 35, 33, 7, 197, 54, 1, 248, 14, 58, 182, 40,
-    6, 248, 4, 54, 32, 24, 50, 248, 16, 58, 182, 40, 6, 248, 4, 54, 96, 24, 38, 248, 4,
-    54, 64, 24, 32, 248, 14, 58, 182, 40, 11, 33, 7, 197, 54, 1, 248, 4, 54, 0, 24, 15,
-    248, 16, 58, 182, 40, 9, 33, 7, 197, 54, 1, 248, 4, 54, 128, 250, 7, 197, 183, 202,
-    54, 127, 248, 0, 125, 84, 248, 15, 34, 114, 33, 186, 192, 42, 79, 70, 248, 15, 42,
-    102, 111, 121, 34, 112, 248, 0, 125, 84, 248, 6, 34, 122, 50, 42, 95, 86, 33, 2, 0,
-    25, 229, 125, 248, 10, 119, 225, 124, 248, 9, 119, 33, 188, 192, 42, 79, 70, 248, 8,
-    42, 102, 111, 121, 34, 112, 250, 200, 192, 248, 10, 119, 248, 6, 126, 248, 15, 119,
-    248, 7, 126, 248, 16, 50, 126, 248, 11, 119, 248, 16, 126, 248, 12, 50, 42, 95, 86,
-    26, 248, 15, 34, 19, 26, 119, 17, 196, 24, 248, 4, 110, 38, 0, 25, 77, 68, 10, 248,
-    10, 102, 229, 51, 245, 51, 205, 65, 53, 225, 248, 13, 123, 34, 114, 248, 14, 203, 46,
-    43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43,
-    203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203,
-    30, 42, 79, 42, 71, 42, 102, 111, 9, 77, 68, 248, 11, 42, 102, 111, 121, 34, 112,
-    248, 11, 42, 95, 86, 33, 2, 0, 25, 229, 125, 248, 17, 119, 225, 124, 248, 16, 50, 42,
-    95, 86, 26, 248, 13, 34, 19, 26, 119, 248, 4, 126, 198, 64, 79, 33, 196, 24, 6, 0, 9,
-    126, 248, 10, 102, 229, 51, 245, 51, 205, 65, 53, 225, 248, 11, 123, 34, 114, 248,
-    12, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35,
-    203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203,
-    46, 43, 203, 30, 42, 79, 42, 71, 42, 95, 42, 87, 123, 145, 95, 122, 152, 75, 71, 42,
-    102, 111, 121, 34, 112, 33, 188, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25,
-    203, 56, 203, 25, 203, 56, 203, 25, 250, 193, 192, 248, 15, 34, 7, 159, 119, 248, 10,
-    121, 34, 112, 248, 15, 58, 43, 119, 248, 16, 58, 43, 50, 42, 95, 86, 248, 10, 42,
-    102, 111, 25, 229, 125, 248, 17, 119, 225, 124, 248, 16, 50, 42, 79, 70, 203, 56,
-    203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 113, 250, 194, 192, 248, 15, 119, 58,
-    34, 7, 159, 50, 58, 43, 119, 248, 15, 58, 43, 50, 42, 95, 86, 248, 10, 42, 102, 111,
-    25, 229, 125, 248, 16, 119, 225, 124, 248, 15, 50, 42, 79, 58, 71, 203, 56, 203, 25,
-    203, 56, 203, 25, 203, 56, 203, 25, 121, 60, 119, 248, 6, 42, 95, 86, 26, 79, 19, 26,
-    71, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 248, 12,
-    121, 34, 112, 248, 4, 126, 214, 128, 210, 186, 124, 250, 192, 192, 79, 7, 159, 71,
-    248, 12, 42, 102, 111, 9, 77, 68, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203,
-    25, 248, 15, 113, 248, 14, 42, 35, 150, 202, 113, 124, 248, 15, 126, 33, 50, 197,
-    150, 48, 63, 248, 16, 126, 33, 51, 197, 150, 48, 54, 33, 47, 197, 70, 33, 50, 197,
-    94, 22, 0, 248, 16, 126, 14, 0, 197, 213, 95, 81, 213, 205, 105, 53, 232, 4, 193,
-    123, 33, 48, 197, 134, 35, 95, 122, 142, 87, 248, 15, 110, 38, 0, 25, 93, 84, 197,
-    51, 213, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 83, 40, 46, 248, 15, 126, 14, 0,
-    135, 203, 17, 135, 203, 17, 135, 203, 17, 33, 192, 192, 110, 245, 125, 7, 159, 103,
-    241, 149, 111, 121, 156, 103, 41, 41, 41, 41, 77, 68, 11, 248, 6, 42, 102, 111, 121,
-    34, 112, 24, 6, 248, 16, 52, 195, 231, 123, 1, 186, 192, 33, 52, 197, 42, 198, 240,
-    95, 126, 206, 255, 87, 203, 35, 203, 18, 203, 35, 203, 18, 203, 35, 203, 18, 203, 35,
-    203, 18, 248, 13, 123, 34, 114, 248, 6, 42, 95, 86, 26, 248, 15, 34, 19, 26, 119,
-    248, 13, 42, 95, 42, 87, 123, 150, 35, 122, 158, 56, 5, 248, 15, 42, 95, 86, 123, 2,
-    3, 122, 2, 195, 114, 125, 250, 191, 192, 79, 7, 159, 71, 248, 12, 42, 102, 111, 9,
-    77, 68, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 248, 16, 70, 248, 14,
-    126, 144, 40, 115, 121, 33, 50, 197, 150, 48, 60, 120, 33, 51, 197, 150, 48, 53, 250,
-    47, 197, 248, 16, 119, 33, 50, 197, 94, 22, 0, 104, 38, 0, 197, 213, 229, 205, 105,
-    53, 232, 4, 193, 123, 33, 48, 197, 134, 35, 95, 122, 142, 87, 105, 38, 0, 25, 93, 84,
-    248, 16, 126, 245, 51, 213, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203, 91, 40, 39, 105,
-    38, 0, 35, 41, 41, 41, 77, 68, 250, 191, 192, 95, 7, 159, 87, 121, 147, 111, 120,
-    154, 103, 41, 41, 41, 41, 35, 77, 68, 248, 6, 42, 102, 111, 121, 34, 112, 24, 3, 4,
-    24, 135, 248, 6, 42, 95, 86, 26, 79, 19, 26, 248, 15, 113, 35, 119, 203, 126, 40, 4,
-    43, 175, 34, 119, 248, 15, 42, 79, 70, 33, 186, 192, 121, 34, 112, 33, 186, 192, 42,
-    79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250,
-    191, 192, 111, 7, 159, 103, 9, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58,
-    203, 27, 248, 16, 115, 250, 192, 192, 111, 7, 159, 103, 9, 203, 60, 203, 29, 203, 60,
-    203, 29, 203, 60, 203, 29, 125, 60, 248, 15, 119, 248, 8, 42, 95, 86, 26, 79, 19, 26,
-    71, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 62, 64,
-    248, 4, 150, 210, 151, 126, 126, 214, 192, 210, 151, 126, 250, 194, 192, 111, 7, 159,
-    103, 9, 77, 68, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 248, 14, 113,
-    248, 15, 42, 150, 202, 132, 126, 248, 16, 126, 33, 50, 197, 150, 48, 64, 248, 14,
-    126, 33, 51, 197, 150, 48, 55, 33, 47, 197, 70, 33, 50, 197, 94, 22, 0, 248, 14, 126,
-    14, 0, 197, 213, 95, 81, 213, 205, 105, 53, 232, 4, 193, 123, 33, 48, 197, 134, 35,
-    95, 122, 142, 87, 248, 16, 110, 38, 0, 25, 93, 84, 197, 51, 213, 205, 7, 19, 232, 3,
-    123, 24, 2, 62, 15, 15, 48, 46, 248, 14, 126, 14, 0, 135, 203, 17, 135, 203, 17, 135,
-    203, 17, 33, 194, 192, 110, 245, 125, 7, 159, 103, 241, 149, 111, 121, 156, 103, 41,
-    41, 41, 41, 77, 68, 11, 248, 8, 42, 102, 111, 121, 34, 112, 24, 6, 248, 16, 52, 195,
-    251, 125, 248, 8, 42, 95, 86, 26, 79, 19, 26, 71, 33, 188, 192, 121, 34, 112, 195,
-    54, 127, 250, 193, 192, 111, 7, 159, 103, 9, 203, 60, 203, 29, 203, 60, 203, 29, 203,
-    60, 203, 29, 77, 248, 15, 42, 150, 40, 116, 248, 16, 126, 33, 50, 197, 150, 48, 57,
-    121, 33, 51, 197, 150, 48, 50, 33, 47, 197, 70, 33, 50, 197, 94, 22, 0, 105, 38, 0,
-    197, 213, 229, 205, 105, 53, 232, 4, 193, 123, 33, 48, 197, 134, 35, 95, 122, 142,
-    87, 248, 16, 110, 38, 0, 25, 93, 84, 197, 51, 213, 205, 7, 19, 232, 3, 24, 2, 30, 15,
-    203, 75, 40, 39, 12, 105, 38, 0, 41, 41, 41, 77, 68, 250, 193, 192, 95, 7, 159, 87,
-    121, 147, 111, 120, 154, 103, 41, 41, 41, 41, 35, 77, 68, 248, 8, 42, 102, 111, 121,
-    34, 112, 24, 5, 248, 16, 52, 24, 134, 248, 8, 42, 95, 86, 26, 79, 19, 26, 71, 33,
-    188, 192, 121, 34, 112, 248, 5, 126, 214, 4, 40, 26, 250, 7, 197, 245, 51, 248, 6,
-    126, 245, 51, 17, 185, 192, 213, 30, 2, 33, 101, 70, 205, 8, 0, 232, 4, 24, 13, 17,
-    185, 192, 213, 30, 2, 33, 6, 70, 205, 8, 0, 225, 17, 0, 0, 250, 31, 218, 230, 1, 71,
-    14, 0, 120, 61, 177, 32, 55, 175, 245, 51, 17, 186, 192, 213, 17, 191, 192, 213, 30,
-    4, 33, 178, 94, 205, 8, 0, 232, 5, 123, 183, 32, 110, 175, 245, 51, 30, 2, 33, 135,
-    72, 205, 8, 0, 51, 122, 179, 40, 14, 33, 47, 0, 25, 126, 183, 40, 6, 33, 9, 197, 123,
-    34, 114, 250, 27, 198, 203, 103, 40, 73, 250, 28, 198, 203, 103, 32, 66, 122, 179,
-    32, 13, 33, 8, 1, 229, 30, 2, 33, 160, 71, 205, 8, 0, 225, 122, 179, 40, 45, 33, 47,
-    0, 25, 126, 183, 32, 37, 33, 37, 0, 25, 126, 183, 40, 29, 35, 78, 35, 70, 17, 0, 0,
-    213, 38, 1, 229, 51, 17, 0, 0, 213, 197, 245, 51, 30, 3, 33, 144, 93, 205, 8, 0, 232,
-    8, 232, 17, 201, 255, 255, 37, 10, 89, 103, 5, 97, 87, 1, 39, 0, 1, 30, 5, 33, 138,
-    68, 205, 8, 0, 30, 5, 33, 240, 66, 205, 8, 0, 30, 3, 33, 0, 64, 205, 8, 0, 30, 5, 33,
-    55, 67, 205, 8, 0, 30, 5, 33, 41, 68, 205, 8, 0, 30, 4, 33, 185, 83, 205, 8, 0, 30,
-    5, 33, 244, 64, 205, 8, 0, 30, 5, 33, 0, 64, 205, 8, 0, 30, 2, 33, 0, 64, 205, 8, 0,
-    30, 1, 33, 130, 64, 205, 8, 0, 175, 245, 51, 30, 3, 33, 230, 75, 205, 8, 0, 51, 175,
-    245, 51, 30, 3, 33, 38, 76, 205, 8, 0, 51, 175, 245, 51, 30, 5, 33, 142, 67, 205, 8,
-    0, 51, 201, 232, 251, 205, 7, 51, 123, 183, 40, 15, 254, 1, 40, 11, 254, 2, 40, 241,
-    214, 3, 202, 65, 65, 24, 234, 205, 221, 22, 250, 27, 198, 214, 240, 32, 41, 62, 1,
-    245, 51, 30, 3, 33, 212, 92, 205, 8, 0, 51, 1, 0, 64, 62, 4, 38, 0, 229, 51, 17, 0,
-    0, 213, 197, 245, 51, 30, 3, 33, 144, 93, 205, 8, 0, 232, 6, 24, 183, 33, 144, 203,
-    126, 214, 1, 62, 0, 23, 238, 1, 32, 25, 250, 27, 198, 183, 40, 3, 205, 131, 21, 205,
-    128, 2, 250, 31, 218, 230, 15, 32, 3, 205, 85, 22, 205, 252, 25, 14, 192, 240, 146,
-    145, 32, 7, 33, 53, 218, 54, 223, 24, 5, 33, 53, 218, 54, 192, 33, 14, 197, 54, 0,
-    205, 241, 19, 30, 4, 33, 3, 84, 205, 8, 0, 205, 115, 14, 205, 97, 27, 205, 224, 36,
-    30, 2, 33, 63, 77, 205, 8, 0, 33, 31, 218, 52, 62, 40, 245, 51, 250, 14, 197, 245,
-    51, 205, 228, 12, 225, 250, 53, 218, 224, 146, 205, 53, 14, 195, 130, 64, 250, 146,
-    203, 61, 40, 26, 250, 146, 203, 214, 2, 40, 88, 250, 146, 203, 214, 3, 202, 33, 66,
-    250, 146, 203, 214, 4, 202, 65, 66, 195, 130, 64, 30, 5, 33, 22, 67, 205, 8, 0, 205,
-    164, 5, 175, 245, 51, 30, 3, 33, 212, 92, 205, 8, 0, 51, 33, 156, 5, 70, 33, 157, 5,
-    42, 79, 126, 38, 1, 104, 229, 71, 197, 30, 3, 33, 172, 66, 205, 8, 0, 232, 4, 123,
-    214, 1, 62, 0, 23, 248, 4, 119, 30, 3, 33, 134, 75, 205, 8, 0, 195, 147, 66, 30, 5,
-    33, 22, 67, 205, 8, 0, 175, 245, 51, 30, 3, 33, 212, 92, 205, 8, 0, 51, 175, 245, 51,
-    30, 3, 33, 38, 76, 205, 8, 0, 51, 175, 245, 51, 30, 3, 33, 230, 75, 205, 8, 0, 51,
-    175, 245, 51, 30, 5, 33, 142, 67, 205, 8, 0, 51, 33, 149, 203, 42, 95, 86, 248, 0,
-    77, 68, 248, 3, 121, 34, 112, 250, 148, 203, 245, 51, 213, 248, 6, 42, 95, 86, 213,
-    205, 224, 18, 232, 5, 10, 105, 96, 35, 78, 35, 70, 38, 1, 229, 51, 245, 51, 197, 30,
-    3, 33, 172, 66, 205, 8, 0, 232, 4, 123, 214, 1, 62, 0, 23, 248, 4, 119, 24, 114, 250,
-    148, 203, 33, 149, 203, 78, 35, 70, 245, 51, 197, 205, 7, 19, 232, 3, 123, 245, 51,
-    30, 4, 33, 156, 76, 205, 8, 0, 51, 195, 130, 64, 30, 5, 33, 208, 65, 205, 8, 0, 30,
-    5, 33, 22, 67, 205, 8, 0, 250, 148, 203, 33, 149, 203, 78, 35, 70, 245, 51, 197, 205,
-    7, 19, 232, 3, 123, 245, 51, 30, 4, 33, 130, 77, 205, 8, 0, 51, 33, 145, 203, 115,
-    33, 38, 197, 78, 33, 39, 197, 42, 102, 111, 175, 71, 197, 229, 30, 3, 33, 172, 66,
-    205, 8, 0, 232, 4, 123, 214, 1, 62, 0, 23, 248, 4, 119, 243, 250, 61, 197, 61, 40, 9,
-    250, 61, 197, 214, 2, 40, 12, 24, 20, 17, 36, 27, 213, 205, 121, 54, 225, 24, 18, 17,
-    248, 23, 213, 205, 121, 54, 225, 24, 8, 17, 124, 23, 213, 205, 121, 54, 225, 175,
-    224, 69, 251, 250, 46, 218, 183, 32, 6, 240, 64, 246, 2, 224, 64, 30, 2, 33, 85, 64,
-    205, 8, 0, 205, 123, 2, 14, 192, 240, 146, 145, 32, 7, 33, 53, 218, 54, 223, 24, 5,
-    33, 53, 218, 54, 192, 33, 14, 197, 54, 0, 205, 241, 19, 30, 4, 33, 200, 89, 205, 8,
-    0, 205, 115, 14, 62, 40, 245, 51, 250, 14, 197, 245, 51, 205, 228, 12, 225, 250, 53,
-    218, 224, 146, 248, 4, 126, 183, 202, 130, 64, 30, 5, 33, 182, 65, 205, 8, 0, 195,
-    130, 64, 232, 5, 201, 33, 31, 200, 54, 0, 250, 160, 192, 214, 17, 32, 6, 250, 67, 1,
-    7, 56, 3, 175, 24, 2, 62, 1, 234, 30, 200, 250, 161, 192, 183, 40, 6, 250, 30, 200,
-    183, 32, 3, 175, 24, 2, 62, 1, 234, 161, 192, 175, 167, 245, 17, 0, 0, 213, 17, 0,
-    223, 213, 205, 176, 55, 232, 6, 30, 4, 33, 149, 75, 205, 8, 0, 205, 69, 14, 30, 5,
-    33, 25, 68, 205, 8, 0, 62, 103, 224, 64, 62, 7, 224, 75, 62, 144, 224, 74, 240, 4, 6,
-    0, 79, 197, 205, 175, 52, 225, 205, 164, 5, 62, 1, 245, 51, 30, 3, 33, 212, 92, 205,
-    8, 0, 51, 243, 33, 157, 198, 54, 139, 35, 54, 198, 175, 224, 69, 17, 36, 24, 213,
-    205, 104, 14, 225, 240, 65, 246, 64, 224, 65, 33, 160, 192, 126, 214, 17, 32, 12, 33,
-    67, 1, 126, 7, 48, 5, 1, 128, 0, 24, 3, 1, 192, 0, 121, 224, 6, 62, 7, 224, 7, 240,
-    255, 246, 14, 224, 255, 251, 240, 64, 246, 128, 224, 64, 62, 4, 38, 0, 229, 51, 17,
-    0, 0, 213, 17, 0, 64, 213, 245, 51, 30, 3, 33, 144, 93, 205, 8, 0, 232, 6, 195, 128,
-    64, 234, 35, 197, 240, 255, 234, 36, 197, 240, 64, 234, 37, 197, 62, 128, 224, 64,
-    62, 1, 234, 255, 0, 62, 0, 234, 15, 0, 118, 0, 62, 0, 224, 64, 8, 158, 157, 49, 158,
-    157, 229, 213, 197, 250, 35, 197, 245, 240, 79, 95, 203, 71, 40, 17, 33, 150, 157,
-    14, 10, 70, 175, 224, 79, 112, 44, 60, 224, 79, 13, 32, 244, 175, 224, 38, 224, 79,
-    123, 234, 187, 157, 62, 1, 224, 79, 33, 203, 157, 6, 18, 175, 14, 21, 239, 125, 198,
-    11, 111, 5, 32, 245, 175, 224, 79, 62, 3, 224, 71, 62, 128, 224, 104, 175, 14, 105,
-    226, 226, 61, 226, 226, 226, 226, 226, 226, 62, 112, 224, 66, 62, 92, 224, 67, 205,
-    137, 69, 33, 183, 157, 17, 156, 157, 14, 4, 247, 33, 203, 157, 17, 234, 70, 6, 3, 62,
-    32, 34, 14, 19, 247, 62, 32, 34, 125, 198, 11, 111, 5, 32, 240, 62, 32, 14, 21, 239,
-    46, 107, 14, 4, 247, 193, 205, 58, 69, 14, 8, 247, 250, 160, 192, 205, 62, 69, 62,
-    32, 34, 34, 34, 46, 139, 14, 4, 247, 193, 205, 58, 69, 14, 6, 247, 193, 205, 58, 69,
-    62, 32, 34, 34, 34, 125, 198, 11, 111, 14, 4, 247, 193, 197, 205, 58, 69, 17, 61, 71,
-    14, 7, 247, 209, 205, 90, 69, 17, 68, 71, 203, 125, 40, 227, 17, 72, 71, 46, 171, 14,
-    6, 247, 250, 37, 197, 205, 62, 69, 14, 4, 247, 240, 77, 205, 62, 69, 14, 4, 247, 250,
-    36, 197, 205, 62, 69, 54, 32, 46, 203, 14, 7, 247, 62, 32, 34, 26, 19, 34, 254, 32,
-    40, 12, 26, 19, 79, 26, 19, 71, 10, 205, 62, 69, 24, 234, 62, 137, 224, 64, 175, 224,
-    15, 118, 24, 250, 205, 63, 69, 121, 71, 120, 230, 240, 203, 55, 198, 48, 254, 58, 56,
-    2, 198, 7, 34, 120, 230, 15, 198, 48, 254, 58, 56, 2, 198, 7, 34, 201, 66, 75, 205,
-    58, 69, 62, 32, 34, 34, 123, 214, 8, 95, 122, 222, 0, 87, 125, 198, 11, 111, 62, 32,
-    34, 26, 19, 205, 62, 69, 26, 19, 205, 62, 69, 62, 32, 34, 203, 101, 32, 239, 125,
-    230, 127, 32, 227, 201, 33, 176, 69, 42, 95, 42, 87, 179, 200, 78, 35, 203, 121, 40,
-    10, 42, 18, 19, 18, 19, 12, 32, 249, 24, 240, 12, 13, 40, 230, 42, 18, 19, 18, 19,
-    13, 32, 248, 24, 226, 0, 144, 248, 255, 0, 0, 146, 248, 255, 0, 208, 146, 8, 255,
-    255, 255, 255, 195, 255, 255, 255, 0, 0, 147, 80, 255, 207, 167, 51, 51, 51, 151,
-    207, 255, 207, 143, 15, 207, 207, 207, 3, 255, 135, 51, 231, 207, 159, 63, 3, 255,
-    135, 115, 243, 199, 243, 115, 135, 255, 199, 167, 103, 3, 231, 231, 231, 255, 3, 63,
-    63, 135, 243, 51, 135, 255, 135, 51, 63, 7, 51, 51, 135, 255, 3, 243, 243, 231, 231,
-    207, 207, 255, 135, 51, 51, 135, 51, 51, 135, 255, 135, 51, 51, 131, 243, 51, 135, 0,
-    160, 147, 8, 255, 255, 159, 159, 255, 159, 159, 255, 0, 16, 148, 66, 255, 207, 183,
-    123, 123, 3, 123, 123, 255, 7, 123, 123, 7, 123, 123, 7, 255, 195, 191, 127, 127,
-    127, 191, 195, 255, 15, 119, 123, 123, 123, 119, 15, 255, 3, 127, 127, 3, 127, 127,
-    3, 255, 3, 127, 127, 3, 127, 127, 127, 255, 131, 127, 127, 67, 123, 123, 135, 255,
-    123, 123, 123, 3, 123, 123, 123, 255, 131, 251, 239, 2, 131, 255, 250, 251, 10, 7,
-    255, 123, 119, 111, 95, 31, 111, 115, 255, 250, 127, 19, 3, 255, 123, 51, 75, 123,
-    123, 123, 123, 255, 123, 59, 91, 107, 115, 123, 123, 255, 135, 251, 123, 35, 135,
-    255, 7, 123, 123, 7, 127, 127, 127, 255, 135, 123, 123, 123, 91, 103, 147, 255, 7,
-    123, 123, 7, 111, 119, 123, 255, 131, 127, 127, 135, 251, 123, 135, 255, 1, 250, 239,
-    1, 255, 250, 123, 2, 135, 255, 251, 123, 34, 183, 207, 255, 123, 123, 123, 123, 75,
-    51, 123, 255, 123, 123, 183, 207, 183, 123, 123, 255, 187, 187, 187, 215, 239, 239,
-    239, 255, 3, 251, 247, 239, 223, 191, 3, 0, 0, 0, 75, 69, 82, 78, 69, 76, 32, 80, 65,
-    78, 73, 67, 32, 80, 76, 69, 65, 83, 69, 83, 69, 78, 68, 32, 65, 32, 67, 76, 69, 65,
-    82, 32, 80, 73, 67, 32, 79, 70, 84, 72, 73, 83, 32, 83, 67, 82, 69, 69, 78, 32, 84,
-    79, 32, 68, 69, 86, 83, 32, 65, 70, 58, 32, 32, 77, 79, 68, 69, 76, 58, 32, 66, 67,
-    58, 32, 32, 32, 68, 69, 58, 32, 72, 76, 58, 32, 32, 86, 73, 69, 87, 58, 32, 83, 80,
-    58, 32, 76, 67, 68, 67, 58, 32, 75, 49, 58, 32, 73, 69, 58, 32, 32, 66, 65, 78, 75,
-    58, 82, 144, 255, 86, 187, 157, 87, 112, 255, 32, 248, 6, 78, 33, 32, 200, 126, 230,
-    240, 71, 121, 230, 15, 176, 119, 33, 0, 64, 119, 30, 4, 105, 203, 61, 25, 121, 230,
-    1, 203, 55, 7, 230, 224, 71, 14, 0, 120, 198, 64, 71, 125, 245, 51, 17, 0, 32, 213,
-    197, 17, 0, 160, 213, 205, 35, 19, 232, 7, 201, 6, 0, 120, 214, 4, 208, 197, 197, 51,
-    30, 4, 33, 103, 71, 205, 8, 0, 51, 193, 4, 24, 236, 33, 32, 200, 78, 197, 30, 5, 33,
-    218, 66, 205, 8, 0, 123, 193, 183, 32, 2, 95, 201, 197, 248, 8, 126, 245, 51, 30, 5,
-    33, 82, 66, 205, 8, 0, 51, 193, 67, 33, 32, 200, 126, 113, 33, 0, 64, 113, 88, 201,
-    248, 2, 126, 224, 1, 62, 129, 224, 2, 240, 2, 7, 56, 251, 240, 1, 95, 201, 136, 51,
-    1, 0, 0, 0, 1, 0, 0, 0, 136, 51, 15, 0, 0, 0, 15, 0, 0, 0, 136, 51, 4, 0, 0, 0, 4, 0,
-    0, 0, 136, 51, 8, 0, 0, 0, 1, 0, 0, 0, 33, 17, 198, 70, 14, 0, 197, 248, 4, 126, 245,
-    51, 205, 231, 71, 51, 123, 193, 22, 0, 177, 95, 122, 176, 33, 17, 198, 115, 35, 119,
-    201, 248, 2, 42, 79, 70, 30, 0, 123, 248, 4, 150, 48, 15, 28, 10, 3, 197, 213, 245,
-    51, 205, 33, 72, 51, 209, 193, 24, 235, 33, 18, 198, 126, 214, 129, 32, 3, 43, 94,
-    201, 17, 240, 0, 201, 59, 250, 19, 198, 183, 32, 32, 1, 255, 72, 30, 6, 123, 183, 40,
-    15, 10, 3, 197, 213, 245, 51, 205, 231, 71, 51, 209, 193, 29, 24, 237, 33, 20, 198,
-    62, 134, 34, 175, 119, 248, 0, 54, 16, 248, 3, 42, 95, 86, 248, 0, 126, 183, 40, 34,
-    26, 79, 6, 0, 33, 20, 198, 42, 102, 111, 9, 125, 234, 20, 198, 124, 234, 21, 198, 26,
-    213, 245, 51, 205, 231, 71, 51, 209, 248, 0, 53, 19, 24, 216, 33, 19, 198, 52, 126,
-    214, 40, 32, 47, 250, 20, 198, 245, 51, 205, 231, 71, 51, 250, 21, 198, 245, 51, 205,
-    231, 71, 51, 175, 245, 51, 205, 231, 71, 51, 175, 245, 51, 205, 231, 71, 51, 33, 19,
-    198, 54, 0, 175, 33, 20, 198, 34, 119, 30, 1, 24, 2, 30, 0, 51, 201, 136, 51, 4, 0,
-    128, 2, 248, 2, 42, 79, 70, 197, 62, 10, 245, 51, 17, 3, 72, 213, 205, 64, 72, 232,
-    3, 123, 193, 95, 248, 4, 166, 35, 87, 126, 146, 200, 121, 80, 11, 178, 32, 3, 30,
-    240, 201, 123, 230, 240, 192, 205, 53, 14, 24, 213, 33, 19, 198, 54, 0, 62, 10, 245,
-    51, 17, 249, 71, 213, 205, 64, 72, 232, 3, 248, 6, 78, 6, 0, 33, 255, 0, 229, 197,
-    205, 5, 73, 232, 4, 201, 232, 225, 248, 24, 54, 0, 248, 38, 126, 203, 63, 248, 30,
-    119, 248, 23, 119, 248, 30, 126, 183, 32, 4, 95, 195, 146, 75, 205, 101, 2, 248, 27,
-    123, 34, 114, 248, 37, 126, 248, 29, 34, 54, 0, 62, 5, 248, 29, 203, 38, 35, 203, 22,
-    61, 32, 246, 43, 42, 95, 86, 248, 27, 42, 102, 111, 25, 229, 125, 248, 27, 119, 225,
-    124, 248, 26, 119, 33, 19, 198, 54, 0, 248, 23, 126, 135, 248, 27, 119, 248, 27, 126,
-    183, 202, 2, 75, 248, 0, 125, 84, 248, 16, 34, 122, 50, 42, 35, 50, 42, 35, 119, 248,
-    28, 54, 0, 248, 25, 126, 248, 29, 119, 248, 26, 126, 248, 30, 119, 248, 28, 126, 214,
-    20, 202, 233, 74, 248, 29, 42, 95, 86, 213, 205, 168, 12, 225, 248, 23, 115, 58, 43,
-    119, 248, 16, 126, 248, 22, 119, 248, 17, 126, 248, 23, 50, 42, 95, 58, 43, 87, 213,
-    62, 1, 245, 51, 126, 245, 51, 205, 186, 52, 232, 4, 248, 18, 42, 79, 70, 197, 205,
-    107, 72, 225, 123, 183, 40, 3, 248, 24, 52, 248, 24, 126, 214, 9, 194, 220, 74, 248,
-    24, 54, 0, 62, 10, 245, 51, 17, 13, 72, 213, 205, 64, 72, 232, 3, 248, 38, 126, 248,
-    22, 34, 175, 50, 42, 95, 86, 33, 1, 0, 123, 149, 95, 122, 156, 248, 21, 50, 115, 248,
-    27, 126, 248, 22, 34, 54, 0, 248, 20, 42, 35, 150, 32, 11, 43, 42, 35, 150, 32, 5,
-    248, 39, 78, 24, 3, 1, 0, 0, 33, 39, 218, 113, 6, 0, 33, 40, 218, 54, 228, 33, 41,
-    218, 54, 127, 33, 106, 1, 9, 77, 68, 33, 42, 218, 121, 34, 112, 62, 14, 245, 51, 17,
-    32, 218, 213, 205, 64, 72, 232, 3, 33, 2, 2, 229, 17, 120, 0, 213, 205, 5, 73, 232,
-    4, 123, 230, 240, 194, 146, 75, 33, 2, 0, 229, 17, 176, 4, 213, 205, 5, 73, 232, 4,
-    123, 230, 240, 194, 146, 75, 62, 10, 245, 51, 17, 249, 71, 213, 205, 64, 72, 232, 3,
-    33, 255, 0, 229, 17, 10, 0, 213, 205, 5, 73, 232, 4, 123, 248, 23, 119, 183, 40, 4,
-    94, 195, 146, 75, 248, 28, 52, 35, 52, 194, 212, 73, 35, 52, 195, 212, 73, 248, 27,
-    53, 43, 43, 42, 95, 86, 33, 32, 0, 25, 229, 125, 248, 27, 119, 225, 124, 248, 26,
-    119, 195, 174, 73, 248, 24, 126, 183, 40, 122, 62, 10, 245, 51, 17, 13, 72, 213, 205,
-    64, 72, 232, 3, 248, 39, 126, 248, 30, 119, 17, 39, 218, 126, 18, 58, 34, 175, 50,
-    58, 43, 119, 248, 30, 58, 43, 119, 33, 40, 218, 54, 228, 33, 41, 218, 54, 127, 248,
-    27, 42, 95, 86, 33, 106, 1, 25, 229, 125, 248, 31, 119, 225, 124, 248, 30, 50, 17,
-    42, 218, 42, 18, 19, 126, 18, 62, 14, 245, 51, 17, 32, 218, 213, 205, 64, 72, 232, 3,
-    33, 2, 2, 229, 17, 120, 0, 213, 205, 5, 73, 232, 4, 123, 230, 240, 32, 34, 33, 2, 0,
-    229, 17, 176, 4, 213, 205, 5, 73, 232, 4, 123, 230, 240, 32, 16, 62, 10, 245, 51, 17,
-    3, 72, 213, 205, 64, 72, 232, 3, 248, 30, 115, 232, 31, 201, 59, 59, 33, 0, 0, 54,
-    10, 33, 32, 200, 126, 230, 240, 119, 234, 0, 64, 33, 33, 198, 62, 4, 34, 175, 119, 1,
-    224, 75, 105, 96, 35, 58, 110, 181, 40, 36, 105, 96, 35, 35, 93, 84, 26, 248, 0, 34,
-    19, 26, 119, 33, 33, 198, 42, 95, 86, 225, 229, 25, 93, 84, 33, 33, 198, 123, 34,
-    114, 3, 3, 3, 3, 24, 212, 51, 51, 201, 152, 203, 0, 14, 88, 202, 48, 1, 136, 203, 2,
-    0, 138, 203, 2, 0, 140, 203, 2, 0, 142, 203, 2, 0, 144, 203, 1, 0, 192, 197, 40, 0,
-    232, 197, 8, 0, 240, 197, 20, 0, 4, 198, 8, 0, 61, 198, 1, 0, 66, 198, 2, 0, 41, 198,
-    20, 0, 38, 197, 3, 0, 185, 192, 68, 4, 253, 196, 2, 0, 1, 197, 2, 0, 7, 197, 1, 0, 9,
-    197, 2, 0, 191, 217, 2, 0, 0, 0, 0, 0, 232, 251, 1, 0, 0, 175, 248, 0, 34, 119, 17,
-    0, 0, 248, 4, 54, 0, 248, 4, 126, 248, 7, 150, 48, 57, 121, 33, 33, 198, 134, 35, 79,
-    120, 142, 71, 58, 110, 103, 9, 229, 125, 248, 4, 119, 225, 124, 248, 3, 50, 175, 150,
-    35, 62, 32, 158, 48, 20, 19, 51, 51, 213, 123, 214, 3, 122, 222, 0, 56, 5, 17, 0, 0,
-    24, 23, 1, 0, 0, 248, 4, 52, 24, 191, 248, 8, 42, 95, 86, 248, 0, 126, 18, 33, 0,
-    160, 9, 93, 84, 232, 5, 201, 232, 243, 248, 0, 229, 248, 21, 126, 245, 51, 205, 56,
-    76, 232, 3, 248, 11, 123, 34, 114, 58, 182, 202, 127, 77, 248, 0, 78, 33, 32, 200,
-    126, 230, 240, 71, 121, 230, 15, 176, 119, 234, 0, 64, 248, 11, 42, 79, 70, 33, 147,
-    5, 84, 93, 248, 7, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26, 119, 248, 7, 42, 2, 3, 42,
-    2, 3, 42, 2, 3, 42, 2, 42, 95, 86, 33, 4, 0, 25, 229, 125, 248, 11, 119, 225, 124,
-    248, 10, 34, 54, 224, 35, 54, 75, 248, 11, 42, 95, 86, 26, 248, 1, 34, 19, 26, 119,
-    58, 182, 40, 110, 248, 11, 42, 95, 86, 33, 2, 0, 25, 229, 125, 248, 5, 119, 225, 124,
-    248, 4, 50, 42, 95, 42, 87, 26, 34, 19, 26, 119, 248, 9, 58, 43, 119, 248, 10, 58,
-    43, 119, 248, 5, 42, 95, 86, 213, 248, 3, 42, 95, 86, 213, 248, 11, 42, 95, 86, 213,
-    205, 109, 55, 232, 6, 248, 3, 42, 95, 86, 26, 248, 7, 34, 19, 26, 50, 42, 95, 42, 87,
-    42, 102, 111, 25, 229, 125, 248, 11, 119, 225, 124, 248, 10, 34, 42, 95, 86, 33, 4,
-    0, 25, 229, 125, 248, 13, 119, 225, 124, 248, 12, 119, 24, 130, 232, 13, 201, 232,
-    245, 248, 4, 229, 248, 19, 126, 245, 51, 205, 56, 76, 232, 3, 75, 66, 120, 177, 32,
-    4, 95, 195, 142, 78, 248, 4, 86, 33, 32, 200, 126, 230, 240, 95, 122, 230, 15, 179,
-    119, 234, 0, 64, 105, 96, 93, 84, 26, 248, 0, 34, 19, 26, 34, 19, 26, 34, 19, 26,
-    119, 33, 147, 5, 84, 93, 248, 7, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26, 119, 248, 0,
-    126, 248, 7, 150, 32, 24, 248, 1, 126, 248, 8, 150, 32, 16, 248, 2, 126, 248, 9, 150,
-    32, 8, 248, 3, 126, 248, 10, 150, 40, 5, 30, 0, 195, 142, 78, 3, 3, 3, 3, 248, 9, 54,
-    224, 35, 54, 75, 248, 9, 42, 95, 86, 26, 248, 5, 34, 19, 26, 119, 58, 182, 40, 76,
-    248, 9, 42, 95, 86, 33, 2, 0, 25, 229, 125, 248, 9, 119, 225, 124, 248, 8, 50, 42,
-    95, 86, 26, 111, 19, 26, 103, 89, 80, 229, 213, 248, 9, 42, 95, 86, 213, 205, 109,
-    55, 232, 6, 248, 7, 42, 95, 86, 26, 111, 19, 26, 103, 9, 77, 68, 248, 9, 42, 95, 86,
-    33, 4, 0, 25, 229, 125, 248, 11, 119, 225, 124, 248, 10, 119, 24, 164, 250, 61, 198,
-    60, 40, 14, 250, 66, 198, 234, 64, 198, 250, 67, 198, 234, 65, 198, 24, 28, 175, 224,
-    33, 175, 224, 28, 175, 224, 23, 175, 224, 18, 62, 192, 224, 35, 62, 192, 224, 25, 62,
-    192, 224, 20, 62, 255, 224, 37, 30, 1, 232, 11, 201, 59, 248, 0, 229, 248, 9, 126,
-    245, 51, 205, 56, 76, 232, 3, 75, 122, 71, 179, 40, 27, 248, 0, 94, 250, 32, 200,
-    230, 240, 111, 123, 230, 15, 181, 234, 32, 200, 234, 0, 64, 175, 2, 3, 2, 3, 2, 3, 2,
-    51, 201, 232, 247, 248, 0, 229, 248, 17, 126, 245, 51, 205, 56, 76, 232, 3, 75, 66,
-    120, 177, 32, 4, 95, 195, 109, 79, 248, 0, 86, 33, 32, 200, 126, 230, 240, 95, 122,
-    230, 15, 179, 119, 234, 0, 64, 105, 96, 93, 84, 26, 248, 1, 34, 19, 26, 34, 19, 26,
-    34, 19, 26, 119, 33, 147, 5, 84, 93, 248, 5, 26, 34, 19, 26, 34, 19, 26, 34, 19, 26,
-    119, 248, 1, 126, 248, 5, 150, 32, 24, 248, 2, 126, 248, 6, 150, 32, 16, 248, 3, 126,
-    248, 7, 150, 32, 8, 248, 4, 126, 248, 8, 150, 40, 4, 30, 0, 24, 57, 248, 19, 58, 182,
-    40, 49, 126, 248, 7, 119, 248, 19, 126, 248, 8, 50, 203, 38, 35, 203, 22, 3, 3, 3, 3,
-    248, 16, 42, 94, 135, 203, 19, 129, 79, 123, 136, 71, 248, 20, 42, 95, 86, 248, 7,
-    42, 102, 111, 229, 197, 213, 205, 109, 55, 232, 6, 30, 1, 232, 9, 201, 59, 175, 33,
-    203, 199, 34, 119, 175, 33, 205, 199, 34, 119, 248, 0, 54, 0, 248, 0, 78, 6, 0, 105,
-    96, 41, 41, 41, 9, 41, 41, 9, 1, 159, 198, 9, 77, 68, 33, 35, 0, 9, 93, 84, 33, 205,
-    199, 42, 18, 19, 58, 18, 121, 34, 112, 248, 0, 52, 126, 214, 5, 32, 211, 51, 201,
-    232, 238, 250, 205, 199, 248, 2, 119, 250, 206, 199, 248, 3, 119, 58, 182, 202, 182,
-    83, 248, 24, 78, 6, 0, 105, 96, 41, 41, 9, 41, 9, 41, 9, 229, 125, 248, 18, 119, 225,
-    124, 248, 17, 119, 17, 88, 199, 58, 110, 103, 25, 77, 68, 248, 2, 42, 95, 86, 33, 12,
-    0, 25, 229, 125, 248, 17, 119, 225, 124, 248, 16, 50, 42, 95, 86, 33, 23, 0, 229,
-    197, 213, 205, 109, 55, 232, 6, 248, 17, 54, 2, 62, 224, 248, 27, 150, 56, 35, 126,
-    214, 160, 56, 6, 248, 17, 54, 3, 24, 24, 62, 96, 248, 27, 150, 48, 6, 248, 17, 54, 0,
-    24, 11, 248, 27, 126, 214, 32, 56, 4, 248, 17, 54, 1, 248, 2, 42, 79, 70, 248, 28,
-    126, 230, 1, 111, 10, 230, 254, 181, 2, 248, 2, 42, 79, 70, 248, 28, 126, 15, 230, 1,
-    7, 230, 2, 111, 10, 230, 253, 181, 2, 248, 2, 42, 95, 86, 33, 9, 0, 25, 93, 84, 248,
-    15, 126, 248, 4, 119, 248, 16, 126, 248, 5, 119, 213, 248, 17, 42, 95, 86, 33, 9, 0,
-    25, 209, 77, 68, 248, 17, 126, 135, 129, 79, 62, 0, 136, 71, 10, 18, 248, 2, 42, 95,
-    86, 33, 10, 0, 25, 93, 84, 10, 18, 248, 2, 42, 95, 86, 33, 11, 0, 25, 93, 84, 3, 10,
-    60, 18, 248, 15, 42, 95, 86, 33, 19, 0, 25, 42, 79, 126, 248, 16, 113, 35, 119, 248,
-    2, 42, 79, 70, 3, 248, 25, 126, 248, 14, 119, 248, 26, 126, 248, 15, 50, 42, 95, 86,
-    26, 248, 12, 34, 19, 26, 50, 89, 80, 42, 18, 19, 126, 18, 248, 6, 121, 34, 120, 34,
-    3, 3, 121, 34, 112, 248, 14, 42, 102, 111, 35, 35, 42, 79, 70, 248, 8, 42, 102, 111,
-    121, 34, 112, 17, 196, 24, 248, 27, 110, 38, 0, 25, 77, 68, 10, 248, 10, 119, 248,
-    27, 126, 198, 64, 79, 33, 196, 24, 6, 0, 9, 126, 248, 11, 119, 248, 10, 78, 121, 7,
-    159, 71, 105, 96, 41, 9, 41, 9, 41, 9, 41, 9, 41, 9, 41, 9, 41, 9, 229, 125, 248, 16,
-    119, 225, 124, 248, 15, 50, 58, 43, 119, 248, 15, 58, 43, 119, 203, 46, 43, 203, 30,
-    35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35,
-    203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 43, 78,
-    121, 7, 159, 71, 105, 96, 41, 9, 41, 9, 41, 9, 41, 9, 41, 9, 41, 9, 41, 9, 229, 125,
-    248, 16, 119, 225, 124, 248, 15, 119, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30,
-    35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35,
-    203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 248, 16, 62, 255, 150, 35, 62, 0,
-    158, 48, 75, 248, 6, 42, 95, 86, 26, 79, 19, 26, 71, 248, 12, 42, 94, 129, 79, 123,
-    136, 71, 248, 6, 42, 102, 111, 121, 34, 112, 248, 8, 42, 95, 86, 26, 79, 19, 26, 71,
-    248, 14, 42, 95, 86, 121, 147, 79, 120, 154, 71, 248, 8, 42, 102, 111, 121, 34, 112,
-    248, 16, 42, 95, 86, 33, 255, 0, 123, 149, 95, 122, 156, 248, 17, 50, 115, 24, 170,
-    248, 17, 58, 182, 202, 232, 82, 248, 6, 42, 95, 86, 26, 248, 14, 34, 19, 26, 34, 42,
-    119, 245, 51, 248, 11, 126, 245, 51, 205, 65, 53, 225, 248, 12, 123, 34, 114, 248,
-    13, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35,
-    203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203,
-    46, 43, 203, 30, 35, 35, 126, 248, 0, 119, 248, 15, 126, 248, 1, 119, 248, 12, 42,
-    79, 70, 225, 229, 9, 77, 68, 248, 6, 42, 102, 111, 121, 34, 112, 248, 8, 42, 95, 86,
-    26, 248, 15, 34, 19, 26, 34, 126, 245, 51, 248, 12, 126, 245, 51, 205, 65, 53, 225,
-    248, 13, 123, 34, 114, 248, 13, 126, 248, 10, 119, 248, 14, 126, 248, 11, 119, 203,
-    46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43,
-    203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203,
-    30, 248, 15, 126, 248, 12, 119, 248, 16, 126, 248, 13, 119, 248, 10, 42, 79, 42, 71,
-    42, 95, 86, 123, 145, 95, 122, 152, 75, 71, 248, 8, 42, 102, 111, 121, 34, 112, 248,
-    4, 42, 95, 86, 33, 18, 0, 25, 77, 68, 10, 248, 16, 119, 248, 27, 126, 248, 17, 119,
-    248, 2, 42, 95, 86, 33, 5, 0, 25, 77, 68, 17, 196, 24, 248, 17, 110, 38, 0, 25, 93,
-    84, 26, 197, 248, 18, 102, 229, 51, 245, 51, 205, 65, 53, 225, 193, 203, 42, 203, 27,
-    203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203,
-    27, 203, 42, 203, 27, 105, 96, 123, 34, 114, 3, 3, 248, 17, 126, 198, 64, 95, 33,
-    196, 24, 22, 0, 25, 126, 197, 248, 18, 102, 229, 51, 245, 51, 205, 65, 53, 225, 193,
-    203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203,
-    27, 203, 42, 203, 27, 203, 42, 203, 27, 123, 2, 3, 122, 2, 33, 206, 199, 58, 182, 40,
-    17, 42, 79, 70, 33, 35, 0, 9, 93, 84, 26, 33, 205, 199, 34, 19, 26, 119, 248, 2, 42,
-    95, 86, 33, 35, 0, 25, 77, 68, 33, 203, 199, 42, 2, 3, 126, 2, 248, 2, 126, 234, 203,
-    199, 248, 3, 126, 234, 204, 199, 232, 18, 201, 175, 33, 216, 199, 34, 119, 175, 33,
-    218, 199, 34, 119, 175, 33, 220, 199, 34, 119, 175, 33, 222, 199, 34, 119, 33, 224,
-    199, 54, 0, 33, 225, 199, 54, 0, 30, 4, 33, 227, 83, 195, 8, 0, 33, 231, 199, 54, 0,
-    33, 228, 199, 54, 0, 33, 212, 199, 62, 255, 34, 54, 127, 33, 214, 199, 62, 255, 34,
-    54, 127, 33, 31, 218, 54, 0, 201, 59, 59, 33, 26, 197, 42, 198, 176, 79, 126, 206,
-    255, 71, 33, 28, 197, 42, 198, 184, 95, 126, 206, 255, 87, 203, 120, 40, 5, 1, 0, 0,
-    24, 24, 51, 51, 197, 213, 17, 220, 199, 248, 2, 26, 19, 150, 35, 26, 158, 209, 48, 6,
-    33, 220, 199, 42, 79, 70, 203, 122, 40, 5, 17, 0, 0, 24, 24, 51, 51, 213, 213, 17,
-    222, 199, 248, 2, 26, 19, 150, 35, 26, 158, 209, 48, 6, 33, 222, 199, 42, 95, 86,
-    250, 212, 199, 234, 236, 199, 250, 213, 199, 33, 237, 199, 119, 203, 46, 43, 203, 30,
-    35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 250, 214, 199, 234, 232, 199,
-    250, 215, 199, 33, 233, 199, 119, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 35,
-    203, 46, 43, 203, 30, 33, 238, 199, 121, 34, 112, 203, 46, 43, 203, 30, 35, 203, 46,
-    43, 203, 30, 35, 203, 46, 43, 203, 30, 33, 234, 199, 123, 34, 114, 203, 46, 43, 203,
-    30, 35, 203, 46, 43, 203, 30, 35, 203, 46, 43, 203, 30, 33, 212, 199, 121, 34, 112,
-    33, 214, 199, 123, 34, 114, 250, 224, 199, 248, 0, 34, 7, 159, 119, 225, 229, 9, 125,
-    234, 216, 199, 124, 234, 217, 199, 250, 225, 199, 79, 7, 159, 71, 121, 131, 33, 218,
-    199, 34, 120, 138, 119, 17, 139, 198, 213, 205, 26, 85, 225, 123, 183, 32, 20, 17,
-    145, 198, 213, 205, 26, 85, 225, 123, 183, 32, 8, 17, 151, 198, 213, 205, 26, 85,
-    225, 51, 51, 201, 232, 243, 248, 15, 126, 248, 11, 119, 248, 16, 126, 248, 12, 50,
-    42, 79, 70, 3, 10, 248, 8, 119, 248, 11, 42, 95, 86, 33, 5, 0, 25, 229, 125, 248, 11,
-    119, 225, 124, 248, 10, 34, 42, 95, 86, 33, 3, 0, 25, 51, 51, 229, 248, 8, 126, 183,
-    202, 150, 86, 248, 11, 42, 79, 70, 3, 3, 10, 79, 214, 127, 32, 7, 175, 248, 5, 34,
-    119, 24, 48, 203, 121, 40, 23, 175, 145, 71, 33, 216, 199, 42, 78, 4, 24, 3, 135,
-    203, 17, 5, 32, 250, 248, 5, 34, 113, 24, 21, 33, 216, 199, 42, 71, 94, 12, 24, 4,
-    203, 43, 203, 24, 13, 32, 249, 248, 5, 120, 34, 115, 248, 5, 78, 248, 9, 42, 102,
-    111, 113, 248, 5, 42, 79, 42, 71, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203,
-    25, 113, 33, 238, 199, 42, 79, 70, 11, 248, 7, 42, 119, 248, 11, 42, 95, 86, 33, 4,
-    0, 25, 229, 125, 248, 11, 119, 225, 124, 248, 10, 119, 33, 236, 199, 126, 145, 32,
-    31, 35, 126, 144, 32, 26, 248, 8, 42, 198, 21, 79, 42, 95, 86, 26, 71, 209, 213, 26,
-    197, 51, 71, 197, 205, 11, 34, 232, 3, 195, 145, 86, 33, 238, 199, 42, 95, 86, 107,
-    98, 35, 229, 125, 248, 13, 119, 225, 124, 248, 12, 119, 250, 236, 199, 248, 11, 150,
-    32, 79, 250, 237, 199, 248, 12, 150, 32, 71, 248, 7, 126, 248, 11, 34, 175, 50, 42,
-    95, 86, 33, 1, 0, 123, 149, 95, 122, 156, 248, 7, 50, 123, 34, 203, 126, 40, 7, 175,
-    248, 11, 34, 119, 24, 12, 248, 8, 126, 61, 248, 12, 119, 58, 34, 7, 159, 119, 248,
-    11, 58, 43, 79, 42, 95, 86, 26, 71, 209, 213, 26, 197, 51, 71, 197, 205, 11, 34, 232,
-    3, 24, 47, 250, 236, 199, 33, 238, 199, 150, 32, 9, 250, 237, 199, 33, 239, 199, 150,
-    40, 29, 248, 9, 42, 95, 86, 26, 71, 209, 213, 26, 197, 51, 245, 51, 17, 0, 0, 213,
-    248, 9, 42, 95, 86, 213, 205, 216, 89, 232, 6, 30, 0, 195, 197, 89, 33, 216, 199, 78,
-    248, 9, 42, 102, 111, 113, 33, 238, 199, 42, 95, 86, 33, 1, 0, 123, 149, 95, 122,
-    156, 248, 11, 50, 115, 250, 238, 199, 248, 12, 119, 33, 234, 199, 42, 95, 86, 33, 1,
-    0, 123, 149, 95, 122, 156, 248, 3, 50, 115, 250, 234, 199, 61, 248, 4, 50, 42, 35, 7,
-    230, 1, 50, 42, 35, 34, 7, 159, 119, 250, 236, 199, 248, 10, 150, 194, 140, 87, 250,
-    237, 199, 248, 11, 150, 194, 140, 87, 248, 12, 126, 198, 21, 248, 8, 34, 209, 213,
-    26, 119, 42, 35, 34, 54, 0, 248, 11, 93, 84, 248, 2, 26, 19, 150, 35, 26, 158, 26,
-    87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 62, 0, 23,
-    248, 10, 119, 183, 40, 12, 248, 2, 126, 248, 11, 119, 248, 3, 126, 248, 12, 119, 248,
-    12, 203, 126, 40, 6, 43, 175, 34, 119, 24, 27, 248, 10, 126, 183, 40, 8, 248, 4, 126,
-    248, 12, 119, 24, 6, 248, 9, 126, 248, 12, 119, 248, 12, 58, 34, 7, 159, 119, 248,
-    11, 78, 248, 5, 126, 183, 40, 5, 17, 0, 0, 24, 4, 248, 6, 42, 95, 67, 197, 121, 245,
-    51, 248, 11, 126, 245, 51, 205, 133, 90, 225, 51, 248, 9, 126, 245, 51, 30, 2, 33,
-    17, 68, 205, 8, 0, 225, 195, 146, 88, 33, 238, 199, 42, 79, 70, 3, 33, 236, 199, 126,
-    145, 194, 84, 88, 35, 126, 144, 194, 84, 88, 248, 11, 203, 126, 40, 4, 14, 0, 24, 6,
-    248, 12, 126, 61, 79, 7, 248, 8, 121, 34, 209, 213, 26, 119, 42, 35, 34, 54, 0, 248,
-    11, 93, 84, 248, 2, 26, 19, 150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32,
-    8, 191, 24, 5, 203, 122, 40, 1, 55, 62, 0, 23, 248, 10, 119, 183, 40, 12, 248, 2,
-    126, 248, 11, 119, 248, 3, 126, 248, 12, 119, 248, 12, 203, 126, 40, 6, 43, 175, 34,
-    119, 24, 21, 248, 10, 126, 183, 40, 5, 248, 4, 94, 24, 3, 248, 9, 94, 123, 248, 11,
-    34, 7, 159, 119, 248, 11, 42, 119, 248, 5, 126, 183, 40, 7, 175, 248, 10, 34, 119,
-    24, 12, 248, 6, 126, 248, 10, 119, 248, 7, 126, 248, 11, 119, 248, 10, 42, 34, 126,
-    245, 51, 248, 9, 126, 245, 51, 205, 133, 90, 225, 248, 11, 126, 245, 51, 248, 9, 126,
-    245, 51, 30, 2, 33, 17, 68, 205, 8, 0, 225, 24, 62, 250, 236, 199, 33, 238, 199, 150,
-    32, 9, 250, 237, 199, 33, 239, 199, 150, 40, 44, 33, 61, 197, 126, 61, 32, 5, 209,
-    213, 26, 24, 2, 62, 255, 38, 21, 229, 51, 245, 51, 33, 218, 199, 42, 95, 86, 213, 33,
-    216, 199, 42, 95, 86, 213, 205, 216, 89, 232, 6, 30, 1, 195, 197, 89, 33, 234, 199,
-    42, 95, 86, 33, 1, 0, 123, 149, 95, 122, 156, 248, 8, 50, 115, 33, 238, 199, 42, 79,
-    58, 71, 11, 94, 250, 234, 199, 248, 9, 119, 123, 61, 248, 12, 50, 43, 120, 7, 230, 1,
-    34, 35, 58, 34, 7, 159, 119, 250, 232, 199, 248, 7, 150, 32, 54, 250, 233, 199, 248,
-    8, 150, 32, 46, 248, 10, 126, 183, 40, 4, 35, 175, 34, 119, 248, 11, 42, 119, 248, 9,
-    42, 35, 198, 19, 34, 87, 94, 213, 205, 96, 90, 225, 248, 11, 42, 87, 94, 213, 30, 2,
-    33, 26, 67, 205, 8, 0, 225, 195, 171, 89, 33, 234, 199, 42, 79, 70, 3, 33, 232, 199,
-    126, 145, 32, 94, 35, 126, 144, 32, 89, 248, 10, 126, 183, 40, 4, 35, 175, 34, 119,
-    248, 11, 42, 50, 209, 213, 26, 119, 248, 5, 34, 54, 0, 248, 7, 93, 84, 248, 5, 26,
-    19, 150, 35, 26, 158, 26, 87, 203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122,
-    40, 1, 55, 56, 6, 248, 9, 42, 35, 61, 119, 248, 11, 42, 71, 197, 78, 197, 205, 96,
-    90, 225, 51, 248, 13, 126, 245, 51, 30, 2, 33, 26, 67, 205, 8, 0, 225, 24, 61, 250,
-    232, 199, 33, 234, 199, 150, 32, 9, 250, 233, 199, 33, 235, 199, 150, 40, 43, 33, 61,
-    197, 126, 61, 32, 5, 209, 213, 26, 24, 2, 62, 255, 38, 21, 229, 51, 245, 51, 33, 218,
-    199, 42, 95, 86, 213, 33, 216, 199, 42, 95, 86, 213, 205, 216, 89, 232, 6, 30, 1, 24,
-    26, 250, 31, 218, 15, 56, 18, 250, 228, 199, 183, 40, 3, 205, 63, 34, 250, 231, 199,
-    183, 40, 3, 205, 115, 33, 30, 1, 232, 13, 201, 30, 4, 33, 227, 83, 205, 8, 0, 30, 4,
-    33, 3, 84, 195, 8, 0, 59, 33, 231, 199, 54, 0, 33, 228, 199, 54, 0, 248, 3, 42, 79,
-    70, 203, 40, 203, 25, 203, 40, 203, 25, 203, 40, 203, 25, 105, 96, 43, 203, 124, 40,
-    4, 14, 0, 24, 3, 13, 121, 7, 248, 0, 113, 248, 5, 42, 79, 42, 71, 203, 40, 203, 25,
-    203, 40, 203, 25, 203, 40, 203, 25, 126, 95, 7, 159, 87, 107, 98, 9, 203, 124, 40, 4,
-    6, 0, 24, 6, 121, 248, 7, 134, 71, 7, 14, 0, 248, 8, 126, 145, 40, 41, 250, 51, 197,
-    144, 40, 35, 197, 197, 51, 248, 3, 126, 245, 51, 205, 198, 33, 225, 193, 197, 197,
-    51, 248, 3, 126, 245, 51, 30, 2, 33, 26, 67, 205, 8, 0, 225, 193, 12, 4, 24, 209, 51,
-    201, 250, 231, 199, 183, 40, 5, 205, 115, 33, 24, 245, 248, 3, 126, 33, 51, 197, 150,
-    208, 248, 2, 126, 234, 229, 199, 248, 3, 126, 234, 230, 199, 33, 231, 199, 54, 23,
-    201, 250, 228, 199, 183, 40, 5, 205, 63, 34, 24, 245, 248, 2, 126, 234, 226, 199,
-    248, 3, 78, 33, 227, 199, 113, 250, 51, 197, 6, 0, 89, 22, 0, 147, 111, 120, 154, 71,
-    88, 22, 0, 62, 21, 189, 62, 0, 152, 203, 123, 40, 7, 203, 122, 32, 8, 191, 24, 5,
-    203, 122, 40, 1, 55, 48, 4, 62, 21, 24, 4, 250, 51, 197, 145, 234, 228, 199, 201,
-    232, 225, 248, 0, 77, 68, 89, 80, 33, 20, 0, 229, 108, 229, 213, 205, 176, 55, 232,
-    6, 62, 185, 2, 105, 96, 35, 229, 125, 248, 22, 119, 225, 124, 248, 21, 50, 42, 102,
-    111, 54, 1, 197, 205, 92, 13, 225, 62, 228, 224, 73, 62, 228, 224, 72, 62, 228, 224,
-    71, 175, 224, 66, 175, 224, 67, 240, 64, 248, 22, 34, 240, 64, 230, 253, 224, 64,
-    240, 64, 230, 223, 224, 64, 240, 64, 246, 1, 224, 64, 240, 64, 246, 128, 224, 64, 30,
-    0, 121, 34, 120, 34, 121, 34, 120, 34, 54, 0, 248, 27, 126, 214, 14, 40, 70, 248, 23,
-    126, 248, 28, 119, 248, 24, 126, 248, 29, 34, 115, 175, 254, 20, 40, 21, 248, 28, 94,
-    35, 86, 35, 245, 126, 18, 241, 52, 43, 43, 52, 32, 2, 35, 52, 60, 24, 231, 248, 30,
-    94, 248, 25, 42, 86, 213, 95, 213, 33, 20, 1, 229, 248, 33, 102, 46, 0, 229, 205,
-    100, 57, 232, 6, 209, 248, 27, 52, 24, 179, 89, 80, 33, 20, 0, 229, 108, 229, 213,
-    205, 176, 55, 232, 6, 248, 39, 175, 150, 35, 62, 32, 158, 48, 5, 17, 0, 0, 24, 25,
-    248, 39, 42, 95, 86, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58,
-    203, 27, 203, 58, 203, 27, 248, 30, 115, 126, 183, 40, 5, 62, 128, 150, 48, 108, 197,
-    248, 43, 126, 245, 51, 248, 40, 42, 95, 86, 213, 175, 15, 245, 205, 112, 18, 232, 5,
-    193, 62, 153, 2, 248, 20, 42, 102, 111, 54, 0, 197, 205, 92, 13, 225, 248, 30, 126,
-    183, 40, 4, 126, 198, 128, 119, 248, 37, 42, 95, 86, 33, 0, 16, 25, 229, 125, 248,
-    39, 119, 225, 124, 248, 38, 119, 248, 30, 126, 135, 197, 248, 43, 102, 229, 51, 248,
-    40, 94, 35, 86, 213, 103, 46, 0, 229, 205, 112, 18, 232, 5, 193, 62, 153, 2, 248, 20,
-    42, 102, 111, 54, 1, 197, 205, 92, 13, 225, 24, 41, 248, 30, 126, 135, 197, 248, 43,
-    102, 229, 51, 248, 40, 94, 35, 86, 213, 103, 46, 0, 229, 205, 112, 18, 232, 5, 193,
-    62, 153, 2, 248, 20, 42, 102, 111, 54, 0, 197, 205, 92, 13, 225, 248, 44, 42, 95, 42,
-    87, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 123, 197,
-    102, 229, 51, 248, 45, 94, 35, 86, 213, 103, 46, 0, 229, 205, 112, 18, 232, 5, 193,
-    248, 49, 42, 95, 42, 87, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203,
-    58, 203, 27, 123, 197, 102, 229, 51, 248, 50, 94, 35, 86, 213, 103, 46, 128, 229,
-    205, 112, 18, 232, 5, 193, 62, 161, 2, 248, 20, 42, 102, 111, 54, 0, 197, 205, 92,
-    13, 225, 248, 22, 126, 224, 64, 89, 80, 33, 16, 0, 229, 108, 229, 213, 205, 176, 55,
-    232, 6, 197, 175, 60, 245, 205, 183, 53, 232, 4, 175, 103, 46, 18, 229, 62, 20, 245,
-    51, 175, 15, 245, 205, 24, 55, 232, 5, 62, 185, 2, 248, 20, 42, 102, 111, 54, 0, 197,
-    205, 92, 13, 225, 232, 31, 201, 33, 25, 201, 54, 0, 33, 26, 201, 54, 0, 33, 27, 201,
-    54, 255, 201, 1, 33, 200, 248, 6, 94, 22, 0, 107, 98, 41, 41, 41, 9, 93, 84, 33, 7,
-    0, 25, 126, 15, 208, 33, 4, 0, 25, 77, 68, 33, 5, 0, 25, 42, 110, 95, 85, 10, 1, 1,
-    0, 197, 38, 1, 229, 51, 1, 0, 0, 197, 213, 245, 51, 30, 3, 33, 144, 93, 205, 8, 0,
-    232, 8, 201, 248, 8, 126, 183, 32, 20, 43, 43, 126, 33, 25, 201, 150, 32, 11, 248, 7,
-    126, 33, 26, 201, 150, 32, 2, 95, 201, 248, 7, 58, 87, 94, 213, 30, 4, 33, 244, 95,
-    205, 8, 0, 225, 67, 248, 6, 126, 234, 25, 201, 248, 7, 126, 234, 26, 201, 120, 60,
-    40, 14, 197, 51, 30, 4, 33, 18, 93, 205, 8, 0, 51, 30, 1, 201, 30, 0, 201, 232, 247,
-    248, 17, 126, 248, 7, 119, 248, 18, 126, 248, 8, 50, 42, 95, 86, 26, 79, 19, 26, 71,
-    89, 80, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 248,
-    15, 42, 79, 70, 10, 111, 7, 159, 103, 25, 203, 60, 203, 29, 203, 60, 203, 29, 203,
-    60, 203, 29, 229, 125, 248, 2, 119, 225, 105, 96, 35, 126, 111, 7, 159, 103, 25, 93,
-    84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 248, 1, 115, 248, 7, 42,
-    102, 111, 35, 35, 42, 102, 95, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203,
-    27, 203, 58, 203, 27, 105, 96, 35, 35, 126, 111, 7, 159, 103, 25, 203, 60, 203, 29,
-    203, 60, 203, 29, 203, 60, 203, 29, 229, 125, 248, 4, 119, 225, 3, 3, 3, 10, 111, 7,
-    159, 103, 25, 77, 68, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 248, 3,
-    121, 34, 54, 0, 78, 250, 49, 218, 145, 40, 86, 105, 38, 0, 41, 41, 41, 125, 198, 33,
-    95, 124, 206, 200, 87, 26, 248, 5, 119, 107, 98, 35, 245, 126, 248, 8, 119, 42, 119,
-    241, 107, 98, 35, 35, 110, 133, 61, 248, 8, 50, 43, 19, 19, 19, 26, 134, 35, 35, 71,
-    5, 126, 248, 0, 150, 56, 25, 35, 126, 248, 5, 150, 56, 18, 120, 248, 2, 150, 56, 12,
-    35, 126, 248, 7, 150, 56, 5, 248, 4, 94, 24, 8, 12, 248, 4, 113, 24, 164, 30, 255,
-    232, 9, 201, 232, 252, 248, 12, 42, 95, 86, 213, 248, 12, 42, 95, 86, 213, 30, 4, 33,
-    157, 93, 205, 8, 0, 232, 4, 248, 0, 115, 14, 0, 250, 27, 201, 248, 0, 150, 62, 1, 40,
-    1, 175, 248, 1, 119, 248, 14, 126, 183, 32, 11, 248, 1, 126, 183, 40, 5, 30, 0, 195,
-    241, 95, 248, 0, 126, 60, 62, 1, 40, 1, 175, 95, 248, 0, 42, 35, 6, 0, 135, 203, 16,
-    135, 203, 16, 135, 203, 16, 34, 112, 250, 27, 201, 60, 202, 166, 95, 123, 183, 32, 7,
-    248, 1, 203, 70, 194, 166, 95, 203, 67, 32, 61, 17, 33, 200, 248, 2, 42, 102, 111,
-    25, 93, 84, 33, 7, 0, 25, 126, 15, 48, 42, 33, 4, 0, 25, 77, 68, 33, 5, 0, 25, 42,
-    110, 95, 85, 10, 1, 1, 0, 197, 38, 1, 229, 51, 1, 0, 0, 197, 213, 245, 51, 30, 3, 33,
-    144, 93, 205, 8, 0, 232, 8, 14, 1, 17, 33, 200, 33, 27, 201, 110, 38, 0, 41, 41, 41,
-    25, 93, 84, 33, 7, 0, 25, 203, 78, 40, 42, 33, 4, 0, 25, 77, 68, 33, 5, 0, 25, 42,
-    110, 95, 85, 10, 1, 2, 0, 197, 38, 1, 229, 51, 1, 0, 0, 197, 213, 245, 51, 30, 3, 33,
-    144, 93, 205, 8, 0, 232, 8, 14, 1, 248, 0, 126, 234, 27, 201, 89, 24, 75, 248, 0,
-    126, 234, 27, 201, 203, 67, 32, 63, 17, 33, 200, 248, 2, 42, 102, 111, 25, 93, 84,
-    33, 7, 0, 25, 126, 15, 48, 44, 33, 4, 0, 25, 77, 68, 33, 5, 0, 25, 42, 110, 95, 85,
-    10, 1, 1, 0, 197, 38, 1, 229, 51, 1, 0, 0, 197, 213, 245, 51, 30, 3, 33, 144, 93,
-    205, 8, 0, 232, 8, 30, 1, 24, 2, 30, 0, 232, 4, 201, 232, 248, 248, 0, 54, 0, 78,
-    250, 49, 218, 145, 202, 127, 96, 105, 38, 0, 41, 41, 41, 125, 198, 33, 95, 124, 206,
-    200, 87, 26, 71, 107, 98, 35, 126, 248, 1, 119, 107, 98, 35, 35, 126, 128, 61, 248,
-    2, 50, 19, 19, 19, 26, 134, 35, 35, 61, 119, 248, 14, 94, 22, 0, 19, 248, 4, 123, 34,
-    122, 34, 120, 34, 54, 0, 248, 4, 93, 84, 248, 6, 26, 19, 150, 35, 26, 158, 26, 87,
-    203, 126, 40, 7, 203, 122, 32, 8, 191, 24, 5, 203, 122, 40, 1, 55, 56, 28, 248, 2,
-    126, 248, 14, 150, 56, 20, 35, 126, 248, 1, 150, 56, 13, 35, 35, 126, 248, 15, 150,
-    56, 5, 248, 0, 94, 24, 9, 12, 248, 0, 113, 195, 251, 95, 30, 255, 232, 8, 201, 248,
-    8, 126, 234, 46, 218, 183, 40, 7, 240, 64, 246, 2, 224, 64, 201, 240, 64, 230, 253,
-    224, 64, 201, 250, 27, 198, 33, 28, 198, 150, 40, 7, 250, 27, 198, 248, 8, 166, 192,
-    248, 6, 42, 79, 70, 33, 16, 0, 9, 54, 1, 105, 96, 35, 58, 94, 87, 27, 27, 123, 2, 3,
-    122, 2, 201, 1, 192, 197, 248, 8, 126, 61, 95, 7, 123, 230, 7, 111, 38, 0, 93, 84,
-    41, 41, 25, 9, 77, 68, 89, 80, 19, 19, 248, 9, 42, 18, 3, 3, 3, 42, 95, 86, 123, 2,
-    3, 122, 2, 201, 1, 232, 197, 248, 8, 94, 123, 183, 200, 203, 67, 40, 4, 248, 9, 126,
-    2, 203, 59, 3, 24, 240, 1, 232, 197, 248, 8, 94, 123, 183, 200, 203, 67, 40, 2, 175,
-    2, 203, 59, 3, 24, 242, 248, 8, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248,
-    6, 42, 95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 248,
-    10, 94, 33, 23, 198, 22, 0, 25, 94, 22, 0, 123, 2, 3, 122, 2, 201, 248, 8, 70, 120,
-    230, 1, 14, 0, 203, 72, 40, 19, 177, 40, 8, 30, 5, 33, 182, 65, 195, 8, 0, 30, 5, 33,
-    13, 65, 195, 8, 0, 177, 40, 8, 30, 5, 33, 208, 65, 195, 8, 0, 30, 5, 33, 46, 65, 195,
-    8, 0, 1, 240, 197, 248, 8, 126, 61, 95, 7, 123, 230, 3, 111, 38, 0, 93, 84, 41, 41,
-    25, 9, 77, 68, 89, 80, 19, 19, 248, 9, 42, 18, 3, 3, 3, 42, 95, 86, 123, 2, 3, 122,
-    2, 201, 1, 4, 198, 248, 8, 126, 61, 95, 7, 123, 230, 3, 111, 38, 0, 41, 9, 77, 68,
-    248, 9, 126, 2, 3, 126, 2, 201, 1, 4, 198, 248, 8, 126, 61, 95, 7, 123, 230, 3, 111,
-    38, 0, 41, 9, 54, 0, 201, 1, 4, 198, 248, 8, 126, 61, 95, 7, 123, 230, 3, 111, 38, 0,
-    41, 9, 77, 68, 3, 126, 2, 201, 232, 250, 248, 12, 42, 95, 42, 87, 42, 79, 70, 203,
-    33, 203, 16, 33, 8, 0, 25, 51, 51, 229, 248, 15, 203, 126, 40, 10, 209, 213, 26, 111,
-    19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 125, 76, 248, 2, 34, 113, 248, 16, 42, 79,
-    70, 203, 33, 203, 16, 203, 126, 40, 11, 209, 213, 26, 111, 19, 26, 103, 9, 77, 24, 5,
-    33, 152, 203, 9, 77, 68, 248, 4, 121, 34, 112, 248, 18, 42, 79, 70, 203, 33, 203, 16,
-    203, 126, 40, 10, 209, 213, 26, 111, 19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 126,
-    245, 51, 250, 50, 197, 245, 51, 205, 93, 53, 225, 75, 66, 248, 4, 42, 95, 86, 26, 38,
-    0, 111, 9, 77, 68, 33, 41, 197, 86, 121, 33, 42, 197, 134, 35, 79, 120, 142, 71, 213,
-    51, 197, 205, 7, 19, 232, 3, 75, 6, 0, 248, 2, 42, 102, 111, 121, 34, 112, 232, 6,
-    201, 232, 252, 248, 10, 42, 95, 86, 248, 17, 42, 79, 70, 203, 33, 203, 16, 33, 8, 0,
-    25, 229, 125, 248, 4, 119, 225, 124, 248, 3, 119, 248, 18, 203, 126, 40, 13, 248, 2,
-    42, 95, 86, 26, 111, 19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 125, 76, 248, 0, 34,
-    113, 248, 12, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 14, 248, 2, 42, 95, 86, 26,
-    111, 19, 26, 103, 9, 77, 24, 5, 33, 152, 203, 9, 77, 124, 248, 2, 113, 35, 119, 248,
-    15, 42, 79, 70, 3, 3, 209, 213, 26, 111, 19, 26, 103, 41, 41, 41, 41, 9, 77, 68, 248,
-    2, 42, 95, 86, 26, 248, 14, 102, 229, 51, 197, 248, 22, 102, 229, 51, 245, 51, 205,
-    112, 18, 232, 9, 201, 232, 249, 248, 13, 126, 248, 2, 119, 248, 14, 126, 248, 3, 119,
-    248, 15, 42, 79, 70, 203, 33, 203, 16, 248, 2, 42, 95, 86, 33, 8, 0, 25, 51, 51, 229,
-    248, 16, 203, 126, 40, 10, 209, 213, 26, 111, 19, 26, 103, 9, 24, 4, 33, 152, 203, 9,
-    125, 76, 248, 4, 34, 113, 248, 17, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 11,
-    209, 213, 26, 111, 19, 26, 103, 9, 77, 24, 5, 33, 152, 203, 9, 77, 68, 248, 19, 94,
-    203, 67, 40, 32, 250, 27, 198, 33, 28, 198, 150, 40, 23, 248, 4, 42, 102, 111, 62, 1,
-    34, 54, 0, 33, 27, 198, 94, 22, 0, 123, 2, 3, 122, 2, 24, 77, 203, 75, 40, 38, 197,
-    30, 5, 33, 202, 67, 205, 8, 0, 123, 193, 248, 6, 119, 183, 40, 21, 43, 58, 110, 103,
-    62, 2, 34, 54, 0, 248, 6, 94, 22, 0, 123, 2, 3, 122, 2, 24, 35, 248, 2, 42, 95, 86,
-    33, 16, 0, 25, 54, 1, 248, 2, 42, 95, 86, 26, 79, 19, 26, 71, 121, 198, 250, 79, 120,
-    206, 255, 71, 58, 110, 103, 121, 34, 112, 232, 7, 201, 248, 8, 126, 183, 40, 7, 240,
-    64, 246, 4, 224, 64, 201, 240, 64, 230, 251, 224, 64, 201, 232, 253, 248, 16, 42, 79,
-    70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 9, 42, 95, 86, 33, 8, 0, 25, 42, 102,
-    111, 9, 24, 4, 33, 152, 203, 9, 78, 197, 248, 14, 126, 245, 51, 250, 50, 197, 245,
-    51, 205, 93, 53, 225, 193, 248, 11, 126, 6, 0, 131, 95, 120, 138, 87, 33, 41, 197,
-    70, 123, 33, 42, 197, 134, 35, 95, 122, 142, 87, 197, 51, 213, 205, 7, 19, 232, 3,
-    67, 248, 0, 112, 248, 14, 42, 95, 86, 105, 38, 0, 19, 19, 41, 41, 41, 41, 25, 77, 92,
-    250, 60, 197, 214, 5, 32, 89, 120, 214, 128, 48, 46, 62, 9, 248, 12, 150, 48, 8, 248,
-    1, 175, 34, 54, 128, 24, 6, 248, 1, 175, 34, 54, 144, 248, 0, 110, 38, 0, 41, 41, 41,
-    41, 125, 84, 213, 95, 248, 3, 42, 102, 111, 25, 209, 69, 124, 104, 24, 17, 120, 6, 0,
-    198, 128, 111, 120, 206, 255, 103, 41, 41, 41, 41, 124, 198, 136, 67, 93, 248, 13,
-    102, 229, 51, 33, 16, 0, 229, 197, 87, 213, 205, 63, 19, 232, 7, 24, 19, 248, 13,
-    126, 245, 51, 83, 89, 213, 62, 1, 245, 51, 197, 51, 205, 112, 18, 232, 5, 232, 3,
-    201, 33, 32, 200, 78, 203, 169, 248, 8, 126, 183, 40, 11, 203, 233, 33, 32, 200, 113,
-    33, 0, 64, 113, 201, 33, 32, 200, 113, 33, 0, 64, 113, 201, 248, 10, 42, 245, 51, 42,
-    95, 86, 213, 248, 12, 58, 87, 94, 213, 205, 112, 18, 232, 5, 201, 232, 247, 248, 17,
-    42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 16, 248, 15, 42, 95, 86, 33, 8, 0, 25,
-    42, 102, 111, 9, 77, 24, 5, 33, 152, 203, 9, 77, 68, 10, 248, 3, 119, 248, 20, 42,
-    79, 70, 3, 3, 248, 0, 125, 84, 248, 4, 34, 122, 50, 42, 95, 86, 248, 19, 126, 245,
-    51, 197, 213, 205, 224, 18, 232, 5, 248, 4, 42, 95, 86, 26, 248, 8, 119, 248, 4, 42,
-    79, 70, 3, 105, 96, 35, 58, 94, 87, 248, 8, 126, 245, 51, 213, 205, 7, 19, 232, 3,
-    248, 6, 123, 34, 122, 50, 43, 43, 42, 95, 86, 26, 248, 8, 119, 105, 96, 42, 79, 70,
-    3, 3, 248, 6, 42, 35, 102, 229, 51, 197, 245, 51, 248, 7, 126, 245, 51, 205, 112, 18,
-    232, 14, 201, 33, 50, 218, 54, 15, 248, 8, 126, 33, 245, 199, 119, 214, 2, 202, 29,
-    2, 201, 201, 232, 235, 250, 245, 199, 183, 32, 8, 33, 50, 218, 54, 15, 195, 32, 105,
-    250, 50, 218, 214, 15, 32, 5, 33, 50, 218, 54, 0, 250, 50, 218, 248, 20, 119, 62, 32,
-    248, 33, 150, 62, 0, 23, 248, 0, 119, 248, 30, 126, 7, 230, 1, 248, 1, 119, 248, 27,
-    126, 248, 2, 119, 248, 28, 126, 248, 3, 119, 248, 29, 126, 248, 4, 119, 248, 30, 126,
-    248, 5, 50, 203, 38, 35, 203, 22, 250, 50, 218, 246, 2, 248, 6, 119, 250, 50, 218,
-    246, 4, 248, 7, 119, 248, 32, 126, 7, 230, 1, 248, 8, 119, 248, 31, 126, 248, 9, 119,
-    248, 32, 126, 248, 10, 50, 203, 38, 35, 203, 22, 248, 20, 126, 230, 1, 248, 11, 34,
-    54, 0, 248, 2, 42, 95, 86, 33, 8, 0, 25, 229, 125, 248, 15, 119, 225, 124, 248, 14,
-    119, 248, 20, 126, 230, 2, 248, 15, 34, 54, 0, 248, 20, 126, 230, 4, 248, 17, 34,
-    175, 34, 35, 58, 230, 8, 34, 54, 0, 250, 245, 199, 61, 40, 11, 250, 245, 199, 214, 2,
-    202, 138, 103, 195, 223, 104, 248, 12, 58, 182, 32, 120, 248, 0, 126, 183, 40, 4,
-    248, 33, 54, 32, 33, 25, 200, 54, 0, 33, 29, 200, 54, 0, 248, 1, 126, 183, 40, 20,
-    248, 13, 42, 95, 86, 26, 79, 19, 26, 71, 248, 4, 42, 102, 111, 9, 77, 68, 24, 11, 1,
-    152, 203, 248, 4, 42, 102, 111, 9, 77, 68, 248, 33, 94, 22, 0, 213, 197, 17, 246,
-    199, 213, 205, 109, 55, 232, 6, 33, 27, 200, 54, 246, 35, 54, 199, 248, 33, 126, 234,
-    26, 200, 33, 31, 198, 54, 0, 245, 51, 205, 10, 2, 51, 250, 26, 200, 183, 62, 1, 40,
-    1, 175, 234, 29, 200, 33, 50, 218, 126, 246, 1, 119, 195, 223, 104, 248, 16, 58, 182,
-    32, 16, 250, 29, 200, 183, 202, 223, 104, 248, 6, 126, 234, 50, 218, 195, 223, 104,
-    248, 18, 58, 182, 32, 16, 250, 25, 200, 183, 202, 223, 104, 248, 7, 126, 234, 50,
-    218, 195, 223, 104, 248, 20, 58, 182, 194, 223, 104, 248, 0, 126, 183, 40, 4, 248,
-    33, 54, 32, 248, 8, 126, 183, 40, 20, 248, 13, 42, 95, 86, 26, 79, 19, 26, 71, 248,
-    9, 42, 102, 111, 9, 77, 68, 24, 11, 1, 152, 203, 248, 9, 42, 102, 111, 9, 77, 68,
-    248, 33, 94, 22, 0, 213, 17, 246, 199, 213, 197, 205, 109, 55, 232, 6, 33, 50, 218,
-    126, 246, 8, 119, 195, 223, 104, 248, 18, 58, 182, 32, 19, 33, 25, 200, 54, 0, 33,
-    29, 200, 54, 0, 248, 7, 126, 234, 50, 218, 195, 223, 104, 248, 20, 58, 182, 194, 41,
-    104, 250, 25, 200, 183, 202, 223, 104, 248, 0, 126, 183, 40, 4, 248, 33, 54, 32, 248,
-    8, 126, 183, 40, 33, 248, 13, 42, 95, 86, 26, 248, 19, 34, 19, 26, 50, 42, 95, 86,
-    248, 9, 42, 102, 111, 25, 229, 125, 248, 19, 119, 225, 124, 248, 18, 119, 24, 27, 17,
-    152, 203, 248, 9, 42, 102, 111, 25, 229, 125, 248, 21, 119, 225, 124, 248, 20, 50,
-    58, 43, 119, 248, 20, 58, 43, 119, 248, 17, 42, 35, 50, 42, 35, 119, 248, 33, 126,
-    248, 17, 34, 175, 50, 42, 95, 42, 87, 213, 17, 246, 199, 213, 42, 95, 86, 213, 205,
-    109, 55, 232, 6, 33, 50, 218, 126, 246, 8, 119, 195, 223, 104, 248, 12, 58, 182, 194,
-    205, 104, 248, 0, 126, 183, 40, 4, 248, 33, 54, 32, 248, 1, 126, 183, 40, 33, 248,
-    13, 42, 95, 86, 26, 248, 19, 34, 19, 26, 50, 42, 95, 86, 248, 4, 42, 102, 111, 25,
-    229, 125, 248, 19, 119, 225, 124, 248, 18, 119, 24, 27, 17, 152, 203, 248, 4, 42,
-    102, 111, 25, 229, 125, 248, 21, 119, 225, 124, 248, 20, 50, 58, 43, 119, 248, 20,
-    58, 43, 119, 248, 17, 42, 35, 50, 42, 35, 119, 248, 33, 126, 248, 17, 34, 54, 0, 43,
-    94, 35, 86, 35, 213, 94, 35, 86, 213, 17, 246, 199, 213, 205, 109, 55, 232, 6, 33,
-    27, 200, 54, 246, 35, 54, 199, 248, 33, 126, 234, 26, 200, 33, 31, 198, 54, 0, 245,
-    51, 205, 10, 2, 51, 250, 26, 200, 183, 62, 1, 40, 1, 175, 234, 29, 200, 33, 50, 218,
-    126, 246, 1, 119, 24, 18, 248, 16, 58, 182, 32, 12, 250, 29, 200, 183, 40, 6, 248, 6,
-    126, 234, 50, 218, 250, 50, 218, 214, 15, 40, 58, 248, 2, 42, 95, 86, 26, 79, 19, 26,
-    71, 17, 6, 0, 121, 147, 95, 120, 154, 248, 20, 50, 115, 248, 2, 42, 95, 86, 248, 19,
-    42, 18, 19, 126, 18, 248, 2, 42, 95, 86, 33, 16, 0, 25, 229, 125, 248, 21, 119, 225,
-    124, 248, 20, 50, 42, 102, 111, 54, 1, 232, 21, 201, 181, 0, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 252, 251, 252, 251, 252,
-    255, 248, 247, 248, 239, 240, 255, 224, 255, 192, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 63, 223, 63, 223, 63, 255, 31, 239, 31, 255, 15,
-    247, 15, 251, 7, 255, 255, 254, 255, 199, 248, 255, 224, 239, 240, 247, 248, 255,
-    240, 239, 240, 255, 128, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 191, 64, 127, 225, 125, 243, 127, 251, 255, 0, 255, 0,
-    255, 0, 255, 0, 239, 240, 247, 248, 255, 248, 251, 28, 253, 3, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 255, 127, 255, 231, 31, 255, 7, 255, 15, 255,
-    31, 239, 31, 255, 15, 223, 224, 255, 224, 255, 240, 247, 248, 255, 240, 255, 224,
-    223, 224, 255, 224, 110, 255, 94, 239, 94, 231, 94, 227, 95, 224, 95, 224, 255, 0,
-    255, 0, 235, 28, 251, 12, 235, 28, 251, 28, 255, 56, 247, 56, 239, 48, 255, 0, 255,
-    7, 255, 7, 255, 15, 255, 31, 239, 31, 247, 15, 255, 7, 247, 15, 255, 240, 255, 248,
-    255, 240, 255, 224, 223, 224, 239, 240, 255, 240, 247, 248, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 254, 1, 255, 1, 255, 1, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 7,
-    248, 255, 255, 255, 255, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 241, 14, 255, 14,
-    253, 142, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 241, 14, 255, 14, 255, 14, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 243, 15, 255, 31, 255, 63, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 124, 131, 251, 231, 235, 247, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0,
-    0, 255, 255, 255, 255, 255, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 231, 24, 91, 188,
-    91, 188, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 199, 56, 255, 120, 251, 124, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 240, 15, 239, 31, 239, 31, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 31, 224, 251, 252, 255, 252, 255, 0, 227, 28, 191, 126, 255, 127,
-    107, 247, 93, 227, 126, 193, 95, 224, 255, 0, 235, 28, 235, 28, 235, 28, 235, 156,
-    171, 220, 203, 252, 251, 252, 255, 15, 255, 31, 255, 15, 247, 15, 255, 7, 247, 15,
-    239, 31, 255, 31, 255, 240, 255, 224, 223, 224, 255, 224, 255, 240, 247, 248, 255,
-    240, 255, 224, 255, 1, 255, 1, 255, 1, 255, 1, 255, 1, 255, 1, 255, 1, 255, 1, 191,
-    195, 189, 195, 191, 193, 191, 195, 195, 255, 255, 255, 254, 255, 182, 207, 253, 142,
-    189, 206, 189, 206, 189, 206, 253, 142, 125, 142, 237, 30, 239, 30, 255, 14, 255, 14,
-    255, 14, 255, 14, 255, 14, 255, 14, 255, 14, 255, 14, 255, 56, 183, 120, 191, 120,
-    253, 62, 255, 31, 247, 15, 254, 1, 255, 0, 127, 240, 191, 112, 255, 0, 255, 0, 159,
-    224, 239, 240, 247, 248, 191, 120, 183, 120, 183, 120, 183, 120, 183, 120, 183, 120,
-    183, 120, 183, 120, 183, 120, 219, 60, 219, 60, 219, 60, 219, 60, 219, 60, 219, 60,
-    219, 60, 219, 60, 255, 0, 255, 0, 255, 0, 254, 1, 255, 1, 255, 1, 253, 3, 255, 3,
-    127, 252, 239, 252, 221, 238, 247, 206, 247, 206, 190, 199, 255, 135, 199, 255, 237,
-    30, 237, 30, 237, 30, 238, 31, 239, 31, 239, 31, 237, 30, 109, 158, 253, 30, 237, 30,
-    237, 30, 31, 252, 251, 252, 127, 252, 253, 30, 255, 14, 239, 112, 255, 120, 255, 56,
-    231, 24, 255, 0, 255, 0, 255, 31, 191, 127, 251, 124, 187, 124, 219, 60, 247, 8, 255,
-    0, 255, 0, 255, 224, 247, 248, 255, 15, 255, 7, 255, 7, 247, 15, 239, 31, 255, 31,
-    255, 15, 255, 7, 223, 224, 239, 240, 247, 248, 247, 248, 239, 240, 255, 224, 255,
-    224, 239, 240, 255, 1, 255, 1, 255, 1, 255, 1, 254, 1, 255, 0, 255, 0, 255, 0, 191,
-    199, 187, 199, 191, 195, 189, 195, 126, 129, 255, 0, 255, 0, 255, 0, 255, 14, 126,
-    143, 247, 143, 187, 199, 62, 193, 255, 0, 255, 0, 255, 0, 255, 14, 239, 30, 253, 254,
-    255, 252, 15, 240, 255, 0, 255, 0, 255, 0, 143, 112, 255, 120, 255, 63, 223, 63, 240,
-    15, 255, 0, 255, 0, 255, 0, 119, 56, 183, 120, 255, 240, 239, 240, 63, 192, 255, 0,
-    255, 0, 255, 0, 183, 120, 183, 120, 183, 120, 183, 120, 207, 48, 255, 0, 255, 0, 255,
-    0, 219, 60, 219, 60, 223, 63, 223, 63, 224, 31, 255, 0, 255, 0, 255, 0, 255, 3, 251,
-    7, 251, 247, 247, 255, 1, 254, 255, 0, 255, 0, 255, 0, 255, 255, 131, 255, 253, 3,
-    255, 1, 254, 1, 255, 0, 255, 0, 255, 0, 109, 158, 237, 158, 174, 223, 239, 223, 48,
-    207, 255, 0, 255, 0, 255, 0, 255, 14, 237, 30, 63, 252, 251, 252, 15, 240, 255, 0,
-    255, 0, 255, 0, 252, 127, 127, 224, 95, 224, 127, 192, 95, 224, 111, 240, 252, 127,
-    255, 63, 255, 248, 251, 28, 235, 28, 251, 12, 235, 28, 223, 56, 255, 248, 255, 240,
-    255, 7, 255, 15, 239, 31, 239, 31, 255, 15, 255, 7, 255, 7, 255, 15, 247, 248, 255,
-    240, 239, 240, 223, 224, 255, 224, 255, 240, 247, 248, 255, 240, 239, 31, 255, 0,
-    255, 0, 231, 24, 253, 62, 254, 127, 103, 255, 95, 227, 223, 224, 255, 0, 255, 0, 239,
-    24, 251, 28, 235, 28, 107, 156, 171, 220, 255, 31, 239, 31, 247, 15, 255, 7, 247, 15,
-    255, 15, 255, 31, 239, 31, 239, 240, 255, 224, 255, 224, 255, 240, 247, 248, 255,
-    240, 255, 224, 231, 248, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 254,
-    1, 127, 193, 95, 224, 111, 240, 255, 120, 191, 120, 231, 24, 255, 0, 255, 0, 235,
-    220, 251, 252, 251, 124, 187, 124, 219, 60, 235, 28, 255, 0, 254, 1, 247, 15, 255, 7,
-    247, 15, 255, 15, 255, 31, 239, 31, 247, 15, 231, 31, 254, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 255, 128, 255, 192, 255,
-    224, 239, 240, 231, 248, 255, 248, 255, 248, 252, 3, 252, 3, 248, 7, 248, 7, 249, 6,
-    249, 6, 245, 10, 241, 14, 252, 3, 252, 3, 254, 1, 254, 1, 254, 1, 254, 1, 254, 1,
-    255, 0, 255, 0, 127, 128, 127, 128, 127, 128, 63, 192, 191, 64, 63, 192, 95, 160,
-    255, 0, 255, 1, 251, 7, 255, 7, 255, 15, 239, 31, 255, 31, 223, 63, 127, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 251, 252, 255, 252,
-    255, 252, 255, 252, 252, 255, 255, 255, 255, 255, 254, 255, 225, 30, 229, 26, 235,
-    20, 235, 20, 0, 255, 131, 255, 188, 195, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 16,
-    239, 15, 255, 15, 255, 175, 95, 255, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 255,
-    255, 255, 255, 255, 255, 0, 255, 0, 255, 0, 255, 0, 32, 223, 163, 223, 162, 223, 131,
-    252, 95, 160, 95, 160, 15, 240, 15, 240, 80, 175, 7, 255, 255, 7, 253, 3, 255, 63,
-    255, 63, 255, 63, 255, 63, 63, 255, 255, 255, 255, 255, 255, 255, 253, 254, 255, 252,
-    255, 248, 247, 248, 247, 248, 255, 240, 255, 240, 255, 240, 255, 0, 255, 0, 247, 8,
-    193, 62, 138, 117, 30, 225, 62, 193, 126, 129, 207, 63, 239, 31, 239, 31, 255, 15,
-    247, 15, 255, 7, 127, 135, 127, 135, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 248, 255, 223, 224, 191, 192, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    63, 255, 239, 31, 251, 7, 223, 224, 207, 240, 255, 224, 223, 224, 254, 193, 252, 195,
-    189, 194, 185, 198, 254, 1, 255, 0, 255, 0, 7, 248, 35, 220, 243, 12, 243, 12, 251,
-    4, 255, 255, 255, 127, 191, 127, 255, 63, 255, 63, 223, 63, 223, 63, 255, 31, 255,
-    240, 255, 240, 255, 240, 247, 248, 255, 248, 227, 252, 233, 246, 222, 241, 62, 193,
-    62, 193, 152, 103, 193, 62, 247, 8, 255, 0, 255, 0, 255, 0, 127, 135, 127, 135, 247,
-    15, 255, 15, 255, 15, 255, 31, 223, 63, 191, 127, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 254, 255, 253, 254, 242, 253, 255, 255, 255, 254, 255, 252, 255, 248,
-    7, 248, 79, 176, 159, 96, 31, 224, 253, 3, 254, 1, 255, 0, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 255, 255, 255, 127, 255, 191, 127, 199, 63, 233, 23, 226, 29,
-    240, 15, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 127, 255, 159,
-    127, 185, 198, 189, 194, 252, 195, 254, 193, 223, 224, 239, 240, 255, 240, 255, 248,
-    251, 4, 243, 12, 107, 148, 7, 248, 159, 96, 255, 0, 255, 0, 254, 1, 223, 63, 223, 63,
-    223, 63, 255, 63, 191, 127, 159, 127, 255, 159, 223, 63, 255, 255, 255, 255, 254,
-    255, 255, 254, 255, 255, 254, 253, 251, 252, 253, 254, 191, 217, 181, 207, 95, 231,
-    175, 119, 223, 63, 239, 159, 223, 255, 191, 127, 190, 193, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 254, 255,
-    253, 254, 250, 253, 244, 251, 240, 255, 192, 255, 144, 239, 0, 255, 1, 254, 0, 255,
-    2, 253, 0, 255, 0, 255, 63, 192, 127, 128, 126, 129, 124, 131, 252, 3, 250, 5, 240,
-    15, 241, 14, 192, 63, 15, 240, 32, 223, 159, 96, 63, 192, 127, 128, 255, 0, 255, 0,
-    15, 240, 199, 56, 9, 246, 230, 25, 248, 7, 252, 3, 254, 1, 255, 0, 248, 7, 248, 7,
-    252, 3, 252, 3, 124, 131, 62, 193, 190, 65, 94, 161, 79, 191, 23, 239, 139, 119, 5,
-    251, 2, 253, 1, 254, 0, 255, 128, 127, 255, 255, 255, 255, 255, 255, 255, 255, 127,
-    255, 127, 255, 191, 127, 95, 191, 253, 254, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 251, 6, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 239, 119, 127, 199, 233, 159, 151, 249, 237, 243, 254, 231,
-    231, 252, 254, 249, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 127, 255, 255,
-    127, 255, 255, 250, 247, 255, 241, 255, 248, 252, 207, 219, 231, 175, 243, 123, 159,
-    119, 143, 191, 127, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 253, 254, 255, 248, 239, 240, 255, 224, 255, 192, 191, 192, 255, 128,
-    8, 247, 240, 15, 240, 15, 240, 15, 240, 15, 240, 15, 240, 15, 240, 15, 17, 238, 5,
-    250, 5, 250, 3, 252, 11, 244, 3, 252, 3, 252, 3, 252, 235, 20, 235, 20, 231, 24, 231,
-    24, 215, 40, 215, 40, 215, 40, 215, 40, 30, 225, 174, 81, 175, 80, 143, 112, 207, 48,
-    207, 48, 207, 48, 207, 48, 0, 255, 0, 255, 16, 239, 0, 255, 64, 191, 0, 255, 64, 191,
-    64, 191, 3, 255, 31, 224, 31, 224, 31, 224, 31, 224, 31, 224, 31, 224, 31, 224, 255,
-    255, 255, 255, 191, 127, 223, 63, 239, 31, 247, 15, 255, 7, 251, 7, 253, 251, 254,
-    255, 253, 254, 252, 255, 255, 255, 255, 255, 255, 255, 255, 255, 95, 191, 223, 63,
-    191, 127, 215, 239, 119, 143, 223, 63, 47, 243, 251, 199, 223, 231, 191, 127, 239,
-    31, 175, 223, 127, 255, 255, 63, 255, 63, 255, 255, 127, 128, 127, 128, 127, 128,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 240, 15, 240, 15, 240, 15, 240, 15, 240, 15,
-    240, 15, 240, 15, 240, 15, 11, 244, 5, 250, 5, 250, 5, 250, 1, 254, 16, 239, 2, 253,
-    0, 255, 215, 40, 231, 24, 231, 24, 235, 20, 227, 28, 245, 10, 244, 11, 250, 5, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 127, 128, 255, 0, 255, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 254, 1, 253, 2, 207, 48, 143, 112, 175, 80, 142, 113, 94,
-    161, 94, 161, 190, 65, 60, 195, 64, 191, 64, 191, 0, 255, 144, 111, 0, 255, 128, 127,
-    0, 255, 0, 255, 31, 224, 31, 224, 31, 224, 31, 224, 31, 224, 31, 224, 31, 224, 31,
-    224, 251, 7, 255, 3, 253, 3, 253, 3, 253, 3, 253, 3, 253, 3, 253, 3, 255, 207, 254,
-    249, 238, 241, 251, 231, 253, 254, 247, 248, 245, 251, 254, 255, 255, 127, 255, 127,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 254, 255, 254, 249, 254, 245, 250, 233, 246, 255, 255, 255, 255, 255, 255,
-    135, 127, 187, 71, 171, 87, 136, 119, 130, 125, 6, 249, 1, 254, 0, 255, 0, 255, 2,
-    253, 1, 254, 0, 255, 0, 255, 125, 130, 126, 129, 191, 64, 95, 160, 31, 224, 39, 216,
-    23, 232, 129, 126, 63, 192, 207, 48, 32, 223, 135, 120, 232, 23, 255, 0, 255, 0, 255,
-    0, 243, 12, 224, 31, 27, 228, 135, 120, 63, 192, 255, 0, 255, 0, 255, 0, 125, 130,
-    250, 5, 240, 15, 244, 11, 228, 27, 192, 63, 128, 127, 0, 255, 0, 255, 0, 255, 0, 255,
-    128, 127, 0, 255, 0, 255, 0, 255, 0, 255, 253, 3, 253, 3, 253, 3, 253, 3, 253, 3,
-    253, 3, 252, 3, 254, 1, 255, 255, 255, 255, 255, 255, 135, 249, 182, 201, 167, 216,
-    39, 216, 7, 248, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 127, 255, 31, 255,
-    95, 191, 251, 252, 250, 253, 255, 255, 253, 254, 255, 252, 255, 255, 254, 255, 253,
-    254, 209, 238, 209, 238, 209, 238, 209, 238, 209, 238, 209, 238, 209, 238, 233, 246,
-    136, 119, 176, 79, 139, 119, 139, 119, 169, 87, 130, 125, 136, 119, 138, 117, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 2, 253, 40, 215, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 127, 128, 15, 240, 0, 255, 6, 249, 0, 255, 0,
-    255, 0, 255, 32, 223, 252, 3, 224, 31, 4, 251, 192, 63, 0, 255, 0, 255, 0, 255, 18,
-    237, 128, 127, 0, 255, 0, 255, 32, 223, 0, 255, 0, 255, 0, 255, 0, 255, 254, 1, 252,
-    3, 253, 3, 253, 3, 252, 3, 254, 1, 254, 1, 255, 0, 7, 248, 55, 200, 167, 216, 167,
-    216, 183, 200, 7, 248, 7, 248, 39, 216, 47, 223, 15, 255, 15, 255, 15, 255, 7, 255,
-    15, 255, 47, 223, 95, 191, 254, 255, 254, 255, 253, 254, 254, 255, 255, 255, 253,
-    254, 254, 255, 255, 255, 255, 255, 255, 255, 127, 255, 255, 127, 127, 255, 191, 255,
-    223, 63, 175, 127, 241, 254, 253, 254, 255, 254, 254, 255, 255, 254, 254, 255, 252,
-    255, 255, 252, 168, 87, 139, 119, 187, 71, 135, 127, 207, 63, 223, 15, 207, 255, 191,
-    127, 240, 15, 240, 15, 232, 23, 232, 23, 232, 23, 224, 31, 232, 23, 232, 23, 0, 255,
-    4, 251, 0, 255, 16, 239, 4, 251, 0, 255, 0, 255, 0, 255, 0, 255, 32, 223, 0, 255, 0,
-    255, 0, 255, 0, 255, 0, 255, 0, 255, 0, 255, 4, 251, 0, 255, 0, 255, 0, 255, 0, 255,
-    0, 255, 0, 255, 8, 247, 64, 191, 32, 223, 0, 255, 128, 127, 0, 255, 1, 254, 0, 255,
-    0, 255, 0, 255, 0, 255, 0, 255, 144, 111, 0, 255, 0, 255, 0, 255, 0, 255, 128, 127,
-    32, 223, 0, 255, 144, 111, 0, 255, 0, 255, 0, 255, 47, 208, 15, 240, 31, 224, 31,
-    224, 31, 224, 31, 224, 31, 224, 31, 224, 252, 3, 253, 3, 253, 3, 253, 3, 253, 3, 253,
-    3, 253, 3, 253, 3, 55, 200, 167, 216, 182, 201, 129, 255, 255, 241, 221, 227, 246,
-    239, 251, 252, 63, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    127, 255, 255, 252, 253, 254, 251, 255, 250, 253, 251, 252, 242, 255, 253, 243, 255,
-    248, 208, 25, 208, 25, 208, 25, 208, 25, 208, 25, 208, 25, 208, 25, 208, 25, 208, 25,
-    208, 25, 208, 25, 208, 25, 208, 25, 208, 25, 208, 25, 208, 25, 2, 36, 16, 0, 90, 0,
-    0, 38, 32, 0, 90, 0, 0, 36, 16, 0, 90, 0, 0, 38, 16, 0, 90, 0, 0, 40, 32, 0, 90, 0,
-    0, 36, 32, 0, 90, 0, 0, 38, 32, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0,
-    90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90,
-    0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0,
-    90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90,
-    0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0,
-    90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90,
-    0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0,
-    90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90,
-    0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0,
-    90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90,
-    0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0,
-    90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90,
-    0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0,
-    90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 48,
-    240, 0, 90, 0, 0, 51, 240, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90,
-    0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0,
-    90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90,
-    0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0,
-    90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90,
-    0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0,
-    90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90,
-    0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 90, 0, 0, 150, 116, 86, 117, 22, 118, 86, 117, 0,
-    64, 181, 128, 0, 128, 181, 128, 0, 0, 9, 192, 0, 48, 176, 192, 0, 112, 176, 192, 0,
-    176, 176, 192, 0, 0, 176, 128, 0, 64, 176, 128, 0, 128, 176, 128, 0, 192, 176, 128,
-    28, 128, 178, 192, 25, 128, 181, 128, 71, 0, 183, 128, 21, 96, 183, 192, 0, 128, 0,
-    128, 128, 32, 1, 192, 128, 32, 2, 192, 128, 32, 3, 192, 128, 32, 4, 192, 128, 32, 5,
-    192, 128, 32, 6, 192, 0, 32, 7, 128, 0, 32, 8, 128, 0, 32, 9, 128, 0, 32, 10, 128, 0,
-    32, 11, 128, 0, 32, 12, 128, 0, 32, 13, 128, 0, 32, 14, 128, 0, 32, 15, 128, 145,
-    112, 32, 32, 32, 32, 32, 32, 145, 96, 31, 31, 31, 31, 31, 31, 145, 0, 28, 28, 28, 28,
-    28, 28, 146, 0, 32, 32, 32, 32, 32, 32, 148, 0, 24, 24, 24, 24, 24, 24, 148, 0, 16,
-    16, 16, 16, 16, 16, 177, 0, 24, 24, 24, 24, 24, 24, 177, 128, 24, 24, 24, 24, 24, 24,
-    177, 128, 16, 15, 15, 15, 15, 15, 145, 128, 32, 32, 32, 32, 32, 32, 178, 64, 16, 15,
-    15, 15, 15, 15, 177, 86, 27, 14, 17, 254, 0, 0, 183, 0, 13, 11, 9, 7, 7, 7, 183, 128,
-    8, 7, 6, 5, 4, 4, 0, 0, 0, 0, 0, 0, 0, 0, 136, 136, 136, 136, 136, 136, 136, 136,
-    136, 136, 136, 136, 136, 136, 136, 136, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255,
-    255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255,
-    255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 204, 204, 204, 204, 204, 204,
-    204, 204, 51, 51, 51, 51, 51, 51, 51, 51, 170, 170, 170, 170, 170, 170, 170, 170, 85,
-    85, 85, 85, 85, 85, 85, 85, 136, 136, 136, 136, 136, 136, 136, 136, 119, 119, 119,
-    119, 119, 119, 119, 119, 255, 255, 204, 204, 255, 255, 204, 204, 0, 0, 51, 51, 0, 0,
-    51, 51, 254, 220, 186, 152, 118, 84, 50, 16, 1, 35, 69, 103, 137, 171, 205, 239, 255,
-    255, 186, 152, 169, 135, 50, 16, 52, 86, 69, 103, 188, 222, 205, 239, 255, 238, 221,
-    204, 187, 170, 153, 136, 119, 102, 85, 68, 51, 34, 17, 0, 255, 254, 253, 252, 251,
-    250, 249, 248, 231, 214, 197, 180, 163, 146, 129, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 6, 149, 116, 214, 118, 216, 118, 218, 118, 220, 118, 222, 118, 26,
-    119, 86, 119, 117, 116, 206, 119, 33, 30, 197, 54, 0, 33, 31, 197, 54, 0, 33, 32,
-    197, 54, 0, 33, 33, 197, 54, 0, 250, 190, 192, 234, 189, 217, 214, 3, 32, 14, 33, 30,
-    197, 54, 48, 33, 187, 217, 175, 34, 54, 8, 24, 106, 250, 189, 217, 61, 32, 40, 33,
-    30, 197, 54, 192, 33, 52, 197, 42, 198, 112, 79, 126, 206, 255, 71, 203, 33, 203, 16,
-    203, 33, 203, 16, 203, 33, 203, 16, 203, 33, 203, 16, 33, 187, 217, 121, 34, 112, 24,
-    60, 250, 189, 217, 214, 2, 32, 15, 33, 31, 197, 54, 48, 33, 187, 217, 62, 128, 34,
-    54, 7, 24, 38, 33, 31, 197, 54, 208, 33, 54, 197, 42, 198, 136, 79, 126, 206, 255,
-    71, 203, 33, 203, 16, 203, 33, 203, 16, 203, 33, 203, 16, 203, 33, 203, 16, 33, 187,
-    217, 121, 34, 112, 33, 186, 217, 54, 0, 201, 232, 243, 33, 7, 197, 54, 0, 33, 189,
-    217, 70, 250, 27, 198, 203, 64, 40, 26, 203, 87, 40, 9, 33, 7, 197, 54, 1, 6, 2, 24,
-    35, 203, 95, 40, 31, 33, 7, 197, 54, 1, 6, 0, 24, 22, 203, 79, 40, 9, 33, 7, 197, 54,
-    1, 6, 3, 24, 9, 15, 48, 6, 33, 7, 197, 54, 1, 70, 250, 190, 192, 144, 40, 21, 250, 7,
-    197, 245, 51, 197, 51, 17, 185, 192, 213, 30, 2, 33, 101, 70, 205, 8, 0, 232, 4, 250,
-    7, 197, 183, 202, 106, 126, 248, 4, 125, 84, 248, 11, 34, 114, 33, 186, 192, 42, 79,
-    70, 248, 11, 42, 102, 111, 121, 34, 112, 248, 4, 125, 84, 248, 2, 34, 122, 50, 42,
-    95, 86, 33, 2, 0, 25, 229, 125, 248, 10, 119, 225, 124, 248, 9, 119, 33, 188, 192,
-    42, 79, 70, 248, 8, 42, 102, 111, 121, 34, 112, 250, 200, 192, 248, 10, 119, 250,
-    190, 192, 248, 1, 119, 248, 2, 42, 79, 70, 89, 80, 26, 248, 11, 34, 19, 26, 119, 248,
-    1, 94, 22, 0, 203, 35, 203, 18, 33, 196, 25, 25, 51, 51, 93, 84, 213, 26, 197, 248,
-    12, 102, 229, 51, 245, 51, 205, 65, 53, 225, 193, 248, 11, 42, 102, 111, 25, 93, 84,
-    105, 96, 123, 34, 114, 3, 3, 89, 80, 26, 248, 11, 34, 19, 26, 50, 43, 209, 213, 19,
-    26, 197, 102, 229, 51, 245, 51, 205, 65, 53, 225, 193, 248, 11, 42, 102, 111, 25, 93,
-    84, 123, 2, 3, 122, 2, 33, 189, 217, 78, 250, 190, 192, 248, 11, 119, 203, 65, 202,
-    113, 124, 33, 186, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203,
-    25, 203, 56, 203, 25, 250, 191, 192, 111, 7, 159, 103, 9, 93, 84, 203, 58, 203, 27,
-    203, 58, 203, 27, 203, 58, 203, 27, 248, 12, 115, 250, 192, 192, 111, 7, 159, 103, 9,
-    203, 60, 203, 29, 203, 60, 203, 29, 203, 60, 203, 29, 125, 60, 248, 10, 50, 43, 42,
-    95, 86, 26, 79, 19, 26, 71, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25,
-    203, 56, 203, 25, 248, 2, 121, 34, 112, 248, 11, 126, 183, 194, 199, 123, 250, 194,
-    192, 79, 7, 159, 71, 248, 2, 42, 102, 111, 9, 77, 68, 203, 56, 203, 25, 203, 56, 203,
-    25, 203, 56, 203, 25, 248, 11, 113, 248, 10, 42, 35, 150, 202, 180, 123, 248, 12,
-    126, 33, 50, 197, 150, 48, 64, 248, 11, 126, 33, 51, 197, 150, 48, 55, 33, 47, 197,
-    70, 33, 50, 197, 94, 22, 0, 248, 11, 126, 14, 0, 197, 213, 95, 81, 213, 205, 105, 53,
-    232, 4, 193, 123, 33, 48, 197, 134, 35, 95, 122, 142, 87, 248, 12, 110, 38, 0, 25,
-    93, 84, 197, 51, 213, 205, 7, 19, 232, 3, 123, 24, 2, 62, 15, 15, 48, 46, 248, 11,
-    126, 14, 0, 135, 203, 17, 135, 203, 17, 135, 203, 17, 33, 194, 192, 110, 245, 125, 7,
-    159, 103, 241, 149, 111, 121, 156, 103, 41, 41, 41, 41, 77, 68, 11, 248, 8, 42, 102,
-    111, 121, 34, 112, 24, 6, 248, 12, 52, 195, 42, 123, 248, 8, 42, 95, 86, 26, 79, 19,
-    26, 71, 33, 188, 192, 121, 34, 112, 195, 106, 126, 250, 193, 192, 79, 7, 159, 71,
-    248, 2, 42, 102, 111, 9, 77, 68, 203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203,
-    25, 248, 12, 70, 248, 10, 126, 144, 40, 115, 120, 33, 50, 197, 150, 48, 60, 121, 33,
-    51, 197, 150, 48, 53, 250, 47, 197, 248, 12, 119, 33, 50, 197, 110, 38, 0, 89, 22, 0,
-    197, 229, 213, 205, 105, 53, 232, 4, 193, 123, 33, 48, 197, 134, 35, 95, 122, 142,
-    87, 104, 38, 0, 25, 93, 84, 248, 12, 126, 245, 51, 213, 205, 7, 19, 232, 3, 24, 2,
-    30, 15, 203, 75, 40, 39, 12, 105, 38, 0, 41, 41, 41, 77, 68, 250, 193, 192, 95, 7,
-    159, 87, 121, 147, 111, 120, 154, 103, 41, 41, 41, 41, 35, 77, 68, 248, 8, 42, 102,
-    111, 121, 34, 112, 24, 3, 4, 24, 135, 248, 8, 42, 95, 86, 26, 79, 19, 26, 71, 33,
-    188, 192, 121, 34, 112, 195, 106, 126, 33, 188, 192, 42, 79, 70, 203, 56, 203, 25,
-    203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 193, 192, 111, 7, 159,
-    103, 9, 93, 84, 203, 58, 203, 27, 203, 58, 203, 27, 203, 58, 203, 27, 248, 10, 115,
-    250, 194, 192, 111, 7, 159, 103, 9, 203, 60, 203, 29, 203, 60, 203, 29, 203, 60, 203,
-    29, 125, 60, 248, 12, 119, 248, 2, 42, 95, 86, 26, 79, 19, 26, 71, 203, 56, 203, 25,
-    203, 56, 203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 248, 11, 126, 61, 194, 188,
-    125, 250, 192, 192, 111, 7, 159, 103, 9, 77, 203, 60, 203, 25, 203, 60, 203, 25, 203,
-    60, 203, 25, 248, 10, 70, 248, 12, 126, 144, 40, 114, 121, 33, 50, 197, 150, 48, 60,
-    120, 33, 51, 197, 150, 48, 53, 250, 47, 197, 248, 11, 119, 33, 50, 197, 110, 38, 0,
-    88, 22, 0, 197, 229, 213, 205, 105, 53, 232, 4, 193, 123, 33, 48, 197, 134, 35, 95,
-    122, 142, 87, 105, 38, 0, 25, 93, 84, 248, 11, 126, 245, 51, 213, 205, 7, 19, 232, 3,
-    24, 2, 30, 15, 203, 83, 40, 38, 105, 38, 0, 41, 41, 41, 77, 68, 250, 192, 192, 95, 7,
-    159, 87, 121, 147, 111, 120, 154, 103, 41, 41, 41, 41, 77, 68, 11, 248, 2, 42, 102,
-    111, 121, 34, 112, 24, 3, 4, 24, 136, 33, 52, 197, 42, 198, 240, 79, 126, 206, 255,
-    71, 203, 33, 203, 16, 203, 33, 203, 16, 203, 33, 203, 16, 203, 33, 203, 16, 248, 2,
-    42, 95, 86, 26, 248, 11, 34, 19, 26, 119, 248, 8, 121, 34, 112, 248, 8, 93, 84, 248,
-    11, 26, 19, 150, 35, 26, 158, 48, 11, 248, 8, 126, 248, 11, 50, 43, 126, 248, 12,
-    119, 17, 186, 192, 248, 11, 42, 18, 19, 126, 18, 195, 106, 126, 250, 191, 192, 111,
-    7, 159, 103, 9, 77, 203, 60, 203, 25, 203, 60, 203, 25, 203, 60, 203, 25, 248, 10,
-    70, 248, 12, 126, 144, 40, 115, 121, 33, 50, 197, 150, 48, 60, 120, 33, 51, 197, 150,
-    48, 53, 250, 47, 197, 248, 11, 119, 33, 50, 197, 94, 22, 0, 104, 38, 0, 197, 213,
-    229, 205, 105, 53, 232, 4, 193, 123, 33, 48, 197, 134, 35, 95, 122, 142, 87, 105, 38,
-    0, 25, 93, 84, 248, 11, 126, 245, 51, 213, 205, 7, 19, 232, 3, 24, 2, 30, 15, 203,
-    91, 40, 39, 38, 0, 105, 35, 41, 41, 41, 77, 68, 250, 191, 192, 111, 7, 159, 95, 121,
-    149, 111, 120, 155, 103, 41, 41, 41, 41, 35, 77, 68, 248, 2, 42, 102, 111, 121, 34,
-    112, 24, 3, 4, 24, 135, 248, 2, 42, 95, 86, 26, 79, 19, 26, 71, 203, 120, 40, 5, 17,
-    0, 0, 24, 2, 89, 80, 75, 66, 33, 186, 192, 121, 34, 112, 250, 186, 217, 183, 194,
-    100, 127, 33, 52, 218, 70, 33, 189, 217, 78, 248, 9, 120, 34, 121, 34, 17, 186, 192,
-    26, 34, 19, 26, 119, 105, 38, 0, 41, 17, 196, 25, 25, 126, 197, 51, 245, 51, 205, 65,
-    53, 225, 248, 11, 42, 102, 111, 25, 77, 68, 33, 186, 192, 121, 34, 112, 33, 188, 192,
-    42, 79, 70, 248, 10, 94, 22, 0, 203, 35, 203, 18, 33, 197, 25, 25, 93, 84, 26, 197,
-    248, 11, 102, 229, 51, 245, 51, 205, 65, 53, 225, 193, 107, 98, 9, 77, 68, 33, 188,
-    192, 121, 34, 112, 33, 187, 217, 42, 79, 70, 250, 189, 217, 61, 32, 28, 33, 187, 192,
-    58, 94, 87, 33, 187, 217, 42, 147, 126, 154, 48, 13, 33, 186, 217, 54, 1, 33, 186,
-    192, 121, 34, 112, 24, 104, 250, 189, 217, 214, 3, 32, 29, 33, 187, 192, 58, 94, 87,
-    33, 187, 217, 123, 150, 35, 122, 158, 48, 13, 33, 186, 217, 54, 1, 33, 186, 192, 121,
-    34, 112, 24, 68, 250, 189, 217, 183, 32, 28, 33, 189, 192, 58, 94, 87, 33, 187, 217,
-    42, 147, 126, 154, 48, 13, 33, 186, 217, 54, 1, 33, 188, 192, 121, 34, 112, 24, 34,
-    250, 189, 217, 214, 2, 32, 27, 33, 189, 192, 58, 94, 87, 33, 187, 217, 123, 150, 35,
-    122, 158, 48, 11, 33, 186, 217, 54, 1, 33, 188, 192, 121, 34, 112, 250, 31, 218, 230,
-    1, 71, 14, 0, 120, 61, 177, 194, 251, 127, 175, 245, 51, 17, 186, 192, 213, 17, 191,
-    192, 213, 30, 4, 33, 178, 94, 205, 8, 0, 232, 5, 123, 183, 32, 112, 175, 245, 51, 30,
-    2, 33, 135, 72, 205, 8, 0, 51, 122, 179, 40, 16, 33, 47, 0, 25, 126, 183, 40, 8, 33,
-    9, 197, 123, 34, 114, 24, 80, 250, 27, 198, 203, 103, 40, 73, 250, 28, 198, 203, 103,
-    32, 66, 122, 179, 32, 13, 33, 8, 1, 229, 30, 2, 33, 160, 71, 205, 8, 0, 225, 122,
-    179, 40, 45, 33, 47, 0, 25, 126, 183, 32, 37, 33, 37, 0, 25, 126, 183, 40, 29, 35,
-    78, 35, 70, 17, 0, 0, 213, 38, 1, 229, 51, 17, 0, 0, 213, 197, 245, 51, 30, 3, 33,
-    144, 93, 205, 8, 0, 232, 8, 232, 13, 201, 255, 255, 175, 33, 28, 197, 34, 119, 175,
-    33, 26, 197, 34, 119, 33, 31, 197, 54, 0, 33, 30, 197, 54, 0, 30, 5, 33, 30, 64, 195,
-    8, 0, 33, 33, 197, 54, 0, 33, 32, 197, 54, 0, 33, 34, 197, 54, 3, 201, 248, 3, 58,
-    94, 183, 200, 87, 38, 4, 123, 230, 3, 40, 1, 61, 203, 63, 203, 29, 203, 63, 203, 29,
-    203, 59, 203, 59, 37, 32, 235, 93, 21, 32, 229, 201, 248, 3, 58, 94, 183, 200, 87,
-    38, 4, 123, 230, 3, 254, 3, 40, 1, 60, 203, 63, 203, 29, 203, 63, 203, 29, 203, 59,
-    203, 59, 37, 32, 233, 93, 21, 32, 227, 201, 62, 4, 248, 2, 150, 48, 2, 54, 4, 250,
-    30, 218, 183, 32, 52, 250, 72, 198, 248, 2, 102, 229, 51, 245, 51, 205, 46, 64, 225,
-    123, 224, 71, 250, 73, 198, 248, 2, 102, 229, 51, 245, 51, 205, 46, 64, 225, 123,
-    224, 72, 250, 74, 198, 248, 2, 102, 229, 51, 245, 51, 205, 46, 64, 225, 123, 224, 73,
-    201, 250, 72, 198, 248, 2, 102, 229, 51, 245, 51, 205, 81, 64, 225, 123, 224, 71,
-    250, 73, 198, 248, 2, 102, 229, 51, 245, 51, 205, 81, 64, 225, 123, 224, 72, 250, 74,
-    198, 248, 2, 102, 229, 51, 245, 51, 205, 81, 64, 225, 123, 224, 73, 201, 0, 1, 3, 7,
-    15, 31, 63, 250, 239, 64, 234, 13, 198, 33, 14, 198, 54, 5, 33, 12, 198, 54, 0, 62,
-    5, 245, 51, 205, 118, 64, 51, 201, 250, 14, 198, 183, 200, 33, 15, 198, 54, 0, 33,
-    16, 198, 54, 0, 33, 12, 198, 54, 1, 33, 14, 198, 62, 5, 119, 245, 51, 205, 118, 64,
-    51, 201, 250, 14, 198, 214, 5, 200, 24, 0, 33, 15, 198, 54, 0, 33, 16, 198, 54, 1,
-    33, 12, 198, 54, 1, 33, 14, 198, 54, 0, 175, 245, 51, 205, 118, 64, 51, 201, 250, 12,
-    198, 183, 200, 33, 15, 198, 78, 52, 121, 33, 13, 198, 166, 192, 250, 16, 198, 183,
-    32, 21, 33, 14, 198, 126, 183, 40, 1, 53, 250, 14, 198, 183, 32, 28, 33, 12, 198, 54,
-    0, 24, 21, 33, 14, 198, 126, 214, 5, 48, 1, 52, 250, 14, 198, 214, 5, 32, 5, 33, 12,
-    198, 54, 0, 250, 14, 198, 245, 51, 205, 118, 64, 51, 201, 250, 14, 198, 245, 51, 205,
-    118, 64, 51, 201, 1, 237, 64, 248, 6, 110, 38, 0, 9, 77, 68, 10, 234, 13, 198, 201,
-    30, 5, 33, 13, 65, 205, 8, 0, 250, 12, 198, 183, 200, 205, 53, 14, 30, 5, 33, 82, 65,
-    205, 8, 0, 24, 238, 30, 5, 33, 46, 65, 205, 8, 0, 250, 12, 198, 183, 200, 205, 53,
-    14, 30, 5, 33, 82, 65, 205, 8, 0, 24, 238, 197, 243, 240, 144, 245, 248, 6, 126, 79,
-    203, 63, 198, 4, 234, 0, 32, 38, 64, 203, 65, 40, 2, 203, 236, 175, 111, 22, 160, 95,
-    62, 10, 234, 0, 0, 121, 234, 0, 64, 26, 71, 62, 0, 234, 0, 0, 62, 169, 234, 170, 10,
-    62, 86, 234, 85, 5, 62, 160, 234, 170, 10, 120, 119, 6, 0, 190, 40, 11, 229, 225,
-    229, 225, 5, 32, 246, 30, 0, 24, 9, 19, 35, 62, 192, 186, 32, 200, 30, 1, 62, 240,
-    234, 0, 64, 62, 10, 234, 0, 0, 241, 234, 0, 32, 251, 193, 201, 248, 6, 70, 248, 0,
-    84, 93, 33, 152, 255, 57, 249, 213, 229, 14, 104, 17, 234, 65, 247, 120, 193, 30, 0,
-    214, 1, 56, 19, 230, 3, 245, 96, 105, 51, 231, 59, 123, 183, 40, 6, 241, 214, 1, 48,
-    241, 245, 241, 225, 249, 201, 243, 240, 144, 245, 62, 0, 234, 0, 0, 62, 4, 234, 0,
-    32, 62, 240, 234, 0, 64, 62, 169, 234, 170, 10, 62, 86, 234, 85, 5, 62, 128, 234,
-    170, 10, 62, 169, 234, 170, 10, 62, 86, 234, 85, 5, 62, 48, 234, 0, 64, 17, 0, 0,
-    250, 0, 64, 254, 255, 40, 14, 229, 225, 229, 225, 29, 32, 242, 21, 32, 239, 30, 0,
-    24, 2, 30, 1, 62, 10, 234, 0, 0, 241, 234, 0, 32, 251, 201, 248, 0, 84, 93, 33, 170,
-    255, 57, 249, 213, 229, 14, 86, 17, 132, 66, 247, 225, 231, 225, 249, 201, 17, 5, 0,
-    213, 17, 0, 0, 213, 17, 22, 198, 213, 205, 176, 55, 232, 6, 33, 28, 198, 54, 0, 33,
-    27, 198, 54, 0, 33, 29, 198, 54, 0, 33, 22, 198, 54, 1, 201, 243, 17, 36, 27, 213,
-    205, 138, 54, 225, 17, 124, 23, 213, 205, 138, 54, 225, 17, 248, 23, 213, 205, 138,
-    54, 225, 240, 64, 230, 239, 224, 64, 251, 201, 33, 61, 198, 54, 255, 33, 241, 199,
-    54, 255, 175, 33, 242, 199, 34, 119, 62, 128, 224, 38, 62, 255, 224, 37, 62, 119,
-    224, 36, 175, 224, 33, 175, 224, 28, 175, 224, 23, 175, 224, 18, 62, 192, 224, 35,
-    62, 192, 224, 25, 62, 192, 224, 20, 62, 255, 224, 37, 33, 62, 198, 54, 0, 33, 63,
-    198, 54, 0, 33, 68, 198, 54, 0, 33, 69, 198, 54, 0, 33, 70, 198, 54, 0, 33, 71, 198,
-    54, 0, 201, 248, 6, 126, 183, 40, 24, 14, 0, 121, 214, 4, 48, 34, 6, 0, 105, 96, 41,
-    41, 9, 17, 41, 198, 25, 175, 34, 119, 12, 24, 234, 17, 20, 0, 213, 17, 0, 0, 213, 17,
-    41, 198, 213, 205, 176, 55, 232, 6, 243, 33, 40, 198, 54, 0, 33, 39, 198, 54, 0, 251,
-    201, 250, 39, 198, 33, 40, 198, 150, 40, 30, 243, 33, 40, 198, 52, 126, 230, 3, 119,
-    198, 35, 79, 62, 198, 206, 0, 71, 10, 79, 251, 121, 230, 4, 71, 121, 230, 240, 176,
-    95, 201, 30, 0, 201, 248, 2, 126, 234, 69, 198, 183, 200, 175, 224, 33, 175, 224, 28,
-    175, 224, 23, 175, 224, 18, 62, 192, 224, 35, 62, 192, 224, 25, 62, 192, 224, 20, 62,
-    255, 224, 37, 201, 33, 74, 198, 54, 27, 33, 72, 198, 54, 27, 33, 73, 198, 54, 135,
-    201, 17, 18, 0, 213, 17, 59, 68, 213, 17, 139, 198, 213, 205, 109, 55, 232, 6, 201,
-    0, 15, 2, 0, 2, 0, 0, 31, 1, 2, 2, 0, 0, 0, 0, 4, 16, 0, 248, 9, 58, 183, 200, 87,
-    58, 95, 58, 110, 103, 197, 21, 40, 28, 68, 77, 62, 32, 133, 111, 140, 149, 103, 229,
-    213, 240, 65, 230, 2, 32, 250, 42, 2, 3, 29, 32, 244, 209, 225, 21, 32, 228, 229,
-    248, 14, 86, 225, 240, 65, 230, 2, 32, 250, 122, 34, 29, 32, 245, 193, 201, 33, 245,
-    199, 54, 0, 33, 22, 200, 54, 0, 1, 246, 199, 33, 23, 200, 121, 34, 112, 33, 25, 200,
-    54, 0, 33, 26, 200, 54, 0, 33, 27, 200, 121, 34, 112, 33, 29, 200, 54, 0, 201, 59,
-    248, 9, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 7, 42, 95, 86, 33, 8, 0,
-    25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 240, 255, 248, 0, 119, 126, 203,
-    159, 245, 51, 205, 187, 1, 51, 197, 248, 13, 126, 245, 51, 30, 4, 33, 53, 73, 205, 8,
-    0, 51, 193, 22, 0, 123, 2, 3, 122, 2, 248, 0, 126, 245, 51, 205, 187, 1, 225, 201,
-    59, 248, 9, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 7, 42, 95, 86, 33,
-    8, 0, 25, 42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 240, 255, 248, 0, 119,
-    126, 203, 159, 245, 51, 205, 187, 1, 51, 197, 248, 15, 58, 87, 58, 95, 213, 126, 245,
-    51, 30, 4, 33, 87, 73, 205, 8, 0, 232, 3, 193, 22, 0, 123, 2, 3, 122, 2, 248, 0, 126,
-    245, 51, 205, 187, 1, 225, 201, 248, 8, 126, 245, 51, 30, 4, 33, 145, 78, 205, 8, 0,
-    51, 201, 232, 252, 248, 10, 42, 95, 42, 87, 42, 79, 70, 203, 33, 203, 16, 33, 8, 0,
-    25, 51, 51, 229, 248, 13, 203, 126, 40, 10, 209, 213, 26, 111, 19, 26, 103, 9, 24, 4,
-    33, 152, 203, 9, 125, 76, 248, 2, 34, 113, 248, 14, 42, 79, 70, 203, 33, 203, 16,
-    203, 126, 40, 11, 209, 213, 26, 111, 19, 26, 103, 9, 77, 24, 5, 33, 152, 203, 9, 77,
-    68, 248, 17, 203, 126, 40, 4, 43, 175, 34, 119, 197, 248, 20, 42, 95, 86, 213, 248,
-    20, 42, 95, 86, 213, 248, 26, 126, 245, 51, 30, 4, 33, 194, 78, 205, 8, 0, 232, 7,
-    75, 6, 0, 248, 2, 42, 102, 111, 121, 34, 112, 232, 4, 201, 232, 252, 248, 10, 42, 95,
-    42, 87, 42, 79, 70, 203, 33, 203, 16, 33, 8, 0, 25, 229, 125, 248, 4, 119, 225, 124,
-    248, 3, 119, 248, 13, 203, 126, 40, 13, 248, 2, 42, 95, 86, 26, 111, 19, 26, 103, 9,
-    24, 4, 33, 152, 203, 9, 125, 76, 248, 0, 34, 113, 248, 14, 42, 79, 70, 203, 33, 203,
-    16, 203, 126, 40, 14, 248, 2, 42, 95, 86, 26, 111, 19, 26, 103, 9, 77, 24, 5, 33,
-    152, 203, 9, 77, 68, 209, 213, 26, 248, 2, 34, 19, 26, 119, 10, 79, 33, 196, 24, 6,
-    0, 9, 78, 248, 16, 94, 62, 7, 147, 71, 4, 24, 2, 203, 41, 5, 32, 251, 121, 7, 159,
-    71, 213, 197, 248, 6, 42, 79, 70, 197, 205, 105, 53, 232, 4, 75, 66, 209, 28, 24, 4,
-    203, 40, 203, 25, 29, 32, 249, 225, 229, 121, 34, 112, 232, 4, 201, 232, 252, 248,
-    10, 42, 95, 42, 87, 42, 79, 70, 203, 33, 203, 16, 33, 8, 0, 25, 229, 125, 248, 4,
-    119, 225, 124, 248, 3, 119, 248, 13, 203, 126, 40, 13, 248, 2, 42, 95, 86, 26, 111,
-    19, 26, 103, 9, 24, 4, 33, 152, 203, 9, 125, 76, 248, 0, 34, 113, 248, 14, 42, 79,
-    70, 203, 33, 203, 16, 203, 126, 40, 14, 248, 2, 42, 95, 86, 26, 111, 19, 26, 103, 9,
-    77, 24, 5, 33, 152, 203, 9, 77, 68, 209, 213, 26, 248, 2, 34, 19, 26, 119, 10, 198,
-    64, 79, 33, 196, 24, 6, 0, 9, 78, 248, 16, 94, 62, 7, 147, 71, 4, 24, 2, 203, 41, 5,
-    32, 251, 121, 7, 159, 71, 213, 197, 248, 6, 42, 79, 70, 197, 205, 105, 53, 232, 4,
-    75, 66, 209, 28, 24, 4, 203, 40, 203, 25, 29, 32, 249, 225, 229, 121, 34, 112, 232,
-    4, 201, 59, 59, 33, 70, 198, 54, 0, 248, 11, 42, 79, 58, 43, 71, 126, 248, 0, 119,
-    89, 80, 42, 119, 250, 61, 198, 248, 0, 150, 32, 12, 33, 66, 198, 126, 145, 32, 5, 35,
-    126, 144, 40, 23, 33, 61, 198, 54, 255, 33, 66, 198, 123, 34, 114, 33, 64, 198, 123,
-    34, 114, 248, 1, 126, 234, 61, 198, 51, 51, 201, 33, 61, 198, 54, 255, 175, 224, 33,
-    175, 224, 28, 175, 224, 23, 175, 224, 18, 62, 192, 224, 35, 62, 192, 224, 25, 62,
-    192, 224, 20, 62, 255, 224, 37, 201, 248, 8, 126, 234, 70, 198, 234, 222, 217, 79,
-    15, 48, 7, 175, 224, 18, 62, 192, 224, 20, 203, 73, 40, 7, 175, 224, 23, 62, 192,
-    224, 25, 203, 81, 40, 3, 175, 224, 28, 203, 89, 40, 7, 175, 224, 33, 62, 192, 224,
-    35, 62, 255, 224, 37, 201, 1, 41, 198, 248, 8, 126, 230, 3, 111, 38, 0, 93, 84, 41,
-    41, 25, 9, 77, 68, 89, 80, 19, 19, 248, 9, 42, 18, 3, 3, 3, 42, 95, 86, 123, 2, 3,
-    122, 2, 201, 248, 8, 126, 245, 51, 205, 252, 5, 51, 201, 248, 8, 126, 224, 36, 201,
-    59, 59, 248, 14, 126, 248, 0, 119, 248, 13, 58, 43, 94, 35, 86, 43, 43, 78, 248, 1,
-    50, 126, 33, 71, 198, 150, 56, 84, 33, 241, 199, 54, 255, 248, 0, 126, 234, 71, 198,
-    250, 63, 198, 71, 15, 48, 7, 175, 224, 18, 62, 192, 224, 20, 203, 72, 40, 7, 175,
-    224, 23, 62, 192, 224, 25, 203, 80, 40, 3, 175, 224, 28, 203, 88, 40, 7, 175, 224,
-    33, 62, 192, 224, 35, 62, 255, 224, 37, 248, 1, 126, 234, 63, 198, 33, 241, 199, 54,
-    255, 33, 244, 199, 54, 0, 33, 242, 199, 123, 34, 114, 33, 241, 199, 113, 51, 51, 201,
-    232, 244, 248, 18, 126, 248, 0, 119, 248, 19, 126, 248, 1, 34, 193, 197, 3, 3, 10,
-    119, 248, 21, 126, 248, 11, 119, 126, 230, 1, 248, 3, 119, 248, 11, 126, 230, 2, 248,
-    4, 119, 248, 11, 126, 230, 4, 248, 5, 119, 209, 213, 26, 248, 10, 34, 19, 26, 50,
-    126, 248, 6, 119, 248, 11, 126, 248, 7, 119, 248, 4, 126, 183, 40, 10, 17, 160, 223,
-    248, 10, 123, 34, 114, 24, 8, 17, 75, 198, 248, 10, 123, 34, 114, 248, 10, 42, 79,
-    70, 248, 20, 126, 248, 8, 50, 43, 126, 248, 9, 50, 43, 126, 248, 10, 34, 54, 0, 248,
-    8, 126, 183, 202, 198, 73, 229, 203, 70, 225, 202, 180, 73, 248, 9, 126, 248, 6, 119,
-    248, 10, 126, 248, 7, 119, 250, 30, 200, 183, 32, 7, 62, 1, 248, 11, 150, 48, 27, 89,
-    80, 197, 248, 4, 126, 245, 51, 33, 8, 0, 229, 248, 11, 42, 102, 111, 229, 213, 205,
-    35, 19, 232, 7, 193, 24, 115, 248, 6, 42, 95, 86, 248, 11, 126, 183, 40, 8, 248, 11,
-    126, 61, 40, 68, 24, 96, 248, 2, 126, 245, 51, 213, 205, 7, 19, 232, 3, 248, 6, 115,
-    42, 119, 248, 4, 126, 183, 40, 18, 35, 35, 17, 72, 198, 126, 18, 248, 3, 126, 183,
-    40, 5, 248, 6, 126, 224, 71, 248, 5, 126, 183, 40, 50, 35, 35, 17, 73, 198, 126, 18,
-    248, 3, 126, 183, 40, 37, 248, 7, 126, 224, 72, 24, 30, 248, 5, 126, 183, 40, 24,
-    248, 2, 126, 245, 51, 213, 205, 7, 19, 232, 3, 33, 74, 198, 115, 248, 3, 126, 183,
-    40, 3, 123, 224, 73, 248, 9, 42, 95, 86, 33, 8, 0, 25, 229, 125, 248, 11, 119, 225,
-    124, 248, 10, 119, 33, 8, 0, 9, 77, 68, 248, 11, 52, 248, 8, 126, 203, 63, 119, 195,
-    236, 72, 248, 9, 42, 79, 70, 225, 229, 121, 34, 112, 232, 12, 201, 248, 9, 42, 79,
-    70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 6, 42, 95, 86, 33, 8, 0, 25, 42, 102,
-    111, 9, 24, 4, 33, 152, 203, 9, 77, 68, 33, 6, 0, 9, 86, 33, 4, 0, 9, 126, 213, 51,
-    245, 51, 197, 248, 12, 126, 245, 51, 30, 4, 33, 176, 79, 205, 8, 0, 232, 5, 201, 232,
-    249, 248, 15, 78, 6, 0, 105, 96, 41, 41, 9, 41, 9, 41, 9, 1, 88, 199, 9, 77, 124,
-    248, 3, 113, 35, 119, 33, 38, 197, 70, 33, 39, 197, 42, 102, 111, 17, 29, 0, 25, 93,
-    84, 248, 0, 213, 125, 84, 248, 7, 34, 122, 50, 209, 42, 79, 126, 197, 51, 213, 71,
-    197, 205, 224, 18, 232, 5, 248, 17, 42, 95, 86, 248, 3, 42, 79, 70, 248, 16, 126,
-    245, 51, 33, 23, 0, 229, 213, 197, 205, 35, 19, 232, 7, 248, 3, 42, 95, 86, 33, 4, 0,
-    25, 77, 68, 248, 5, 42, 95, 58, 87, 26, 94, 35, 102, 107, 35, 245, 42, 102, 111, 241,
-    93, 84, 197, 33, 56, 197, 102, 229, 51, 245, 51, 213, 205, 91, 19, 232, 6, 75, 248,
-    3, 42, 95, 86, 33, 8, 0, 25, 93, 84, 121, 33, 56, 197, 150, 48, 9, 33, 128, 197, 6,
-    0, 9, 126, 24, 1, 175, 18, 232, 7, 201, 33, 0, 96, 54, 0, 54, 1, 201, 59, 59, 248,
-    10, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 15, 248, 8, 42, 95, 86, 33, 8, 0, 25,
-    42, 102, 111, 9, 24, 4, 33, 152, 203, 9, 125, 76, 248, 0, 34, 113, 248, 12, 126, 230,
-    3, 198, 8, 245, 33, 32, 200, 126, 230, 240, 79, 241, 245, 230, 15, 177, 79, 241, 113,
-    33, 0, 64, 113, 33, 0, 160, 78, 6, 0, 214, 11, 32, 20, 33, 32, 200, 126, 230, 240,
-    246, 12, 119, 234, 0, 64, 250, 0, 160, 15, 48, 2, 203, 192, 225, 229, 121, 34, 112,
-    51, 51, 201, 59, 248, 9, 42, 79, 70, 203, 33, 203, 16, 203, 126, 40, 16, 248, 7, 42,
-    95, 86, 33, 8, 0, 25, 42, 102, 111, 9, 77, 24, 5, 33, 152, 203, 9, 77, 105, 42, 79,
-    70, 248, 11, 126, 230, 3, 198, 8, 248, 0, 119, 250, 32, 200, 230, 240, 95, 248, 0,
-    126, 230, 15, 179, 234, 32, 200, 234, 0, 64, 33, 0, 160, 113, 248, 0, 126, 214, 11,
-    32, 24, 33, 32, 200, 126, 230, 240, 246, 12, 119, 234, 0, 64, 33, 0, 160, 126, 230,
-    14, 95, 120, 230, 1, 179, 119, 51, 201, 248, 8, 78, 33, 32, 200, 126, 230, 240, 246,
-    12, 119, 234, 0, 64, 121, 183, 40, 8, 33, 0, 160, 126, 230, 191, 119, 201, 33, 0,
-    160, 126, 246, 64, 119, 201, 17, 38, 197, 33, 126, 197, 42, 79, 70, 33, 3, 0, 229,
-    213, 197, 205, 109, 55, 232, 6, 17, 186, 192, 33, 126, 197, 42, 79, 70, 3, 3, 3, 33,
-    4, 0, 229, 213, 197, 205, 109, 55, 232, 6, 33, 126, 197, 42, 79, 70, 33, 7, 0, 9, 1,
-    190, 192, 10, 119, 33, 126, 197, 126, 198, 8, 34, 126, 206, 0, 119, 201, 33, 146,
-    203, 54, 2, 33, 147, 203, 54, 3, 33, 148, 203, 54, 1, 33, 126, 197, 42, 95, 86, 123,
-    106, 234, 149, 203, 125, 234, 150, 203, 19, 19, 19, 1, 4, 0, 197, 213, 17, 186, 192,
-    213, 205, 109, 55, 232, 6, 1, 190, 192, 33, 126, 197, 42, 95, 86, 33, 7, 0, 25, 126,
-    2, 201, 33, 126, 197, 126, 198, 248, 34, 126, 206, 255, 119, 195, 1, 76, 33, 126,
-    197, 54, 62, 35, 54, 197, 195, 1, 76, 33, 126, 197, 54, 62, 35, 54, 197, 201, 197,
-    248, 8, 42, 95, 42, 87, 42, 79, 70, 197, 122, 135, 56, 5, 205, 155, 76, 24, 22, 31,
-    47, 87, 123, 47, 95, 19, 33, 84, 202, 126, 183, 40, 1, 53, 62, 45, 2, 3, 205, 155,
-    76, 96, 105, 209, 122, 47, 87, 123, 47, 95, 19, 25, 84, 93, 193, 201, 197, 33, 87,
-    202, 175, 50, 50, 119, 6, 16, 203, 35, 203, 18, 126, 143, 39, 34, 126, 143, 39, 34,
-    126, 143, 39, 50, 43, 5, 32, 236, 193, 250, 84, 202, 214, 5, 56, 15, 40, 13, 87, 62,
-    48, 2, 3, 21, 32, 251, 62, 5, 234, 84, 202, 250, 84, 202, 183, 40, 2, 62, 1, 87, 30,
-    48, 33, 87, 202, 58, 230, 15, 130, 40, 17, 146, 131, 22, 1, 2, 3, 250, 84, 202, 183,
-    40, 5, 254, 5, 48, 1, 11, 126, 203, 55, 230, 15, 130, 40, 17, 146, 131, 22, 1, 2, 3,
-    250, 84, 202, 183, 40, 5, 254, 4, 48, 1, 11, 58, 230, 15, 130, 40, 17, 146, 131, 22,
-    1, 2, 3, 250, 84, 202, 183, 40, 5, 254, 3, 48, 1, 11, 126, 203, 55, 230, 15, 130, 40,
-    15, 146, 131, 2, 3, 250, 84, 202, 183, 40, 5, 254, 2, 48, 1, 11, 126, 230, 15, 131,
-    2, 3, 175, 2, 201, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
-    19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39,
-    40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60,
-    61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81,
-    82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101,
-    102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115, 116, 117, 118,
-    119, 120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135,
-    136, 137, 138, 139, 140, 141, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152,
-    153, 154, 155, 156, 157, 158, 159, 160, 161, 162, 163, 164, 165, 166, 167, 168, 169,
-    170, 171, 172, 173, 174, 175, 176, 177, 178, 179, 180, 181, 182, 183, 184, 185, 186,
-    187, 188, 189, 190, 191, 192, 193, 194, 195, 196, 197, 198, 199, 200, 201, 202, 203,
-    204, 205, 206, 207, 208, 209, 210, 211, 212, 213, 214, 215, 216, 217, 218, 219, 220,
-    221, 222, 223, 224, 225, 226, 227, 228, 229, 230, 231, 232, 233, 234, 235, 236, 237,
-    238, 239, 240, 241, 242, 243, 244, 245, 246, 247, 248, 249, 250, 251, 252, 253, 254,
-    255, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
-    22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42,
-    43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63,
-    64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84,
-    85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 102, 103, 20,
-    18, 1, 243, 75, 0, 0, 0, 5, 69, 77, 0, 0, 0, 20, 18, 1, 117, 98, 0, 0, 0, 5, 69, 77,
-    0, 0, 0, 0, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 0, 4, 5, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 6, 7, 2, 8, 9, 10, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, 2, 11, 12, 2, 2, 13, 14, 2, 15, 16, 17, 18, 19, 20, 21, 22, 2, 23, 24, 25, 2, 26,
-    27, 2, 2, 28, 29, 2, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 2, 42, 43, 2, 2,
-    44, 45, 2, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 2, 58, 59, 2, 2, 60, 61,
-    2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 62, 63, 2, 2, 64, 65, 2, 66, 2, 2, 2, 2, 2,
-    2, 2, 2, 2, 2, 2, 2, 67, 68, 2, 2, 69, 70, 71, 72, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2,
-    2, 2, 73, 74, 75, 76, 0, 77, 78, 79, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80, 80,
-    81, 82, 83, 0, 0, 84, 85, 86, 0, 0, 0, 0, 0, 87, 88, 0, 0, 0, 0, 0, 89, 90, 91, 0, 0,
-    92, 93, 94, 0, 0, 0, 95, 96, 2, 97, 98, 99, 0, 0, 0, 100, 101, 102, 0, 103, 104, 105,
-    0, 0, 0, 106, 107, 108, 109, 110, 111, 112, 113, 0, 0, 114, 115, 116, 117, 118, 119,
-    0, 0, 0, 120, 121, 122, 123, 2, 2, 124, 125, 126, 127, 0, 0, 0, 139, 140, 141, 0, 0,
-    0, 0, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 0, 0, 0, 0, 152, 153, 0, 0,
-    154, 155, 2, 143, 156, 157, 158, 159, 160, 161, 150, 162, 163, 164, 0, 0, 165, 0, 0,
-    0, 166, 167, 2, 143, 168, 169, 170, 171, 172, 168, 150, 173, 174, 175, 0, 0, 176,
-    177, 0, 0, 178, 179, 2, 180, 181, 182, 183, 184, 185, 186, 187, 188, 189, 190, 0, 0,
-    191, 20, 18, 4, 35, 105, 0, 0, 0, 5, 201, 78, 0, 0, 0, 0, 1, 1, 2, 1, 1, 1, 1, 1, 1,
-    1, 1, 1, 3, 1, 1, 1, 1, 4, 1, 1, 1, 1, 5, 6, 1, 1, 1, 1, 1, 1, 1, 7, 8, 9, 10, 11,
-    12, 13, 14, 1, 1, 1, 15, 1, 1, 1, 1, 1, 1, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 1,
-    1, 1, 26, 1, 1, 1, 1, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 35, 1, 1, 1, 38, 1,
-    1, 1, 39, 40, 41, 35, 35, 42, 35, 43, 44, 45, 46, 35, 35, 1, 1, 1, 38, 1, 1, 47, 48,
-    35, 35, 35, 35, 35, 49, 50, 51, 52, 53, 35, 35, 1, 1, 1, 38, 54, 55, 35, 35, 35, 35,
-    35, 35, 35, 56, 57, 58, 59, 35, 35, 35, 1, 1, 1, 38, 60, 61, 62, 63, 35, 35, 35, 35,
-    35, 35, 35, 64, 65, 35, 35, 35, 1, 1, 1, 66, 67, 68, 1, 69, 70, 71, 72, 35, 35, 35,
-    35, 51, 73, 35, 35, 35, 1, 1, 1, 74, 75, 76, 1, 77, 78, 79, 72, 35, 35, 35, 35, 35,
-    35, 35, 35, 35, 1, 1, 1, 80, 81, 82, 83, 84, 85, 86, 87, 88, 35, 35, 35, 35, 35, 35,
-    35, 35, 1, 1, 1, 89, 90, 91, 92, 93, 94, 95, 96, 97, 35, 35, 35, 35, 35, 35, 35, 35,
-    1, 1, 1, 98, 99, 100, 101, 102, 103, 104, 105, 35, 35, 35, 35, 35, 35, 35, 35, 35,
-    106, 107, 108, 109, 1, 1, 110, 111, 112, 113, 114, 35, 115, 116, 117, 118, 119, 35,
-    35, 35, 120, 121, 122, 123, 124, 1, 125, 126, 127, 35, 134, 135, 136, 137, 138, 139,
-    140, 141, 35, 35, 35, 35, 142, 143, 144, 145, 146, 147, 148, 149, 150, 151, 152, 153,
-    154, 155, 156, 157, 158, 159, 160, 161, 162, 163, 164, 165, 166, 167, 168, 35, 169,
-    170, 171, 172, 173, 174, 35, 35, 25, 175, 176, 177, 35, 178, 179, 180, 181, 182, 183,
-    184, 185, 186, 187, 188, 189, 35, 35, 35, 190, 191, 20, 18, 3, 120, 95, 0, 0, 0, 5,
-    63, 80, 0, 0, 0, 0, 0, 62, 62, 193, 193, 206, 142, 204, 132, 196, 132, 248, 248, 0,
-    0, 63, 0, 127, 63, 192, 64, 223, 95, 240, 80, 208, 80, 240, 80, 240, 80, 255, 0, 255,
-    255, 0, 0, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 252, 0, 254, 252, 3, 2, 251, 250, 15,
-    10, 11, 10, 15, 10, 15, 10, 240, 80, 240, 80, 240, 80, 240, 80, 240, 80, 240, 80,
-    240, 80, 240, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 10, 15, 10, 15,
-    10, 15, 10, 15, 10, 15, 10, 15, 10, 15, 10, 208, 112, 240, 80, 208, 112, 240, 80,
-    223, 127, 192, 127, 127, 63, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0, 255, 255,
-    255, 255, 0, 11, 14, 15, 10, 11, 14, 15, 10, 251, 254, 3, 254, 254, 252, 252, 0, 255,
-    228, 0, 255, 208, 224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 37, 18, 1, 85, 89, 175, 5, 1, 83, 129, 255, 20, 0, 1, 255, 255, 13, 255,
-    255, 0, 81, 131, 3, 33, 7, 198, 13, 87, 3, 0, 20, 18, 0, 0, 0, 0, 0, 0, 5, 104, 92,
-    5, 167, 81, 5, 91, 82, 5, 85, 82, 5, 88, 82, 5, 195, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 18, 1, 85, 90, 14, 5,
-    1, 83, 129, 255, 20, 0, 1, 255, 255, 13, 255, 255, 0, 81, 131, 3, 33, 1, 198, 13, 87,
-    3, 0, 20, 18, 5, 0, 0, 0, 0, 0, 5, 104, 92, 5, 173, 78, 5, 28, 84, 5, 85, 82, 5, 88,
-    82, 5, 132, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 37, 18, 1, 85, 90, 51, 5, 1, 83, 129, 144, 85, 120, 247, 1, 2, 83,
-    130, 96, 20, 0, 1, 255, 255, 13, 255, 255, 0, 81, 131, 3, 33, 1, 198, 13, 87, 3, 0,
-    20, 18, 5, 0, 0, 0, 0, 0, 5, 221, 92, 5, 187, 78, 5, 221, 85, 5, 85, 82, 5, 88, 82,
-    5, 69, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 37, 18, 1, 85, 90, 58, 5, 1, 83, 129, 255, 20, 0, 1, 255, 255, 13,
-    255, 255, 0, 81, 131, 3, 33, 7, 198, 13, 87, 3, 0, 20, 18, 0, 0, 0, 0, 0, 0, 5, 104,
-    92, 5, 49, 80, 5, 166, 87, 5, 85, 82, 5, 88, 82, 5, 14, 89, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 1,
-    48, 217, 164, 34, 25, 0, 217, 166, 34, 41, 0, 217, 170, 34, 15, 160, 217, 168, 34, 0,
-    152, 217, 172, 34, 0, 228, 217, 174, 34, 0, 208, 217, 176, 34, 64, 0, 217, 178, 34,
-    7, 0, 217, 180, 34, 2, 0, 217, 182, 34, 78, 32, 217, 184, 34, 0, 16, 218, 52, 34, 0,
-    8, 217, 190, 34, 0, 0, 218, 30, 11, 0, 64, 0, 77, 97, 100, 101, 32, 98, 121, 10, 82,
-    65, 80, 72, 65, 69, 76, 32, 65, 77, 79, 82, 73, 77, 0, 71, 3, 1, 4, 20, 0, 0, 69,
-    255, 14, 0, 65, 255, 0, 68, 7, 1, 69, 254, 18, 0, 68, 3, 1, 85, 89, 233, 5, 1, 83,
-    129, 255, 0, 18, 4, 33, 1, 198, 13, 87, 1, 20, 0, 0, 255, 252, 20, 8, 128, 255, 253,
-    20, 8, 0, 255, 254, 53, 255, 252, 50, 0, 255, 252, 39, 3, 2, 5, 44, 89, 0, 18, 4, 33,
-    3, 198, 13, 87, 1, 20, 0, 0, 255, 252, 20, 4, 0, 255, 253, 20, 5, 128, 255, 254, 53,
-    255, 252, 50, 0, 255, 252, 39, 3, 2, 5, 107, 87, 0, 97, 96, 0, 120, 206, 4, 0, 64, 0,
-    72, 101, 108, 108, 111, 32, 116, 104, 101, 114, 101, 33, 10, 82, 85, 83, 84, 76, 65,
-    66, 32, 67, 79, 78, 70, 0, 71, 3, 1, 4, 20, 0, 0, 69, 255, 14, 0, 65, 255, 0, 68, 7,
-    1, 69, 254, 18, 0, 68, 3, 1, 85, 90, 119, 5, 1, 83, 129, 255, 0, 18, 4, 33, 1, 198,
-    13, 87, 1, 20, 0, 0, 255, 252, 20, 0, 0, 255, 253, 20, 0, 0, 255, 254, 53, 255, 252,
-    50, 0, 255, 252, 39, 3, 2, 5, 162, 85, 0, 20, 0, 0, 0, 0, 0, 224, 224, 240, 176, 248,
-    152, 252, 140, 254, 6, 254, 2, 254, 2, 6, 254, 140, 252, 152, 248, 176, 240, 224,
-    224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 127, 127, 64, 127, 64, 127, 64,
-    64, 127, 127, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 192, 224, 96, 240, 48,
-    248, 24, 252, 12, 252, 4, 252, 60, 224, 32, 32, 224, 32, 224, 32, 224, 32, 224, 32,
-    224, 224, 224, 0, 0, 0, 0, 224, 224, 224, 32, 224, 32, 224, 32, 224, 32, 224, 32,
-    224, 32, 252, 60, 4, 252, 12, 252, 24, 248, 48, 240, 224, 224, 192, 192, 0, 0, 0, 0,
-    7, 7, 7, 4, 7, 4, 7, 4, 7, 4, 7, 4, 7, 4, 63, 60, 32, 63, 48, 63, 24, 31, 12, 15, 6,
-    7, 3, 3, 0, 0, 0, 0, 0, 0, 112, 112, 120, 88, 124, 76, 254, 198, 255, 3, 255, 1, 255,
-    1, 3, 255, 198, 254, 76, 124, 88, 120, 112, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 63, 63, 63, 32, 63, 32, 63, 32, 32, 63, 63, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    192, 192, 224, 96, 240, 48, 248, 24, 252, 12, 252, 4, 252, 60, 224, 32, 32, 224, 32,
-    224, 32, 224, 32, 224, 32, 224, 224, 224, 0, 0, 0, 0, 0, 0, 0, 0, 224, 224, 224, 32,
-    224, 32, 224, 32, 224, 32, 224, 32, 224, 32, 252, 60, 4, 252, 12, 252, 24, 248, 48,
-    240, 96, 224, 192, 192, 0, 0, 0, 0, 7, 7, 7, 4, 7, 4, 7, 4, 7, 4, 7, 4, 7, 4, 63, 60,
-    32, 63, 48, 63, 24, 31, 12, 15, 7, 7, 3, 3, 0, 8, 6, 0, 0, 248, 8, 0, 128, 0, 0, 0,
-    0, 8, 0, 0, 0, 248, 2, 0, 128, 0, 0, 0, 0, 8, 4, 0, 0, 248, 4, 32, 128, 0, 0, 0, 0,
-    0, 0, 32, 0, 8, 2, 32, 128, 0, 0, 0, 0, 8, 16, 0, 0, 248, 18, 0, 128, 0, 0, 0, 0, 8,
-    10, 0, 0, 248, 12, 0, 128, 0, 0, 0, 0, 8, 14, 0, 0, 248, 14, 32, 128, 0, 0, 0, 0, 0,
-    10, 32, 0, 8, 12, 32, 128, 0, 0, 0, 222, 91, 234, 91, 246, 91, 2, 92, 14, 92, 222,
-    91, 26, 92, 234, 91, 38, 92, 246, 91, 50, 92, 2, 92, 0, 0, 1, 1, 2, 2, 3, 3, 4, 5, 6,
-    7, 8, 9, 10, 11, 0, 0, 12, 0, 240, 62, 92, 86, 92, 102, 92, 0, 15, 0, 7, 5, 156, 90,
-    0, 0, 0, 4, 0, 0, 0, 224, 224, 224, 32, 224, 32, 224, 32, 224, 32, 224, 32, 224, 32,
-    252, 60, 4, 252, 12, 252, 24, 248, 48, 240, 224, 224, 192, 192, 0, 0, 0, 0, 7, 7, 7,
-    4, 7, 4, 7, 4, 7, 4, 7, 4, 7, 4, 63, 60, 32, 63, 48, 63, 24, 31, 12, 15, 6, 7, 3, 3,
-    0, 0, 0, 8, 0, 0, 0, 248, 2, 0, 128, 0, 0, 0, 189, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 240, 201, 92, 203, 92, 219, 92, 0, 15, 248, 7, 5, 123,
-    92, 0, 0, 0, 33, 30, 197, 54, 0, 33, 31, 197, 54, 0, 33, 31, 218, 54, 0, 201, 201,
-    33, 30, 197, 54, 0, 33, 31, 197, 54, 0, 33, 32, 197, 54, 24, 33, 33, 197, 54, 24, 33,
-    190, 192, 54, 1, 33, 201, 192, 54, 0, 250, 204, 192, 71, 4, 250, 203, 192, 197, 51,
-    245, 51, 17, 185, 192, 213, 30, 2, 33, 98, 69, 205, 8, 0, 232, 4, 201, 232, 249, 33,
-    7, 197, 54, 0, 33, 27, 198, 78, 121, 230, 4, 6, 0, 245, 121, 230, 8, 95, 22, 0, 241,
-    203, 73, 40, 24, 33, 7, 197, 54, 1, 176, 40, 4, 14, 224, 24, 67, 122, 179, 40, 4, 14,
-    160, 24, 59, 14, 192, 24, 55, 203, 65, 40, 24, 33, 7, 197, 54, 1, 176, 40, 4, 14, 32,
-    24, 39, 122, 179, 40, 4, 14, 96, 24, 31, 14, 64, 24, 27, 176, 40, 9, 33, 7, 197, 54,
-    1, 14, 0, 24, 15, 122, 179, 40, 9, 33, 7, 197, 54, 1, 14, 128, 24, 2, 14, 0, 250, 7,
-    197, 183, 202, 42, 95, 33, 200, 192, 70, 17, 186, 192, 26, 248, 5, 34, 19, 26, 119,
-    62, 196, 129, 95, 62, 24, 206, 0, 87, 26, 197, 197, 51, 245, 51, 205, 65, 53, 225,
-    193, 203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203, 27, 203, 42,
-    203, 27, 203, 42, 203, 27, 203, 42, 203, 27, 248, 5, 42, 102, 111, 25, 93, 124, 248,
-    5, 115, 35, 50, 17, 186, 192, 42, 18, 19, 126, 18, 33, 188, 192, 42, 102, 111, 121,
-    198, 64, 198, 196, 95, 62, 0, 206, 24, 87, 26, 229, 197, 51, 245, 51, 205, 65, 53,
-    225, 225, 203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203, 27,
-    203, 42, 203, 27, 203, 42, 203, 27, 203, 42, 203, 27, 125, 147, 79, 124, 154, 71, 33,
-    188, 192, 121, 34, 112, 33, 186, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56, 203, 25,
-    203, 56, 203, 25, 203, 56, 203, 25, 250, 191, 192, 248, 5, 34, 7, 159, 50, 42, 95,
-    86, 121, 147, 95, 120, 154, 87, 33, 52, 197, 42, 147, 126, 154, 48, 23, 248, 5, 42,
-    70, 135, 203, 16, 135, 203, 16, 135, 203, 16, 135, 203, 16, 33, 186, 192, 34, 112,
-    24, 43, 250, 192, 192, 95, 7, 159, 87, 123, 129, 79, 122, 136, 71, 33, 52, 197, 42,
-    145, 126, 152, 48, 21, 43, 42, 147, 79, 126, 154, 71, 105, 96, 41, 41, 41, 41, 77,
-    68, 33, 186, 192, 121, 34, 112, 33, 188, 192, 42, 79, 70, 203, 56, 203, 25, 203, 56,
-    203, 25, 203, 56, 203, 25, 203, 56, 203, 25, 250, 193, 192, 95, 7, 159, 87, 248, 5,
-    121, 34, 112, 75, 66, 58, 110, 103, 9, 77, 68, 33, 54, 197, 42, 145, 126, 152, 48,
-    20, 107, 98, 41, 41, 41, 41, 175, 149, 79, 159, 148, 71, 33, 188, 192, 121, 34, 112,
-    24, 45, 250, 194, 192, 79, 7, 159, 71, 248, 5, 42, 102, 111, 9, 93, 84, 33, 54, 197,
-    42, 147, 126, 154, 48, 21, 43, 42, 145, 79, 126, 152, 71, 105, 96, 41, 41, 41, 41,
-    77, 68, 33, 188, 192, 121, 34, 112, 17, 186, 192, 213, 17, 191, 192, 213, 30, 4, 33,
-    157, 93, 205, 8, 0, 232, 4, 248, 0, 115, 175, 245, 51, 30, 2, 33, 135, 72, 205, 8, 0,
-    51, 75, 66, 248, 0, 126, 60, 40, 20, 248, 0, 110, 38, 0, 41, 41, 41, 17, 33, 200, 25,
-    35, 35, 35, 35, 126, 183, 32, 3, 175, 24, 2, 62, 1, 248, 1, 119, 33, 37, 0, 9, 229,
-    125, 248, 4, 119, 225, 124, 248, 3, 119, 120, 177, 40, 8, 43, 42, 95, 86, 26, 183,
-    32, 3, 175, 24, 2, 62, 1, 248, 4, 119, 248, 1, 126, 183, 32, 6, 248, 4, 126, 183, 40,
-    33, 33, 201, 192, 54, 1, 250, 206, 192, 60, 33, 205, 192, 70, 245, 51, 197, 51, 17,
-    185, 192, 213, 30, 2, 33, 98, 69, 205, 8, 0, 232, 4, 24, 39, 33, 201, 192, 54, 0,
-    250, 204, 192, 248, 6, 50, 60, 119, 250, 203, 192, 248, 6, 119, 248, 5, 42, 87, 94,
-    213, 17, 185, 192, 213, 30, 2, 33, 98, 69, 205, 8, 0, 232, 4, 250, 27, 198, 203, 103,
-    40, 117, 250, 28, 198, 203, 103, 32, 110, 33, 7, 197, 54, 0, 248, 4, 126, 183, 40,
-    80, 43, 43, 42, 95, 86, 107, 98, 35, 229, 125, 248, 7, 119, 225, 124, 248, 6, 50, 42,
-    95, 86, 26, 248, 0, 34, 19, 26, 50, 126, 248, 4, 119, 248, 1, 126, 248, 5, 50, 43,
-    43, 42, 95, 86, 26, 248, 6, 50, 43, 17, 0, 0, 213, 62, 1, 245, 51, 17, 0, 0, 213, 42,
-    95, 42, 87, 213, 126, 245, 51, 30, 3, 33, 144, 93, 205, 8, 0, 232, 8, 24, 19, 248, 1,
-    126, 183, 40, 13, 43, 126, 245, 51, 30, 4, 33, 18, 93, 205, 8, 0, 51, 232, 7, 201,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255,
-    255, 255, 255, 255, 255, 255, 255, 255, 255,
-];